@@ -12,6 +12,9 @@ pub enum ProviderError {
     InvalidCredentials {
         provider: String,
         raw_message: Option<String>,
+        /// 上游请求 ID（提交工单时需要）
+        #[serde(skip_serializing_if = "Option::is_none")]
+        request_id: Option<String>,
     },
 
     /// 记录已存在
@@ -19,6 +22,12 @@ pub enum ProviderError {
         provider: String,
         record_name: String,
         raw_message: Option<String>,
+        /// 冲突记录的类型和当前值（创建前预检发现冲突时填充）
+        #[serde(skip_serializing_if = "Option::is_none")]
+        existing_value: Option<String>,
+        /// 上游请求 ID（提交工单时需要）
+        #[serde(skip_serializing_if = "Option::is_none")]
+        request_id: Option<String>,
     },
 
     /// 记录不存在
@@ -26,6 +35,9 @@ pub enum ProviderError {
         provider: String,
         record_id: String,
         raw_message: Option<String>,
+        /// 上游请求 ID（提交工单时需要）
+        #[serde(skip_serializing_if = "Option::is_none")]
+        request_id: Option<String>,
     },
 
     /// 参数无效（TTL、值等）
@@ -33,12 +45,18 @@ pub enum ProviderError {
         provider: String,
         param: String,
         detail: String,
+        /// 上游请求 ID（提交工单时需要）
+        #[serde(skip_serializing_if = "Option::is_none")]
+        request_id: Option<String>,
     },
 
     /// 配额超限
     QuotaExceeded {
         provider: String,
         raw_message: Option<String>,
+        /// 上游请求 ID（提交工单时需要）
+        #[serde(skip_serializing_if = "Option::is_none")]
+        request_id: Option<String>,
     },
 
     /// 域名不存在
@@ -46,6 +64,9 @@ pub enum ProviderError {
         provider: String,
         domain: String,
         raw_message: Option<String>,
+        /// 上游请求 ID（提交工单时需要）
+        #[serde(skip_serializing_if = "Option::is_none")]
+        request_id: Option<String>,
     },
 
     /// 域名被锁定/禁用
@@ -53,12 +74,18 @@ pub enum ProviderError {
         provider: String,
         domain: String,
         raw_message: Option<String>,
+        /// 上游请求 ID（提交工单时需要）
+        #[serde(skip_serializing_if = "Option::is_none")]
+        request_id: Option<String>,
     },
 
     /// 权限/操作被拒绝
     PermissionDenied {
         provider: String,
         raw_message: Option<String>,
+        /// 上游请求 ID（提交工单时需要）
+        #[serde(skip_serializing_if = "Option::is_none")]
+        request_id: Option<String>,
     },
 
     /// 响应解析失败
@@ -67,16 +94,84 @@ pub enum ProviderError {
     /// 序列化/反序列化失败
     SerializationError { provider: String, detail: String },
 
+    /// Provider 不支持该功能
+    NotSupported {
+        provider: String,
+        capability: String,
+    },
+
     /// 未知错误（fallback）
     Unknown {
         provider: String,
         raw_code: Option<String>,
         raw_message: String,
+        /// 上游请求 ID（提交工单时需要）
+        #[serde(skip_serializing_if = "Option::is_none")]
+        request_id: Option<String>,
     },
 }
 
-impl std::fmt::Display for ProviderError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ProviderError {
+    /// 上游请求 ID（如 Cloudflare 的 CF-Ray、腾讯云 / 阿里云的 `RequestId`）
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            Self::InvalidCredentials { request_id, .. }
+            | Self::RecordExists { request_id, .. }
+            | Self::RecordNotFound { request_id, .. }
+            | Self::InvalidParameter { request_id, .. }
+            | Self::QuotaExceeded { request_id, .. }
+            | Self::DomainNotFound { request_id, .. }
+            | Self::DomainLocked { request_id, .. }
+            | Self::PermissionDenied { request_id, .. }
+            | Self::Unknown { request_id, .. } => request_id.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// 是否为可重试的瞬时错误（网络故障、配额/限流），凭证无效、记录冲突等返回 false
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::NetworkError { .. } | Self::QuotaExceeded { .. })
+    }
+
+    /// 设置上游请求 ID（不携带请求 ID 的错误类型忽略）
+    #[must_use]
+    pub fn with_request_id(mut self, id: Option<String>) -> Self {
+        match &mut self {
+            Self::InvalidCredentials { request_id, .. }
+            | Self::RecordExists { request_id, .. }
+            | Self::RecordNotFound { request_id, .. }
+            | Self::InvalidParameter { request_id, .. }
+            | Self::QuotaExceeded { request_id, .. }
+            | Self::DomainNotFound { request_id, .. }
+            | Self::DomainLocked { request_id, .. }
+            | Self::PermissionDenied { request_id, .. }
+            | Self::Unknown { request_id, .. } => *request_id = id,
+            _ => {}
+        }
+        self
+    }
+
+    /// 在记录名称或值无效的错误中附上提交的内容（其他错误原样返回）
+    #[must_use]
+    pub fn with_record_input(mut self, name: &str, value: &str) -> Self {
+        let input = match &self {
+            Self::InvalidParameter { param, .. } => match param.as_str() {
+                "name" | "subdomain" => name,
+                "value" => value,
+                _ => return self,
+            },
+            _ => return self,
+        };
+        if let Self::InvalidParameter { detail, .. } = &mut self
+            && !input.is_empty()
+            && !detail.contains(input)
+        {
+            *detail = format!("{detail}（提交的值: {input}）");
+        }
+        self
+    }
+
+    fn fmt_message(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::NetworkError { provider, detail } => {
                 write!(f, "[{provider}] Network error: {detail}")
@@ -84,6 +179,7 @@ impl std::fmt::Display for ProviderError {
             Self::InvalidCredentials {
                 provider,
                 raw_message,
+                ..
             } => {
                 if let Some(msg) = raw_message {
                     write!(f, "[{provider}] Invalid credentials: {msg}")
@@ -94,9 +190,17 @@ impl std::fmt::Display for ProviderError {
             Self::RecordExists {
                 provider,
                 record_name,
+                existing_value,
                 ..
             } => {
-                write!(f, "[{provider}] Record '{record_name}' already exists")
+                if let Some(existing) = existing_value {
+                    write!(
+                        f,
+                        "[{provider}] Record '{record_name}' already exists: {existing}"
+                    )
+                } else {
+                    write!(f, "[{provider}] Record '{record_name}' already exists")
+                }
             }
             Self::RecordNotFound {
                 provider,
@@ -109,6 +213,7 @@ impl std::fmt::Display for ProviderError {
                 provider,
                 param,
                 detail,
+                ..
             } => {
                 write!(f, "[{provider}] Invalid parameter '{param}': {detail}")
             }
@@ -119,6 +224,7 @@ impl std::fmt::Display for ProviderError {
                 provider,
                 domain,
                 raw_message,
+                ..
             } => {
                 if let Some(msg) = raw_message {
                     write!(f, "[{provider}] Domain '{domain}' not found: {msg}")
@@ -130,6 +236,7 @@ impl std::fmt::Display for ProviderError {
                 provider,
                 domain,
                 raw_message,
+                ..
             } => {
                 if let Some(msg) = raw_message {
                     write!(f, "[{provider}] Domain '{domain}' is locked: {msg}")
@@ -140,6 +247,7 @@ impl std::fmt::Display for ProviderError {
             Self::PermissionDenied {
                 provider,
                 raw_message,
+                ..
             } => {
                 if let Some(msg) = raw_message {
                     write!(f, "[{provider}] Permission denied: {msg}")
@@ -153,6 +261,12 @@ impl std::fmt::Display for ProviderError {
             Self::SerializationError { provider, detail } => {
                 write!(f, "[{provider}] Serialization error: {detail}")
             }
+            Self::NotSupported {
+                provider,
+                capability,
+            } => {
+                write!(f, "[{provider}] Not supported: {capability}")
+            }
             Self::Unknown {
                 provider,
                 raw_message,
@@ -164,6 +278,16 @@ impl std::fmt::Display for ProviderError {
     }
 }
 
+impl std::fmt::Display for ProviderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_message(f)?;
+        if let Some(id) = self.request_id() {
+            write!(f, " (Request ID: {id})")?;
+        }
+        Ok(())
+    }
+}
+
 impl std::error::Error for ProviderError {}
 
 /// 库的统一 Result 类型
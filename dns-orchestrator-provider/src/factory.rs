@@ -2,11 +2,11 @@
 
 use std::sync::Arc;
 
-use crate::error::Result;
+use crate::error::{ProviderError, Result};
 use crate::traits::DnsProvider;
 use crate::types::{
-    FieldType, ProviderCredentialField, ProviderCredentials, ProviderFeatures, ProviderMetadata,
-    ProviderType,
+    DnsRecordType, FieldType, ProviderClientConfig, ProviderCredentialField, ProviderCredentials,
+    ProviderFeatures, ProviderMetadata, ProviderType, RateLimit, TtlRange,
 };
 
 #[cfg(feature = "aliyun")]
@@ -20,36 +20,173 @@ use crate::providers::HuaweicloudProvider;
 
 /// 工厂函数 - 根据凭证类型创建 Provider 实例
 pub fn create_provider(credentials: ProviderCredentials) -> Result<Arc<dyn DnsProvider>> {
+    create_provider_with_config(credentials, &ProviderClientConfig::default())
+}
+
+/// 工厂函数 - 使用自定义 HTTP 客户端配置（代理、超时等）创建 Provider 实例
+pub fn create_provider_with_config(
+    credentials: ProviderCredentials,
+    config: &ProviderClientConfig,
+) -> Result<Arc<dyn DnsProvider>> {
+    let provider = credentials.provider_type();
+    credentials
+        .validate()
+        .map_err(|e| ProviderError::InvalidParameter {
+            provider: provider.to_string(),
+            param: e.field().to_string(),
+            detail: e.to_string(),
+            request_id: None,
+        })?;
+    let client = config.build_client(provider.as_str())?;
+
     match credentials {
         #[cfg(feature = "cloudflare")]
         ProviderCredentials::Cloudflare { api_token } => {
-            Ok(Arc::new(CloudflareProvider::new(api_token)))
+            Ok(Arc::new(CloudflareProvider::with_client(client, api_token)))
         }
         #[cfg(feature = "aliyun")]
         ProviderCredentials::Aliyun {
             access_key_id,
             access_key_secret,
-        } => Ok(Arc::new(AliyunProvider::new(
+            region,
+        } => Ok(Arc::new(AliyunProvider::with_client(
+            client,
             access_key_id,
             access_key_secret,
+            region,
         ))),
         #[cfg(feature = "dnspod")]
         ProviderCredentials::Dnspod {
             secret_id,
             secret_key,
-        } => Ok(Arc::new(DnspodProvider::new(secret_id, secret_key))),
+        } => Ok(Arc::new(DnspodProvider::with_client(
+            client, secret_id, secret_key,
+        ))),
         #[cfg(feature = "huaweicloud")]
         ProviderCredentials::Huaweicloud {
             access_key_id,
             secret_access_key,
-        } => Ok(Arc::new(HuaweicloudProvider::new(
-            access_key_id,
-            secret_access_key,
-        ))),
+            region,
+            zone_type,
+        } => Ok(Arc::new(
+            HuaweicloudProvider::with_client(client, access_key_id, secret_access_key, region)
+                .with_zone_type(zone_type.as_deref()),
+        )),
+    }
+}
+
+/// 获取提供商支持的功能
+pub fn get_provider_features(provider: &ProviderType) -> ProviderFeatures {
+    match provider {
+        #[cfg(feature = "cloudflare")]
+        ProviderType::Cloudflare => ProviderFeatures {
+            proxy: true,
+            ttl_range: TtlRange {
+                min: 60,
+                max: 86400,
+                auto: Some(1),
+            },
+            // ALIAS 以 CNAME 创建，根域时由 Cloudflare 自动展平
+            supported_record_types: vec![
+                DnsRecordType::A,
+                DnsRecordType::Aaaa,
+                DnsRecordType::Cname,
+                DnsRecordType::Mx,
+                DnsRecordType::Txt,
+                DnsRecordType::Ns,
+                DnsRecordType::Srv,
+                DnsRecordType::Caa,
+                DnsRecordType::Alias,
+            ],
+            supports_apex_cname: true,
+            // 每个用户 5 分钟内 1200 次
+            rate_limit: Some(RateLimit {
+                requests: 1200,
+                window_secs: 300,
+            }),
+        },
+        #[cfg(feature = "aliyun")]
+        ProviderType::Aliyun => ProviderFeatures {
+            proxy: false,
+            // 免费版最小 TTL 为 600
+            ttl_range: TtlRange {
+                min: 600,
+                max: 86400,
+                auto: None,
+            },
+            // 不支持 ALIAS；显性/隐性 URL 转发不属于标准记录类型，未纳入
+            supported_record_types: vec![
+                DnsRecordType::A,
+                DnsRecordType::Aaaa,
+                DnsRecordType::Cname,
+                DnsRecordType::Mx,
+                DnsRecordType::Txt,
+                DnsRecordType::Ns,
+                DnsRecordType::Srv,
+                DnsRecordType::Caa,
+            ],
+            supports_apex_cname: false,
+            // 云解析 API 单用户 QPS 限制
+            rate_limit: Some(RateLimit {
+                requests: 20,
+                window_secs: 1,
+            }),
+        },
+        #[cfg(feature = "dnspod")]
+        ProviderType::Dnspod => ProviderFeatures {
+            proxy: false,
+            // 免费套餐最小 TTL 为 600
+            ttl_range: TtlRange {
+                min: 600,
+                max: 604_800,
+                auto: None,
+            },
+            // 不支持 ALIAS；URL 转发不属于标准记录类型，未纳入
+            supported_record_types: vec![
+                DnsRecordType::A,
+                DnsRecordType::Aaaa,
+                DnsRecordType::Cname,
+                DnsRecordType::Mx,
+                DnsRecordType::Txt,
+                DnsRecordType::Ns,
+                DnsRecordType::Srv,
+                DnsRecordType::Caa,
+            ],
+            supports_apex_cname: false,
+            // 腾讯云 API 3.0 默认每个接口每秒 20 次
+            rate_limit: Some(RateLimit {
+                requests: 20,
+                window_secs: 1,
+            }),
+        },
+        #[cfg(feature = "huaweicloud")]
+        ProviderType::Huaweicloud => ProviderFeatures {
+            proxy: false,
+            ttl_range: TtlRange {
+                min: 1,
+                max: 2_147_483_647,
+                auto: None,
+            },
+            // 公网 Zone 支持的类型，不支持 ALIAS；内网 Zone 另不支持 NS 和 CAA，由 Provider 按 Zone 类型校验
+            supported_record_types: vec![
+                DnsRecordType::A,
+                DnsRecordType::Aaaa,
+                DnsRecordType::Cname,
+                DnsRecordType::Mx,
+                DnsRecordType::Txt,
+                DnsRecordType::Ns,
+                DnsRecordType::Srv,
+                DnsRecordType::Caa,
+            ],
+            supports_apex_cname: false,
+            // 未公开统一的限流额度
+            rate_limit: None,
+        },
     }
 }
 
 /// 获取所有支持的提供商元数据
+#[allow(clippy::vec_init_then_push)]
 pub fn get_all_provider_metadata() -> Vec<ProviderMetadata> {
     let mut providers = Vec::new();
 
@@ -64,8 +201,9 @@ pub fn get_all_provider_metadata() -> Vec<ProviderMetadata> {
             field_type: FieldType::Password,
             placeholder: Some("输入 Cloudflare API Token".to_string()),
             help_text: Some("在 Cloudflare Dashboard -> My Profile -> API Tokens 创建".to_string()),
+            optional: false,
         }],
-        features: ProviderFeatures { proxy: true },
+        features: get_provider_features(&ProviderType::Cloudflare),
     });
 
     #[cfg(feature = "aliyun")]
@@ -80,6 +218,7 @@ pub fn get_all_provider_metadata() -> Vec<ProviderMetadata> {
                 field_type: FieldType::Text,
                 placeholder: Some("输入 AccessKey ID".to_string()),
                 help_text: None,
+                optional: false,
             },
             ProviderCredentialField {
                 key: "accessKeySecret".to_string(),
@@ -87,9 +226,21 @@ pub fn get_all_provider_metadata() -> Vec<ProviderMetadata> {
                 field_type: FieldType::Password,
                 placeholder: Some("输入 AccessKey Secret".to_string()),
                 help_text: None,
+                optional: false,
+            },
+            ProviderCredentialField {
+                key: "region".to_string(),
+                label: "地域".to_string(),
+                field_type: FieldType::Text,
+                placeholder: Some("cn-hangzhou".to_string()),
+                help_text: Some(
+                    "可选，国际站（alibabacloud.com 控制台创建的 AccessKey）填写 ap-southeast-1，也可填写完整的 endpoint 域名"
+                        .to_string(),
+                ),
+                optional: true,
             },
         ],
-        features: ProviderFeatures::default(),
+        features: get_provider_features(&ProviderType::Aliyun),
     });
 
     #[cfg(feature = "dnspod")]
@@ -104,6 +255,7 @@ pub fn get_all_provider_metadata() -> Vec<ProviderMetadata> {
                 field_type: FieldType::Text,
                 placeholder: Some("输入 SecretId".to_string()),
                 help_text: None,
+                optional: false,
             },
             ProviderCredentialField {
                 key: "secretKey".to_string(),
@@ -111,9 +263,10 @@ pub fn get_all_provider_metadata() -> Vec<ProviderMetadata> {
                 field_type: FieldType::Password,
                 placeholder: Some("输入 SecretKey".to_string()),
                 help_text: None,
+                optional: false,
             },
         ],
-        features: ProviderFeatures::default(),
+        features: get_provider_features(&ProviderType::Dnspod),
     });
 
     #[cfg(feature = "huaweicloud")]
@@ -128,6 +281,7 @@ pub fn get_all_provider_metadata() -> Vec<ProviderMetadata> {
                 field_type: FieldType::Text,
                 placeholder: Some("输入 Access Key ID".to_string()),
                 help_text: None,
+                optional: false,
             },
             ProviderCredentialField {
                 key: "secretAccessKey".to_string(),
@@ -135,9 +289,32 @@ pub fn get_all_provider_metadata() -> Vec<ProviderMetadata> {
                 field_type: FieldType::Password,
                 placeholder: Some("输入 Secret Access Key".to_string()),
                 help_text: None,
+                optional: false,
+            },
+            ProviderCredentialField {
+                key: "region".to_string(),
+                label: "地域".to_string(),
+                field_type: FieldType::Text,
+                placeholder: Some("ap-southeast-1".to_string()),
+                help_text: Some(
+                    "可选，留空使用全局 endpoint，也可填写完整的 endpoint 域名；管理内网 Zone 时必填"
+                        .to_string(),
+                ),
+                optional: true,
+            },
+            ProviderCredentialField {
+                key: "zoneType".to_string(),
+                label: "Zone 类型".to_string(),
+                field_type: FieldType::Text,
+                placeholder: Some("public".to_string()),
+                help_text: Some(
+                    "可选，填写 private 管理 VPC 内网 Zone（需同时填写地域），留空为公网 Zone"
+                        .to_string(),
+                ),
+                optional: true,
             },
         ],
-        features: ProviderFeatures::default(),
+        features: get_provider_features(&ProviderType::Huaweicloud),
     });
 
     providers
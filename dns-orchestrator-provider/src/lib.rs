@@ -45,17 +45,23 @@ mod types;
 pub use error::{ProviderError, Result};
 
 // Re-export factory functions
-pub use factory::{create_provider, get_all_provider_metadata};
+pub use factory::{
+    create_provider, create_provider_with_config, get_all_provider_metadata, get_provider_features,
+};
+
+// Re-export core trait and the shared conflict check (internal traits are not exported)
+pub use traits::{DnsProvider, find_conflict};
 
-// Re-export core trait only (internal traits are not exported)
-pub use traits::DnsProvider;
+// Re-export IDN and record value helpers
+pub use providers::common::{domain_to_ascii, domain_to_unicode, normalize_record_value};
 
 // Re-export types
 pub use types::{
-    CreateDnsRecordRequest, CredentialValidationError, DnsRecord, DnsRecordType, Domain,
-    DomainStatus, FieldType, PaginatedResponse, PaginationParams, ProviderCredentialField,
-    ProviderCredentials, ProviderFeatures, ProviderMetadata, ProviderType, RecordQueryParams,
-    UpdateDnsRecordRequest,
+    CreateDnsRecordRequest, CredentialValidationError, DnsRecord, DnsRecordType, DnssecStatus,
+    Domain, DomainStatus, FieldType, PaginatedResponse, PaginationParams, PermissionInfo,
+    ProviderClientConfig, ProviderCredentialField, ProviderCredentials, ProviderFeatures,
+    ProviderMetadata, ProviderType, RateLimit, RecordLine, RecordPatch, RecordQueryParams,
+    RecordSort, RecordValidationError, TtlRange, UpdateDnsRecordRequest,
 };
 
 // Re-export concrete providers (behind feature flags)
@@ -2,6 +2,7 @@
 
 use crate::error::ProviderError;
 use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
+use crate::types::ProviderType;
 
 use super::AliyunProvider;
 
@@ -9,7 +10,7 @@ use super::AliyunProvider;
 /// 参考: <https://api.aliyun.com/document/Alidns/2015-01-09/errorCode>
 impl ProviderErrorMapper for AliyunProvider {
     fn provider_name(&self) -> &'static str {
-        "aliyun"
+        ProviderType::Aliyun.as_str()
     }
 
     fn map_error(&self, raw: RawApiError, context: ErrorContext) -> ProviderError {
@@ -19,6 +20,7 @@ impl ProviderErrorMapper for AliyunProvider {
                 ProviderError::InvalidCredentials {
                     provider: self.provider_name().to_string(),
                     raw_message: Some(raw.message),
+                    request_id: None,
                 }
             }
             // 记录已存在
@@ -26,6 +28,8 @@ impl ProviderErrorMapper for AliyunProvider {
                 provider: self.provider_name().to_string(),
                 record_name: context.record_name.unwrap_or_default(),
                 raw_message: Some(raw.message),
+                existing_value: None,
+                request_id: None,
             },
             // 记录不存在
             Some("DomainRecordNotBelongToUser" | "InvalidRecordId.NotFound") => {
@@ -33,13 +37,29 @@ impl ProviderErrorMapper for AliyunProvider {
                     provider: self.provider_name().to_string(),
                     record_id: context.record_id.unwrap_or_default(),
                     raw_message: Some(raw.message),
+                    request_id: None,
                 }
             }
+            // 主机记录格式错误
+            Some("InvalidRR.Malformed") => ProviderError::InvalidParameter {
+                provider: self.provider_name().to_string(),
+                param: "name".to_string(),
+                detail: raw.message,
+                request_id: None,
+            },
+            // 记录值格式错误
+            Some("InvalidValue.Malformed") => ProviderError::InvalidParameter {
+                provider: self.provider_name().to_string(),
+                param: "value".to_string(),
+                detail: raw.message,
+                request_id: None,
+            },
             // 域名不存在
             Some("InvalidDomainName.NoExist") => ProviderError::DomainNotFound {
                 provider: self.provider_name().to_string(),
                 domain: context.domain.unwrap_or_default(),
                 raw_message: Some(raw.message),
+                request_id: None,
             },
             // 其他错误 fallback
             _ => self.unknown_error(raw),
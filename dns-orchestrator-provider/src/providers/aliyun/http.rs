@@ -4,10 +4,11 @@ use chrono::Utc;
 use serde::{Deserialize, Serialize};
 
 use crate::error::Result;
+use crate::providers::common::redact;
 use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
 
 use super::{
-    ALIYUN_DNS_HOST, ALIYUN_DNS_VERSION, AliyunProvider, AliyunResponse, EMPTY_BODY_SHA256,
+    ALIYUN_DNS_VERSION, AliyunProvider, AliyunResponse, EMPTY_BODY_SHA256,
     serialize_to_query_string,
 };
 
@@ -17,6 +18,7 @@ impl AliyunProvider {
         &self,
         action: &str,
         params: &B,
+        ctx: ErrorContext,
     ) -> Result<T> {
         // 1. 序列化参数为 query string
         let query_string = serialize_to_query_string(params)?;
@@ -29,18 +31,18 @@ impl AliyunProvider {
 
         // 3. 构造 URL (参数在 query string 中)
         let url = if query_string.is_empty() {
-            format!("https://{ALIYUN_DNS_HOST}/")
+            format!("{}/", self.base_url)
         } else {
-            format!("https://{ALIYUN_DNS_HOST}/?{query_string}")
+            format!("{}/?{query_string}", self.base_url)
         };
 
-        log::debug!("POST {url} Action: {action}");
+        log::debug!("POST {} Action: {action}", redact(&url));
 
         // 4. 发送请求 (body 为空)
         let response = self
             .client
             .post(&url)
-            .header("Host", ALIYUN_DNS_HOST)
+            .header("Host", &self.host)
             .header("x-acs-action", action)
             .header("x-acs-version", ALIYUN_DNS_VERSION)
             .header("x-acs-date", &timestamp)
@@ -59,23 +61,22 @@ impl AliyunProvider {
             .await
             .map_err(|e| self.network_error(format!("读取响应失败: {e}")))?;
 
-        log::debug!("Response Body: {response_text}");
+        log::debug!("Response Body: {}", redact(&response_text));
 
         // 先检查是否有错误响应
         if let Ok(error_response) = serde_json::from_str::<AliyunResponse<()>>(&response_text)
             && let (Some(code), Some(message)) = (error_response.code, error_response.message)
         {
             log::error!("API 错误: {code} - {message}");
-            return Err(self.map_error(
-                RawApiError::with_code(&code, &message),
-                ErrorContext::default(),
-            ));
+            return Err(self
+                .map_error(RawApiError::with_code(&code, &message), ctx)
+                .with_request_id(error_response.request_id));
         }
 
         // 解析成功响应
         serde_json::from_str(&response_text).map_err(|e| {
             log::error!("JSON 解析失败: {e}");
-            log::error!("原始响应: {response_text}");
+            log::error!("原始响应: {}", redact(&response_text));
             self.parse_error(e)
         })
     }
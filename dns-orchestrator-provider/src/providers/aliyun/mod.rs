@@ -6,15 +6,19 @@ mod provider;
 mod sign;
 mod types;
 
+#[cfg(test)]
+mod tests;
+
 use reqwest::Client;
 
 pub(crate) use types::{
     AddDomainRecordResponse, AliyunResponse, DeleteDomainRecordResponse,
-    DescribeDomainRecordsResponse, DescribeDomainsResponse, UpdateDomainRecordResponse,
-    serialize_to_query_string,
+    DescribeDomainInfoResponse, DescribeDomainRecordsResponse, DescribeDomainsResponse,
+    DescribeSupportLinesResponse, UpdateDomainRecordResponse, serialize_to_query_string,
 };
 
-pub(crate) const ALIYUN_DNS_HOST: &str = "alidns.cn-hangzhou.aliyuncs.com";
+/// 默认地域
+pub(crate) const ALIYUN_DEFAULT_REGION: &str = "cn-hangzhou";
 pub(crate) const ALIYUN_DNS_VERSION: &str = "2015-01-09";
 /// 空 body 的 SHA256 hash (固定值)
 pub(crate) const EMPTY_BODY_SHA256: &str =
@@ -25,14 +29,57 @@ pub struct AliyunProvider {
     pub(crate) client: Client,
     pub(crate) access_key_id: String,
     pub(crate) access_key_secret: String,
+    /// API 域名，如 alidns.cn-hangzhou.aliyuncs.com
+    pub(crate) host: String,
+    /// API 根地址，默认为 `https://{host}`
+    pub(crate) base_url: String,
 }
 
 impl AliyunProvider {
-    pub fn new(access_key_id: String, access_key_secret: String) -> Self {
+    /// `region` 可以是地域 ID（如 ap-southeast-1）或完整的 endpoint 域名，None 时使用 cn-hangzhou
+    pub fn new(access_key_id: String, access_key_secret: String, region: Option<String>) -> Self {
+        Self::with_client(Client::new(), access_key_id, access_key_secret, region)
+    }
+
+    /// 使用自定义 HTTP 客户端创建（代理、超时等配置）
+    pub fn with_client(
+        client: Client,
+        access_key_id: String,
+        access_key_secret: String,
+        region: Option<String>,
+    ) -> Self {
+        let host = Self::resolve_host(region.as_deref());
         Self {
-            client: Client::new(),
+            client,
             access_key_id,
             access_key_secret,
+            base_url: format!("https://{host}"),
+            host,
+        }
+    }
+
+    /// 替换 API 根地址（测试时指向 mock server）
+    #[cfg(test)]
+    pub(crate) fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// 根据地域或 endpoint 得到 API 域名
+    fn resolve_host(region: Option<&str>) -> String {
+        let region = region
+            .map(str::trim)
+            .filter(|r| !r.is_empty())
+            .unwrap_or(ALIYUN_DEFAULT_REGION);
+
+        // 包含 "." 视为完整 endpoint（如金融云），去掉可能带上的协议前缀
+        if region.contains('.') {
+            let host = region
+                .trim_start_matches("https://")
+                .trim_start_matches("http://");
+            host.trim_end_matches('/').to_string()
+        } else {
+            format!("alidns.{region}.aliyuncs.com")
         }
     }
 }
@@ -5,16 +5,21 @@ use chrono::DateTime;
 use serde::Serialize;
 
 use crate::error::{ProviderError, Result};
-use crate::providers::common::{parse_record_type, record_type_to_string};
-use crate::traits::{DnsProvider, ProviderErrorMapper};
+use crate::providers::common::{
+    check_apex_cname, check_record_type, check_ttl, normalize_record_value, parse_modified_after,
+    parse_record_type, record_type_to_string, relative_to_full_name, retain_modified_after,
+    sort_records,
+};
+use crate::traits::{DnsProvider, ErrorContext, ProviderErrorMapper, collect_matching_records};
 use crate::types::{
-    CreateDnsRecordRequest, DnsRecord, Domain, DomainStatus, PaginatedResponse, PaginationParams,
-    ProviderType, RecordQueryParams, UpdateDnsRecordRequest,
+    CreateDnsRecordRequest, DnsRecord, DnsRecordType, Domain, DomainStatus, PaginatedResponse,
+    PaginationParams, ProviderType, RecordLine, RecordQueryParams, UpdateDnsRecordRequest,
 };
 
 use super::{
     AddDomainRecordResponse, AliyunProvider, DeleteDomainRecordResponse,
-    DescribeDomainRecordsResponse, DescribeDomainsResponse, UpdateDomainRecordResponse,
+    DescribeDomainInfoResponse, DescribeDomainRecordsResponse, DescribeDomainsResponse,
+    DescribeSupportLinesResponse, UpdateDomainRecordResponse,
 };
 
 impl AliyunProvider {
@@ -38,7 +43,7 @@ impl AliyunProvider {
 #[async_trait]
 impl DnsProvider for AliyunProvider {
     fn id(&self) -> &'static str {
-        "aliyun"
+        ProviderType::Aliyun.as_str()
     }
 
     async fn validate_credentials(&self) -> Result<bool> {
@@ -56,15 +61,12 @@ impl DnsProvider for AliyunProvider {
         };
 
         match self
-            .request::<DescribeDomainsResponse, _>("DescribeDomains", &req)
+            .request::<DescribeDomainsResponse, _>("DescribeDomains", &req, ErrorContext::default())
             .await
         {
             Ok(_) => Ok(true),
             Err(ProviderError::InvalidCredentials { .. }) => Ok(false),
-            Err(e) => {
-                log::warn!("凭证验证失败: {e}");
-                Ok(false)
-            }
+            Err(e) => Err(e),
         }
     }
 
@@ -82,7 +84,9 @@ impl DnsProvider for AliyunProvider {
             page_size: params.page_size.min(100), // 阿里云最大支持 100
         };
 
-        let response: DescribeDomainsResponse = self.request("DescribeDomains", &req).await?;
+        let response: DescribeDomainsResponse = self
+            .request("DescribeDomains", &req, ErrorContext::default())
+            .await?;
 
         let total_count = response.total_count.unwrap_or(0);
         let domains = response
@@ -108,23 +112,58 @@ impl DnsProvider for AliyunProvider {
     }
 
     async fn get_domain(&self, domain_id: &str) -> Result<Domain> {
-        // 阿里云 API 需要域名名称，先从域名列表中查找
-        // 使用大页面一次性获取用于查找
-        let params = PaginationParams {
+        // 传入的是域名名称时直接查询
+        if domain_id.contains('.') {
+            #[derive(Serialize)]
+            struct DescribeDomainInfoRequest {
+                #[serde(rename = "DomainName")]
+                domain_name: String,
+            }
+
+            let req = DescribeDomainInfoRequest {
+                domain_name: domain_id.to_string(),
+            };
+            let info: DescribeDomainInfoResponse = self
+                .request(
+                    "DescribeDomainInfo",
+                    &req,
+                    ErrorContext::for_domain(domain_id),
+                )
+                .await?;
+
+            return Ok(Domain {
+                id: info.domain_id.unwrap_or_else(|| info.domain_name.clone()),
+                name: info.domain_name,
+                provider: ProviderType::Aliyun,
+                status: DomainStatus::Unknown,
+                record_count: info.record_count,
+            });
+        }
+
+        // 阿里云没有按 DomainId 查询的接口，逐页查找（不限于第一页）
+        let mut params = PaginationParams {
             page: 1,
             page_size: 100,
+            ..Default::default()
         };
-        let response = self.list_domains(&params).await?;
+        loop {
+            let response = self.list_domains(&params).await?;
+            let fetched = response.items.len();
+            if let Some(domain) = response.items.into_iter().find(|d| d.id == domain_id) {
+                return Ok(domain);
+            }
+            if !response.has_more || fetched == 0 {
+                break;
+            }
+            params.page += 1;
+        }
 
-        response
-            .items
-            .into_iter()
-            .find(|d| d.id == domain_id || d.name == domain_id)
-            .ok_or_else(|| ProviderError::DomainNotFound {
-                provider: self.provider_name().to_string(),
-                domain: domain_id.to_string(),
-                raw_message: None,
-            })
+        Err(ProviderError::DomainNotFound {
+            provider: self.provider_name().to_string(),
+            domain: domain_id.to_string(),
+            raw_message: None,
+            request_id: None,
+        })
     }
 
     async fn list_records(
@@ -148,11 +187,13 @@ impl DnsProvider for AliyunProvider {
             record_type: Option<String>,
         }
 
+        let modified_after = parse_modified_after(&ProviderType::Aliyun, params)?;
+
         // 获取域名信息 (因为 API 需要域名名称而不是 ID)
         let domain_info = self.get_domain(domain_id).await?;
 
         let req = DescribeDomainRecordsRequest {
-            domain_name: domain_info.name,
+            domain_name: domain_info.name.clone(),
             page_number: params.page,
             page_size: params.page_size.min(100), // 阿里云最大支持 100
             rr_keyword: params.keyword.clone().filter(|k| !k.is_empty()),
@@ -162,31 +203,43 @@ impl DnsProvider for AliyunProvider {
                 .map(|t| record_type_to_string(t).to_string()),
         };
 
-        let response: DescribeDomainRecordsResponse =
-            self.request("DescribeDomainRecords", &req).await?;
+        let response: DescribeDomainRecordsResponse = self
+            .request(
+                "DescribeDomainRecords",
+                &req,
+                ErrorContext::for_domain(&domain_info.name),
+            )
+            .await?;
 
         let total_count = response.total_count.unwrap_or(0);
-        let records = response
+        let mut records: Vec<DnsRecord> = response
             .domain_records
             .and_then(|r| r.record)
             .unwrap_or_default()
             .into_iter()
             .filter_map(|r| {
                 let record_type = parse_record_type(&r.record_type, "aliyun").ok()?;
+                let value = normalize_record_value(&record_type, &r.value);
                 Some(DnsRecord {
                     id: r.record_id,
                     domain_id: domain_id.to_string(),
                     record_type,
+                    fqdn: Some(relative_to_full_name(&r.rr, &domain_info.name)),
                     name: r.rr,
-                    value: r.value,
+                    value,
                     ttl: r.ttl,
                     priority: r.priority,
                     proxied: None, // 阿里云不支持代理
+                    line: r.line,
+                    remark: None,
+                    enabled: None,
                     created_at: Self::timestamp_to_rfc3339(r.create_timestamp),
                     updated_at: Self::timestamp_to_rfc3339(r.update_timestamp),
                 })
             })
             .collect();
+        retain_modified_after(&mut records, modified_after);
+        sort_records(&mut records, params);
 
         Ok(PaginatedResponse::new(
             records,
@@ -196,7 +249,28 @@ impl DnsProvider for AliyunProvider {
         ))
     }
 
+    /// 使用 `RRKeyWord` 在服务端按主机记录过滤（模糊匹配），本地再精确过滤
+    async fn find_records(
+        &self,
+        domain_id: &str,
+        name: &str,
+        record_type: Option<DnsRecordType>,
+    ) -> Result<Vec<DnsRecord>> {
+        collect_matching_records(
+            self,
+            domain_id,
+            Some(name.to_string()),
+            name,
+            record_type.as_ref(),
+        )
+        .await
+    }
+
     async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
+        check_record_type(&ProviderType::Aliyun, &req.record_type)?;
+        check_ttl(&ProviderType::Aliyun, req.ttl)?;
+        check_apex_cname(&ProviderType::Aliyun, &req.record_type, &req.name)?;
+
         #[derive(Serialize)]
         struct AddDomainRecordRequest {
             #[serde(rename = "DomainName")]
@@ -211,21 +285,32 @@ impl DnsProvider for AliyunProvider {
             ttl: u32,
             #[serde(rename = "Priority", skip_serializing_if = "Option::is_none")]
             priority: Option<u16>,
+            /// 解析线路，不传为默认线路
+            #[serde(rename = "Line", skip_serializing_if = "Option::is_none")]
+            line: Option<String>,
         }
 
         // 获取域名信息
         let domain_info = self.get_domain(&req.domain_id).await?;
 
         let api_req = AddDomainRecordRequest {
-            domain_name: domain_info.name,
+            domain_name: domain_info.name.clone(),
             rr: req.name.clone(),
             record_type: record_type_to_string(&req.record_type).to_string(),
-            value: req.value.clone(),
+            value: normalize_record_value(&req.record_type, &req.value),
             ttl: req.ttl,
             priority: req.priority,
+            line: req.line.clone(),
         };
 
-        let response: AddDomainRecordResponse = self.request("AddDomainRecord", &api_req).await?;
+        let response: AddDomainRecordResponse = self
+            .request(
+                "AddDomainRecord",
+                &api_req,
+                ErrorContext::for_record_name(&req.domain_id, &req.name),
+            )
+            .await
+            .map_err(|e| e.with_record_input(&req.name, &req.value))?;
 
         let now = chrono::Utc::now().to_rfc3339();
         Ok(DnsRecord {
@@ -233,10 +318,14 @@ impl DnsProvider for AliyunProvider {
             domain_id: req.domain_id.clone(),
             record_type: req.record_type.clone(),
             name: req.name.clone(),
-            value: req.value.clone(),
+            fqdn: Some(relative_to_full_name(&req.name, &domain_info.name)),
+            value: normalize_record_value(&req.record_type, &req.value),
             ttl: req.ttl,
             priority: req.priority,
             proxied: None,
+            line: req.line.clone(),
+            remark: None,
+            enabled: None,
             created_at: Some(now.clone()),
             updated_at: Some(now),
         })
@@ -247,6 +336,10 @@ impl DnsProvider for AliyunProvider {
         record_id: &str,
         req: &UpdateDnsRecordRequest,
     ) -> Result<DnsRecord> {
+        check_record_type(&ProviderType::Aliyun, &req.record_type)?;
+        check_ttl(&ProviderType::Aliyun, req.ttl)?;
+        check_apex_cname(&ProviderType::Aliyun, &req.record_type, &req.name)?;
+
         #[derive(Serialize)]
         struct UpdateDomainRecordRequest {
             #[serde(rename = "RecordId")]
@@ -261,19 +354,29 @@ impl DnsProvider for AliyunProvider {
             ttl: u32,
             #[serde(rename = "Priority", skip_serializing_if = "Option::is_none")]
             priority: Option<u16>,
+            /// 解析线路，不传为默认线路
+            #[serde(rename = "Line", skip_serializing_if = "Option::is_none")]
+            line: Option<String>,
         }
 
         let api_req = UpdateDomainRecordRequest {
             record_id: record_id.to_string(),
             rr: req.name.clone(),
             record_type: record_type_to_string(&req.record_type).to_string(),
-            value: req.value.clone(),
+            value: normalize_record_value(&req.record_type, &req.value),
             ttl: req.ttl,
             priority: req.priority,
+            line: req.line.clone(),
         };
 
-        let _response: UpdateDomainRecordResponse =
-            self.request("UpdateDomainRecord", &api_req).await?;
+        let _response: UpdateDomainRecordResponse = self
+            .request(
+                "UpdateDomainRecord",
+                &api_req,
+                ErrorContext::for_record_name(&req.domain_id, &req.name).with_record_id(record_id),
+            )
+            .await
+            .map_err(|e| e.with_record_input(&req.name, &req.value))?;
 
         let now = chrono::Utc::now().to_rfc3339();
         Ok(DnsRecord {
@@ -281,16 +384,21 @@ impl DnsProvider for AliyunProvider {
             domain_id: req.domain_id.clone(),
             record_type: req.record_type.clone(),
             name: req.name.clone(),
-            value: req.value.clone(),
+            // UpdateDomainRecord 不需要域名，这里不额外查询
+            fqdn: None,
+            value: normalize_record_value(&req.record_type, &req.value),
             ttl: req.ttl,
             priority: req.priority,
             proxied: None,
+            line: req.line.clone(),
+            remark: None,
+            enabled: None,
             created_at: None,
             updated_at: Some(now),
         })
     }
 
-    async fn delete_record(&self, record_id: &str, _domain_id: &str) -> Result<()> {
+    async fn delete_record(&self, record_id: &str, domain_id: &str) -> Result<()> {
         #[derive(Serialize)]
         struct DeleteDomainRecordRequest {
             #[serde(rename = "RecordId")]
@@ -301,9 +409,49 @@ impl DnsProvider for AliyunProvider {
             record_id: record_id.to_string(),
         };
 
-        let _response: DeleteDomainRecordResponse =
-            self.request("DeleteDomainRecord", &api_req).await?;
+        let _response: DeleteDomainRecordResponse = self
+            .request(
+                "DeleteDomainRecord",
+                &api_req,
+                ErrorContext::for_record(domain_id, record_id),
+            )
+            .await?;
 
         Ok(())
     }
+
+    async fn list_record_lines(&self, domain_id: &str) -> Result<Vec<RecordLine>> {
+        #[derive(Serialize)]
+        struct DescribeSupportLinesRequest {
+            #[serde(rename = "DomainName")]
+            domain_name: String,
+        }
+
+        let domain_info = self.get_domain(domain_id).await?;
+        let req = DescribeSupportLinesRequest {
+            domain_name: domain_info.name,
+        };
+
+        let response: DescribeSupportLinesResponse = self
+            .request(
+                "DescribeSupportLines",
+                &req,
+                ErrorContext::for_domain(&req.domain_name),
+            )
+            .await?;
+
+        Ok(response
+            .record_lines
+            .and_then(|l| l.record_line)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|line| RecordLine {
+                id: line.line_code,
+                name: line
+                    .line_display_name
+                    .filter(|n| !n.is_empty())
+                    .unwrap_or(line.line_name),
+            })
+            .collect())
+    }
 }
@@ -2,9 +2,9 @@
 
 use sha2::{Digest, Sha256};
 
-use crate::providers::common::hmac_sha256;
+use crate::providers::common::{hmac_sha256, redact};
 
-use super::{ALIYUN_DNS_HOST, ALIYUN_DNS_VERSION, AliyunProvider, EMPTY_BODY_SHA256};
+use super::{ALIYUN_DNS_VERSION, AliyunProvider, EMPTY_BODY_SHA256};
 
 impl AliyunProvider {
     /// 生成 ACS3-HMAC-SHA256 签名
@@ -18,7 +18,8 @@ impl AliyunProvider {
     ) -> String {
         // 1. 构造规范化请求头 (使用空 body 的 hash)
         let canonical_headers = format!(
-            "host:{ALIYUN_DNS_HOST}\nx-acs-action:{action}\nx-acs-content-sha256:{EMPTY_BODY_SHA256}\nx-acs-date:{timestamp}\nx-acs-signature-nonce:{nonce}\nx-acs-version:{ALIYUN_DNS_VERSION}\n"
+            "host:{}\nx-acs-action:{action}\nx-acs-content-sha256:{EMPTY_BODY_SHA256}\nx-acs-date:{timestamp}\nx-acs-signature-nonce:{nonce}\nx-acs-version:{ALIYUN_DNS_VERSION}\n",
+            self.host
         );
 
         let signed_headers =
@@ -29,13 +30,13 @@ impl AliyunProvider {
             "POST\n/\n{query_string}\n{canonical_headers}\n{signed_headers}\n{EMPTY_BODY_SHA256}"
         );
 
-        log::debug!("CanonicalRequest:\n{canonical_request}");
+        log::debug!("CanonicalRequest:\n{}", redact(&canonical_request));
 
         // 3. 构造待签名字符串
         let hashed_canonical_request = hex::encode(Sha256::digest(canonical_request.as_bytes()));
         let string_to_sign = format!("ACS3-HMAC-SHA256\n{hashed_canonical_request}");
 
-        log::debug!("StringToSign:\n{string_to_sign}");
+        log::debug!("StringToSign:\n{}", redact(&string_to_sign));
 
         // 4. 计算签名
         let signature = hex::encode(hmac_sha256(
@@ -0,0 +1,172 @@
+use serde_json::json;
+
+use crate::error::ProviderError;
+use crate::providers::mock::{MockResponse, MockServer};
+use crate::traits::DnsProvider;
+use crate::types::{DnsRecordType, PaginationParams, RecordQueryParams};
+
+use super::AliyunProvider;
+
+fn provider(server: &MockServer) -> AliyunProvider {
+    AliyunProvider::new("test-id".to_string(), "test-secret".to_string(), None)
+        .with_base_url(server.url())
+}
+
+#[tokio::test]
+async fn list_domains_parses_domains() {
+    let server = MockServer::start(vec![MockResponse::json(
+        200,
+        &json!({
+            "RequestId": "req-domains",
+            "TotalCount": 2,
+            "PageNumber": 1,
+            "PageSize": 20,
+            "Domains": {
+                "Domain": [
+                    {"DomainId": "d-1", "DomainName": "example.com", "DomainStatus": "ENABLE", "RecordCount": 3},
+                    {"DomainName": "example.org"}
+                ]
+            }
+        }),
+    )])
+    .await;
+
+    let response = provider(&server)
+        .list_domains(&PaginationParams::default())
+        .await
+        .unwrap();
+
+    assert_eq!(response.total_count, 2);
+    assert_eq!(response.items[0].id, "d-1");
+    assert_eq!(response.items[0].record_count, Some(3));
+    // 没有 DomainId 时使用域名作为 ID
+    assert_eq!(response.items[1].id, "example.org");
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert!(requests[0].path.starts_with("/?PageNumber=1&PageSize=20"));
+    assert_eq!(requests[0].header("x-acs-action"), Some("DescribeDomains"));
+    assert!(
+        requests[0]
+            .header("Authorization")
+            .is_some_and(|a| a.starts_with("ACS3-HMAC-SHA256 Credential=test-id,"))
+    );
+}
+
+#[tokio::test]
+async fn error_response_is_detected() {
+    let server = MockServer::start(vec![MockResponse::json(
+        404,
+        &json!({
+            "RequestId": "req-error",
+            "HostId": "alidns.cn-hangzhou.aliyuncs.com",
+            "Code": "InvalidAccessKeyId.NotFound",
+            "Message": "Specified access key is not found."
+        }),
+    )])
+    .await;
+
+    let err = provider(&server)
+        .list_domains(&PaginationParams::default())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, ProviderError::InvalidCredentials { .. }));
+    assert_eq!(err.request_id(), Some("req-error"));
+}
+
+#[tokio::test]
+async fn unknown_error_code_falls_back() {
+    let server = MockServer::start(vec![MockResponse::json(
+        400,
+        &json!({
+            "RequestId": "req-throttle",
+            "Code": "Throttling.User",
+            "Message": "Request was denied due to user flow control."
+        }),
+    )])
+    .await;
+
+    let err = provider(&server)
+        .list_domains(&PaginationParams::default())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        ProviderError::Unknown { raw_code: Some(code), .. } if code == "Throttling.User"
+    ));
+}
+
+// ============ 签名 ============
+
+#[test]
+fn sign_matches_reference_vector() {
+    let provider = AliyunProvider::new(
+        "YourAccessKeyId".to_string(),
+        "YourAccessKeySecret".to_string(),
+        None,
+    );
+
+    let authorization = provider.sign(
+        "DescribeDomains",
+        "PageNumber=1&PageSize=20",
+        "2024-01-01T00:00:00Z",
+        "3156853299f313e23d1673dc12e1703d",
+    );
+
+    assert_eq!(
+        authorization,
+        "ACS3-HMAC-SHA256 Credential=YourAccessKeyId,\
+         SignedHeaders=host;x-acs-action;x-acs-content-sha256;x-acs-date;x-acs-signature-nonce;x-acs-version,\
+         Signature=4c210f707928f2136f2328217f7382531011a1b5f57d4b777878ff415c64cd2d"
+    );
+}
+
+#[test]
+fn sign_depends_on_host() {
+    let sign = |region: &str| {
+        AliyunProvider::new(
+            "YourAccessKeyId".to_string(),
+            "YourAccessKeySecret".to_string(),
+            Some(region.to_string()),
+        )
+        .sign("DescribeDomains", "", "2024-01-01T00:00:00Z", "nonce")
+    };
+
+    assert_ne!(sign("cn-hangzhou"), sign("ap-southeast-1"));
+}
+
+#[tokio::test]
+async fn type_filter_total_is_filtered_count() {
+    let server = MockServer::start(vec![
+        MockResponse::json(
+            200,
+            &json!({"RequestId": "req-info", "DomainId": "d-1", "DomainName": "example.com"}),
+        ),
+        MockResponse::json(
+            200,
+            &json!({
+                "RequestId": "req-records",
+                "TotalCount": 1,
+                "DomainRecords": {"Record": [
+                    {"RecordId": "r-1", "RR": "@", "Type": "MX", "Value": "mx.example.com", "TTL": 600, "Priority": 10}
+                ]}
+            }),
+        ),
+    ])
+    .await;
+
+    let params = RecordQueryParams {
+        record_type: Some(DnsRecordType::Mx),
+        ..Default::default()
+    };
+    let response = provider(&server)
+        .list_records("example.com", &params)
+        .await
+        .unwrap();
+
+    assert!(server.requests()[1].path.contains("&Type=MX"));
+    assert_eq!(response.total_count, 1);
+    assert_eq!(response.items[0].record_type, DnsRecordType::Mx);
+}
@@ -91,6 +91,8 @@ pub struct AliyunResponse<T> {
     pub code: Option<String>,
     #[serde(rename = "Message")]
     pub message: Option<String>,
+    #[serde(rename = "RequestId")]
+    pub request_id: Option<String>,
 }
 
 // ============ 域名相关结构 ============
@@ -121,6 +123,17 @@ pub struct AliyunDomain {
     pub record_count: Option<u32>,
 }
 
+/// `DescribeDomainInfo` 响应
+#[derive(Debug, Deserialize)]
+pub struct DescribeDomainInfoResponse {
+    #[serde(rename = "DomainId")]
+    pub domain_id: Option<String>,
+    #[serde(rename = "DomainName")]
+    pub domain_name: String,
+    #[serde(rename = "RecordCount")]
+    pub record_count: Option<u32>,
+}
+
 // ============ 记录相关结构 ============
 
 #[derive(Debug, Deserialize)]
@@ -151,6 +164,8 @@ pub struct AliyunRecord {
     pub ttl: u32,
     #[serde(rename = "Priority")]
     pub priority: Option<u16>,
+    #[serde(rename = "Line")]
+    pub line: Option<String>,
     #[serde(rename = "CreateTimestamp")]
     pub create_timestamp: Option<i64>,
     #[serde(rename = "UpdateTimestamp")]
@@ -176,3 +191,28 @@ pub struct DeleteDomainRecordResponse {
     #[allow(dead_code)]
     pub record_id: Option<String>,
 }
+
+// ============ 解析线路相关结构 ============
+
+/// `DescribeSupportLines` 响应
+#[derive(Debug, Deserialize)]
+pub struct DescribeSupportLinesResponse {
+    #[serde(rename = "RecordLines")]
+    pub record_lines: Option<RecordLinesWrapper>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RecordLinesWrapper {
+    #[serde(rename = "RecordLine")]
+    pub record_line: Option<Vec<AliyunRecordLine>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AliyunRecordLine {
+    #[serde(rename = "LineCode")]
+    pub line_code: String,
+    #[serde(rename = "LineDisplayName")]
+    pub line_display_name: Option<String>,
+    #[serde(rename = "LineName")]
+    pub line_name: String,
+}
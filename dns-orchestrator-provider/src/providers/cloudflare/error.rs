@@ -2,6 +2,7 @@
 
 use crate::error::ProviderError;
 use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
+use crate::types::ProviderType;
 
 use super::CloudflareProvider;
 
@@ -9,33 +10,45 @@ use super::CloudflareProvider;
 /// 参考: <https://api.cloudflare.com/#getting-started-responses>
 impl ProviderErrorMapper for CloudflareProvider {
     fn provider_name(&self) -> &'static str {
-        "cloudflare"
+        ProviderType::Cloudflare.as_str()
     }
 
     fn map_error(&self, raw: RawApiError, context: ErrorContext) -> ProviderError {
         match raw.code.as_deref() {
-            // 认证错误
-            Some("9109" | "10000") => ProviderError::InvalidCredentials {
+            // 认证错误（1000 为 `/user/tokens/verify` 返回的无效 Token）
+            Some("1000" | "9109" | "10000") => ProviderError::InvalidCredentials {
                 provider: self.provider_name().to_string(),
                 raw_message: Some(raw.message),
+                request_id: None,
             },
             // 记录已存在
             Some("81057") => ProviderError::RecordExists {
                 provider: self.provider_name().to_string(),
                 record_name: context.record_name.unwrap_or_default(),
                 raw_message: Some(raw.message),
+                existing_value: None,
+                request_id: None,
             },
             // 记录不存在
             Some("81044") => ProviderError::RecordNotFound {
                 provider: self.provider_name().to_string(),
                 record_id: context.record_id.unwrap_or_default(),
                 raw_message: Some(raw.message),
+                request_id: None,
+            },
+            // 记录内容无效（如 A 记录的值不是 IPv4 地址）
+            Some("9003" | "9004" | "9005") => ProviderError::InvalidParameter {
+                provider: self.provider_name().to_string(),
+                param: "value".to_string(),
+                detail: raw.message,
+                request_id: None,
             },
             // Zone 不存在
             Some("7003") => ProviderError::DomainNotFound {
                 provider: self.provider_name().to_string(),
                 domain: context.domain.unwrap_or_default(),
                 raw_message: Some(raw.message),
+                request_id: None,
             },
             // 其他错误 fallback
             _ => self.unknown_error(raw),
@@ -3,16 +3,21 @@
 use serde::{Deserialize, Serialize};
 
 use crate::error::Result;
+use crate::providers::common::{header_value, redact};
 use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
 use crate::types::PaginationParams;
 
-use super::{CF_API_BASE, CloudflareDnsRecord, CloudflareProvider, CloudflareResponse};
+use super::{CF_MAX_ZONES_PER_PAGE, CloudflareDnsRecord, CloudflareProvider, CloudflareResponse};
 
 impl CloudflareProvider {
     /// 执行 GET 请求
-    pub(crate) async fn get<T: for<'de> Deserialize<'de>>(&self, path: &str) -> Result<T> {
-        let url = format!("{CF_API_BASE}{path}");
-        log::debug!("GET {url}");
+    pub(crate) async fn get<T: for<'de> Deserialize<'de>>(
+        &self,
+        path: &str,
+        ctx: ErrorContext,
+    ) -> Result<T> {
+        let url = format!("{}{path}", self.base_url);
+        log::debug!("GET {}", redact(&url));
 
         let response = self
             .client
@@ -25,17 +30,19 @@ impl CloudflareProvider {
         let status = response.status();
         log::debug!("Response Status: {status}");
 
+        let request_id = header_value(&response, "cf-ray");
+
         let response_text = response
             .text()
             .await
             .map_err(|e| self.network_error(format!("读取响应失败: {e}")))?;
 
-        log::debug!("Response Body: {response_text}");
+        log::debug!("Response Body: {}", redact(&response_text));
 
         let cf_response: CloudflareResponse<T> =
             serde_json::from_str(&response_text).map_err(|e| {
                 log::error!("JSON 解析失败: {e}");
-                log::error!("原始响应: {response_text}");
+                log::error!("原始响应: {}", redact(&response_text));
                 self.parse_error(e)
             })?;
 
@@ -49,10 +56,9 @@ impl CloudflareProvider {
                 })
                 .unwrap_or_else(|| (String::new(), "Unknown error".to_string()));
             log::error!("API 错误: {message}");
-            return Err(self.map_error(
-                RawApiError::with_code(code, message),
-                ErrorContext::default(),
-            ));
+            return Err(self
+                .map_error(RawApiError::with_code(code, message), ctx)
+                .with_request_id(request_id));
         }
 
         cf_response
@@ -66,15 +72,14 @@ impl CloudflareProvider {
         path: &str,
         params: &PaginationParams,
     ) -> Result<(Vec<T>, u32)> {
-        // Cloudflare zones API 最大 per_page 是 50
         let url = format!(
             "{}{}?page={}&per_page={}",
-            CF_API_BASE,
+            self.base_url,
             path,
             params.page,
-            params.page_size.min(50)
+            params.page_size.min(CF_MAX_ZONES_PER_PAGE)
         );
-        log::debug!("GET {url}");
+        log::debug!("GET {}", redact(&url));
 
         let response = self
             .client
@@ -87,17 +92,19 @@ impl CloudflareProvider {
         let status = response.status();
         log::debug!("Response Status: {status}");
 
+        let request_id = header_value(&response, "cf-ray");
+
         let response_text = response
             .text()
             .await
             .map_err(|e| self.network_error(format!("读取响应失败: {e}")))?;
 
-        log::debug!("Response Body: {response_text}");
+        log::debug!("Response Body: {}", redact(&response_text));
 
         let cf_response: CloudflareResponse<Vec<T>> = serde_json::from_str(&response_text)
             .map_err(|e| {
                 log::error!("JSON 解析失败: {e}");
-                log::error!("原始响应: {response_text}");
+                log::error!("原始响应: {}", redact(&response_text));
                 self.parse_error(e)
             })?;
 
@@ -111,10 +118,12 @@ impl CloudflareProvider {
                 })
                 .unwrap_or_else(|| (String::new(), "Unknown error".to_string()));
             log::error!("API 错误: {message}");
-            return Err(self.map_error(
-                RawApiError::with_code(code, message),
-                ErrorContext::default(),
-            ));
+            return Err(self
+                .map_error(
+                    RawApiError::with_code(code, message),
+                    ErrorContext::default(),
+                )
+                .with_request_id(request_id));
         }
 
         let total_count = cf_response.result_info.map_or(0, |i| i.total_count);
@@ -124,17 +133,23 @@ impl CloudflareProvider {
     }
 
     /// 执行 GET 请求 (带自定义 URL，用于 list_records)
-    pub(crate) async fn get_records(&self, url: &str) -> Result<(Vec<CloudflareDnsRecord>, u32)> {
-        log::debug!("GET {CF_API_BASE}{url}");
+    pub(crate) async fn get_records(
+        &self,
+        url: &str,
+        ctx: ErrorContext,
+    ) -> Result<(Vec<CloudflareDnsRecord>, u32)> {
+        log::debug!("GET {}{}", self.base_url, redact(url));
 
         let response = self
             .client
-            .get(format!("{CF_API_BASE}{url}"))
+            .get(format!("{}{url}", self.base_url))
             .header("Authorization", format!("Bearer {}", self.api_token))
             .send()
             .await
             .map_err(|e| self.network_error(e))?;
 
+        let request_id = header_value(&response, "cf-ray");
+
         let response_text = response
             .text()
             .await
@@ -152,10 +167,9 @@ impl CloudflareProvider {
                         .map(|e| (e.code.to_string(), e.message.clone()))
                 })
                 .unwrap_or_else(|| (String::new(), "Unknown error".to_string()));
-            return Err(self.map_error(
-                RawApiError::with_code(code, message),
-                ErrorContext::default(),
-            ));
+            return Err(self
+                .map_error(RawApiError::with_code(code, message), ctx)
+                .with_request_id(request_id));
         }
 
         let total_count = cf_response.result_info.map_or(0, |i| i.total_count);
@@ -169,12 +183,13 @@ impl CloudflareProvider {
         &self,
         path: &str,
         body: &B,
+        ctx: ErrorContext,
     ) -> Result<T> {
-        let url = format!("{CF_API_BASE}{path}");
+        let url = format!("{}{path}", self.base_url);
         let body_json =
             serde_json::to_string_pretty(body).unwrap_or_else(|_| "无法序列化请求体".to_string());
-        log::debug!("POST {url}");
-        log::debug!("Request Body: {body_json}");
+        log::debug!("POST {}", redact(&url));
+        log::debug!("Request Body: {}", redact(&body_json));
 
         let response = self
             .client
@@ -188,17 +203,19 @@ impl CloudflareProvider {
         let status = response.status();
         log::debug!("Response Status: {status}");
 
+        let request_id = header_value(&response, "cf-ray");
+
         let response_text = response
             .text()
             .await
             .map_err(|e| self.network_error(format!("读取响应失败: {e}")))?;
 
-        log::debug!("Response Body: {response_text}");
+        log::debug!("Response Body: {}", redact(&response_text));
 
         let cf_response: CloudflareResponse<T> =
             serde_json::from_str(&response_text).map_err(|e| {
                 log::error!("JSON 解析失败: {e}");
-                log::error!("原始响应: {response_text}");
+                log::error!("原始响应: {}", redact(&response_text));
                 self.parse_error(e)
             })?;
 
@@ -212,10 +229,9 @@ impl CloudflareProvider {
                 })
                 .unwrap_or_else(|| (String::new(), "Unknown error".to_string()));
             log::error!("API 错误: {message}");
-            return Err(self.map_error(
-                RawApiError::with_code(code, message),
-                ErrorContext::default(),
-            ));
+            return Err(self
+                .map_error(RawApiError::with_code(code, message), ctx)
+                .with_request_id(request_id));
         }
 
         cf_response
@@ -228,12 +244,13 @@ impl CloudflareProvider {
         &self,
         path: &str,
         body: &B,
+        ctx: ErrorContext,
     ) -> Result<T> {
-        let url = format!("{CF_API_BASE}{path}");
+        let url = format!("{}{path}", self.base_url);
         let body_json =
             serde_json::to_string_pretty(body).unwrap_or_else(|_| "无法序列化请求体".to_string());
-        log::debug!("PATCH {url}");
-        log::debug!("Request Body: {body_json}");
+        log::debug!("PATCH {}", redact(&url));
+        log::debug!("Request Body: {}", redact(&body_json));
 
         let response = self
             .client
@@ -247,17 +264,19 @@ impl CloudflareProvider {
         let status = response.status();
         log::debug!("Response Status: {status}");
 
+        let request_id = header_value(&response, "cf-ray");
+
         let response_text = response
             .text()
             .await
             .map_err(|e| self.network_error(format!("读取响应失败: {e}")))?;
 
-        log::debug!("Response Body: {response_text}");
+        log::debug!("Response Body: {}", redact(&response_text));
 
         let cf_response: CloudflareResponse<T> =
             serde_json::from_str(&response_text).map_err(|e| {
                 log::error!("JSON 解析失败: {e}");
-                log::error!("原始响应: {response_text}");
+                log::error!("原始响应: {}", redact(&response_text));
                 self.parse_error(e)
             })?;
 
@@ -271,10 +290,9 @@ impl CloudflareProvider {
                 })
                 .unwrap_or_else(|| (String::new(), "Unknown error".to_string()));
             log::error!("API 错误: {message}");
-            return Err(self.map_error(
-                RawApiError::with_code(code, message),
-                ErrorContext::default(),
-            ));
+            return Err(self
+                .map_error(RawApiError::with_code(code, message), ctx)
+                .with_request_id(request_id));
         }
 
         cf_response
@@ -283,9 +301,9 @@ impl CloudflareProvider {
     }
 
     /// 执行 DELETE 请求
-    pub(crate) async fn delete(&self, path: &str) -> Result<()> {
-        let url = format!("{CF_API_BASE}{path}");
-        log::debug!("DELETE {url}");
+    pub(crate) async fn delete(&self, path: &str, ctx: ErrorContext) -> Result<()> {
+        let url = format!("{}{path}", self.base_url);
+        log::debug!("DELETE {}", redact(&url));
 
         let response = self
             .client
@@ -298,17 +316,19 @@ impl CloudflareProvider {
         let status = response.status();
         log::debug!("Response Status: {status}");
 
+        let request_id = header_value(&response, "cf-ray");
+
         let response_text = response
             .text()
             .await
             .map_err(|e| self.network_error(format!("读取响应失败: {e}")))?;
 
-        log::debug!("Response Body: {response_text}");
+        log::debug!("Response Body: {}", redact(&response_text));
 
         let cf_response: CloudflareResponse<serde_json::Value> =
             serde_json::from_str(&response_text).map_err(|e| {
                 log::error!("JSON 解析失败: {e}");
-                log::error!("原始响应: {response_text}");
+                log::error!("原始响应: {}", redact(&response_text));
                 self.parse_error(e)
             })?;
 
@@ -322,10 +342,9 @@ impl CloudflareProvider {
                 })
                 .unwrap_or_else(|| (String::new(), "Unknown error".to_string()));
             log::error!("API 错误: {message}");
-            return Err(self.map_error(
-                RawApiError::with_code(code, message),
-                ErrorContext::default(),
-            ));
+            return Err(self
+                .map_error(RawApiError::with_code(code, message), ctx)
+                .with_request_id(request_id));
         }
 
         Ok(())
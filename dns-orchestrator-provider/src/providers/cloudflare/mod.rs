@@ -5,23 +5,49 @@ mod http;
 mod provider;
 mod types;
 
+#[cfg(test)]
+mod tests;
+
 use reqwest::Client;
 
-pub(crate) use types::{CloudflareDnsRecord, CloudflareResponse, CloudflareZone};
+pub(crate) use types::{
+    CloudflareDnsRecord, CloudflareDnssec, CloudflareResponse, CloudflareToken, CloudflareZone,
+};
 
 pub(crate) const CF_API_BASE: &str = "https://api.cloudflare.com/client/v4";
+/// zones API 单页最大条数
+pub(crate) const CF_MAX_ZONES_PER_PAGE: u32 = 50;
+/// dns_records API 单页最大条数
+pub(crate) const CF_MAX_RECORDS_PER_PAGE: u32 = 100;
+/// dns_records API 单页最小条数（小于该值会被拒绝）
+pub(crate) const CF_MIN_RECORDS_PER_PAGE: u32 = 5;
 
 /// Cloudflare DNS Provider
 pub struct CloudflareProvider {
     pub(crate) client: Client,
     pub(crate) api_token: String,
+    /// API 根地址，默认为 `CF_API_BASE`
+    pub(crate) base_url: String,
 }
 
 impl CloudflareProvider {
     pub fn new(api_token: String) -> Self {
+        Self::with_client(Client::new(), api_token)
+    }
+
+    /// 使用自定义 HTTP 客户端创建（代理、超时等配置）
+    pub fn with_client(client: Client, api_token: String) -> Self {
         Self {
-            client: Client::new(),
+            client,
             api_token,
+            base_url: CF_API_BASE.to_string(),
         }
     }
+
+    /// 替换 API 根地址（测试时指向 mock server）
+    #[cfg(test)]
+    pub(crate) fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
 }
@@ -3,15 +3,24 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
-use crate::error::Result;
-use crate::providers::common::{full_name_to_relative, parse_record_type, record_type_to_string};
-use crate::traits::{DnsProvider, ProviderErrorMapper};
+use crate::error::{ProviderError, Result};
+use crate::providers::common::{
+    check_apex_cname, check_record_type, check_ttl, full_name_to_relative, normalize_domain_name,
+    normalize_record_value, parse_modified_after, parse_record_type, record_type_to_string,
+    retain_modified_after,
+};
+use crate::traits::{DnsProvider, ErrorContext, ProviderErrorMapper, record_matches};
 use crate::types::{
-    CreateDnsRecordRequest, DnsRecord, Domain, DomainStatus, PaginatedResponse, PaginationParams,
-    ProviderType, RecordQueryParams, UpdateDnsRecordRequest,
+    CreateDnsRecordRequest, DnsRecord, DnsRecordType, DnssecStatus, Domain, DomainStatus,
+    PaginatedResponse, PaginationParams, PermissionInfo, ProviderType, RecordPatch,
+    RecordQueryParams, RecordSort, RecordValidationError, UpdateDnsRecordRequest,
+    validate_record_name,
 };
 
-use super::{CloudflareDnsRecord, CloudflareProvider, CloudflareZone};
+use super::{
+    CF_MAX_RECORDS_PER_PAGE, CF_MAX_ZONES_PER_PAGE, CF_MIN_RECORDS_PER_PAGE, CloudflareDnsRecord,
+    CloudflareDnssec, CloudflareProvider, CloudflareToken, CloudflareZone,
+};
 
 impl CloudflareProvider {
     /// 将 Cloudflare zone 转换为 Domain
@@ -42,6 +51,14 @@ impl CloudflareProvider {
         }
     }
 
+    /// 写入时使用的记录类型：Cloudflare 没有 ALIAS，以 CNAME 创建（根域时自动展平）
+    pub(crate) fn api_record_type(record_type: &DnsRecordType) -> &'static str {
+        match record_type {
+            DnsRecordType::Alias => "CNAME",
+            other => record_type_to_string(other),
+        }
+    }
+
     /// 将 Cloudflare 记录转换为 `DnsRecord`
     pub(crate) fn cf_record_to_dns_record(
         &self,
@@ -50,16 +67,21 @@ impl CloudflareProvider {
         zone_name: &str,
     ) -> Result<DnsRecord> {
         let record_type = parse_record_type(&cf_record.record_type, self.provider_name())?;
+        let value = normalize_record_value(&record_type, &cf_record.content);
 
         Ok(DnsRecord {
             id: cf_record.id,
             domain_id: zone_id.to_string(),
             record_type,
             name: full_name_to_relative(&cf_record.name, zone_name),
-            value: cf_record.content,
+            fqdn: Some(normalize_domain_name(&cf_record.name)),
+            value,
             ttl: cf_record.ttl,
             priority: cf_record.priority,
             proxied: cf_record.proxied,
+            line: None,
+            remark: None,
+            enabled: None,
             created_at: cf_record.created_on,
             updated_at: cf_record.modified_on,
         })
@@ -69,7 +91,7 @@ impl CloudflareProvider {
 #[async_trait]
 impl DnsProvider for CloudflareProvider {
     fn id(&self) -> &'static str {
-        "cloudflare"
+        ProviderType::Cloudflare.as_str()
     }
 
     async fn validate_credentials(&self) -> Result<bool> {
@@ -78,27 +100,116 @@ impl DnsProvider for CloudflareProvider {
             status: String,
         }
 
-        match self.get::<VerifyResponse>("/user/tokens/verify").await {
+        match self
+            .get::<VerifyResponse>("/user/tokens/verify", ErrorContext::default())
+            .await
+        {
             Ok(resp) => Ok(resp.status == "active"),
-            Err(_) => Ok(false),
+            Err(ProviderError::InvalidCredentials { .. }) => Ok(false),
+            Err(e) => Err(e),
         }
     }
 
+    /// `/user/tokens/verify` 只返回 Token ID 和状态，权限策略需要通过 `/user/tokens/{id}` 获取，
+    /// 该接口要求 Token 自身具有 "API Tokens Read" 权限，否则无法确定写入权限
+    async fn check_permissions(&self) -> Result<PermissionInfo> {
+        let verified: CloudflareToken = self
+            .get("/user/tokens/verify", ErrorContext::default())
+            .await?;
+        if verified.status != "active" {
+            return Err(ProviderError::InvalidCredentials {
+                provider: self.provider_name().to_string(),
+                raw_message: Some(format!("token status: {}", verified.status)),
+                request_id: None,
+            });
+        }
+
+        let Ok(token) = self
+            .get::<CloudflareToken>(
+                &format!("/user/tokens/{}", verified.id),
+                ErrorContext::default(),
+            )
+            .await
+        else {
+            return Ok(PermissionInfo::default());
+        };
+
+        let mut permissions: Vec<String> = Vec::new();
+        for group in token
+            .policies
+            .iter()
+            .filter(|p| p.effect == "allow")
+            .flat_map(|p| &p.permission_groups)
+        {
+            if !permissions.contains(&group.name) {
+                permissions.push(group.name.clone());
+            }
+        }
+        let can_write_dns = permissions.iter().any(|p| p == "DNS Write");
+
+        Ok(PermissionInfo {
+            permissions,
+            can_write_dns: Some(can_write_dns),
+        })
+    }
+
     async fn list_domains(&self, params: &PaginationParams) -> Result<PaginatedResponse<Domain>> {
         let (zones, total_count): (Vec<CloudflareZone>, u32) =
             self.get_paginated("/zones", params).await?;
         let domains = zones.into_iter().map(Self::zone_to_domain).collect();
+        // 返回实际生效的 page_size，调用方据此翻页
         Ok(PaginatedResponse::new(
             domains,
             params.page,
-            params.page_size,
+            params.page_size.min(CF_MAX_ZONES_PER_PAGE),
             total_count,
         ))
     }
 
     async fn get_domain(&self, domain_id: &str) -> Result<Domain> {
-        let zone: CloudflareZone = self.get(&format!("/zones/{domain_id}")).await?;
-        Ok(Self::zone_to_domain(zone))
+        let zone: CloudflareZone = self
+            .get(
+                &format!("/zones/{domain_id}"),
+                ErrorContext::for_domain(domain_id),
+            )
+            .await?;
+        let mut domain = Self::zone_to_domain(zone);
+        // zone 响应不含记录数，单独查询（失败时保持为空）
+        domain.record_count = self.count_records(domain_id).await.ok();
+        Ok(domain)
+    }
+
+    async fn count_records(&self, domain_id: &str) -> Result<u32> {
+        // 只读取 result_info.total_count，无需先查询 zone
+        let url = format!("/zones/{domain_id}/dns_records?per_page={CF_MIN_RECORDS_PER_PAGE}");
+        let (_, total_count) = self
+            .get_records(&url, ErrorContext::for_domain(domain_id))
+            .await?;
+        Ok(total_count)
+    }
+
+    async fn get_domain_nameservers(&self, domain_id: &str) -> Result<Vec<String>> {
+        let zone: CloudflareZone = self
+            .get(
+                &format!("/zones/{domain_id}"),
+                ErrorContext::for_domain(domain_id),
+            )
+            .await?;
+        Ok(zone.name_servers)
+    }
+
+    async fn get_dnssec_status(&self, domain_id: &str) -> Result<DnssecStatus> {
+        let dnssec: CloudflareDnssec = self
+            .get(
+                &format!("/zones/{domain_id}/dnssec"),
+                ErrorContext::for_domain(domain_id),
+            )
+            .await?;
+        Ok(DnssecStatus {
+            enabled: dnssec.status == "active",
+            ds_records: dnssec.ds.into_iter().filter(|ds| !ds.is_empty()).collect(),
+            algorithm: dnssec.algorithm,
+        })
     }
 
     async fn list_records(
@@ -106,16 +217,24 @@ impl DnsProvider for CloudflareProvider {
         domain_id: &str,
         params: &RecordQueryParams,
     ) -> Result<PaginatedResponse<DnsRecord>> {
+        let modified_after = parse_modified_after(&ProviderType::Cloudflare, params)?;
+
         // 先获取 zone 信息以获取域名
-        let zone: CloudflareZone = self.get(&format!("/zones/{domain_id}")).await?;
+        let zone: CloudflareZone = self
+            .get(
+                &format!("/zones/{domain_id}"),
+                ErrorContext::for_domain(domain_id),
+            )
+            .await?;
         let zone_name = zone.name;
 
         // 构建查询 URL，包含搜索参数
+        let per_page = params
+            .page_size
+            .clamp(CF_MIN_RECORDS_PER_PAGE, CF_MAX_RECORDS_PER_PAGE);
         let mut url = format!(
             "/zones/{}/dns_records?page={}&per_page={}",
-            domain_id,
-            params.page,
-            params.page_size.min(100)
+            domain_id, params.page, per_page
         );
 
         // 添加搜索关键词（只搜索记录名称）
@@ -131,24 +250,90 @@ impl DnsProvider for CloudflareProvider {
             url.push_str(&format!("&type={}", urlencoding::encode(type_str)));
         }
 
-        let (cf_records, total_count) = self.get_records(&url).await?;
+        // 服务端排序
+        if let Some(sort_by) = params.sort_by {
+            let order = match sort_by {
+                RecordSort::Name => "name",
+                RecordSort::Type => "type",
+                RecordSort::Ttl => "ttl",
+            };
+            let direction = if params.sort_desc { "desc" } else { "asc" };
+            url.push_str(&format!("&order={order}&direction={direction}"));
+        }
+
+        let (cf_records, total_count) = self
+            .get_records(&url, ErrorContext::for_domain(domain_id))
+            .await?;
 
-        let records: Result<Vec<DnsRecord>> = cf_records
+        let mut records = cf_records
             .into_iter()
             .map(|r| self.cf_record_to_dns_record(r, domain_id, &zone_name))
-            .collect();
+            .collect::<Result<Vec<_>>>()?;
+        retain_modified_after(&mut records, modified_after);
 
+        // 返回实际生效的 page_size，调用方据此翻页
         Ok(PaginatedResponse::new(
-            records?,
+            records,
             params.page,
-            params.page_size,
+            per_page,
             total_count,
         ))
     }
 
+    /// 使用 `name=` 在服务端按完整域名精确过滤，类型在本地过滤
+    async fn find_records(
+        &self,
+        domain_id: &str,
+        name: &str,
+        record_type: Option<DnsRecordType>,
+    ) -> Result<Vec<DnsRecord>> {
+        let zone: CloudflareZone = self
+            .get(
+                &format!("/zones/{domain_id}"),
+                ErrorContext::for_domain(domain_id),
+            )
+            .await?;
+        let full_name = self.relative_to_full_name(name, &zone.name);
+
+        let mut records = Vec::new();
+        let mut page = 1;
+        loop {
+            let url = format!(
+                "/zones/{domain_id}/dns_records?name={}&page={page}&per_page={CF_MAX_RECORDS_PER_PAGE}",
+                urlencoding::encode(&full_name)
+            );
+            let (cf_records, total_count) = self
+                .get_records(&url, ErrorContext::for_domain(domain_id))
+                .await?;
+            let fetched = cf_records.len();
+            for cf_record in cf_records {
+                let record = self.cf_record_to_dns_record(cf_record, domain_id, &zone.name)?;
+                if record_matches(&record, name, record_type.as_ref()) {
+                    records.push(record);
+                }
+            }
+
+            if fetched == 0 || page * CF_MAX_RECORDS_PER_PAGE >= total_count {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(records)
+    }
+
     async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
+        check_record_type(&ProviderType::Cloudflare, &req.record_type)?;
+        check_ttl(&ProviderType::Cloudflare, req.ttl)?;
+        check_apex_cname(&ProviderType::Cloudflare, &req.record_type, &req.name)?;
+
         // 先获取 zone 信息
-        let zone: CloudflareZone = self.get(&format!("/zones/{}", req.domain_id)).await?;
+        let zone: CloudflareZone = self
+            .get(
+                &format!("/zones/{}", req.domain_id),
+                ErrorContext::for_domain(&req.domain_id),
+            )
+            .await?;
         let zone_name = zone.name;
 
         let full_name = self.relative_to_full_name(&req.name, &zone_name);
@@ -167,17 +352,22 @@ impl DnsProvider for CloudflareProvider {
         }
 
         let body = CreateRecordBody {
-            record_type: record_type_to_string(&req.record_type).to_string(),
+            record_type: Self::api_record_type(&req.record_type).to_string(),
             name: full_name,
-            content: req.value.clone(),
+            content: normalize_record_value(&req.record_type, &req.value),
             ttl: req.ttl,
             priority: req.priority,
             proxied: req.proxied,
         };
 
         let cf_record: CloudflareDnsRecord = self
-            .post(&format!("/zones/{}/dns_records", req.domain_id), &body)
-            .await?;
+            .post(
+                &format!("/zones/{}/dns_records", req.domain_id),
+                &body,
+                ErrorContext::for_record_name(&req.domain_id, &req.name),
+            )
+            .await
+            .map_err(|e| e.with_record_input(&req.name, &req.value))?;
 
         self.cf_record_to_dns_record(cf_record, &req.domain_id, &zone_name)
     }
@@ -187,8 +377,17 @@ impl DnsProvider for CloudflareProvider {
         record_id: &str,
         req: &UpdateDnsRecordRequest,
     ) -> Result<DnsRecord> {
+        check_record_type(&ProviderType::Cloudflare, &req.record_type)?;
+        check_ttl(&ProviderType::Cloudflare, req.ttl)?;
+        check_apex_cname(&ProviderType::Cloudflare, &req.record_type, &req.name)?;
+
         // 先获取 zone 信息
-        let zone: CloudflareZone = self.get(&format!("/zones/{}", req.domain_id)).await?;
+        let zone: CloudflareZone = self
+            .get(
+                &format!("/zones/{}", req.domain_id),
+                ErrorContext::for_domain(&req.domain_id),
+            )
+            .await?;
         let zone_name = zone.name;
 
         let full_name = self.relative_to_full_name(&req.name, &zone_name);
@@ -207,9 +406,9 @@ impl DnsProvider for CloudflareProvider {
         }
 
         let body = UpdateRecordBody {
-            record_type: record_type_to_string(&req.record_type).to_string(),
+            record_type: Self::api_record_type(&req.record_type).to_string(),
             name: full_name,
-            content: req.value.clone(),
+            content: normalize_record_value(&req.record_type, &req.value),
             ttl: req.ttl,
             priority: req.priority,
             proxied: req.proxied,
@@ -219,14 +418,115 @@ impl DnsProvider for CloudflareProvider {
             .patch(
                 &format!("/zones/{}/dns_records/{}", req.domain_id, record_id),
                 &body,
+                ErrorContext::for_record_name(&req.domain_id, &req.name).with_record_id(record_id),
             )
-            .await?;
+            .await
+            .map_err(|e| e.with_record_input(&req.name, &req.value))?;
 
         self.cf_record_to_dns_record(cf_record, &req.domain_id, &zone_name)
     }
 
-    async fn delete_record(&self, record_id: &str, domain_id: &str) -> Result<()> {
-        self.delete(&format!("/zones/{domain_id}/dns_records/{record_id}"))
+    async fn get_record(&self, domain_id: &str, record_id: &str) -> Result<DnsRecord> {
+        let zone: CloudflareZone = self
+            .get(
+                &format!("/zones/{domain_id}"),
+                ErrorContext::for_domain(domain_id),
+            )
+            .await?;
+        let cf_record: CloudflareDnsRecord = self
+            .get(
+                &format!("/zones/{domain_id}/dns_records/{record_id}"),
+                ErrorContext::for_record(domain_id, record_id),
+            )
+            .await?;
+
+        self.cf_record_to_dns_record(cf_record, domain_id, &zone.name)
+    }
+
+    /// 读取现有记录并合并后按整体更新的规则校验和规范化，PATCH 只发送有变化的字段
+    async fn patch_record(
+        &self,
+        domain_id: &str,
+        record_id: &str,
+        changes: &RecordPatch,
+    ) -> Result<DnsRecord> {
+        let invalid = |e: RecordValidationError| ProviderError::InvalidParameter {
+            provider: self.provider_name().to_string(),
+            param: e.field.clone(),
+            detail: e.to_string(),
+            request_id: None,
+        };
+        // 不依赖现有记录的字段先校验，避免无效请求
+        if let Some(name) = &changes.name {
+            validate_record_name(name).map_err(invalid)?;
+        }
+        if let Some(ttl) = changes.ttl {
+            check_ttl(&ProviderType::Cloudflare, ttl)?;
+        }
+
+        let zone: CloudflareZone = self
+            .get(
+                &format!("/zones/{domain_id}"),
+                ErrorContext::for_domain(domain_id),
+            )
+            .await?;
+        let zone_name = zone.name;
+        let cf_record: CloudflareDnsRecord = self
+            .get(
+                &format!("/zones/{domain_id}/dns_records/{record_id}"),
+                ErrorContext::for_record(domain_id, record_id),
+            )
+            .await?;
+        let current = self.cf_record_to_dns_record(cf_record, domain_id, &zone_name)?;
+
+        let req = changes.apply_to(&current);
+        req.validate().map_err(invalid)?;
+        check_record_type(&ProviderType::Cloudflare, &req.record_type)?;
+        check_apex_cname(&ProviderType::Cloudflare, &req.record_type, &req.name)?;
+
+        // Cloudflare PATCH 只修改请求中包含的字段
+        #[derive(Serialize)]
+        struct PatchRecordBody {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            name: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            content: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            ttl: Option<u32>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            priority: Option<Option<u16>>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            proxied: Option<bool>,
+        }
+
+        let content = normalize_record_value(&req.record_type, &req.value);
+        let body = PatchRecordBody {
+            name: (req.name != current.name)
+                .then(|| self.relative_to_full_name(&req.name, &zone_name)),
+            content: (content != current.value).then_some(content),
+            ttl: (req.ttl != current.ttl).then_some(req.ttl),
+            priority: (req.priority != current.priority).then_some(req.priority),
+            // 清空代理状态即关闭代理
+            proxied: (req.proxied != current.proxied).then(|| req.proxied.unwrap_or(false)),
+        };
+
+        let cf_record: CloudflareDnsRecord = self
+            .patch(
+                &format!("/zones/{domain_id}/dns_records/{record_id}"),
+                &body,
+                ErrorContext::for_record_name(domain_id, &req.name).with_record_id(record_id),
+            )
             .await
+            .map_err(|e| e.with_record_input(&req.name, &req.value))?;
+
+        self.cf_record_to_dns_record(cf_record, domain_id, &zone_name)
+    }
+
+    async fn delete_record(&self, record_id: &str, domain_id: &str) -> Result<()> {
+        self.delete(
+            &format!("/zones/{domain_id}/dns_records/{record_id}"),
+            ErrorContext::for_record(domain_id, record_id),
+        )
+        .await
     }
 }
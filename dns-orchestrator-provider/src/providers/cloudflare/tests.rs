@@ -0,0 +1,793 @@
+use serde_json::json;
+
+use crate::error::ProviderError;
+use crate::providers::mock::{MockResponse, MockServer};
+use crate::traits::DnsProvider;
+use crate::types::{
+    CreateDnsRecordRequest, DnsRecordType, PaginationParams, RecordPatch, RecordQueryParams,
+    RecordSort,
+};
+
+use super::CloudflareProvider;
+
+fn provider(server: &MockServer) -> CloudflareProvider {
+    CloudflareProvider::new("test-token".to_string()).with_base_url(server.url())
+}
+
+#[tokio::test]
+async fn list_domains_parses_zones() {
+    let server = MockServer::start(vec![MockResponse::json(
+        200,
+        &json!({
+            "success": true,
+            "errors": [],
+            "result": [
+                {"id": "zone-1", "name": "example.com", "status": "active"},
+                {"id": "zone-2", "name": "example.org", "status": "pending"}
+            ],
+            "result_info": {"page": 1, "per_page": 20, "total_count": 25}
+        }),
+    )])
+    .await;
+    let provider = provider(&server);
+
+    let response = provider
+        .list_domains(&PaginationParams::default())
+        .await
+        .unwrap();
+
+    assert_eq!(response.items.len(), 2);
+    assert_eq!(response.items[0].id, "zone-1");
+    assert_eq!(response.items[0].name, "example.com");
+    assert_eq!(response.total_count, 25);
+    assert!(response.has_more);
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/zones?page=1&per_page=20");
+    assert_eq!(
+        requests[0].header("Authorization"),
+        Some("Bearer test-token")
+    );
+}
+
+#[tokio::test]
+async fn list_records_converts_names_to_relative() {
+    let server = MockServer::start(vec![
+        MockResponse::json(
+            200,
+            &json!({
+                "success": true,
+                "result": {"id": "zone-1", "name": "example.com", "status": "active"}
+            }),
+        ),
+        MockResponse::json(
+            200,
+            &json!({
+                "success": true,
+                "result": [
+                    {"id": "r1", "type": "A", "name": "www.example.com", "content": "1.2.3.4", "ttl": 1, "proxied": true},
+                    {"id": "r2", "type": "MX", "name": "example.com", "content": "mx.example.com", "ttl": 300, "priority": 10}
+                ],
+                "result_info": {"page": 1, "per_page": 20, "total_count": 2}
+            }),
+        ),
+    ])
+    .await;
+
+    let response = provider(&server)
+        .list_records("zone-1", &RecordQueryParams::default())
+        .await
+        .unwrap();
+
+    assert_eq!(response.items.len(), 2);
+    assert_eq!(response.items[0].name, "www");
+    assert_eq!(response.items[0].fqdn.as_deref(), Some("www.example.com"));
+    assert_eq!(response.items[0].proxied, Some(true));
+    assert_eq!(response.items[1].name, "@");
+    assert_eq!(response.items[1].fqdn.as_deref(), Some("example.com"));
+    assert_eq!(response.items[1].record_type, DnsRecordType::Mx);
+    assert_eq!(response.items[1].priority, Some(10));
+    assert!(!response.has_more);
+}
+
+#[tokio::test]
+async fn modified_after_filters_records_locally() {
+    let server = MockServer::start(vec![
+        MockResponse::json(
+            200,
+            &json!({
+                "success": true,
+                "result": {"id": "zone-1", "name": "example.com", "status": "active"}
+            }),
+        ),
+        MockResponse::json(
+            200,
+            &json!({
+                "success": true,
+                "result": [
+                    {"id": "r1", "type": "A", "name": "old.example.com", "content": "192.0.2.1", "ttl": 1, "modified_on": "2024-01-01T00:00:00Z"},
+                    {"id": "r2", "type": "A", "name": "new.example.com", "content": "192.0.2.2", "ttl": 1, "modified_on": "2024-06-01T00:00:00Z"},
+                    {"id": "r3", "type": "A", "name": "unknown.example.com", "content": "192.0.2.3", "ttl": 1}
+                ],
+                "result_info": {"page": 1, "per_page": 20, "total_count": 3}
+            }),
+        ),
+    ])
+    .await;
+    let provider = provider(&server);
+
+    let params = RecordQueryParams {
+        modified_after: Some("2024-03-01T00:00:00+08:00".to_string()),
+        ..Default::default()
+    };
+    let response = provider.list_records("zone-1", &params).await.unwrap();
+
+    let ids: Vec<_> = response.items.iter().map(|r| r.id.as_str()).collect();
+    assert_eq!(ids, vec!["r2", "r3"]);
+
+    let params = RecordQueryParams {
+        modified_after: Some("yesterday".to_string()),
+        ..Default::default()
+    };
+    let err = provider.list_records("zone-1", &params).await.unwrap_err();
+    assert!(
+        matches!(err, ProviderError::InvalidParameter { ref param, .. } if param == "modified_after")
+    );
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn sort_is_sent_as_order_and_direction() {
+    let server = MockServer::start(vec![
+        MockResponse::json(
+            200,
+            &json!({
+                "success": true,
+                "result": {"id": "zone-1", "name": "example.com", "status": "active"}
+            }),
+        ),
+        MockResponse::json(
+            200,
+            &json!({"success": true, "result": [], "result_info": {"page": 1, "per_page": 20, "total_count": 0}}),
+        ),
+    ])
+    .await;
+
+    let params = RecordQueryParams {
+        sort_by: Some(RecordSort::Ttl),
+        sort_desc: true,
+        ..Default::default()
+    };
+    provider(&server)
+        .list_records("zone-1", &params)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        server.requests()[1].path,
+        "/zones/zone-1/dns_records?page=1&per_page=20&order=ttl&direction=desc"
+    );
+}
+
+#[tokio::test]
+async fn type_filter_total_is_filtered_count() {
+    let server = MockServer::start(vec![
+        MockResponse::json(
+            200,
+            &json!({
+                "success": true,
+                "result": {"id": "zone-1", "name": "example.com", "status": "active"}
+            }),
+        ),
+        MockResponse::json(
+            200,
+            &json!({
+                "success": true,
+                "result": [
+                    {"id": "r1", "type": "MX", "name": "example.com", "content": "mx1.example.com", "ttl": 300, "priority": 10}
+                ],
+                "result_info": {"page": 1, "per_page": 5, "total_count": 6}
+            }),
+        ),
+    ])
+    .await;
+
+    let params = RecordQueryParams {
+        page_size: 5,
+        record_type: Some(DnsRecordType::Mx),
+        ..Default::default()
+    };
+    let response = provider(&server)
+        .list_records("zone-1", &params)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        server.requests()[1].path,
+        "/zones/zone-1/dns_records?page=1&per_page=5&type=MX"
+    );
+    assert_eq!(response.total_count, 6);
+    assert!(response.has_more);
+}
+
+#[tokio::test]
+async fn list_records_raises_page_size_to_minimum() {
+    let server = MockServer::start(vec![
+        MockResponse::json(
+            200,
+            &json!({
+                "success": true,
+                "result": {"id": "zone-1", "name": "example.com", "status": "active"}
+            }),
+        ),
+        MockResponse::json(
+            200,
+            &json!({
+                "success": true,
+                "result": [],
+                "result_info": {"page": 1, "per_page": 5, "total_count": 0}
+            }),
+        ),
+    ])
+    .await;
+
+    let params = RecordQueryParams {
+        page_size: 1,
+        ..Default::default()
+    };
+    provider(&server)
+        .list_records("zone-1", &params)
+        .await
+        .unwrap();
+
+    // Cloudflare 拒绝小于 5 的 per_page
+    assert_eq!(
+        server.requests()[1].path,
+        "/zones/zone-1/dns_records?page=1&per_page=5"
+    );
+}
+
+#[tokio::test]
+async fn unsuccessful_response_is_mapped() {
+    let server = MockServer::start(vec![
+        MockResponse::json(
+            403,
+            &json!({
+                "success": false,
+                "errors": [{"code": 9109, "message": "Invalid access token"}],
+                "result": null
+            }),
+        )
+        .header("cf-ray", "8a1b2c3d4e5f-SJC"),
+    ])
+    .await;
+
+    let err = provider(&server).get_domain("zone-1").await.unwrap_err();
+
+    assert!(matches!(
+        &err,
+        ProviderError::InvalidCredentials { raw_message: Some(message), .. }
+            if message == "Invalid access token"
+    ));
+    assert_eq!(err.request_id(), Some("8a1b2c3d4e5f-SJC"));
+}
+
+#[tokio::test]
+async fn not_found_errors_carry_operation_context() {
+    let server = MockServer::start(vec![
+        MockResponse::json(
+            404,
+            &json!({
+                "success": false,
+                "errors": [{"code": 81044, "message": "Record does not exist."}],
+                "result": null
+            }),
+        ),
+        MockResponse::json(
+            404,
+            &json!({
+                "success": false,
+                "errors": [{"code": 7003, "message": "Could not route to /zones/zone-9"}],
+                "result": null
+            }),
+        ),
+    ])
+    .await;
+    let provider = provider(&server);
+
+    let err = provider.delete_record("r1", "zone-1").await.unwrap_err();
+    assert!(matches!(
+        err,
+        ProviderError::RecordNotFound { ref record_id, .. } if record_id == "r1"
+    ));
+
+    let err = provider.get_domain("zone-9").await.unwrap_err();
+    assert!(matches!(
+        err,
+        ProviderError::DomainNotFound { ref domain, .. } if domain == "zone-9"
+    ));
+}
+
+#[tokio::test]
+async fn unknown_error_code_falls_back() {
+    let server = MockServer::start(vec![MockResponse::json(
+        400,
+        &json!({
+            "success": false,
+            "errors": [{"code": 1004, "message": "DNS Validation Error"}],
+            "result": null
+        }),
+    )])
+    .await;
+
+    let err = provider(&server).get_domain("zone-1").await.unwrap_err();
+
+    assert!(matches!(
+        err,
+        ProviderError::Unknown { raw_code: Some(code), .. } if code == "1004"
+    ));
+}
+
+#[tokio::test]
+async fn invalid_content_is_mapped_with_submitted_value() {
+    let server = MockServer::start(vec![
+        MockResponse::json(
+            200,
+            &json!({
+                "success": true,
+                "result": {"id": "zone-1", "name": "example.com", "status": "active"}
+            }),
+        ),
+        MockResponse::json(
+            400,
+            &json!({
+                "success": false,
+                "errors": [{"code": 9005, "message": "Content for A record must be a valid IPv4 address."}],
+                "result": null
+            }),
+        ),
+    ])
+    .await;
+
+    let err = provider(&server)
+        .create_record(&CreateDnsRecordRequest {
+            domain_id: "zone-1".to_string(),
+            record_type: DnsRecordType::A,
+            name: "www".to_string(),
+            value: "not-an-ip".to_string(),
+            ttl: 1,
+            priority: None,
+            proxied: None,
+            line: None,
+            remark: None,
+        })
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        ProviderError::InvalidParameter { ref param, ref detail, .. }
+            if param == "value" && detail.contains("not-an-ip")
+    ));
+}
+
+#[tokio::test]
+async fn page_size_is_clamped_to_api_limit() {
+    let server = MockServer::start(vec![MockResponse::json(
+        200,
+        &json!({
+            "success": true,
+            "result": [{"id": "zone-1", "name": "example.com", "status": "active"}],
+            "result_info": {"page": 1, "per_page": 50, "total_count": 80}
+        }),
+    )])
+    .await;
+
+    let response = provider(&server)
+        .list_domains(&PaginationParams {
+            page: 1,
+            page_size: 100,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    // 返回实际生效的 page_size，has_more 也按它计算
+    assert_eq!(response.page_size, 50);
+    assert!(response.has_more);
+    assert_eq!(server.requests()[0].path, "/zones?page=1&per_page=50");
+}
+
+#[tokio::test]
+async fn count_records_reads_total_without_zone_lookup() {
+    let server = MockServer::start(vec![MockResponse::json(
+        200,
+        &json!({
+            "success": true,
+            "result": [],
+            "result_info": {"page": 1, "per_page": 5, "total_count": 42}
+        }),
+    )])
+    .await;
+
+    let count = provider(&server).count_records("zone-1").await.unwrap();
+
+    assert_eq!(count, 42);
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].path, "/zones/zone-1/dns_records?per_page=5");
+}
+
+#[tokio::test]
+async fn get_domain_fills_record_count() {
+    let server = MockServer::start(vec![
+        MockResponse::json(
+            200,
+            &json!({
+                "success": true,
+                "result": {"id": "zone-1", "name": "example.com", "status": "active"}
+            }),
+        ),
+        MockResponse::json(
+            200,
+            &json!({
+                "success": true,
+                "result": [],
+                "result_info": {"page": 1, "per_page": 5, "total_count": 7}
+            }),
+        ),
+    ])
+    .await;
+
+    let domain = provider(&server).get_domain("zone-1").await.unwrap();
+
+    assert_eq!(domain.record_count, Some(7));
+}
+
+#[tokio::test]
+async fn create_record_strips_trailing_dot() {
+    let server = MockServer::start(vec![
+        MockResponse::json(
+            200,
+            &json!({
+                "success": true,
+                "result": {"id": "zone-1", "name": "example.com", "status": "active"}
+            }),
+        ),
+        MockResponse::json(
+            200,
+            &json!({
+                "success": true,
+                "result": {"id": "r1", "type": "CNAME", "name": "www.example.com", "content": "target.example.net.", "ttl": 1}
+            }),
+        ),
+    ])
+    .await;
+
+    let record = provider(&server)
+        .create_record(&CreateDnsRecordRequest {
+            domain_id: "zone-1".to_string(),
+            record_type: DnsRecordType::Cname,
+            name: "www".to_string(),
+            value: "target.example.net.".to_string(),
+            ttl: 1,
+            priority: None,
+            proxied: None,
+            line: None,
+            remark: None,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(record.value, "target.example.net");
+    let requests = server.requests();
+    assert!(
+        requests[1]
+            .body
+            .contains(r#""content":"target.example.net""#)
+    );
+}
+
+fn a_record_responses() -> Vec<MockResponse> {
+    vec![
+        MockResponse::json(
+            200,
+            &json!({
+                "success": true,
+                "result": {"id": "zone-1", "name": "example.com", "status": "active"}
+            }),
+        ),
+        MockResponse::json(
+            200,
+            &json!({
+                "success": true,
+                "result": {"id": "r1", "type": "A", "name": "www.example.com", "content": "192.0.2.1", "ttl": 1, "proxied": true}
+            }),
+        ),
+    ]
+}
+
+#[tokio::test]
+async fn patch_record_validates_merged_record_before_patching() {
+    let server = MockServer::start(a_record_responses()).await;
+
+    let err = provider(&server)
+        .patch_record(
+            "zone-1",
+            "r1",
+            &RecordPatch {
+                value: Some("not-an-ip".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ProviderError::InvalidParameter { ref param, .. } if param == "value"));
+    // 只读取了 zone 和现有记录，没有发送 PATCH
+    assert!(server.requests().iter().all(|r| r.method == "GET"));
+
+    // 名称不依赖现有记录，直接在本地拒绝
+    let server = MockServer::start(vec![]).await;
+    let err = provider(&server)
+        .patch_record(
+            "zone-1",
+            "r1",
+            &RecordPatch {
+                name: Some("foo.*".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ProviderError::InvalidParameter { ref param, .. } if param == "name"));
+    assert!(server.requests().is_empty());
+}
+
+#[tokio::test]
+async fn patch_record_sends_only_changed_fields() {
+    let mut responses = a_record_responses();
+    responses.push(MockResponse::json(
+        200,
+        &json!({
+            "success": true,
+            "result": {"id": "r1", "type": "A", "name": "www.example.com", "content": "192.0.2.1", "ttl": 1, "proxied": false}
+        }),
+    ));
+    let server = MockServer::start(responses).await;
+
+    let record = provider(&server)
+        .patch_record(
+            "zone-1",
+            "r1",
+            &RecordPatch {
+                value: Some("192.0.2.1".to_string()),
+                proxied: Some(None),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(record.proxied, Some(false));
+    let requests = server.requests();
+    assert_eq!(requests[2].method, "PATCH");
+    assert_eq!(requests[2].body, r#"{"proxied":false}"#);
+}
+
+#[tokio::test]
+async fn find_records_filters_by_full_name() {
+    let server = MockServer::start(vec![
+        MockResponse::json(
+            200,
+            &json!({
+                "success": true,
+                "result": {"id": "zone-1", "name": "example.com", "status": "active"}
+            }),
+        ),
+        MockResponse::json(
+            200,
+            &json!({
+                "success": true,
+                "result": [
+                    {"id": "r1", "type": "A", "name": "home.example.com", "content": "1.2.3.4", "ttl": 1},
+                    {"id": "r2", "type": "AAAA", "name": "home.example.com", "content": "::1", "ttl": 1}
+                ],
+                "result_info": {"page": 1, "per_page": 100, "total_count": 2}
+            }),
+        ),
+    ])
+    .await;
+
+    let records = provider(&server)
+        .find_records("zone-1", "home", Some(DnsRecordType::A))
+        .await
+        .unwrap();
+
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].id, "r1");
+    let requests = server.requests();
+    assert_eq!(
+        requests[1].path,
+        "/zones/zone-1/dns_records?name=home.example.com&page=1&per_page=100"
+    );
+}
+
+#[tokio::test]
+async fn can_create_record_reports_conflicting_value() {
+    let zone = || {
+        MockResponse::json(
+            200,
+            &json!({
+                "success": true,
+                "result": {"id": "zone-1", "name": "example.com", "status": "active"}
+            }),
+        )
+    };
+    let records = || {
+        MockResponse::json(
+            200,
+            &json!({
+                "success": true,
+                "result": [
+                    {"id": "r1", "type": "A", "name": "home.example.com", "content": "192.0.2.1", "ttl": 1}
+                ],
+                "result_info": {"page": 1, "per_page": 100, "total_count": 1}
+            }),
+        )
+    };
+    let server = MockServer::start(vec![zone(), records(), zone(), records()]).await;
+    let provider = provider(&server);
+    let request = |record_type: DnsRecordType, value: &str| CreateDnsRecordRequest {
+        domain_id: "zone-1".to_string(),
+        record_type,
+        name: "home".to_string(),
+        value: value.to_string(),
+        ttl: 1,
+        priority: None,
+        proxied: None,
+        line: None,
+        remark: None,
+    };
+
+    // 同类型不同值（轮询）允许创建
+    provider
+        .can_create_record(&request(DnsRecordType::A, "192.0.2.2"))
+        .await
+        .unwrap();
+    let err = provider
+        .can_create_record(&request(DnsRecordType::Cname, "target.example.net"))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        ProviderError::RecordExists { existing_value: Some(ref value), .. } if value == "A 192.0.2.1"
+    ));
+}
+
+#[tokio::test]
+async fn alias_is_created_as_apex_cname() {
+    let server = MockServer::start(vec![
+        MockResponse::json(
+            200,
+            &json!({
+                "success": true,
+                "result": {"id": "zone-1", "name": "example.com", "status": "active"}
+            }),
+        ),
+        MockResponse::json(
+            200,
+            &json!({
+                "success": true,
+                "result": {"id": "r1", "type": "CNAME", "name": "example.com", "content": "target.example.net", "ttl": 1}
+            }),
+        ),
+    ])
+    .await;
+
+    let record = provider(&server)
+        .create_record(&CreateDnsRecordRequest {
+            domain_id: "zone-1".to_string(),
+            record_type: DnsRecordType::Alias,
+            name: "@".to_string(),
+            value: "target.example.net".to_string(),
+            ttl: 1,
+            priority: None,
+            proxied: None,
+            line: None,
+            remark: None,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(record.record_type, DnsRecordType::Cname);
+    assert_eq!(record.name, "@");
+    let requests = server.requests();
+    assert!(requests[1].body.contains(r#""type":"CNAME""#));
+}
+
+#[tokio::test]
+async fn wildcard_names_keep_leading_asterisk() {
+    let zone = || {
+        MockResponse::json(
+            200,
+            &json!({
+                "success": true,
+                "result": {"id": "zone-1", "name": "example.com", "status": "active"}
+            }),
+        )
+    };
+    let server = MockServer::start(vec![
+        zone(),
+        MockResponse::json(
+            200,
+            &json!({
+                "success": true,
+                "result": [
+                    {"id": "r1", "type": "A", "name": "*.example.com", "content": "192.0.2.1", "ttl": 1},
+                    {"id": "r2", "type": "A", "name": "*.foo.example.com", "content": "192.0.2.2", "ttl": 1}
+                ],
+                "result_info": {"page": 1, "per_page": 20, "total_count": 2}
+            }),
+        ),
+        zone(),
+        MockResponse::json(
+            200,
+            &json!({
+                "success": true,
+                "result": {"id": "r3", "type": "A", "name": "*.example.com", "content": "192.0.2.3", "ttl": 1}
+            }),
+        ),
+    ])
+    .await;
+    let provider = provider(&server);
+
+    let response = provider
+        .list_records("zone-1", &RecordQueryParams::default())
+        .await
+        .unwrap();
+    let created = provider
+        .create_record(&CreateDnsRecordRequest {
+            domain_id: "zone-1".to_string(),
+            record_type: DnsRecordType::A,
+            name: "*".to_string(),
+            value: "192.0.2.3".to_string(),
+            ttl: 1,
+            priority: None,
+            proxied: None,
+            line: None,
+            remark: None,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(response.items[0].name, "*");
+    assert_eq!(response.items[1].name, "*.foo");
+    assert_eq!(created.name, "*");
+    assert!(
+        server.requests()[3]
+            .body
+            .contains(r#""name":"*.example.com""#)
+    );
+}
+
+#[tokio::test]
+async fn validate_credentials_separates_auth_and_network_failures() {
+    let server = MockServer::start(vec![MockResponse::json(
+        401,
+        &json!({
+            "success": false,
+            "errors": [{"code": 1000, "message": "Invalid API Token"}],
+            "result": null
+        }),
+    )])
+    .await;
+    assert!(!provider(&server).validate_credentials().await.unwrap());
+
+    // 无法连接时返回错误，而不是当作凭证无效
+    let unreachable =
+        CloudflareProvider::new("test-token".to_string()).with_base_url("http://127.0.0.1:1");
+    let err = unreachable.validate_credentials().await.unwrap_err();
+    assert!(matches!(err, ProviderError::NetworkError { .. }));
+}
@@ -33,6 +33,40 @@ pub struct CloudflareZone {
     pub id: String,
     pub name: String,
     pub status: String,
+    /// 分配给 zone 的 NS 服务器
+    #[serde(default)]
+    pub name_servers: Vec<String>,
+}
+
+/// Cloudflare DNSSEC 结构
+#[derive(Debug, Deserialize)]
+pub struct CloudflareDnssec {
+    pub status: String,
+    pub algorithm: Option<String>,
+    pub ds: Option<String>,
+}
+
+/// Cloudflare API Token 结构
+#[derive(Debug, Deserialize)]
+pub struct CloudflareToken {
+    pub id: String,
+    pub status: String,
+    #[serde(default)]
+    pub policies: Vec<CloudflareTokenPolicy>,
+}
+
+/// Cloudflare API Token 权限策略
+#[derive(Debug, Deserialize)]
+pub struct CloudflareTokenPolicy {
+    pub effect: String,
+    #[serde(default)]
+    pub permission_groups: Vec<CloudflarePermissionGroup>,
+}
+
+/// Cloudflare 权限组
+#[derive(Debug, Deserialize)]
+pub struct CloudflarePermissionGroup {
+    pub name: String,
 }
 
 /// Cloudflare DNS Record 结构
@@ -1,13 +1,86 @@
 //! Provider 公共工具函数
 
+use chrono::{DateTime, NaiveDateTime, Utc};
 use hmac::{Hmac, Mac};
 use sha2::Sha256;
 
 use crate::error::{ProviderError, Result};
-use crate::types::DnsRecordType;
+use crate::factory::get_provider_features;
+use crate::types::{DnsRecord, DnsRecordType, ProviderType, RecordQueryParams, RecordSort};
 
 type HmacSha256 = Hmac<Sha256>;
 
+// ============ 请求校验 ============
+
+/// 校验 TTL 是否在 Provider 允许的范围内
+pub fn check_ttl(provider: &ProviderType, ttl: u32) -> Result<()> {
+    let range = get_provider_features(provider).ttl_range;
+    if range.contains(ttl) {
+        return Ok(());
+    }
+
+    let detail = match range.auto {
+        Some(auto) => format!(
+            "TTL 必须为 {auto}（自动）或在 {}-{} 之间",
+            range.min, range.max
+        ),
+        None => format!("TTL 必须在 {}-{} 之间", range.min, range.max),
+    };
+    Err(ProviderError::InvalidParameter {
+        provider: provider.to_string(),
+        param: "ttl".to_string(),
+        detail,
+        request_id: None,
+    })
+}
+
+/// 校验记录类型是否被 Provider 支持
+pub fn check_record_type(provider: &ProviderType, record_type: &DnsRecordType) -> Result<()> {
+    if get_provider_features(provider)
+        .supported_record_types
+        .contains(record_type)
+    {
+        return Ok(());
+    }
+
+    // ALIAS 是可选能力，前端据 NotSupported 提示改用其他方式
+    if *record_type == DnsRecordType::Alias {
+        return Err(ProviderError::NotSupported {
+            provider: provider.to_string(),
+            capability: "alias_record".to_string(),
+        });
+    }
+
+    Err(ProviderError::InvalidParameter {
+        provider: provider.to_string(),
+        param: "record_type".to_string(),
+        detail: format!("不支持的记录类型: {}", record_type_to_string(record_type)),
+        request_id: None,
+    })
+}
+
+/// 校验根域（@）CNAME 是否被 Provider 支持
+pub fn check_apex_cname(
+    provider: &ProviderType,
+    record_type: &DnsRecordType,
+    name: &str,
+) -> Result<()> {
+    let is_apex = name.is_empty() || name == "@";
+    if *record_type != DnsRecordType::Cname
+        || !is_apex
+        || get_provider_features(provider).supports_apex_cname
+    {
+        return Ok(());
+    }
+
+    Err(ProviderError::InvalidParameter {
+        provider: provider.to_string(),
+        param: "name".to_string(),
+        detail: "不支持在根域（@）创建 CNAME 记录".to_string(),
+        request_id: None,
+    })
+}
+
 // ============ 记录类型转换 ============
 
 /// 将字符串转换为 `DnsRecordType`
@@ -21,10 +94,12 @@ pub fn parse_record_type(record_type: &str, provider: &str) -> Result<DnsRecordT
         "NS" => Ok(DnsRecordType::Ns),
         "SRV" => Ok(DnsRecordType::Srv),
         "CAA" => Ok(DnsRecordType::Caa),
+        "ALIAS" => Ok(DnsRecordType::Alias),
         _ => Err(ProviderError::InvalidParameter {
             provider: provider.to_string(),
             param: "record_type".to_string(),
             detail: format!("不支持的记录类型: {record_type}"),
+            request_id: None,
         }),
     }
 }
@@ -40,6 +115,7 @@ pub fn record_type_to_string(record_type: &DnsRecordType) -> &'static str {
         DnsRecordType::Ns => "NS",
         DnsRecordType::Srv => "SRV",
         DnsRecordType::Caa => "CAA",
+        DnsRecordType::Alias => "ALIAS",
     }
 }
 
@@ -87,3 +163,347 @@ pub fn relative_to_full_name(relative_name: &str, zone_name: &str) -> String {
         format!("{relative_name}.{zone}")
     }
 }
+
+// ============ 国际化域名 ============
+
+/// 将国际化域名或记录名称编码为 ASCII（punycode），如 "例え.jp" -> "xn--r8jz45g.jp"
+///
+/// 纯 ASCII 名称（含 `@`、`*`、`_dmarc` 等）原样返回，无法编码时返回 None
+pub fn domain_to_ascii(name: &str) -> Option<String> {
+    if name.is_ascii() {
+        return Some(name.to_string());
+    }
+    idna::domain_to_ascii(name).ok()
+}
+
+/// 将 punycode 域名解码为 Unicode 用于显示，无法解码时原样返回
+pub fn domain_to_unicode(name: &str) -> String {
+    if !name.to_ascii_lowercase().contains("xn--") {
+        return name.to_string();
+    }
+    match idna::domain_to_unicode(name) {
+        (unicode, Ok(())) => unicode,
+        (_, Err(_)) => name.to_string(),
+    }
+}
+
+// ============ 记录值处理 ============
+
+/// 记录值是否为主机名（CNAME/MX/NS/ALIAS），这类值在各 Provider 间末尾点的约定不同
+fn is_hostname_value(record_type: &DnsRecordType) -> bool {
+    matches!(
+        record_type,
+        DnsRecordType::Cname | DnsRecordType::Mx | DnsRecordType::Ns | DnsRecordType::Alias
+    )
+}
+
+/// 将记录值转换为内部规范形式
+///
+/// 内部统一使用不带末尾点的主机名（如 `target.example.com`），
+/// TXT 使用拼接后的单个逻辑值（不带引号），
+/// 各 Provider 读取记录和写入前都先经过此函数
+pub fn normalize_record_value(record_type: &DnsRecordType, value: &str) -> String {
+    let value = value.trim();
+    if is_hostname_value(record_type) {
+        value.trim_end_matches('.').to_string()
+    } else if *record_type == DnsRecordType::Txt {
+        join_txt_chunks(value).unwrap_or_else(|| value.to_string())
+    } else {
+        value.to_string()
+    }
+}
+
+/// 将记录值转换为带末尾点的 FQDN 形式（供要求 FQDN 的 Provider 写入时使用）
+pub fn fqdn_record_value(record_type: &DnsRecordType, value: &str) -> String {
+    let value = normalize_record_value(record_type, value);
+    if is_hostname_value(record_type) && !value.is_empty() {
+        format!("{value}.")
+    } else {
+        value
+    }
+}
+
+// ============ TXT 分段 ============
+
+/// TXT 记录中单个字符串的最大长度（字节）
+const TXT_CHUNK_MAX_LEN: usize = 255;
+
+/// 将带引号的多段 TXT 值（如 `"v=DKIM1; " "p=MIGf..."`）拼接为一个逻辑值
+///
+/// 值不是完整的引号字符串序列时返回 None
+fn join_txt_chunks(value: &str) -> Option<String> {
+    let mut joined = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+    if chars.peek() != Some(&'"') {
+        return None;
+    }
+
+    while let Some(c) = chars.next() {
+        if c != '"' {
+            return None;
+        }
+        loop {
+            match chars.next()? {
+                '"' => break,
+                '\\' => joined.push(chars.next()?),
+                c => joined.push(c),
+            }
+        }
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+    }
+    Some(joined)
+}
+
+/// 将逻辑 TXT 值按 255 字节拆分为多段（不拆开多字节字符）
+fn split_txt_value(value: &str) -> Vec<&str> {
+    let mut chunks = Vec::new();
+    let mut rest = value;
+    while rest.len() > TXT_CHUNK_MAX_LEN {
+        let mut end = TXT_CHUNK_MAX_LEN;
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        let (chunk, tail) = rest.split_at(end);
+        chunks.push(chunk);
+        rest = tail;
+    }
+    chunks.push(rest);
+    chunks
+}
+
+/// 将逻辑 TXT 值转换为带引号的多段形式（供要求显式分段的 Provider 写入时使用）
+pub fn quoted_txt_value(value: &str) -> String {
+    split_txt_value(&normalize_record_value(&DnsRecordType::Txt, value))
+        .into_iter()
+        .map(|chunk| format!("\"{}\"", chunk.replace('\\', "\\\\").replace('"', "\\\"")))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// ============ 修改时间过滤 ============
+
+/// 解析查询参数中的 `modified_after`，格式错误时返回 `InvalidParameter`
+pub fn parse_modified_after(
+    provider: &ProviderType,
+    params: &RecordQueryParams,
+) -> Result<Option<DateTime<Utc>>> {
+    let Some(value) = params.modified_after.as_deref().filter(|v| !v.is_empty()) else {
+        return Ok(None);
+    };
+
+    DateTime::parse_from_rfc3339(value)
+        .map(|t| Some(t.with_timezone(&Utc)))
+        .map_err(|e| ProviderError::InvalidParameter {
+            provider: provider.to_string(),
+            param: "modified_after".to_string(),
+            detail: format!("无效的 RFC3339 时间: {e}"),
+            request_id: None,
+        })
+}
+
+/// 解析记录的修改时间，无时区信息的时间（如 DNSPod、华为云）按 UTC 处理
+fn parse_record_time(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(t) = DateTime::parse_from_rfc3339(value) {
+        return Some(t.with_timezone(&Utc));
+    }
+    ["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S%.f"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .map(|t| t.and_utc())
+}
+
+/// 只保留 `since` 之后修改过的记录，修改时间缺失或无法解析的记录保留
+pub fn retain_modified_after(records: &mut Vec<DnsRecord>, since: Option<DateTime<Utc>>) {
+    let Some(since) = since else {
+        return;
+    };
+    records.retain(|r| {
+        r.updated_at
+            .as_deref()
+            .and_then(parse_record_time)
+            .is_none_or(|t| t > since)
+    });
+}
+
+// ============ 排序 ============
+
+/// 按查询参数对当前页的记录排序（供不支持服务端排序的 Provider 使用）
+pub fn sort_records(records: &mut [DnsRecord], params: &RecordQueryParams) {
+    let Some(sort_by) = params.sort_by else {
+        return;
+    };
+    records.sort_by(|a, b| {
+        let ordering = match sort_by {
+            RecordSort::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            RecordSort::Type => {
+                record_type_to_string(&a.record_type).cmp(record_type_to_string(&b.record_type))
+            }
+            RecordSort::Ttl => a.ttl.cmp(&b.ttl),
+        };
+        if params.sort_desc {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+}
+
+// ============ 日志脱敏 ============
+
+/// 日志中需要遮盖的字段名（不区分大小写，包含即匹配）
+const SENSITIVE_KEYS: &[&str] = &[
+    "authorization",
+    "signature",
+    "credential",
+    "secret",
+    "accesskey",
+    "access_key",
+    "token",
+    "password",
+];
+
+/// 遮盖文本中凭证、签名等字段的值，用于 debug 日志
+///
+/// 识别 `key=value`（query string、签名头）、`key: value`（请求头）和 `"key": "value"`（JSON）
+pub fn redact(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let len = bytes.len();
+    let mut out = String::with_capacity(len);
+    let mut copied = 0;
+    let mut i = 0;
+
+    while i < len {
+        if !is_key_byte(bytes[i]) {
+            i += 1;
+            continue;
+        }
+
+        let key_start = i;
+        while i < len && is_key_byte(bytes[i]) {
+            i += 1;
+        }
+        if !is_sensitive_key(&text[key_start..i]) {
+            continue;
+        }
+
+        // 跳过 JSON key 的右引号和空白，找到分隔符
+        let mut j = i;
+        if j < len && bytes[j] == b'"' {
+            j += 1;
+        }
+        while j < len && bytes[j] == b' ' {
+            j += 1;
+        }
+        if j >= len || (bytes[j] != b'=' && bytes[j] != b':') {
+            continue;
+        }
+        j += 1;
+        while j < len && bytes[j] == b' ' {
+            j += 1;
+        }
+
+        let quoted = j < len && bytes[j] == b'"';
+        if quoted {
+            j += 1;
+        }
+        let value_start = j;
+        while j < len && !is_value_end(bytes[j], quoted) {
+            j += 1;
+        }
+
+        if j > value_start {
+            out.push_str(&text[copied..value_start]);
+            out.push_str("***");
+            copied = j;
+        }
+        i = j;
+    }
+
+    out.push_str(&text[copied..]);
+    out
+}
+
+fn is_key_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_' || b == b'-'
+}
+
+fn is_sensitive_key(key: &str) -> bool {
+    let key = key.to_ascii_lowercase();
+    SENSITIVE_KEYS.iter().any(|k| key.contains(k))
+}
+
+fn is_value_end(b: u8, quoted: bool) -> bool {
+    if quoted {
+        b == b'"'
+    } else {
+        matches!(b, b'&' | b',' | b'"' | b'}' | b'\n')
+    }
+}
+
+// ============ 响应辅助 ============
+
+/// 读取响应头的值（用于获取上游请求 ID）
+pub fn header_value(response: &reqwest::Response, name: &str) -> Option<String> {
+    response
+        .headers()
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{domain_to_ascii, domain_to_unicode, redact};
+
+    #[test]
+    fn mixed_script_domain_round_trips_through_punycode() {
+        let ascii = domain_to_ascii("mañana例え.com").unwrap();
+        assert_eq!(ascii, "xn--maana-pta0595fij8b.com");
+        assert_eq!(domain_to_unicode(&ascii), "mañana例え.com");
+
+        assert_eq!(
+            domain_to_ascii("_dmarc.例え.jp").unwrap(),
+            "_dmarc.xn--r8jz45g.jp"
+        );
+        assert_eq!(domain_to_ascii("@").as_deref(), Some("@"));
+        assert_eq!(domain_to_unicode("www.example.com"), "www.example.com");
+        // 非法的 punycode 保持原样
+        assert_eq!(domain_to_unicode("xn--zzzz.jp"), "xn--zzzz.jp");
+    }
+
+    #[test]
+    fn redact_query_string_values() {
+        assert_eq!(
+            redact("Action=DescribeDomains&AccessKeyId=LTAI123&Signature=abc%3D&Format=JSON"),
+            "Action=DescribeDomains&AccessKeyId=***&Signature=***&Format=JSON"
+        );
+    }
+
+    #[test]
+    fn redact_header_values_case_insensitively() {
+        assert_eq!(
+            redact("Host: dns.example.com\nAUTHORIZATION: Bearer abc.def\nX-Auth-Token: t0k\n"),
+            "Host: dns.example.com\nAUTHORIZATION: ***\nX-Auth-Token: ***\n"
+        );
+        assert_eq!(
+            redact("authorization: SDK-HMAC-SHA256 Access=ak, SignedHeaders=host, Signature=sig"),
+            "authorization: ***, SignedHeaders=host, Signature=***"
+        );
+    }
+
+    #[test]
+    fn redact_json_values() {
+        assert_eq!(
+            redact(r#"{"apiToken":"tok-1","name":"www","SecretKey": "s3cr3t"}"#),
+            r#"{"apiToken":"***","name":"www","SecretKey": "***"}"#
+        );
+    }
+
+    #[test]
+    fn redact_keeps_non_sensitive_values() {
+        let text = r#"name=www&type=A&value=192.0.2.1 {"ttl":600,"line":"default"}"#;
+        assert_eq!(redact(text), text);
+        // 空值无需遮盖
+        assert_eq!(redact("password=&page=1"), "password=&page=1");
+    }
+}
@@ -2,6 +2,7 @@
 
 use crate::error::ProviderError;
 use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
+use crate::types::ProviderType;
 
 use super::DnspodProvider;
 
@@ -9,7 +10,7 @@ use super::DnspodProvider;
 /// 参考: <https://cloud.tencent.com/document/api/1427/56192>
 impl ProviderErrorMapper for DnspodProvider {
     fn provider_name(&self) -> &'static str {
-        "dnspod"
+        ProviderType::Dnspod.as_str()
     }
 
     fn map_error(&self, raw: RawApiError, context: ErrorContext) -> ProviderError {
@@ -34,6 +35,7 @@ impl ProviderErrorMapper for DnspodProvider {
             ) => ProviderError::InvalidCredentials {
                 provider: self.provider_name().to_string(),
                 raw_message: Some(raw.message.clone()),
+                request_id: None,
             },
 
             // ============ 配额/频率限制 ============
@@ -67,6 +69,7 @@ impl ProviderErrorMapper for DnspodProvider {
             ) => ProviderError::QuotaExceeded {
                 provider: self.provider_name().to_string(),
                 raw_message: Some(raw.message),
+                request_id: None,
             },
 
             // ============ 记录已存在 ============
@@ -74,6 +77,8 @@ impl ProviderErrorMapper for DnspodProvider {
                 provider: self.provider_name().to_string(),
                 record_name: context.record_name.unwrap_or_default(),
                 raw_message: Some(raw.message),
+                existing_value: None,
+                request_id: None,
             },
 
             // ============ 域名不存在 ============
@@ -82,6 +87,7 @@ impl ProviderErrorMapper for DnspodProvider {
                     provider: self.provider_name().to_string(),
                     domain: context.domain.unwrap_or_default(),
                     raw_message: Some(raw.message),
+                    request_id: None,
                 }
             }
 
@@ -97,6 +103,7 @@ impl ProviderErrorMapper for DnspodProvider {
                 provider: self.provider_name().to_string(),
                 domain: context.domain.unwrap_or_default(),
                 raw_message: Some(raw.message),
+                request_id: None,
             },
 
             // ============ 权限/操作被拒绝 ============
@@ -122,6 +129,7 @@ impl ProviderErrorMapper for DnspodProvider {
             ) => ProviderError::PermissionDenied {
                 provider: self.provider_name().to_string(),
                 raw_message: Some(raw.message),
+                request_id: None,
             },
 
             // ============ 参数无效 - 线路 ============
@@ -130,6 +138,7 @@ impl ProviderErrorMapper for DnspodProvider {
                     provider: self.provider_name().to_string(),
                     param: "line".to_string(),
                     detail: raw.message,
+                    request_id: None,
                 }
             }
 
@@ -138,6 +147,7 @@ impl ProviderErrorMapper for DnspodProvider {
                 provider: self.provider_name().to_string(),
                 param: "type".to_string(),
                 detail: raw.message,
+                request_id: None,
             },
 
             // ============ 参数无效 - 记录值 ============
@@ -148,6 +158,7 @@ impl ProviderErrorMapper for DnspodProvider {
                 provider: self.provider_name().to_string(),
                 param: "value".to_string(),
                 detail: raw.message,
+                request_id: None,
             },
 
             // ============ 参数无效 - 子域名 ============
@@ -155,6 +166,7 @@ impl ProviderErrorMapper for DnspodProvider {
                 provider: self.provider_name().to_string(),
                 param: "subdomain".to_string(),
                 detail: raw.message,
+                request_id: None,
             },
 
             // ============ 参数无效 - MX优先级 ============
@@ -162,6 +174,7 @@ impl ProviderErrorMapper for DnspodProvider {
                 provider: self.provider_name().to_string(),
                 param: "mx".to_string(),
                 detail: raw.message,
+                request_id: None,
             },
 
             // ============ 参数无效 - 域名 ============
@@ -174,6 +187,7 @@ impl ProviderErrorMapper for DnspodProvider {
                 provider: self.provider_name().to_string(),
                 param: "domain".to_string(),
                 detail: raw.message,
+                request_id: None,
             },
 
             // ============ 参数无效 - 记录ID ============
@@ -181,8 +195,19 @@ impl ProviderErrorMapper for DnspodProvider {
                 provider: self.provider_name().to_string(),
                 param: "record_id".to_string(),
                 detail: raw.message,
+                request_id: None,
             },
 
+            // ============ 参数无效 - 其他 ============
+            Some(code) if code.starts_with("InvalidParameter.") => {
+                ProviderError::InvalidParameter {
+                    provider: self.provider_name().to_string(),
+                    param: code.trim_start_matches("InvalidParameter.").to_string(),
+                    detail: raw.message,
+                    request_id: None,
+                }
+            }
+
             // ============ 其他错误 fallback ============
             _ => self.unknown_error(raw),
         }
@@ -4,6 +4,7 @@ use chrono::Utc;
 use serde::{Deserialize, Serialize};
 
 use crate::error::{ProviderError, Result};
+use crate::providers::common::redact;
 use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
 
 use super::{DNSPOD_API_HOST, DNSPOD_VERSION, DnspodProvider, TencentResponse};
@@ -14,6 +15,7 @@ impl DnspodProvider {
         &self,
         action: &str,
         body: &B,
+        ctx: ErrorContext,
     ) -> Result<T> {
         let payload =
             serde_json::to_string(body).map_err(|e| ProviderError::SerializationError {
@@ -24,13 +26,13 @@ impl DnspodProvider {
         let timestamp = Utc::now().timestamp();
         let authorization = self.sign(action, &payload, timestamp);
 
-        let url = format!("https://{DNSPOD_API_HOST}");
-        log::debug!("POST {url} Action: {action}");
-        log::debug!("Request Body: {payload}");
+        let url = &self.base_url;
+        log::debug!("POST {} Action: {action}", redact(url));
+        log::debug!("Request Body: {}", redact(&payload));
 
         let response = self
             .client
-            .post(&url)
+            .post(url)
             .header("Content-Type", "application/json; charset=utf-8")
             .header("Host", DNSPOD_API_HOST)
             .header("X-TC-Action", action)
@@ -50,21 +52,20 @@ impl DnspodProvider {
             .await
             .map_err(|e| self.network_error(format!("读取响应失败: {e}")))?;
 
-        log::debug!("Response Body: {response_text}");
+        log::debug!("Response Body: {}", redact(&response_text));
 
         let tc_response: TencentResponse<T> =
             serde_json::from_str(&response_text).map_err(|e| {
                 log::error!("JSON 解析失败: {e}");
-                log::error!("原始响应: {response_text}");
+                log::error!("原始响应: {}", redact(&response_text));
                 self.parse_error(e)
             })?;
 
         if let Some(error) = tc_response.response.error {
             log::error!("API 错误: {} - {}", error.code, error.message);
-            return Err(self.map_error(
-                RawApiError::with_code(&error.code, &error.message),
-                ErrorContext::default(),
-            ));
+            return Err(self
+                .map_error(RawApiError::with_code(&error.code, &error.message), ctx)
+                .with_request_id(Some(tc_response.response.request_id)));
         }
 
         tc_response
@@ -6,11 +6,14 @@ mod provider;
 mod sign;
 mod types;
 
+#[cfg(test)]
+mod tests;
+
 use reqwest::Client;
 
 pub(crate) use types::{
-    CreateRecordResponse, DomainListResponse, ModifyRecordResponse, RecordListResponse,
-    TencentResponse,
+    CreateRecordResponse, DnspodDomainInfo, DomainInfoResponse, DomainListResponse,
+    ModifyRecordResponse, RecordLineListResponse, RecordListResponse, TencentResponse,
 };
 
 pub(crate) const DNSPOD_API_HOST: &str = "dnspod.tencentcloudapi.com";
@@ -22,14 +25,29 @@ pub struct DnspodProvider {
     pub(crate) client: Client,
     pub(crate) secret_id: String,
     pub(crate) secret_key: String,
+    /// API 根地址，默认为 `https://{DNSPOD_API_HOST}`
+    pub(crate) base_url: String,
 }
 
 impl DnspodProvider {
     pub fn new(secret_id: String, secret_key: String) -> Self {
+        Self::with_client(Client::new(), secret_id, secret_key)
+    }
+
+    /// 使用自定义 HTTP 客户端创建（代理、超时等配置）
+    pub fn with_client(client: Client, secret_id: String, secret_key: String) -> Self {
         Self {
-            client: Client::new(),
+            client,
             secret_id,
             secret_key,
+            base_url: format!("https://{DNSPOD_API_HOST}"),
         }
     }
+
+    /// 替换 API 根地址（测试时指向 mock server）
+    #[cfg(test)]
+    pub(crate) fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
 }
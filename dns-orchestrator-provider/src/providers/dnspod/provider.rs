@@ -4,16 +4,20 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
 use crate::error::{ProviderError, Result};
-use crate::providers::common::{parse_record_type, record_type_to_string};
-use crate::traits::{DnsProvider, ProviderErrorMapper};
+use crate::providers::common::{
+    check_apex_cname, check_record_type, check_ttl, fqdn_record_value, normalize_record_value,
+    parse_modified_after, parse_record_type, record_type_to_string, relative_to_full_name,
+    retain_modified_after, sort_records,
+};
+use crate::traits::{DnsProvider, ErrorContext, ProviderErrorMapper, record_matches};
 use crate::types::{
-    CreateDnsRecordRequest, DnsRecord, Domain, DomainStatus, PaginatedResponse, PaginationParams,
-    ProviderType, RecordQueryParams, UpdateDnsRecordRequest,
+    CreateDnsRecordRequest, DnsRecord, DnsRecordType, Domain, DomainStatus, PaginatedResponse,
+    PaginationParams, ProviderType, RecordLine, RecordQueryParams, UpdateDnsRecordRequest,
 };
 
 use super::{
-    CreateRecordResponse, DnspodProvider, DomainListResponse, ModifyRecordResponse,
-    RecordListResponse,
+    CreateRecordResponse, DnspodDomainInfo, DnspodProvider, DomainInfoResponse, DomainListResponse,
+    ModifyRecordResponse, RecordLineListResponse, RecordListResponse,
 };
 
 impl DnspodProvider {
@@ -26,12 +30,137 @@ impl DnspodProvider {
             _ => DomainStatus::Unknown,
         }
     }
+
+    /// 调用 `DescribeDomain`，`domain_id` 可以是数字 ID 或域名
+    pub(crate) async fn describe_domain(&self, domain_id: &str) -> Result<DnspodDomainInfo> {
+        #[derive(Serialize)]
+        struct DescribeDomainRequest {
+            /// 传了 `DomainId` 时 API 忽略此参数
+            #[serde(rename = "Domain")]
+            domain: String,
+            #[serde(rename = "DomainId", skip_serializing_if = "Option::is_none")]
+            domain_id: Option<u64>,
+        }
+
+        let req = DescribeDomainRequest {
+            domain: domain_id.to_string(),
+            domain_id: domain_id.parse().ok(),
+        };
+
+        let response: DomainInfoResponse = self
+            .request("DescribeDomain", &req, ErrorContext::for_domain(domain_id))
+            .await?;
+        Ok(response.domain_info)
+    }
+
+    /// 调用 `DescribeRecordList`，`subdomain` 为主机记录精确过滤
+    pub(crate) async fn describe_records(
+        &self,
+        domain_id: &str,
+        params: &RecordQueryParams,
+        subdomain: Option<&str>,
+    ) -> Result<PaginatedResponse<DnsRecord>> {
+        #[derive(Serialize)]
+        struct DescribeRecordListRequest {
+            #[serde(rename = "Domain")]
+            domain: String,
+            #[serde(rename = "Offset")]
+            offset: u32,
+            #[serde(rename = "Limit")]
+            limit: u32,
+            #[serde(rename = "Keyword", skip_serializing_if = "Option::is_none")]
+            keyword: Option<String>,
+            #[serde(rename = "Subdomain", skip_serializing_if = "Option::is_none")]
+            subdomain: Option<String>,
+            #[serde(rename = "RecordType", skip_serializing_if = "Option::is_none")]
+            record_type: Option<String>,
+        }
+
+        let modified_after = parse_modified_after(&ProviderType::Dnspod, params)?;
+        let domain_info = self.get_domain(domain_id).await?;
+
+        let offset = (params.page - 1) * params.page_size;
+        let req = DescribeRecordListRequest {
+            domain: domain_info.name.clone(),
+            offset,
+            limit: params.page_size.min(100),
+            keyword: params.keyword.clone().filter(|k| !k.is_empty()),
+            subdomain: subdomain.map(str::to_string),
+            record_type: params
+                .record_type
+                .as_ref()
+                .map(|t| record_type_to_string(t).to_string()),
+        };
+
+        let response: Result<RecordListResponse> = self
+            .request(
+                "DescribeRecordList",
+                &req,
+                ErrorContext::for_domain(&domain_info.name),
+            )
+            .await;
+
+        match response {
+            Ok(data) => {
+                let total_count = data
+                    .record_count_info
+                    .and_then(|c| c.total_count)
+                    .unwrap_or(0);
+
+                let mut records: Vec<DnsRecord> = data
+                    .record_list
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|r| {
+                        let record_type = parse_record_type(&r.record_type, "dnspod").ok()?;
+                        let value = normalize_record_value(&record_type, &r.value);
+                        Some(DnsRecord {
+                            id: r.record_id.to_string(),
+                            domain_id: domain_id.to_string(),
+                            record_type,
+                            fqdn: Some(relative_to_full_name(&r.name, &domain_info.name)),
+                            name: r.name,
+                            value,
+                            ttl: r.ttl,
+                            priority: r.mx,
+                            proxied: None,
+                            line: r.line,
+                            remark: r.remark.filter(|r| !r.is_empty()),
+                            enabled: r.status.as_deref().map(|s| s == "ENABLE"),
+                            created_at: None,
+                            updated_at: r.updated_on,
+                        })
+                    })
+                    .collect();
+                retain_modified_after(&mut records, modified_after);
+                sort_records(&mut records, params);
+
+                Ok(PaginatedResponse::new(
+                    records,
+                    params.page,
+                    params.page_size,
+                    total_count,
+                ))
+            }
+            Err(ProviderError::Unknown { raw_code, .. })
+                if raw_code.as_deref() == Some("ResourceNotFound.NoDataOfRecord") =>
+            {
+                Ok(PaginatedResponse::new(
+                    vec![],
+                    params.page,
+                    params.page_size,
+                    0,
+                ))
+            }
+            Err(e) => Err(e),
+        }
+    }
 }
 
 #[async_trait]
 impl DnsProvider for DnspodProvider {
     fn id(&self) -> &'static str {
-        "dnspod"
+        ProviderType::Dnspod.as_str()
     }
 
     async fn validate_credentials(&self) -> Result<bool> {
@@ -49,15 +178,12 @@ impl DnsProvider for DnspodProvider {
         };
 
         match self
-            .request::<DomainListResponse, _>("DescribeDomainList", &req)
+            .request::<DomainListResponse, _>("DescribeDomainList", &req, ErrorContext::default())
             .await
         {
             Ok(_) => Ok(true),
             Err(ProviderError::InvalidCredentials { .. }) => Ok(false),
-            Err(e) => {
-                log::warn!("凭证验证失败: {e}");
-                Ok(false)
-            }
+            Err(e) => Err(e),
         }
     }
 
@@ -77,7 +203,9 @@ impl DnsProvider for DnspodProvider {
             limit: params.page_size.min(100),
         };
 
-        let response: DomainListResponse = self.request("DescribeDomainList", &req).await?;
+        let response: DomainListResponse = self
+            .request("DescribeDomainList", &req, ErrorContext::default())
+            .await?;
 
         let total_count = response
             .domain_count_info
@@ -106,21 +234,15 @@ impl DnsProvider for DnspodProvider {
     }
 
     async fn get_domain(&self, domain_id: &str) -> Result<Domain> {
-        let params = PaginationParams {
-            page: 1,
-            page_size: 100,
-        };
-        let response = self.list_domains(&params).await?;
-
-        response
-            .items
-            .into_iter()
-            .find(|d| d.id == domain_id)
-            .ok_or_else(|| ProviderError::DomainNotFound {
-                provider: self.provider_name().to_string(),
-                domain: domain_id.to_string(),
-                raw_message: None,
-            })
+        let info = self.describe_domain(domain_id).await?;
+
+        Ok(Domain {
+            id: info.domain_id.to_string(),
+            name: info.domain,
+            provider: ProviderType::Dnspod,
+            status: Self::convert_domain_status(&info.status),
+            record_count: info.record_count,
+        })
     }
 
     async fn list_records(
@@ -128,86 +250,49 @@ impl DnsProvider for DnspodProvider {
         domain_id: &str,
         params: &RecordQueryParams,
     ) -> Result<PaginatedResponse<DnsRecord>> {
-        #[derive(Serialize)]
-        struct DescribeRecordListRequest {
-            #[serde(rename = "Domain")]
-            domain: String,
-            #[serde(rename = "Offset")]
-            offset: u32,
-            #[serde(rename = "Limit")]
-            limit: u32,
-            #[serde(rename = "Keyword", skip_serializing_if = "Option::is_none")]
-            keyword: Option<String>,
-            #[serde(rename = "RecordType", skip_serializing_if = "Option::is_none")]
-            record_type: Option<String>,
-        }
-
-        let domain_info = self.get_domain(domain_id).await?;
+        self.describe_records(domain_id, params, None).await
+    }
 
-        let offset = (params.page - 1) * params.page_size;
-        let req = DescribeRecordListRequest {
-            domain: domain_info.name,
-            offset,
-            limit: params.page_size.min(100),
-            keyword: params.keyword.clone().filter(|k| !k.is_empty()),
-            record_type: params
-                .record_type
-                .as_ref()
-                .map(|t| record_type_to_string(t).to_string()),
+    /// 使用 `Subdomain` 在服务端按主机记录精确过滤，类型在本地过滤
+    async fn find_records(
+        &self,
+        domain_id: &str,
+        name: &str,
+        record_type: Option<DnsRecordType>,
+    ) -> Result<Vec<DnsRecord>> {
+        let mut params = RecordQueryParams {
+            page: 1,
+            page_size: 100,
+            ..Default::default()
         };
-
-        let response: Result<RecordListResponse> = self.request("DescribeRecordList", &req).await;
-
-        match response {
-            Ok(data) => {
-                let total_count = data
-                    .record_count_info
-                    .and_then(|c| c.total_count)
-                    .unwrap_or(0);
-
-                let records = data
-                    .record_list
-                    .unwrap_or_default()
+        let mut records = Vec::new();
+
+        loop {
+            let response = self
+                .describe_records(domain_id, &params, Some(name))
+                .await?;
+            let fetched = response.items.len();
+            records.extend(
+                response
+                    .items
                     .into_iter()
-                    .filter_map(|r| {
-                        let record_type = parse_record_type(&r.record_type, "dnspod").ok()?;
-                        Some(DnsRecord {
-                            id: r.record_id.to_string(),
-                            domain_id: domain_id.to_string(),
-                            record_type,
-                            name: r.name,
-                            value: r.value,
-                            ttl: r.ttl,
-                            priority: r.mx,
-                            proxied: None,
-                            created_at: None,
-                            updated_at: r.updated_on,
-                        })
-                    })
-                    .collect();
+                    .filter(|r| record_matches(r, name, record_type.as_ref())),
+            );
 
-                Ok(PaginatedResponse::new(
-                    records,
-                    params.page,
-                    params.page_size,
-                    total_count,
-                ))
+            if !response.has_more || fetched == 0 {
+                break;
             }
-            Err(ProviderError::Unknown { raw_code, .. })
-                if raw_code.as_deref() == Some("ResourceNotFound.NoDataOfRecord") =>
-            {
-                Ok(PaginatedResponse::new(
-                    vec![],
-                    params.page,
-                    params.page_size,
-                    0,
-                ))
-            }
-            Err(e) => Err(e),
+            params.page += 1;
         }
+
+        Ok(records)
     }
 
     async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
+        check_record_type(&ProviderType::Dnspod, &req.record_type)?;
+        check_ttl(&ProviderType::Dnspod, req.ttl)?;
+        check_apex_cname(&ProviderType::Dnspod, &req.record_type, &req.name)?;
+
         #[derive(Serialize)]
         struct CreateRecordRequest {
             #[serde(rename = "Domain")]
@@ -224,21 +309,32 @@ impl DnsProvider for DnspodProvider {
             ttl: u32,
             #[serde(rename = "MX", skip_serializing_if = "Option::is_none")]
             mx: Option<u16>,
+            #[serde(rename = "Remark", skip_serializing_if = "Option::is_none")]
+            remark: Option<String>,
         }
 
         let domain_info = self.get_domain(&req.domain_id).await?;
 
         let api_req = CreateRecordRequest {
-            domain: domain_info.name,
+            domain: domain_info.name.clone(),
             sub_domain: req.name.clone(),
             record_type: record_type_to_string(&req.record_type).to_string(),
-            record_line: "默认".to_string(),
-            value: req.value.clone(),
+            record_line: req.line.clone().unwrap_or_else(|| "默认".to_string()),
+            // DNSPod 返回的主机名带末尾点，写入时保持一致
+            value: fqdn_record_value(&req.record_type, &req.value),
             ttl: req.ttl,
             mx: req.priority,
+            remark: req.remark.clone(),
         };
 
-        let response: CreateRecordResponse = self.request("CreateRecord", &api_req).await?;
+        let response: CreateRecordResponse = self
+            .request(
+                "CreateRecord",
+                &api_req,
+                ErrorContext::for_record_name(&domain_info.name, &req.name),
+            )
+            .await
+            .map_err(|e| e.with_record_input(&req.name, &req.value))?;
 
         let now = chrono::Utc::now().to_rfc3339();
         Ok(DnsRecord {
@@ -246,10 +342,14 @@ impl DnsProvider for DnspodProvider {
             domain_id: req.domain_id.clone(),
             record_type: req.record_type.clone(),
             name: req.name.clone(),
-            value: req.value.clone(),
+            fqdn: Some(relative_to_full_name(&req.name, &domain_info.name)),
+            value: normalize_record_value(&req.record_type, &req.value),
             ttl: req.ttl,
             priority: req.priority,
             proxied: None,
+            line: req.line.clone(),
+            remark: req.remark.clone(),
+            enabled: None,
             created_at: Some(now.clone()),
             updated_at: Some(now),
         })
@@ -260,6 +360,10 @@ impl DnsProvider for DnspodProvider {
         record_id: &str,
         req: &UpdateDnsRecordRequest,
     ) -> Result<DnsRecord> {
+        check_record_type(&ProviderType::Dnspod, &req.record_type)?;
+        check_ttl(&ProviderType::Dnspod, req.ttl)?;
+        check_apex_cname(&ProviderType::Dnspod, &req.record_type, &req.name)?;
+
         #[derive(Serialize)]
         struct ModifyRecordRequest {
             #[serde(rename = "Domain")]
@@ -278,6 +382,8 @@ impl DnsProvider for DnspodProvider {
             ttl: u32,
             #[serde(rename = "MX", skip_serializing_if = "Option::is_none")]
             mx: Option<u16>,
+            #[serde(rename = "Remark", skip_serializing_if = "Option::is_none")]
+            remark: Option<String>,
         }
 
         let record_id_num: u64 = record_id
@@ -286,22 +392,33 @@ impl DnsProvider for DnspodProvider {
                 provider: self.provider_name().to_string(),
                 record_id: record_id.to_string(),
                 raw_message: None,
+                request_id: None,
             })?;
 
         let domain_info = self.get_domain(&req.domain_id).await?;
 
         let api_req = ModifyRecordRequest {
-            domain: domain_info.name,
+            domain: domain_info.name.clone(),
             record_id: record_id_num,
             sub_domain: req.name.clone(),
             record_type: record_type_to_string(&req.record_type).to_string(),
-            record_line: "默认".to_string(),
-            value: req.value.clone(),
+            record_line: req.line.clone().unwrap_or_else(|| "默认".to_string()),
+            // DNSPod 返回的主机名带末尾点，写入时保持一致
+            value: fqdn_record_value(&req.record_type, &req.value),
             ttl: req.ttl,
             mx: req.priority,
+            remark: req.remark.clone(),
         };
 
-        let _response: ModifyRecordResponse = self.request("ModifyRecord", &api_req).await?;
+        let _response: ModifyRecordResponse = self
+            .request(
+                "ModifyRecord",
+                &api_req,
+                ErrorContext::for_record_name(&domain_info.name, &req.name)
+                    .with_record_id(record_id),
+            )
+            .await
+            .map_err(|e| e.with_record_input(&req.name, &req.value))?;
 
         let now = chrono::Utc::now().to_rfc3339();
         Ok(DnsRecord {
@@ -309,10 +426,14 @@ impl DnsProvider for DnspodProvider {
             domain_id: req.domain_id.clone(),
             record_type: req.record_type.clone(),
             name: req.name.clone(),
-            value: req.value.clone(),
+            fqdn: Some(relative_to_full_name(&req.name, &domain_info.name)),
+            value: normalize_record_value(&req.record_type, &req.value),
             ttl: req.ttl,
             priority: req.priority,
             proxied: None,
+            line: req.line.clone(),
+            remark: req.remark.clone(),
+            enabled: None,
             created_at: None,
             updated_at: Some(now),
         })
@@ -336,6 +457,7 @@ impl DnsProvider for DnspodProvider {
                 provider: self.provider_name().to_string(),
                 record_id: record_id.to_string(),
                 raw_message: None,
+                request_id: None,
             })?;
 
         let domain_info = self.get_domain(domain_id).await?;
@@ -345,8 +467,97 @@ impl DnsProvider for DnspodProvider {
             record_id: record_id_num,
         };
 
-        let _response: DeleteRecordResponse = self.request("DeleteRecord", &api_req).await?;
+        let _response: DeleteRecordResponse = self
+            .request(
+                "DeleteRecord",
+                &api_req,
+                ErrorContext::for_record(domain_id, record_id),
+            )
+            .await?;
 
         Ok(())
     }
+
+    async fn set_record_enabled(
+        &self,
+        domain_id: &str,
+        record_id: &str,
+        enabled: bool,
+    ) -> Result<()> {
+        #[derive(Serialize)]
+        struct ModifyRecordStatusRequest {
+            #[serde(rename = "Domain")]
+            domain: String,
+            #[serde(rename = "RecordId")]
+            record_id: u64,
+            /// ENABLE / DISABLE
+            #[serde(rename = "Status")]
+            status: &'static str,
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct ModifyRecordStatusResponse {}
+
+        let record_id_num: u64 = record_id
+            .parse()
+            .map_err(|_| ProviderError::RecordNotFound {
+                provider: self.provider_name().to_string(),
+                record_id: record_id.to_string(),
+                raw_message: None,
+                request_id: None,
+            })?;
+
+        let domain_info = self.get_domain(domain_id).await?;
+
+        let api_req = ModifyRecordStatusRequest {
+            domain: domain_info.name,
+            record_id: record_id_num,
+            status: if enabled { "ENABLE" } else { "DISABLE" },
+        };
+
+        let _response: ModifyRecordStatusResponse = self
+            .request(
+                "ModifyRecordStatus",
+                &api_req,
+                ErrorContext::for_record(domain_id, record_id),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// 线路随套餐等级不同，先查询域名的 `Grade`
+    async fn list_record_lines(&self, domain_id: &str) -> Result<Vec<RecordLine>> {
+        #[derive(Serialize)]
+        struct DescribeRecordLineListRequest {
+            #[serde(rename = "Domain")]
+            domain: String,
+            #[serde(rename = "DomainGrade")]
+            domain_grade: String,
+        }
+
+        let info = self.describe_domain(domain_id).await?;
+        let req = DescribeRecordLineListRequest {
+            domain_grade: info.grade.unwrap_or_else(|| "DP_FREE".to_string()),
+            domain: info.domain,
+        };
+
+        let response: RecordLineListResponse = self
+            .request(
+                "DescribeRecordLineList",
+                &req,
+                ErrorContext::for_domain(&req.domain),
+            )
+            .await?;
+
+        Ok(response
+            .line_list
+            .unwrap_or_default()
+            .into_iter()
+            .map(|line| RecordLine {
+                id: line.name.clone(),
+                name: line.name,
+            })
+            .collect())
+    }
 }
@@ -0,0 +1,242 @@
+use serde_json::json;
+
+use crate::error::ProviderError;
+use crate::providers::mock::{MockResponse, MockServer};
+use crate::traits::DnsProvider;
+use crate::types::{DnsRecordType, RecordQueryParams};
+
+use super::DnspodProvider;
+
+fn provider(server: &MockServer) -> DnspodProvider {
+    DnspodProvider::new("test-id".to_string(), "test-key".to_string()).with_base_url(server.url())
+}
+
+fn domain_info_response() -> MockResponse {
+    MockResponse::json(
+        200,
+        &json!({
+            "Response": {
+                "DomainInfo": {"DomainId": 1001, "Domain": "example.com", "Status": "ENABLE", "RecordCount": 2},
+                "RequestId": "req-domain"
+            }
+        }),
+    )
+}
+
+#[tokio::test]
+async fn list_records_parses_records() {
+    let server = MockServer::start(vec![
+        domain_info_response(),
+        MockResponse::json(
+            200,
+            &json!({
+                "Response": {
+                    "RecordList": [
+                        {"RecordId": 1, "Name": "www", "Type": "A", "Value": "1.2.3.4", "TTL": 600,
+                         "Line": "默认", "Status": "ENABLE", "Remark": "", "UpdatedOn": "2024-01-01 00:00:00"},
+                        {"RecordId": 2, "Name": "@", "Type": "MX", "Value": "mx.example.com.", "TTL": 600,
+                         "MX": 10, "Line": "电信", "Status": "DISABLE", "Remark": "backup"}
+                    ],
+                    "RecordCountInfo": {"TotalCount": 2},
+                    "RequestId": "req-records"
+                }
+            }),
+        ),
+    ])
+    .await;
+
+    let response = provider(&server)
+        .list_records("1001", &RecordQueryParams::default())
+        .await
+        .unwrap();
+
+    assert_eq!(response.total_count, 2);
+    let records = response.items;
+    assert_eq!(records[0].id, "1");
+    assert_eq!(records[0].remark, None);
+    assert_eq!(records[0].enabled, Some(true));
+    assert_eq!(records[1].record_type, DnsRecordType::Mx);
+    assert_eq!(records[1].value, "mx.example.com");
+    assert_eq!(records[1].priority, Some(10));
+    assert_eq!(records[1].line.as_deref(), Some("电信"));
+    assert_eq!(records[1].remark.as_deref(), Some("backup"));
+    assert_eq!(records[1].enabled, Some(false));
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[1].header("X-TC-Action"),
+        Some("DescribeRecordList")
+    );
+    assert!(requests[1].body.contains(r#""Domain":"example.com""#));
+}
+
+#[tokio::test]
+async fn type_filter_total_is_filtered_count() {
+    let server = MockServer::start(vec![
+        domain_info_response(),
+        MockResponse::json(
+            200,
+            &json!({
+                "Response": {
+                    "RecordList": [
+                        {"RecordId": 2, "Name": "@", "Type": "MX", "Value": "mx.example.com.", "TTL": 600, "MX": 10}
+                    ],
+                    "RecordCountInfo": {"TotalCount": 1},
+                    "RequestId": "req-records"
+                }
+            }),
+        ),
+    ])
+    .await;
+
+    let params = RecordQueryParams {
+        record_type: Some(DnsRecordType::Mx),
+        ..Default::default()
+    };
+    let response = provider(&server)
+        .list_records("1001", &params)
+        .await
+        .unwrap();
+
+    assert!(server.requests()[1].body.contains(r#""RecordType":"MX""#));
+    assert_eq!(response.total_count, 1);
+    assert!(!response.has_more);
+}
+
+#[tokio::test]
+async fn no_data_of_record_is_empty_list() {
+    let server = MockServer::start(vec![
+        domain_info_response(),
+        MockResponse::json(
+            200,
+            &json!({
+                "Response": {
+                    "Error": {"Code": "ResourceNotFound.NoDataOfRecord", "Message": "记录列表为空。"},
+                    "RequestId": "req-empty"
+                }
+            }),
+        ),
+    ])
+    .await;
+
+    let response = provider(&server)
+        .list_records("1001", &RecordQueryParams::default())
+        .await
+        .unwrap();
+
+    assert!(response.items.is_empty());
+    assert_eq!(response.total_count, 0);
+    assert!(!response.has_more);
+}
+
+#[tokio::test]
+async fn error_response_is_mapped() {
+    let server = MockServer::start(vec![MockResponse::json(
+        200,
+        &json!({
+            "Response": {
+                "Error": {"Code": "AuthFailure.SignatureFailure", "Message": "签名错误"},
+                "RequestId": "req-auth"
+            }
+        }),
+    )])
+    .await;
+
+    let err = provider(&server).get_domain("1001").await.unwrap_err();
+
+    assert!(matches!(err, ProviderError::InvalidCredentials { .. }));
+    assert_eq!(err.request_id(), Some("req-auth"));
+}
+
+// ============ 签名 ============
+
+#[test]
+fn sign_matches_reference_vector() {
+    let provider = DnspodProvider::new(
+        "AKIDz8krbsJ5yKBZQpn74WFkmLPx3EXAMPLE".to_string(),
+        "Gu5t9xGARNpq86cd98joQYCN3EXAMPLE".to_string(),
+    );
+
+    let authorization = provider.sign(
+        "DescribeDomainList",
+        r#"{"Offset":0,"Limit":20}"#,
+        1_551_113_065,
+    );
+
+    assert_eq!(
+        authorization,
+        "TC3-HMAC-SHA256 Credential=AKIDz8krbsJ5yKBZQpn74WFkmLPx3EXAMPLE/2019-02-25/dnspod/tc3_request, \
+         SignedHeaders=content-type;host;x-tc-action, \
+         Signature=2acdf4bc71f79b162325e71df91575cb3ae0e6d6a212dbd347181ddb77fddae4"
+    );
+}
+
+#[tokio::test]
+async fn find_records_uses_subdomain_filter() {
+    let server = MockServer::start(vec![
+        domain_info_response(),
+        MockResponse::json(
+            200,
+            &json!({
+                "Response": {
+                    "RecordList": [
+                        {"RecordId": 1, "Name": "home", "Type": "A", "Value": "1.2.3.4", "TTL": 600},
+                        {"RecordId": 2, "Name": "home", "Type": "TXT", "Value": "note", "TTL": 600}
+                    ],
+                    "RecordCountInfo": {"TotalCount": 2},
+                    "RequestId": "req-records"
+                }
+            }),
+        ),
+    ])
+    .await;
+
+    let records = provider(&server)
+        .find_records("1001", "home", Some(DnsRecordType::A))
+        .await
+        .unwrap();
+
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].id, "1");
+    let requests = server.requests();
+    assert!(requests[1].body.contains(r#""Subdomain":"home""#));
+}
+
+#[tokio::test]
+async fn list_record_lines_uses_domain_grade() {
+    let server = MockServer::start(vec![
+        MockResponse::json(
+            200,
+            &json!({
+                "Response": {
+                    "DomainInfo": {"DomainId": 1001, "Domain": "example.com", "Status": "ENABLE", "Grade": "DP_PLUS"},
+                    "RequestId": "req-domain"
+                }
+            }),
+        ),
+        MockResponse::json(
+            200,
+            &json!({
+                "Response": {
+                    "LineList": [
+                        {"Name": "默认", "LineId": "0"},
+                        {"Name": "电信", "LineId": "10=0"}
+                    ],
+                    "RequestId": "req-lines"
+                }
+            }),
+        ),
+    ])
+    .await;
+
+    let lines = provider(&server).list_record_lines("1001").await.unwrap();
+
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[1].id, "电信");
+    let requests = server.requests();
+    assert_eq!(
+        requests[1].header("X-TC-Action"),
+        Some("DescribeRecordLineList")
+    );
+    assert!(requests[1].body.contains(r#""DomainGrade":"DP_PLUS""#));
+}
@@ -17,7 +17,6 @@ pub struct TencentResponseInner<T> {
     #[serde(rename = "Error")]
     pub error: Option<TencentError>,
     #[serde(rename = "RequestId")]
-    #[allow(dead_code)]
     pub request_id: String,
 }
 
@@ -57,6 +56,41 @@ pub struct DnspodDomain {
     pub record_count: Option<u32>,
 }
 
+/// `DescribeDomain` 响应
+#[derive(Debug, Deserialize)]
+pub struct DomainInfoResponse {
+    #[serde(rename = "DomainInfo")]
+    pub domain_info: DnspodDomainInfo,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DnspodDomainInfo {
+    #[serde(rename = "DomainId")]
+    pub domain_id: u64,
+    #[serde(rename = "Domain")]
+    pub domain: String,
+    #[serde(rename = "Status")]
+    pub status: String,
+    #[serde(rename = "RecordCount")]
+    pub record_count: Option<u32>,
+    /// 套餐等级（如 `DP_FREE`），决定可用的解析线路
+    #[serde(rename = "Grade")]
+    pub grade: Option<String>,
+}
+
+/// `DescribeRecordLineList` 响应
+#[derive(Debug, Deserialize)]
+pub struct RecordLineListResponse {
+    #[serde(rename = "LineList")]
+    pub line_list: Option<Vec<DnspodLine>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DnspodLine {
+    #[serde(rename = "Name")]
+    pub name: String,
+}
+
 // ============ DNSPod 记录相关结构 ============
 
 #[derive(Debug, Deserialize)]
@@ -87,6 +121,13 @@ pub struct DnspodRecord {
     pub ttl: u32,
     #[serde(rename = "MX")]
     pub mx: Option<u16>,
+    #[serde(rename = "Line")]
+    pub line: Option<String>,
+    /// ENABLE / DISABLE
+    #[serde(rename = "Status")]
+    pub status: Option<String>,
+    #[serde(rename = "Remark")]
+    pub remark: Option<String>,
     #[serde(rename = "UpdatedOn")]
     pub updated_on: Option<String>,
 }
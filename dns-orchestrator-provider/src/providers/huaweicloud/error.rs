@@ -2,6 +2,7 @@
 
 use crate::error::ProviderError;
 use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
+use crate::types::ProviderType;
 
 use super::HuaweicloudProvider;
 
@@ -9,7 +10,7 @@ use super::HuaweicloudProvider;
 /// 参考: <https://support.huaweicloud.com/api-dns/ErrorCode.html>
 impl ProviderErrorMapper for HuaweicloudProvider {
     fn provider_name(&self) -> &'static str {
-        "huaweicloud"
+        ProviderType::Huaweicloud.as_str()
     }
 
     fn map_error(&self, raw: RawApiError, context: ErrorContext) -> ProviderError {
@@ -18,24 +19,29 @@ impl ProviderErrorMapper for HuaweicloudProvider {
             Some("APIGW.0301" | "APIGW.0101") => ProviderError::InvalidCredentials {
                 provider: self.provider_name().to_string(),
                 raw_message: Some(raw.message),
+                request_id: None,
             },
             // 记录已存在
             Some("DNS.0312") => ProviderError::RecordExists {
                 provider: self.provider_name().to_string(),
                 record_name: context.record_name.unwrap_or_default(),
                 raw_message: Some(raw.message),
+                existing_value: None,
+                request_id: None,
             },
             // 记录不存在
             Some("DNS.0305") => ProviderError::RecordNotFound {
                 provider: self.provider_name().to_string(),
                 record_id: context.record_id.unwrap_or_default(),
                 raw_message: Some(raw.message),
+                request_id: None,
             },
             // Zone 不存在
             Some("DNS.0101") => ProviderError::DomainNotFound {
                 provider: self.provider_name().to_string(),
                 domain: context.domain.unwrap_or_default(),
                 raw_message: Some(raw.message),
+                request_id: None,
             },
             // 其他错误 fallback
             _ => self.unknown_error(raw),
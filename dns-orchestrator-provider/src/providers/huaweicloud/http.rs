@@ -4,10 +4,11 @@ use chrono::Utc;
 use serde::{Deserialize, Serialize};
 
 use crate::error::{ProviderError, Result};
-use crate::traits::{ProviderErrorMapper, RawApiError};
+use crate::providers::common::{header_value, redact};
+use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
 
+use super::HuaweicloudProvider;
 use super::types::ErrorResponse;
-use super::{HUAWEICLOUD_DNS_HOST, HuaweicloudProvider};
 
 impl HuaweicloudProvider {
     /// 执行 GET 请求
@@ -15,29 +16,30 @@ impl HuaweicloudProvider {
         &self,
         path: &str,
         query: &str,
+        ctx: ErrorContext,
     ) -> Result<T> {
         let now = Utc::now();
         let timestamp = now.format("%Y%m%dT%H%M%SZ").to_string();
 
         let headers = vec![
-            ("Host".to_string(), HUAWEICLOUD_DNS_HOST.to_string()),
+            ("Host".to_string(), self.host.clone()),
             ("X-Sdk-Date".to_string(), timestamp.clone()),
         ];
 
         let authorization = self.sign("GET", path, query, &headers, "", &timestamp);
 
         let url = if query.is_empty() {
-            format!("https://{HUAWEICLOUD_DNS_HOST}{path}")
+            format!("{}{path}", self.base_url)
         } else {
-            format!("https://{HUAWEICLOUD_DNS_HOST}{path}?{query}")
+            format!("{}{path}?{query}", self.base_url)
         };
 
-        log::debug!("GET {url}");
+        log::debug!("GET {}", redact(&url));
 
         let response = self
             .client
             .get(&url)
-            .header("Host", HUAWEICLOUD_DNS_HOST)
+            .header("Host", &self.host)
             .header("X-Sdk-Date", &timestamp)
             .header("Authorization", authorization)
             .send()
@@ -45,26 +47,32 @@ impl HuaweicloudProvider {
             .map_err(|e| self.network_error(e))?;
 
         let status = response.status();
+        let request_id = header_value(&response, "X-Request-Id");
         let response_text = response
             .text()
             .await
             .map_err(|e| self.network_error(format!("读取响应失败: {e}")))?;
 
-        log::debug!("Response Status: {status}, Body: {response_text}");
+        log::debug!(
+            "Response Status: {status}, Body: {}",
+            redact(&response_text)
+        );
 
         if !status.is_success() {
             if let Ok(error) = serde_json::from_str::<ErrorResponse>(&response_text) {
-                return Err(self.map_error(
-                    RawApiError::with_code(
-                        error.error_code.unwrap_or_default(),
-                        error.error_msg.unwrap_or_default(),
-                    ),
-                    Default::default(),
-                ));
+                return Err(self
+                    .map_error(
+                        RawApiError::with_code(
+                            error.error_code.unwrap_or_default(),
+                            error.error_msg.unwrap_or_default(),
+                        ),
+                        ctx,
+                    )
+                    .with_request_id(request_id.clone()));
             }
-            return Err(
-                self.unknown_error(RawApiError::new(format!("HTTP {status}: {response_text}")))
-            );
+            return Err(self
+                .unknown_error(RawApiError::new(format!("HTTP {status}: {response_text}")))
+                .with_request_id(request_id));
         }
 
         serde_json::from_str(&response_text).map_err(|e| {
@@ -78,6 +86,7 @@ impl HuaweicloudProvider {
         &self,
         path: &str,
         body: &B,
+        ctx: ErrorContext,
     ) -> Result<T> {
         let payload =
             serde_json::to_string(body).map_err(|e| ProviderError::SerializationError {
@@ -89,20 +98,20 @@ impl HuaweicloudProvider {
         let timestamp = now.format("%Y%m%dT%H%M%SZ").to_string();
 
         let headers = vec![
-            ("Host".to_string(), HUAWEICLOUD_DNS_HOST.to_string()),
+            ("Host".to_string(), self.host.clone()),
             ("X-Sdk-Date".to_string(), timestamp.clone()),
             ("Content-Type".to_string(), "application/json".to_string()),
         ];
 
         let authorization = self.sign("POST", path, "", &headers, &payload, &timestamp);
 
-        let url = format!("https://{HUAWEICLOUD_DNS_HOST}{path}");
-        log::debug!("POST {url} Body: {payload}");
+        let url = format!("{}{path}", self.base_url);
+        log::debug!("POST {} Body: {}", redact(&url), redact(&payload));
 
         let response = self
             .client
             .post(&url)
-            .header("Host", HUAWEICLOUD_DNS_HOST)
+            .header("Host", &self.host)
             .header("X-Sdk-Date", &timestamp)
             .header("Content-Type", "application/json")
             .header("Authorization", authorization)
@@ -112,26 +121,32 @@ impl HuaweicloudProvider {
             .map_err(|e| self.network_error(e))?;
 
         let status = response.status();
+        let request_id = header_value(&response, "X-Request-Id");
         let response_text = response
             .text()
             .await
             .map_err(|e| self.network_error(format!("读取响应失败: {e}")))?;
 
-        log::debug!("Response Status: {status}, Body: {response_text}");
+        log::debug!(
+            "Response Status: {status}, Body: {}",
+            redact(&response_text)
+        );
 
         if !status.is_success() {
             if let Ok(error) = serde_json::from_str::<ErrorResponse>(&response_text) {
-                return Err(self.map_error(
-                    RawApiError::with_code(
-                        error.error_code.unwrap_or_default(),
-                        error.error_msg.unwrap_or_default(),
-                    ),
-                    Default::default(),
-                ));
+                return Err(self
+                    .map_error(
+                        RawApiError::with_code(
+                            error.error_code.unwrap_or_default(),
+                            error.error_msg.unwrap_or_default(),
+                        ),
+                        ctx,
+                    )
+                    .with_request_id(request_id.clone()));
             }
-            return Err(
-                self.unknown_error(RawApiError::new(format!("HTTP {status}: {response_text}")))
-            );
+            return Err(self
+                .unknown_error(RawApiError::new(format!("HTTP {status}: {response_text}")))
+                .with_request_id(request_id));
         }
 
         serde_json::from_str(&response_text).map_err(|e| {
@@ -145,6 +160,7 @@ impl HuaweicloudProvider {
         &self,
         path: &str,
         body: &B,
+        ctx: ErrorContext,
     ) -> Result<T> {
         let payload =
             serde_json::to_string(body).map_err(|e| ProviderError::SerializationError {
@@ -156,20 +172,20 @@ impl HuaweicloudProvider {
         let timestamp = now.format("%Y%m%dT%H%M%SZ").to_string();
 
         let headers = vec![
-            ("Host".to_string(), HUAWEICLOUD_DNS_HOST.to_string()),
+            ("Host".to_string(), self.host.clone()),
             ("X-Sdk-Date".to_string(), timestamp.clone()),
             ("Content-Type".to_string(), "application/json".to_string()),
         ];
 
         let authorization = self.sign("PUT", path, "", &headers, &payload, &timestamp);
 
-        let url = format!("https://{HUAWEICLOUD_DNS_HOST}{path}");
-        log::debug!("PUT {url} Body: {payload}");
+        let url = format!("{}{path}", self.base_url);
+        log::debug!("PUT {} Body: {}", redact(&url), redact(&payload));
 
         let response = self
             .client
             .put(&url)
-            .header("Host", HUAWEICLOUD_DNS_HOST)
+            .header("Host", &self.host)
             .header("X-Sdk-Date", &timestamp)
             .header("Content-Type", "application/json")
             .header("Authorization", authorization)
@@ -179,26 +195,32 @@ impl HuaweicloudProvider {
             .map_err(|e| self.network_error(e))?;
 
         let status = response.status();
+        let request_id = header_value(&response, "X-Request-Id");
         let response_text = response
             .text()
             .await
             .map_err(|e| self.network_error(format!("读取响应失败: {e}")))?;
 
-        log::debug!("Response Status: {status}, Body: {response_text}");
+        log::debug!(
+            "Response Status: {status}, Body: {}",
+            redact(&response_text)
+        );
 
         if !status.is_success() {
             if let Ok(error) = serde_json::from_str::<ErrorResponse>(&response_text) {
-                return Err(self.map_error(
-                    RawApiError::with_code(
-                        error.error_code.unwrap_or_default(),
-                        error.error_msg.unwrap_or_default(),
-                    ),
-                    Default::default(),
-                ));
+                return Err(self
+                    .map_error(
+                        RawApiError::with_code(
+                            error.error_code.unwrap_or_default(),
+                            error.error_msg.unwrap_or_default(),
+                        ),
+                        ctx,
+                    )
+                    .with_request_id(request_id.clone()));
             }
-            return Err(
-                self.unknown_error(RawApiError::new(format!("HTTP {status}: {response_text}")))
-            );
+            return Err(self
+                .unknown_error(RawApiError::new(format!("HTTP {status}: {response_text}")))
+                .with_request_id(request_id));
         }
 
         serde_json::from_str(&response_text).map_err(|e| {
@@ -208,24 +230,24 @@ impl HuaweicloudProvider {
     }
 
     /// 执行 DELETE 请求
-    pub(crate) async fn delete(&self, path: &str) -> Result<()> {
+    pub(crate) async fn delete(&self, path: &str, ctx: ErrorContext) -> Result<()> {
         let now = Utc::now();
         let timestamp = now.format("%Y%m%dT%H%M%SZ").to_string();
 
         let headers = vec![
-            ("Host".to_string(), HUAWEICLOUD_DNS_HOST.to_string()),
+            ("Host".to_string(), self.host.clone()),
             ("X-Sdk-Date".to_string(), timestamp.clone()),
         ];
 
         let authorization = self.sign("DELETE", path, "", &headers, "", &timestamp);
 
-        let url = format!("https://{HUAWEICLOUD_DNS_HOST}{path}");
-        log::debug!("DELETE {url}");
+        let url = format!("{}{path}", self.base_url);
+        log::debug!("DELETE {}", redact(&url));
 
         let response = self
             .client
             .delete(&url)
-            .header("Host", HUAWEICLOUD_DNS_HOST)
+            .header("Host", &self.host)
             .header("X-Sdk-Date", &timestamp)
             .header("Authorization", authorization)
             .send()
@@ -235,23 +257,26 @@ impl HuaweicloudProvider {
         let status = response.status();
 
         if !status.is_success() {
+            let request_id = header_value(&response, "X-Request-Id");
             let response_text = response
                 .text()
                 .await
                 .map_err(|e| self.network_error(format!("读取响应失败: {e}")))?;
 
             if let Ok(error) = serde_json::from_str::<ErrorResponse>(&response_text) {
-                return Err(self.map_error(
-                    RawApiError::with_code(
-                        error.error_code.unwrap_or_default(),
-                        error.error_msg.unwrap_or_default(),
-                    ),
-                    Default::default(),
-                ));
+                return Err(self
+                    .map_error(
+                        RawApiError::with_code(
+                            error.error_code.unwrap_or_default(),
+                            error.error_msg.unwrap_or_default(),
+                        ),
+                        ctx,
+                    )
+                    .with_request_id(request_id.clone()));
             }
-            return Err(
-                self.unknown_error(RawApiError::new(format!("HTTP {status}: {response_text}")))
-            );
+            return Err(self
+                .unknown_error(RawApiError::new(format!("HTTP {status}: {response_text}")))
+                .with_request_id(request_id));
         }
 
         Ok(())
@@ -6,8 +6,15 @@ mod provider;
 mod sign;
 mod types;
 
+#[cfg(test)]
+mod tests;
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
 use reqwest::Client;
 
+/// 默认 endpoint（全局）
 pub(crate) const HUAWEICLOUD_DNS_HOST: &str = "dns.myhuaweicloud.com";
 
 /// 华为云 DNS Provider
@@ -15,14 +22,77 @@ pub struct HuaweicloudProvider {
     pub(crate) client: Client,
     pub(crate) access_key_id: String,
     pub(crate) secret_access_key: String,
+    /// API 域名，如 dns.ap-southeast-1.myhuaweicloud.com
+    pub(crate) host: String,
+    /// API 根地址，默认为 `https://{host}`
+    pub(crate) base_url: String,
+    /// Zone 类型（public / private）
+    pub(crate) zone_type: &'static str,
+    /// 按记录集 ID 加锁，串行化对同一多值记录集的读改写
+    pub(crate) recordset_locks: Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>,
 }
 
 impl HuaweicloudProvider {
-    pub fn new(access_key_id: String, secret_access_key: String) -> Self {
+    /// `region` 可以是地域 ID（如 ap-southeast-1）或完整的 endpoint 域名，None 时使用全局 endpoint
+    pub fn new(access_key_id: String, secret_access_key: String, region: Option<String>) -> Self {
+        Self::with_client(Client::new(), access_key_id, secret_access_key, region)
+    }
+
+    /// 使用自定义 HTTP 客户端创建（代理、超时等配置）
+    pub fn with_client(
+        client: Client,
+        access_key_id: String,
+        secret_access_key: String,
+        region: Option<String>,
+    ) -> Self {
+        let host = Self::resolve_host(region.as_deref());
         Self {
-            client: Client::new(),
+            client,
             access_key_id,
             secret_access_key,
+            base_url: format!("https://{host}"),
+            host,
+            zone_type: "public",
+            recordset_locks: Mutex::default(),
+        }
+    }
+
+    /// 替换 API 根地址（测试时指向 mock server）
+    #[cfg(test)]
+    pub(crate) fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// 设置 Zone 类型，"private" 管理内网（VPC）域名，其他值按 public 处理
+    #[must_use]
+    pub fn with_zone_type(mut self, zone_type: Option<&str>) -> Self {
+        self.zone_type = match zone_type {
+            Some("private") => "private",
+            _ => "public",
+        };
+        self
+    }
+
+    /// 是否为内网 Zone
+    pub(crate) fn is_private_zone(&self) -> bool {
+        self.zone_type == "private"
+    }
+
+    /// 根据地域或 endpoint 得到 API 域名
+    fn resolve_host(region: Option<&str>) -> String {
+        let Some(region) = region.map(str::trim).filter(|r| !r.is_empty()) else {
+            return HUAWEICLOUD_DNS_HOST.to_string();
+        };
+
+        // 包含 "." 视为完整 endpoint，去掉可能带上的协议前缀
+        if region.contains('.') {
+            let host = region
+                .trim_start_matches("https://")
+                .trim_start_matches("http://");
+            host.trim_end_matches('/').to_string()
+        } else {
+            format!("dns.{region}.myhuaweicloud.com")
         }
     }
 }
@@ -1,21 +1,28 @@
 //! 华为云 DnsProvider trait 实现
 
+use std::sync::Arc;
+
 use async_trait::async_trait;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::error::{ProviderError, Result};
 use crate::providers::common::{
-    full_name_to_relative, normalize_domain_name, parse_record_type, record_type_to_string,
-    relative_to_full_name,
+    check_apex_cname, check_record_type, check_ttl, fqdn_record_value, full_name_to_relative,
+    normalize_domain_name, normalize_record_value, parse_modified_after, parse_record_type,
+    quoted_txt_value, record_type_to_string, relative_to_full_name, retain_modified_after,
+    sort_records,
 };
-use crate::traits::{DnsProvider, ProviderErrorMapper};
+use crate::traits::{DnsProvider, ErrorContext, collect_matching_records};
 use crate::types::{
     CreateDnsRecordRequest, DnsRecord, DnsRecordType, Domain, DomainStatus, PaginatedResponse,
     PaginationParams, ProviderType, RecordQueryParams, UpdateDnsRecordRequest,
 };
 
 use super::HuaweicloudProvider;
-use super::types::{CreateRecordSetResponse, ListRecordSetsResponse, ListZonesResponse};
+use super::types::{
+    CreateRecordSetResponse, HuaweicloudRecordSet, HuaweicloudZone, ListNameServersResponse,
+    ListRecordSetsResponse, ListZonesResponse,
+};
 
 impl HuaweicloudProvider {
     /// 将华为云域名状态转换为内部状态
@@ -35,25 +42,152 @@ impl HuaweicloudProvider {
             _ => DomainStatus::Unknown,
         }
     }
+
+    /// 解析记录 ID，返回记录集 ID 和值在记录集中的原始内容
+    ///
+    /// 华为云一个记录集可包含多个值，列表中每个值拆为一条记录，
+    /// 多值记录集的记录 ID 为 `{记录集 ID}#{原始值}`，单值记录集直接使用记录集 ID。
+    /// 按内容而非序号定位，删除或修改兄弟记录后其余记录 ID 仍然有效
+    pub(crate) fn split_record_id(record_id: &str) -> (&str, Option<&str>) {
+        match record_id.split_once('#') {
+            Some((rrset_id, value)) if !value.is_empty() => (rrset_id, Some(value)),
+            _ => (record_id, None),
+        }
+    }
+
+    /// 内网 Zone 只支持 A/AAAA/CNAME/MX/TXT/SRV（及 PTR），NS 和 CAA 只能用于公网 Zone
+    fn check_zone_record_type(&self, record_type: &DnsRecordType) -> Result<()> {
+        if !self.is_private_zone() || !matches!(record_type, DnsRecordType::Ns | DnsRecordType::Caa)
+        {
+            return Ok(());
+        }
+        Err(ProviderError::InvalidParameter {
+            provider: "huaweicloud".to_string(),
+            param: "record_type".to_string(),
+            detail: format!(
+                "内网 Zone 不支持 {} 记录",
+                record_type_to_string(record_type)
+            ),
+            request_id: None,
+        })
+    }
+
+    /// 获取记录集的写锁，同一记录集的读改写（删除/修改其中一个值）需要串行执行
+    async fn lock_recordset(&self, rrset_id: &str) -> tokio::sync::OwnedMutexGuard<()> {
+        let lock = {
+            let mut locks = self
+                .recordset_locks
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            // 清理已无人持有的锁，避免表无限增长
+            locks.retain(|_, lock| Arc::strong_count(lock) > 1);
+            Arc::clone(locks.entry(rrset_id.to_string()).or_default())
+        };
+        lock.lock_owned().await
+    }
+
+    /// 拆分记录集中的单个值，MX 记录值包含优先级（如 "10 mx.example.com."）
+    fn parse_record_value(record_type: &DnsRecordType, value: &str) -> (Option<u16>, String) {
+        let (priority, value) = match (record_type, value.split_once(' ')) {
+            (DnsRecordType::Mx, Some((priority, host))) => (priority.parse().ok(), host),
+            _ => (None, value),
+        };
+        (priority, normalize_record_value(record_type, value))
+    }
+
+    /// 构造写入记录集的值（MX 需要包含优先级，主机名需要末尾带点，TXT 需要带引号分段）
+    fn format_record_value(
+        record_type: &DnsRecordType,
+        value: &str,
+        priority: Option<u16>,
+    ) -> String {
+        match record_type {
+            DnsRecordType::Mx => format!(
+                "{} {}",
+                priority.unwrap_or(10),
+                fqdn_record_value(record_type, value)
+            ),
+            DnsRecordType::Txt => quoted_txt_value(value),
+            _ => fqdn_record_value(record_type, value),
+        }
+    }
+
+    /// 获取单个记录集
+    async fn get_recordset(&self, zone_id: &str, rrset_id: &str) -> Result<HuaweicloudRecordSet> {
+        self.get(
+            &format!("/v2/zones/{zone_id}/recordsets/{rrset_id}"),
+            "",
+            ErrorContext::for_record(zone_id, rrset_id),
+        )
+        .await
+    }
+
+    /// 记录集中已找不到记录 ID 对应的值（列表之后被其他操作修改或删除）
+    fn record_value_gone(record_id: &str) -> ProviderError {
+        ProviderError::RecordNotFound {
+            provider: ProviderType::Huaweicloud.to_string(),
+            record_id: record_id.to_string(),
+            raw_message: Some("记录集已被修改，原记录值不存在，请刷新后重试".to_string()),
+            request_id: None,
+        }
+    }
+
+    /// 列表中隐藏的系统记录集数量：SOA，以及内网 Zone 的根 NS
+    fn hidden_recordset_count(&self) -> u32 {
+        if self.is_private_zone() { 2 } else { 1 }
+    }
+
+    /// 是否为列表中隐藏的系统记录集（`zone_name` 带末尾点）
+    fn is_hidden_recordset(&self, recordset: &HuaweicloudRecordSet, zone_name: &str) -> bool {
+        // 内网 Zone 的根 NS 记录由系统维护，不可修改
+        recordset.record_type == "SOA"
+            || (self.is_private_zone()
+                && recordset.record_type == "NS"
+                && recordset.name == zone_name)
+    }
+
+    /// 构造分页结果
+    ///
+    /// 部分接口会省略 `metadata.total_count`，此时按本页是否取满（`fetched == limit`）
+    /// 推断是否还有下一页，`total_count` 取已知的最小值
+    pub(crate) fn paginate<T>(
+        items: Vec<T>,
+        page: u32,
+        page_size: u32,
+        limit: u32,
+        fetched: usize,
+        total_count: Option<u32>,
+    ) -> PaginatedResponse<T> {
+        if let Some(total_count) = total_count {
+            return PaginatedResponse::new(items, page, page_size, total_count);
+        }
+
+        let offset = (page - 1) * page_size;
+        let fetched = u32::try_from(fetched).unwrap_or(u32::MAX);
+        let mut response = PaginatedResponse::new(items, page, page_size, offset + fetched);
+        response.has_more = fetched >= limit && limit > 0;
+        response
+    }
 }
 
 #[async_trait]
 impl DnsProvider for HuaweicloudProvider {
     fn id(&self) -> &'static str {
-        "huaweicloud"
+        ProviderType::Huaweicloud.as_str()
     }
 
     async fn validate_credentials(&self) -> Result<bool> {
         match self
-            .get::<ListZonesResponse>("/v2/zones", "type=public&limit=1")
+            .get::<ListZonesResponse>(
+                "/v2/zones",
+                &format!("type={}&limit=1", self.zone_type),
+                ErrorContext::default(),
+            )
             .await
         {
             Ok(_) => Ok(true),
             Err(ProviderError::InvalidCredentials { .. }) => Ok(false),
-            Err(e) => {
-                log::warn!("凭证验证失败: {e}");
-                Ok(false)
-            }
+            Err(e) => Err(e),
         }
     }
 
@@ -61,15 +195,17 @@ impl DnsProvider for HuaweicloudProvider {
         // 华为云使用 offset/limit 分页
         let offset = (params.page - 1) * params.page_size;
         let limit = params.page_size.min(500); // 华为云最大支持 500
-        let query = format!("type=public&offset={offset}&limit={limit}");
+        let query = format!("type={}&offset={offset}&limit={limit}", self.zone_type);
 
-        let response: ListZonesResponse = self.get("/v2/zones", &query).await?;
+        let response: ListZonesResponse = self
+            .get("/v2/zones", &query, ErrorContext::default())
+            .await?;
 
-        let total_count = response.metadata.and_then(|m| m.total_count).unwrap_or(0);
+        let total_count = response.metadata.and_then(|m| m.total_count);
+        let zones = response.zones.unwrap_or_default();
+        let fetched = zones.len();
 
-        let domains = response
-            .zones
-            .unwrap_or_default()
+        let domains = zones
             .into_iter()
             .map(|z| Domain {
                 id: z.id,
@@ -80,31 +216,89 @@ impl DnsProvider for HuaweicloudProvider {
             })
             .collect();
 
-        Ok(PaginatedResponse::new(
+        Ok(Self::paginate(
             domains,
             params.page,
             params.page_size,
+            limit,
+            fetched,
             total_count,
         ))
     }
 
     async fn get_domain(&self, domain_id: &str) -> Result<Domain> {
-        // 使用大页面一次性获取用于查找
-        let params = PaginationParams {
-            page: 1,
-            page_size: 100,
-        };
-        let response = self.list_domains(&params).await?;
+        let zone: HuaweicloudZone = self
+            .get(
+                &format!("/v2/zones/{domain_id}"),
+                "",
+                ErrorContext::for_domain(domain_id),
+            )
+            .await?;
+
+        Ok(Domain {
+            id: zone.id,
+            name: normalize_domain_name(&zone.name),
+            provider: ProviderType::Huaweicloud,
+            status: Self::convert_domain_status(zone.status.as_deref()),
+            record_count: zone.record_num,
+        })
+    }
 
-        response
-            .items
+    async fn get_domain_nameservers(&self, domain_id: &str) -> Result<Vec<String>> {
+        let response: ListNameServersResponse = self
+            .get(
+                &format!("/v2/zones/{domain_id}/nameservers"),
+                "",
+                ErrorContext::for_domain(domain_id),
+            )
+            .await?;
+
+        let mut nameservers = response.nameservers;
+        nameservers.sort_by_key(|ns| ns.priority.unwrap_or(u32::MAX));
+        Ok(nameservers
             .into_iter()
-            .find(|d| d.id == domain_id || d.name == domain_id)
-            .ok_or_else(|| ProviderError::DomainNotFound {
-                provider: self.provider_name().to_string(),
-                domain: domain_id.to_string(),
-                raw_message: None,
-            })
+            .filter_map(|ns| ns.hostname.or(ns.address))
+            .map(|host| normalize_domain_name(&host))
+            .collect())
+    }
+
+    /// 返回拆分后的记录条数（不含列表中隐藏的系统记录集），与 `list_records` 的条数一致
+    ///
+    /// 接口的 total_count 按记录集计数，多值记录集需要遍历全部记录集才能得到拆分后的条数
+    async fn count_records(&self, domain_id: &str) -> Result<u32> {
+        let zone_name = format!("{}.", self.get_domain(domain_id).await?.name);
+        let path = format!("/v2/zones/{domain_id}/recordsets");
+        let limit = 500; // 华为云最大支持 500
+        let mut offset = 0;
+        let mut count = 0usize;
+
+        loop {
+            let response: ListRecordSetsResponse = self
+                .get(
+                    &path,
+                    &format!("offset={offset}&limit={limit}"),
+                    ErrorContext::for_domain(domain_id),
+                )
+                .await?;
+            let total_count = response.metadata.and_then(|m| m.total_count);
+            let recordsets = response.recordsets.unwrap_or_default();
+            let fetched = u32::try_from(recordsets.len()).unwrap_or(u32::MAX);
+
+            count += recordsets
+                .iter()
+                .filter(|r| !self.is_hidden_recordset(r, &zone_name))
+                .filter(|r| parse_record_type(&r.record_type, "huaweicloud").is_ok())
+                .map(|r| r.records.as_ref().map_or(0, Vec::len))
+                .sum::<usize>();
+
+            offset += fetched;
+            // 空页也视为结束，避免 total_count 错误导致死循环
+            if fetched < limit || total_count.is_some_and(|total| offset >= total) {
+                break;
+            }
+        }
+
+        Ok(u32::try_from(count).unwrap_or(u32::MAX))
     }
 
     async fn list_records(
@@ -112,6 +306,8 @@ impl DnsProvider for HuaweicloudProvider {
         domain_id: &str,
         params: &RecordQueryParams,
     ) -> Result<PaginatedResponse<DnsRecord>> {
+        let modified_after = parse_modified_after(&ProviderType::Huaweicloud, params)?;
+
         // 获取域名信息以获取域名名称
         let domain_info = self.get_domain(domain_id).await?;
 
@@ -134,71 +330,109 @@ impl DnsProvider for HuaweicloudProvider {
         }
 
         let path = format!("/v2/zones/{domain_id}/recordsets");
-        let response: ListRecordSetsResponse = self.get(&path, &query).await?;
-
-        let total_count = response.metadata.and_then(|m| m.total_count).unwrap_or(0);
-
-        let records = response
-            .recordsets
-            .unwrap_or_default()
-            .into_iter()
-            .filter_map(|r| {
-                // 跳过 SOA 和 NS 根记录
-                if r.record_type == "SOA" {
-                    return None;
-                }
-
-                let record_type = parse_record_type(&r.record_type, "huaweicloud").ok()?;
-                let value = r.records.as_ref()?.first()?.clone();
-
-                // 提取优先级（对于 MX 记录）
-                let (priority, actual_value) = if r.record_type == "MX" {
-                    let parts: Vec<&str> = value.splitn(2, ' ').collect();
-                    if parts.len() == 2 {
-                        (parts[0].parse().ok(), parts[1].to_string())
+        let response: ListRecordSetsResponse = self
+            .get(&path, &query, ErrorContext::for_domain(domain_id))
+            .await?;
+
+        let mut total_count = response.metadata.and_then(|m| m.total_count);
+        // 未过滤时总数包含隐藏的系统记录集，需要扣除
+        if params.keyword.as_deref().is_none_or(str::is_empty) && params.record_type.is_none() {
+            total_count = total_count.map(|t| t.saturating_sub(self.hidden_recordset_count()));
+        }
+        let recordsets = response.recordsets.unwrap_or_default();
+        // 过滤前的条数，用于推断是否还有下一页
+        let fetched = recordsets.len();
+
+        let zone_name = format!("{}.", domain_info.name);
+        let mut records = Vec::new();
+        for r in recordsets {
+            if self.is_hidden_recordset(&r, &zone_name) {
+                continue;
+            }
+            let Ok(record_type) = parse_record_type(&r.record_type, "huaweicloud") else {
+                continue;
+            };
+
+            // 记录集的每个值拆为一条记录，与其他 Provider 的列表形式一致
+            let values = r.records.unwrap_or_default();
+            let multi_value = values.len() > 1;
+            let name = full_name_to_relative(&r.name, &domain_info.name);
+            for raw_value in &values {
+                let (priority, value) = Self::parse_record_value(&record_type, raw_value);
+                records.push(DnsRecord {
+                    id: if multi_value {
+                        format!("{}#{raw_value}", r.id)
                     } else {
-                        (None, value)
-                    }
-                } else {
-                    (None, value)
-                };
-
-                Some(DnsRecord {
-                    id: r.id,
+                        r.id.clone()
+                    },
                     domain_id: domain_id.to_string(),
-                    record_type,
-                    name: full_name_to_relative(&r.name, &domain_info.name),
-                    value: actual_value,
+                    record_type: record_type.clone(),
+                    name: name.clone(),
+                    fqdn: Some(normalize_domain_name(&r.name)),
+                    value,
                     ttl: r.ttl.unwrap_or(300),
                     priority,
                     proxied: None,
-                    created_at: r.created_at,
-                    updated_at: r.updated_at,
-                })
-            })
-            .collect();
+                    line: None,
+                    remark: None,
+                    enabled: r.status.as_deref().map(|s| s != "DISABLE"),
+                    created_at: r.created_at.clone(),
+                    updated_at: r.updated_at.clone(),
+                });
+            }
+        }
+        retain_modified_after(&mut records, modified_after);
+        sort_records(&mut records, params);
+
+        // 接口的 total_count 按记录集计数，多值记录集拆分后与实际条数不一致；
+        // 第一页就取完全部记录集时直接按拆分后的条数计算，保证按类型过滤时总数准确。
+        // 跨多页时分页和 total_count 仍按记录集计算（多值记录集计为一条，
+        // 按关键词过滤时还可能包含 SOA），精确计算需要遍历全部记录集
+        if offset == 0 && fetched < limit as usize {
+            let total_count = u32::try_from(records.len()).unwrap_or(u32::MAX);
+            let mut response =
+                PaginatedResponse::new(records, params.page, params.page_size, total_count);
+            response.has_more = false;
+            return Ok(response);
+        }
 
-        Ok(PaginatedResponse::new(
+        Ok(Self::paginate(
             records,
             params.page,
             params.page_size,
+            limit,
+            fetched,
             total_count,
         ))
     }
 
+    /// 使用 `name` 参数在服务端按完整域名模糊过滤，本地再精确过滤
+    async fn find_records(
+        &self,
+        domain_id: &str,
+        name: &str,
+        record_type: Option<DnsRecordType>,
+    ) -> Result<Vec<DnsRecord>> {
+        // 根记录的完整域名即 zone 名称，模糊匹配无法缩小范围；
+        // 通配符标签不参与模糊匹配，避免 `*` 被服务端当作匹配符
+        let keyword = name.trim_start_matches('*').trim_start_matches('.');
+        let keyword = (name != "@" && !keyword.is_empty()).then(|| keyword.to_string());
+        collect_matching_records(self, domain_id, keyword, name, record_type.as_ref()).await
+    }
+
     async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
+        check_record_type(&ProviderType::Huaweicloud, &req.record_type)?;
+        self.check_zone_record_type(&req.record_type)?;
+        check_ttl(&ProviderType::Huaweicloud, req.ttl)?;
+        check_apex_cname(&ProviderType::Huaweicloud, &req.record_type, &req.name)?;
+
         // 获取域名信息
         let domain_info = self.get_domain(&req.domain_id).await?;
 
         // 构造完整的记录名称（华为云需要末尾带点）
         let full_name = format!("{}.", relative_to_full_name(&req.name, &domain_info.name));
 
-        // 构造记录值（MX 需要包含优先级）
-        let record_value = if req.record_type == DnsRecordType::Mx {
-            format!("{} {}", req.priority.unwrap_or(10), req.value)
-        } else {
-            req.value.clone()
-        };
+        let record_value = Self::format_record_value(&req.record_type, &req.value, req.priority);
 
         #[derive(Serialize)]
         struct CreateRecordSetRequest {
@@ -217,7 +451,13 @@ impl DnsProvider for HuaweicloudProvider {
         };
 
         let path = format!("/v2/zones/{}/recordsets", req.domain_id);
-        let response: CreateRecordSetResponse = self.post(&path, &api_req).await?;
+        let response: CreateRecordSetResponse = self
+            .post(
+                &path,
+                &api_req,
+                ErrorContext::for_record_name(&req.domain_id, &req.name),
+            )
+            .await?;
 
         let now = chrono::Utc::now().to_rfc3339();
         Ok(DnsRecord {
@@ -225,10 +465,14 @@ impl DnsProvider for HuaweicloudProvider {
             domain_id: req.domain_id.clone(),
             record_type: req.record_type.clone(),
             name: req.name.clone(),
-            value: req.value.clone(),
+            fqdn: Some(relative_to_full_name(&req.name, &domain_info.name)),
+            value: normalize_record_value(&req.record_type, &req.value),
             ttl: req.ttl,
             priority: req.priority,
             proxied: None,
+            line: None,
+            remark: None,
+            enabled: None,
             created_at: Some(now.clone()),
             updated_at: Some(now),
         })
@@ -239,17 +483,39 @@ impl DnsProvider for HuaweicloudProvider {
         record_id: &str,
         req: &UpdateDnsRecordRequest,
     ) -> Result<DnsRecord> {
+        check_record_type(&ProviderType::Huaweicloud, &req.record_type)?;
+        self.check_zone_record_type(&req.record_type)?;
+        check_ttl(&ProviderType::Huaweicloud, req.ttl)?;
+        check_apex_cname(&ProviderType::Huaweicloud, &req.record_type, &req.name)?;
+
         // 获取域名信息
         let domain_info = self.get_domain(&req.domain_id).await?;
 
         // 构造完整的记录名称（华为云需要末尾带点）
         let full_name = format!("{}.", relative_to_full_name(&req.name, &domain_info.name));
 
-        // 构造记录值（MX 需要包含优先级）
-        let record_value = if req.record_type == DnsRecordType::Mx {
-            format!("{} {}", req.priority.unwrap_or(10), req.value)
-        } else {
-            req.value.clone()
+        let record_value = Self::format_record_value(&req.record_type, &req.value, req.priority);
+
+        // 多值记录集只替换与原值相同的那个值，其余值保持不变，原值已不在时按冲突失败；
+        // 持锁完成读改写，避免并发修改同一记录集时互相覆盖
+        let (rrset_id, old_value) = Self::split_record_id(record_id);
+        let _guard;
+        let records = match old_value {
+            None => vec![record_value.clone()],
+            Some(old_value) => {
+                _guard = self.lock_recordset(rrset_id).await;
+                let mut records = self
+                    .get_recordset(&req.domain_id, rrset_id)
+                    .await?
+                    .records
+                    .unwrap_or_default();
+                let slot = records
+                    .iter_mut()
+                    .find(|v| v.as_str() == old_value)
+                    .ok_or_else(|| Self::record_value_gone(record_id))?;
+                slot.clone_from(&record_value);
+                records
+            }
         };
 
         #[derive(Serialize)]
@@ -264,30 +530,108 @@ impl DnsProvider for HuaweicloudProvider {
         let api_req = UpdateRecordSetRequest {
             name: full_name,
             record_type: record_type_to_string(&req.record_type).to_string(),
-            records: vec![record_value],
+            records,
             ttl: req.ttl,
         };
 
-        let path = format!("/v2/zones/{}/recordsets/{}", req.domain_id, record_id);
-        let _response: CreateRecordSetResponse = self.put(&path, &api_req).await?;
+        let path = format!("/v2/zones/{}/recordsets/{rrset_id}", req.domain_id);
+        let ctx =
+            ErrorContext::for_record_name(&req.domain_id, &req.name).with_record_id(record_id);
+        let _response: CreateRecordSetResponse = self.put(&path, &api_req, ctx).await?;
 
         let now = chrono::Utc::now().to_rfc3339();
         Ok(DnsRecord {
-            id: record_id.to_string(),
+            // 记录 ID 包含值内容，修改后随新值变化
+            id: match old_value {
+                Some(_) => format!("{rrset_id}#{record_value}"),
+                None => record_id.to_string(),
+            },
             domain_id: req.domain_id.clone(),
             record_type: req.record_type.clone(),
             name: req.name.clone(),
-            value: req.value.clone(),
+            fqdn: Some(relative_to_full_name(&req.name, &domain_info.name)),
+            value: normalize_record_value(&req.record_type, &req.value),
             ttl: req.ttl,
             priority: req.priority,
             proxied: None,
+            line: None,
+            remark: None,
+            enabled: None,
             created_at: None,
             updated_at: Some(now),
         })
     }
 
     async fn delete_record(&self, record_id: &str, domain_id: &str) -> Result<()> {
-        let path = format!("/v2/zones/{domain_id}/recordsets/{record_id}");
-        self.delete(&path).await
+        let (rrset_id, value) = Self::split_record_id(record_id);
+        let path = format!("/v2/zones/{domain_id}/recordsets/{rrset_id}");
+        let Some(value) = value else {
+            return self
+                .delete(&path, ErrorContext::for_record(domain_id, record_id))
+                .await;
+        };
+
+        let _guard = self.lock_recordset(rrset_id).await;
+        let rrset = self.get_recordset(domain_id, rrset_id).await?;
+        let mut records = rrset.records.unwrap_or_default();
+        let index = records
+            .iter()
+            .position(|v| v == value)
+            .ok_or_else(|| Self::record_value_gone(record_id))?;
+        records.remove(index);
+        if records.is_empty() {
+            return self
+                .delete(&path, ErrorContext::for_record(domain_id, record_id))
+                .await;
+        }
+
+        // 只删除多值记录集中的一个值：用剩余的值更新记录集
+        #[derive(Serialize)]
+        struct UpdateRecordSetRequest {
+            name: String,
+            #[serde(rename = "type")]
+            record_type: String,
+            records: Vec<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            ttl: Option<u32>,
+        }
+
+        let api_req = UpdateRecordSetRequest {
+            name: rrset.name,
+            record_type: rrset.record_type,
+            records,
+            ttl: rrset.ttl,
+        };
+        let ctx = ErrorContext::for_record(domain_id, record_id);
+        let _response: CreateRecordSetResponse = self.put(&path, &api_req, ctx).await?;
+        Ok(())
+    }
+
+    async fn set_record_enabled(
+        &self,
+        domain_id: &str,
+        record_id: &str,
+        enabled: bool,
+    ) -> Result<()> {
+        #[derive(Serialize)]
+        struct SetRecordSetStatusRequest {
+            /// ENABLE / DISABLE
+            status: &'static str,
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct SetRecordSetStatusResponse {}
+
+        let api_req = SetRecordSetStatusRequest {
+            status: if enabled { "ENABLE" } else { "DISABLE" },
+        };
+
+        // 状态作用于整个记录集
+        let (rrset_id, _) = Self::split_record_id(record_id);
+        let path = format!("/v2.1/recordsets/{rrset_id}/statuses/set");
+        let ctx = ErrorContext::for_record(domain_id, record_id);
+        let _response: SetRecordSetStatusResponse = self.put(&path, &api_req, ctx).await?;
+
+        Ok(())
     }
 }
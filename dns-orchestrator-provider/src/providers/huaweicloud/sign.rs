@@ -2,7 +2,7 @@
 
 use sha2::{Digest, Sha256};
 
-use crate::providers::common::hmac_sha256;
+use crate::providers::common::{hmac_sha256, redact};
 
 use super::HuaweicloudProvider;
 
@@ -36,7 +36,7 @@ impl HuaweicloudProvider {
 
         // 3. 构造规范请求头
         let mut sorted_headers: Vec<_> = headers.iter().collect();
-        sorted_headers.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
+        sorted_headers.sort_by_key(|a| a.0.to_lowercase());
 
         let canonical_headers: String = sorted_headers
             .iter()
@@ -57,13 +57,13 @@ impl HuaweicloudProvider {
             "{method}\n{canonical_uri}\n{canonical_query}\n{canonical_headers}\n{signed_headers}\n{hashed_payload}"
         );
 
-        log::debug!("CanonicalRequest:\n{canonical_request}");
+        log::debug!("CanonicalRequest:\n{}", redact(&canonical_request));
 
         // 6. 构造待签名字符串（3 行格式）
         let hashed_canonical_request = hex::encode(Sha256::digest(canonical_request.as_bytes()));
         let string_to_sign = format!("SDK-HMAC-SHA256\n{timestamp}\n{hashed_canonical_request}");
 
-        log::debug!("StringToSign:\n{string_to_sign}");
+        log::debug!("StringToSign:\n{}", redact(&string_to_sign));
 
         // 7. 计算签名（直接用 SK）
         let signature = hex::encode(hmac_sha256(
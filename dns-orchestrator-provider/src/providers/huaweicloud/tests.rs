@@ -0,0 +1,758 @@
+use serde_json::json;
+
+use crate::error::ProviderError;
+use crate::providers::mock::{MockResponse, MockServer};
+use crate::traits::DnsProvider;
+use crate::types::{
+    CreateDnsRecordRequest, DnsRecordType, PaginationParams, RecordQueryParams, RecordSort,
+    UpdateDnsRecordRequest,
+};
+
+use super::HuaweicloudProvider;
+
+fn provider(server: &MockServer) -> HuaweicloudProvider {
+    HuaweicloudProvider::new("test-ak".to_string(), "test-sk".to_string(), None)
+        .with_base_url(server.url())
+}
+
+#[tokio::test]
+async fn list_domains_parses_zones() {
+    let server = MockServer::start(vec![MockResponse::json(
+        200,
+        &json!({
+            "zones": [
+                {"id": "z-1", "name": "example.com.", "status": "ACTIVE", "record_num": 4}
+            ],
+            "metadata": {"total_count": 1}
+        }),
+    )])
+    .await;
+
+    let response = provider(&server)
+        .list_domains(&PaginationParams::default())
+        .await
+        .unwrap();
+
+    assert_eq!(response.items.len(), 1);
+    assert_eq!(response.items[0].name, "example.com");
+    assert_eq!(response.items[0].record_count, Some(4));
+    assert!(!response.has_more);
+
+    let requests = server.requests();
+    assert_eq!(requests[0].path, "/v2/zones?type=public&offset=0&limit=20");
+}
+
+#[tokio::test]
+async fn error_response_is_mapped() {
+    let server = MockServer::start(vec![
+        MockResponse::json(
+            401,
+            &json!({"error_code": "APIGW.0301", "error_msg": "Incorrect IAM authentication information"}),
+        )
+        .header("X-Request-Id", "req-hw"),
+    ])
+    .await;
+
+    let err = provider(&server).get_domain("z-1").await.unwrap_err();
+
+    assert!(matches!(err, ProviderError::InvalidCredentials { .. }));
+    assert_eq!(err.request_id(), Some("req-hw"));
+}
+
+// ============ 签名 ============
+
+fn signing_provider() -> HuaweicloudProvider {
+    HuaweicloudProvider::new(
+        "QTWAOYTTINDUT2QVKYUC".to_string(),
+        "MFyfvK41ba2giqM7Uio6PznpdUKGpownRZlmVmHc".to_string(),
+        None,
+    )
+}
+
+fn signing_headers() -> Vec<(String, String)> {
+    vec![
+        ("Host".to_string(), "dns.myhuaweicloud.com".to_string()),
+        ("X-Sdk-Date".to_string(), "20240101T000000Z".to_string()),
+    ]
+}
+
+#[test]
+fn sign_get_matches_reference_vector() {
+    let authorization = signing_provider().sign(
+        "GET",
+        "/v2/zones",
+        "type=public&offset=0&limit=20",
+        &signing_headers(),
+        "",
+        "20240101T000000Z",
+    );
+
+    assert_eq!(
+        authorization,
+        "SDK-HMAC-SHA256 Access=QTWAOYTTINDUT2QVKYUC, SignedHeaders=host;x-sdk-date, \
+         Signature=2dcc777db36a477845d582d2ec483b9e218d6548ed3591527bdae1453bb359ed"
+    );
+}
+
+#[test]
+fn sign_normalizes_uri_and_query() {
+    let provider = signing_provider();
+    let headers = signing_headers();
+
+    // 末尾 "/" 和 query 参数顺序不影响签名
+    let expected = provider.sign(
+        "GET",
+        "/v2/zones",
+        "type=public&offset=0&limit=20",
+        &headers,
+        "",
+        "20240101T000000Z",
+    );
+    let normalized = provider.sign(
+        "GET",
+        "/v2/zones/",
+        "limit=20&offset=0&type=public",
+        &headers,
+        "",
+        "20240101T000000Z",
+    );
+
+    assert_eq!(expected, normalized);
+}
+
+#[test]
+fn sign_post_matches_reference_vector() {
+    // 请求头顺序与签名顺序不同，签名时按小写名称排序
+    let headers = vec![
+        ("X-Sdk-Date".to_string(), "20240101T000000Z".to_string()),
+        ("Host".to_string(), "dns.myhuaweicloud.com".to_string()),
+        ("Content-Type".to_string(), "application/json".to_string()),
+    ];
+
+    let authorization = signing_provider().sign(
+        "POST",
+        "/v2.1/zones/z-1/recordsets",
+        "",
+        &headers,
+        r#"{"name":"www.example.com.","type":"A","ttl":300,"records":["1.2.3.4"]}"#,
+        "20240101T000000Z",
+    );
+
+    assert_eq!(
+        authorization,
+        "SDK-HMAC-SHA256 Access=QTWAOYTTINDUT2QVKYUC, SignedHeaders=content-type;host;x-sdk-date, \
+         Signature=5b52936a6accf1d43dfbb0e5c0d46431275a09cdc2c26e182bac76ae9c0349b9"
+    );
+}
+
+// ============ 分页 ============
+
+#[tokio::test]
+async fn missing_total_count_infers_has_more_from_full_page() {
+    let zones: Vec<_> = (0..20)
+        .map(|i| json!({"id": format!("z-{i}"), "name": format!("example{i}.com."), "status": "ACTIVE"}))
+        .collect();
+    let server = MockServer::start(vec![
+        MockResponse::json(200, &json!({"zones": zones})),
+        MockResponse::json(
+            200,
+            &json!({"zones": [{"id": "z-20", "name": "example20.com."}]}),
+        ),
+    ])
+    .await;
+    let provider = provider(&server);
+
+    let first = provider
+        .list_domains(&PaginationParams::default())
+        .await
+        .unwrap();
+    assert_eq!(first.items.len(), 20);
+    assert!(first.has_more);
+
+    let second = provider
+        .list_domains(&PaginationParams {
+            page: 2,
+            page_size: 20,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(second.items.len(), 1);
+    assert_eq!(second.total_count, 21);
+    assert!(!second.has_more);
+}
+
+#[tokio::test]
+async fn missing_total_count_counts_filtered_records() {
+    // SOA 记录会被过滤，但仍计入本页条数
+    let mut recordsets = vec![json!({
+        "id": "soa", "name": "example.com.", "type": "SOA", "records": ["ns1.example.com. admin 1 2 3 4 5"]
+    })];
+    recordsets.extend((1..20).map(|i| {
+        json!({"id": format!("r-{i}"), "name": format!("host{i}.example.com."), "type": "A", "ttl": 300, "records": ["1.2.3.4"]})
+    }));
+    let server = MockServer::start(vec![
+        MockResponse::json(
+            200,
+            &json!({"id": "z-1", "name": "example.com.", "status": "ACTIVE"}),
+        ),
+        MockResponse::json(200, &json!({"recordsets": recordsets})),
+    ])
+    .await;
+
+    let response = provider(&server)
+        .list_records("z-1", &RecordQueryParams::default())
+        .await
+        .unwrap();
+
+    assert_eq!(response.items.len(), 19);
+    assert!(response.has_more);
+}
+
+#[tokio::test]
+async fn count_records_counts_split_values() {
+    let server = MockServer::start(vec![
+        MockResponse::json(
+            200,
+            &json!({"id": "zone-1", "name": "example.com.", "status": "ACTIVE"}),
+        ),
+        MockResponse::json(
+            200,
+            &json!({
+                "recordsets": [
+                    {"id": "r1", "name": "example.com.", "type": "SOA", "records": ["ns1. admin. 1 7200 900 1209600 300"]},
+                    {"id": "r2", "name": "www.example.com.", "type": "A", "records": ["192.0.2.1", "192.0.2.2"]},
+                    {"id": "r3", "name": "mail.example.com.", "type": "MX", "records": ["10 mx.example.com."]}
+                ],
+                "metadata": {"total_count": 3}
+            }),
+        ),
+    ])
+    .await;
+
+    let count = provider(&server).count_records("zone-1").await.unwrap();
+
+    // 与 list_records 一致：多值记录集按值拆分，不含 SOA 记录集
+    assert_eq!(count, 3);
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(
+        requests[1].path,
+        "/v2/zones/zone-1/recordsets?offset=0&limit=500"
+    );
+}
+
+// ============ 记录值规范化 ============
+
+fn zone_response() -> MockResponse {
+    MockResponse::json(
+        200,
+        &json!({"id": "z-1", "name": "example.com.", "status": "ACTIVE"}),
+    )
+}
+
+#[tokio::test]
+async fn list_records_strips_trailing_dots() {
+    let server = MockServer::start(vec![
+        zone_response(),
+        MockResponse::json(
+            200,
+            &json!({
+                "recordsets": [
+                    {"id": "r1", "name": "www.example.com.", "type": "CNAME", "ttl": 300, "records": ["target.example.net."]},
+                    {"id": "r2", "name": "example.com.", "type": "MX", "ttl": 300, "records": ["10 mx.example.com."]}
+                ],
+                "metadata": {"total_count": 2}
+            }),
+        ),
+    ])
+    .await;
+
+    let response = provider(&server)
+        .list_records("z-1", &RecordQueryParams::default())
+        .await
+        .unwrap();
+
+    assert_eq!(response.items[0].value, "target.example.net");
+    assert_eq!(response.items[1].value, "mx.example.com");
+    assert_eq!(response.items[1].priority, Some(10));
+}
+
+#[tokio::test]
+async fn write_adds_single_trailing_dot() {
+    let server = MockServer::start(vec![
+        zone_response(),
+        MockResponse::json(
+            200,
+            &json!({"id": "r1", "name": "www.example.com.", "type": "CNAME"}),
+        ),
+        zone_response(),
+        MockResponse::json(
+            200,
+            &json!({"id": "r2", "name": "example.com.", "type": "MX"}),
+        ),
+    ])
+    .await;
+    let provider = provider(&server);
+
+    let created = provider
+        .create_record(&CreateDnsRecordRequest {
+            domain_id: "z-1".to_string(),
+            record_type: DnsRecordType::Cname,
+            name: "www".to_string(),
+            value: "target.example.net".to_string(),
+            ttl: 300,
+            priority: None,
+            proxied: None,
+            line: None,
+            remark: None,
+        })
+        .await
+        .unwrap();
+    let updated = provider
+        .update_record(
+            "r2",
+            &UpdateDnsRecordRequest {
+                domain_id: "z-1".to_string(),
+                record_type: DnsRecordType::Mx,
+                name: "@".to_string(),
+                value: "mx.example.com.".to_string(),
+                ttl: 300,
+                priority: Some(5),
+                proxied: None,
+                line: None,
+                remark: None,
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(created.value, "target.example.net");
+    assert_eq!(updated.value, "mx.example.com");
+    let requests = server.requests();
+    assert!(
+        requests[1]
+            .body
+            .contains(r#""records":["target.example.net."]"#)
+    );
+    assert!(
+        requests[3]
+            .body
+            .contains(r#""records":["5 mx.example.com."]"#)
+    );
+}
+
+#[tokio::test]
+async fn long_txt_is_chunked_on_write_and_joined_on_read() {
+    let dkim = format!("v=DKIM1; k=rsa; p={}", "A".repeat(280));
+    let server = MockServer::start(vec![
+        zone_response(),
+        MockResponse::json(
+            200,
+            &json!({"id": "r1", "name": "mail._domainkey.example.com.", "type": "TXT"}),
+        ),
+        zone_response(),
+        MockResponse::json(
+            200,
+            &json!({
+                "recordsets": [{
+                    "id": "r1",
+                    "name": "mail._domainkey.example.com.",
+                    "type": "TXT",
+                    "ttl": 300,
+                    "records": [format!("\"{}\" \"{}\"", &dkim[..255], &dkim[255..])]
+                }],
+                "metadata": {"total_count": 1}
+            }),
+        ),
+    ])
+    .await;
+    let provider = provider(&server);
+
+    provider
+        .create_record(&CreateDnsRecordRequest {
+            domain_id: "z-1".to_string(),
+            record_type: DnsRecordType::Txt,
+            name: "mail._domainkey".to_string(),
+            value: dkim.clone(),
+            ttl: 300,
+            priority: None,
+            proxied: None,
+            line: None,
+            remark: None,
+        })
+        .await
+        .unwrap();
+    let response = provider
+        .list_records("z-1", &RecordQueryParams::default())
+        .await
+        .unwrap();
+
+    let requests = server.requests();
+    let expected = format!(r#""records":["\"{}\" \"{}\""]"#, &dkim[..255], &dkim[255..]);
+    assert!(requests[1].body.contains(&expected));
+    assert_eq!(response.items[0].value, dkim);
+}
+
+#[tokio::test]
+async fn multi_value_recordset_is_split_into_records() {
+    let server = MockServer::start(vec![
+        zone_response(),
+        MockResponse::json(
+            200,
+            &json!({
+                "recordsets": [
+                    {"id": "r1", "name": "www.example.com.", "type": "A", "ttl": 300, "records": ["192.0.2.1", "192.0.2.2"]},
+                    {"id": "r2", "name": "api.example.com.", "type": "A", "ttl": 300, "records": ["192.0.2.3"]}
+                ],
+                "metadata": {"total_count": 2}
+            }),
+        ),
+    ])
+    .await;
+
+    let response = provider(&server)
+        .list_records("z-1", &RecordQueryParams::default())
+        .await
+        .unwrap();
+
+    let records: Vec<_> = response
+        .items
+        .iter()
+        .map(|r| (r.id.as_str(), r.value.as_str()))
+        .collect();
+    assert_eq!(
+        records,
+        vec![
+            ("r1#192.0.2.1", "192.0.2.1"),
+            ("r1#192.0.2.2", "192.0.2.2"),
+            ("r2", "192.0.2.3")
+        ]
+    );
+}
+
+#[tokio::test]
+async fn type_filter_total_counts_split_records() {
+    let server = MockServer::start(vec![
+        zone_response(),
+        MockResponse::json(
+            200,
+            &json!({
+                "recordsets": [
+                    {"id": "r1", "name": "example.com.", "type": "MX", "ttl": 300, "records": ["10 mx1.example.com.", "20 mx2.example.com."]}
+                ],
+                "metadata": {"total_count": 1}
+            }),
+        ),
+    ])
+    .await;
+
+    let params = RecordQueryParams {
+        record_type: Some(DnsRecordType::Mx),
+        ..Default::default()
+    };
+    let response = provider(&server)
+        .list_records("z-1", &params)
+        .await
+        .unwrap();
+
+    assert!(server.requests()[1].path.contains("&type=MX"));
+    assert_eq!(response.items.len(), 2);
+    assert_eq!(response.total_count, 2);
+    assert!(!response.has_more);
+}
+
+#[tokio::test]
+async fn sort_is_applied_to_page() {
+    let server = MockServer::start(vec![
+        zone_response(),
+        MockResponse::json(
+            200,
+            &json!({
+                "recordsets": [
+                    {"id": "r1", "name": "b.example.com.", "type": "A", "ttl": 300, "records": ["192.0.2.1"]},
+                    {"id": "r2", "name": "A.example.com.", "type": "A", "ttl": 600, "records": ["192.0.2.2"]},
+                    {"id": "r3", "name": "c.example.com.", "type": "A", "ttl": 60, "records": ["192.0.2.3"]}
+                ],
+                "metadata": {"total_count": 3}
+            }),
+        ),
+    ])
+    .await;
+
+    let params = RecordQueryParams {
+        sort_by: Some(RecordSort::Name),
+        sort_desc: true,
+        ..Default::default()
+    };
+    let response = provider(&server)
+        .list_records("z-1", &params)
+        .await
+        .unwrap();
+
+    let names: Vec<_> = response.items.iter().map(|r| r.name.as_str()).collect();
+    assert_eq!(names, vec!["c", "b", "A"]);
+}
+
+#[tokio::test]
+async fn delete_one_value_keeps_rest_of_recordset() {
+    let server = MockServer::start(vec![
+        MockResponse::json(
+            200,
+            &json!({"id": "r1", "name": "www.example.com.", "type": "A", "ttl": 300, "records": ["192.0.2.1", "192.0.2.2"]}),
+        ),
+        MockResponse::json(
+            200,
+            &json!({"id": "r1", "name": "www.example.com.", "type": "A"}),
+        ),
+    ])
+    .await;
+
+    provider(&server)
+        .delete_record("r1#192.0.2.1", "z-1")
+        .await
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].path, "/v2/zones/z-1/recordsets/r1");
+    assert_eq!(requests[1].method, "PUT");
+    assert_eq!(requests[1].path, "/v2/zones/z-1/recordsets/r1");
+    assert!(requests[1].body.contains(r#""records":["192.0.2.2"]"#));
+}
+
+#[tokio::test]
+async fn delete_sibling_values_in_a_row() {
+    let server = MockServer::start(vec![
+        MockResponse::json(
+            200,
+            &json!({"id": "r1", "name": "www.example.com.", "type": "A", "ttl": 300, "records": ["192.0.2.1", "192.0.2.2", "192.0.2.3"]}),
+        ),
+        MockResponse::json(
+            200,
+            &json!({"id": "r1", "name": "www.example.com.", "type": "A"}),
+        ),
+        MockResponse::json(
+            200,
+            &json!({"id": "r1", "name": "www.example.com.", "type": "A", "ttl": 300, "records": ["192.0.2.2", "192.0.2.3"]}),
+        ),
+        MockResponse::json(
+            200,
+            &json!({"id": "r1", "name": "www.example.com.", "type": "A"}),
+        ),
+    ])
+    .await;
+
+    let provider = provider(&server);
+    provider.delete_record("r1#192.0.2.1", "z-1").await.unwrap();
+    provider.delete_record("r1#192.0.2.2", "z-1").await.unwrap();
+
+    let requests = server.requests();
+    assert!(
+        requests[1]
+            .body
+            .contains(r#""records":["192.0.2.2","192.0.2.3"]"#)
+    );
+    assert!(requests[3].body.contains(r#""records":["192.0.2.3"]"#));
+}
+
+#[tokio::test]
+async fn update_one_value_keeps_rest_of_recordset() {
+    let server = MockServer::start(vec![
+        zone_response(),
+        MockResponse::json(
+            200,
+            &json!({"id": "r1", "name": "www.example.com.", "type": "A", "ttl": 300, "records": ["192.0.2.1", "192.0.2.2"]}),
+        ),
+        MockResponse::json(
+            200,
+            &json!({"id": "r1", "name": "www.example.com.", "type": "A"}),
+        ),
+    ])
+    .await;
+
+    let updated = provider(&server)
+        .update_record(
+            "r1#192.0.2.2",
+            &UpdateDnsRecordRequest {
+                domain_id: "z-1".to_string(),
+                record_type: DnsRecordType::A,
+                name: "www".to_string(),
+                value: "192.0.2.9".to_string(),
+                ttl: 300,
+                priority: None,
+                proxied: None,
+                line: None,
+                remark: None,
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(updated.id, "r1#192.0.2.9");
+    let requests = server.requests();
+    assert_eq!(requests[2].path, "/v2/zones/z-1/recordsets/r1");
+    assert!(
+        requests[2]
+            .body
+            .contains(r#""records":["192.0.2.1","192.0.2.9"]"#)
+    );
+}
+
+#[tokio::test]
+async fn update_fails_when_value_changed_after_listing() {
+    let server = MockServer::start(vec![
+        zone_response(),
+        MockResponse::json(
+            200,
+            &json!({"id": "r1", "name": "www.example.com.", "type": "A", "ttl": 300, "records": ["192.0.2.1", "192.0.2.3"]}),
+        ),
+    ])
+    .await;
+
+    let result = provider(&server)
+        .update_record(
+            "r1#192.0.2.2",
+            &UpdateDnsRecordRequest {
+                domain_id: "z-1".to_string(),
+                record_type: DnsRecordType::A,
+                name: "www".to_string(),
+                value: "192.0.2.9".to_string(),
+                ttl: 300,
+                priority: None,
+                proxied: None,
+                line: None,
+                remark: None,
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(ProviderError::RecordNotFound { .. })));
+    // 没有写回记录集
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn apex_cname_is_rejected_locally() {
+    let server = MockServer::start(vec![]).await;
+
+    let err = provider(&server)
+        .create_record(&CreateDnsRecordRequest {
+            domain_id: "z-1".to_string(),
+            record_type: DnsRecordType::Cname,
+            name: "@".to_string(),
+            value: "target.example.net".to_string(),
+            ttl: 300,
+            priority: None,
+            proxied: None,
+            line: None,
+            remark: None,
+        })
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, ProviderError::InvalidParameter { ref param, .. } if param == "name"));
+    assert!(server.requests().is_empty());
+}
+
+#[tokio::test]
+async fn private_zone_rejects_caa_locally() {
+    let server = MockServer::start(vec![]).await;
+
+    let err = provider(&server)
+        .with_zone_type(Some("private"))
+        .create_record(&CreateDnsRecordRequest {
+            domain_id: "z-1".to_string(),
+            record_type: DnsRecordType::Caa,
+            name: "@".to_string(),
+            value: "0 issue \"letsencrypt.org\"".to_string(),
+            ttl: 300,
+            priority: None,
+            proxied: None,
+            line: None,
+            remark: None,
+        })
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(err, ProviderError::InvalidParameter { ref param, .. } if param == "record_type")
+    );
+    assert!(server.requests().is_empty());
+}
+
+#[tokio::test]
+async fn nameservers_are_sorted_by_priority() {
+    let server = MockServer::start(vec![MockResponse::json(
+        200,
+        &json!({
+            "nameservers": [
+                {"hostname": "ns1-cloud.huaweicloud-dns.net.", "priority": 2},
+                {"hostname": "ns1.huaweicloud-dns.com.", "priority": 1}
+            ]
+        }),
+    )])
+    .await;
+
+    let nameservers = provider(&server)
+        .get_domain_nameservers("z-1")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        nameservers,
+        vec!["ns1.huaweicloud-dns.com", "ns1-cloud.huaweicloud-dns.net"]
+    );
+    assert_eq!(server.requests()[0].path, "/v2/zones/z-1/nameservers");
+}
+
+#[tokio::test]
+async fn wildcard_names_keep_leading_asterisk() {
+    let server = MockServer::start(vec![
+        zone_response(),
+        MockResponse::json(
+            200,
+            &json!({
+                "recordsets": [
+                    {"id": "r1", "name": "*.example.com.", "type": "A", "ttl": 300, "records": ["192.0.2.1"]},
+                    {"id": "r2", "name": "*.foo.example.com.", "type": "A", "ttl": 300, "records": ["192.0.2.2"]}
+                ],
+                "metadata": {"total_count": 2}
+            }),
+        ),
+        zone_response(),
+        MockResponse::json(
+            200,
+            &json!({"id": "r3", "name": "*.foo.example.com.", "type": "A"}),
+        ),
+    ])
+    .await;
+    let provider = provider(&server);
+
+    let response = provider
+        .list_records("z-1", &RecordQueryParams::default())
+        .await
+        .unwrap();
+    let created = provider
+        .create_record(&CreateDnsRecordRequest {
+            domain_id: "z-1".to_string(),
+            record_type: DnsRecordType::A,
+            name: "*.foo".to_string(),
+            value: "192.0.2.3".to_string(),
+            ttl: 300,
+            priority: None,
+            proxied: None,
+            line: None,
+            remark: None,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(response.items[0].name, "*");
+    assert_eq!(response.items[1].name, "*.foo");
+    assert_eq!(created.fqdn.as_deref(), Some("*.foo.example.com"));
+    assert!(
+        server.requests()[3]
+            .body
+            .contains(r#""name":"*.foo.example.com.""#)
+    );
+}
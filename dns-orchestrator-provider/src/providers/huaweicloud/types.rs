@@ -24,6 +24,22 @@ pub struct HuaweicloudZone {
     pub record_num: Option<u32>,
 }
 
+/// `GET /v2/zones/{zone_id}/nameservers` 响应
+#[derive(Debug, Deserialize)]
+pub struct ListNameServersResponse {
+    #[serde(default)]
+    pub nameservers: Vec<HuaweicloudNameServer>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HuaweicloudNameServer {
+    /// 公网 zone 返回主机名
+    pub hostname: Option<String>,
+    /// 内网 zone 返回 IP 地址
+    pub address: Option<String>,
+    pub priority: Option<u32>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ListRecordSetsResponse {
     pub recordsets: Option<Vec<HuaweicloudRecordSet>>,
@@ -39,7 +55,7 @@ pub struct HuaweicloudRecordSet {
     pub record_type: String,
     pub records: Option<Vec<String>>,
     pub ttl: Option<u32>,
-    #[allow(dead_code)]
+    /// ACTIVE / DISABLE / PENDING_* 等
     pub status: Option<String>,
     #[serde(rename = "created_at")]
     pub created_at: Option<String>,
@@ -1,6 +1,8 @@
 //! DNS Provider implementations
 
 pub mod common;
+#[cfg(test)]
+pub(crate) mod mock;
 
 #[cfg(feature = "aliyun")]
 mod aliyun;
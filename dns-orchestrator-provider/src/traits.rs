@@ -1,9 +1,11 @@
 use async_trait::async_trait;
 
 use crate::error::{ProviderError, Result};
+use crate::providers::common::{normalize_record_value, record_type_to_string};
 use crate::types::{
-    CreateDnsRecordRequest, DnsRecord, Domain, PaginatedResponse, PaginationParams,
-    RecordQueryParams, UpdateDnsRecordRequest,
+    CreateDnsRecordRequest, DnsRecord, DnsRecordType, DnssecStatus, Domain, PaginatedResponse,
+    PaginationParams, PermissionInfo, RecordLine, RecordPatch, RecordQueryParams,
+    UpdateDnsRecordRequest,
 };
 
 /// 原始 API 错误（内部使用）
@@ -43,6 +45,39 @@ pub(crate) struct ErrorContext {
     pub domain: Option<String>,
 }
 
+impl ErrorContext {
+    /// 域名级操作的上下文
+    pub fn for_domain(domain: impl Into<String>) -> Self {
+        Self {
+            domain: Some(domain.into()),
+            ..Self::default()
+        }
+    }
+
+    /// 按 ID 操作已有记录的上下文
+    pub fn for_record(domain: impl Into<String>, record_id: impl Into<String>) -> Self {
+        Self {
+            record_id: Some(record_id.into()),
+            ..Self::for_domain(domain)
+        }
+    }
+
+    /// 按名称创建或修改记录的上下文
+    pub fn for_record_name(domain: impl Into<String>, record_name: impl Into<String>) -> Self {
+        Self {
+            record_name: Some(record_name.into()),
+            ..Self::for_domain(domain)
+        }
+    }
+
+    /// 补充记录 ID（更新记录时同时知道名称和 ID）
+    #[must_use]
+    pub fn with_record_id(mut self, record_id: impl Into<String>) -> Self {
+        self.record_id = Some(record_id.into());
+        self
+    }
+}
+
 /// Provider 错误映射 Trait（内部使用）
 /// 各 Provider 实现此 trait 以将原始 API 错误映射到统一错误类型
 pub(crate) trait ProviderErrorMapper {
@@ -74,6 +109,7 @@ pub(crate) trait ProviderErrorMapper {
             provider: self.provider_name().to_string(),
             raw_code: raw.code,
             raw_message: raw.message,
+            request_id: None,
         }
     }
 }
@@ -85,8 +121,19 @@ pub trait DnsProvider: Send + Sync {
     fn id(&self) -> &'static str;
 
     /// 验证凭证是否有效
+    ///
+    /// 仅在凭证被拒绝时返回 `Ok(false)`；网络故障等其他错误原样返回 `Err`，
+    /// 以便区分"无法连接提供商"和"凭证无效"
     async fn validate_credentials(&self) -> Result<bool>;
 
+    /// 检查凭证拥有的权限
+    async fn check_permissions(&self) -> Result<PermissionInfo> {
+        Err(ProviderError::NotSupported {
+            provider: self.id().to_string(),
+            capability: "permissions".to_string(),
+        })
+    }
+
     /// 获取域名列表 (分页)
     async fn list_domains(&self, params: &PaginationParams) -> Result<PaginatedResponse<Domain>>;
 
@@ -100,6 +147,63 @@ pub trait DnsProvider: Send + Sync {
         params: &RecordQueryParams,
     ) -> Result<PaginatedResponse<DnsRecord>>;
 
+    /// 获取单条 DNS 记录（默认遍历域名下的全部记录查找）
+    async fn get_record(&self, domain_id: &str, record_id: &str) -> Result<DnsRecord> {
+        self.list_all_records(domain_id)
+            .await?
+            .into_iter()
+            .find(|r| r.id == record_id)
+            .ok_or_else(|| ProviderError::RecordNotFound {
+                provider: self.id().to_string(),
+                record_id: record_id.to_string(),
+                raw_message: None,
+                request_id: None,
+            })
+    }
+
+    /// 统计域名下的 DNS 记录数量（默认只请求一条记录，读取响应中的总数）
+    async fn count_records(&self, domain_id: &str) -> Result<u32> {
+        let params = RecordQueryParams {
+            page: 1,
+            page_size: 1,
+            ..Default::default()
+        };
+        Ok(self.list_records(domain_id, &params).await?.total_count)
+    }
+
+    /// 按名称（相对名称，如 `www`、`@`）和可选的记录类型查找记录
+    ///
+    /// 默认遍历全部记录后在本地过滤，Provider 可覆盖为使用服务端名称过滤
+    async fn find_records(
+        &self,
+        domain_id: &str,
+        name: &str,
+        record_type: Option<DnsRecordType>,
+    ) -> Result<Vec<DnsRecord>> {
+        collect_matching_records(self, domain_id, None, name, record_type.as_ref()).await
+    }
+
+    /// 创建前检查同名记录是否冲突，冲突时返回带现有值的 `RecordExists`
+    ///
+    /// 冲突指完全相同的记录（类型和值都相同），或 CNAME 与同名的其他记录共存
+    async fn can_create_record(&self, req: &CreateDnsRecordRequest) -> Result<()> {
+        let existing = self.find_records(&req.domain_id, &req.name, None).await?;
+        match find_conflict(&existing, req) {
+            Some(conflict) => Err(ProviderError::RecordExists {
+                provider: self.id().to_string(),
+                record_name: req.name.clone(),
+                raw_message: None,
+                existing_value: Some(format!(
+                    "{} {}",
+                    record_type_to_string(&conflict.record_type),
+                    conflict.value
+                )),
+                request_id: None,
+            }),
+            None => Ok(()),
+        }
+    }
+
     /// 创建 DNS 记录
     async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord>;
 
@@ -110,6 +214,179 @@ pub trait DnsProvider: Send + Sync {
         req: &UpdateDnsRecordRequest,
     ) -> Result<DnsRecord>;
 
+    /// 只修改指定字段（默认先读取现有记录，合并后整体更新）
+    async fn patch_record(
+        &self,
+        domain_id: &str,
+        record_id: &str,
+        changes: &RecordPatch,
+    ) -> Result<DnsRecord> {
+        let current = self.get_record(domain_id, record_id).await?;
+        let req = changes.apply_to(&current);
+        req.validate()
+            .map_err(|e| ProviderError::InvalidParameter {
+                provider: self.id().to_string(),
+                param: e.field.clone(),
+                detail: e.to_string(),
+                request_id: None,
+            })?;
+        self.update_record(record_id, &req).await
+    }
+
     /// 删除 DNS 记录
     async fn delete_record(&self, record_id: &str, domain_id: &str) -> Result<()>;
+
+    /// 启用或暂停 DNS 记录（暂停的记录不参与解析，但不会被删除）
+    async fn set_record_enabled(
+        &self,
+        _domain_id: &str,
+        _record_id: &str,
+        _enabled: bool,
+    ) -> Result<()> {
+        Err(ProviderError::NotSupported {
+            provider: self.id().to_string(),
+            capability: "record_enabled".to_string(),
+        })
+    }
+
+    /// 获取域名的 DNSSEC 状态
+    async fn get_dnssec_status(&self, _domain_id: &str) -> Result<DnssecStatus> {
+        Err(ProviderError::NotSupported {
+            provider: self.id().to_string(),
+            capability: "dnssec".to_string(),
+        })
+    }
+
+    /// 获取域名应在注册商处配置的 NS 服务器
+    async fn get_domain_nameservers(&self, _domain_id: &str) -> Result<Vec<String>> {
+        Err(ProviderError::NotSupported {
+            provider: self.id().to_string(),
+            capability: "nameservers".to_string(),
+        })
+    }
+
+    /// 获取域名可用的解析线路（随套餐不同）
+    async fn list_record_lines(&self, _domain_id: &str) -> Result<Vec<RecordLine>> {
+        Err(ProviderError::NotSupported {
+            provider: self.id().to_string(),
+            capability: "record_lines".to_string(),
+        })
+    }
+
+    /// 获取全部域名（自动翻页）
+    async fn list_all_domains(&self) -> Result<Vec<Domain>> {
+        let mut params = PaginationParams {
+            page: 1,
+            page_size: 100,
+            cursor: None,
+        };
+        let mut domains = Vec::new();
+
+        loop {
+            let response = self.list_domains(&params).await?;
+            let fetched = response.items.len();
+            domains.extend(response.items);
+
+            if !response.has_more || fetched == 0 {
+                break;
+            }
+            params.page += 1;
+            params.cursor = response.next_cursor;
+        }
+
+        Ok(domains)
+    }
+
+    /// 获取域名下的全部 DNS 记录（自动翻页）
+    async fn list_all_records(&self, domain_id: &str) -> Result<Vec<DnsRecord>> {
+        let mut params = RecordQueryParams {
+            page: 1,
+            page_size: 100,
+            ..Default::default()
+        };
+        let mut records = Vec::new();
+
+        loop {
+            let response = self.list_records(domain_id, &params).await?;
+            let fetched = response.items.len();
+            records.extend(response.items);
+
+            // 空页也视为结束，避免 provider 返回错误的 total_count 导致死循环
+            if !response.has_more || fetched == 0 {
+                break;
+            }
+            params.page += 1;
+            params.cursor = response.next_cursor;
+        }
+
+        Ok(records)
+    }
+}
+
+/// 记录名称（忽略大小写）和类型是否匹配（内部使用）
+pub(crate) fn record_matches(
+    record: &DnsRecord,
+    name: &str,
+    record_type: Option<&DnsRecordType>,
+) -> bool {
+    let name = if name.is_empty() { "@" } else { name };
+    record.name.eq_ignore_ascii_case(name) && record_type.is_none_or(|t| record.record_type == *t)
+}
+
+/// 在已有记录中查找与待创建记录冲突的同名记录
+///
+/// 同类型不同值（如轮询的多条 A 记录）不算冲突；`existing` 可以包含其他名称的记录
+pub fn find_conflict<'a>(
+    existing: &'a [DnsRecord],
+    req: &CreateDnsRecordRequest,
+) -> Option<&'a DnsRecord> {
+    let is_cname = |t: &DnsRecordType| matches!(t, DnsRecordType::Cname | DnsRecordType::Alias);
+    existing.iter().find(|r| {
+        if !record_matches(r, &req.name, None) {
+            return false;
+        }
+        if is_cname(&r.record_type) || is_cname(&req.record_type) {
+            return true;
+        }
+        r.record_type == req.record_type
+            && r.value
+                .eq_ignore_ascii_case(&normalize_record_value(&req.record_type, &req.value))
+    })
+}
+
+/// 按关键词翻页读取记录，并在本地按名称和类型精确过滤（内部使用）
+/// 供使用 `keyword` 做服务端名称过滤的 `find_records` 实现复用
+pub(crate) async fn collect_matching_records<P: DnsProvider + ?Sized>(
+    provider: &P,
+    domain_id: &str,
+    keyword: Option<String>,
+    name: &str,
+    record_type: Option<&DnsRecordType>,
+) -> Result<Vec<DnsRecord>> {
+    let mut params = RecordQueryParams {
+        page: 1,
+        page_size: 100,
+        keyword,
+        ..Default::default()
+    };
+    let mut records = Vec::new();
+
+    loop {
+        let response = provider.list_records(domain_id, &params).await?;
+        let fetched = response.items.len();
+        records.extend(
+            response
+                .items
+                .into_iter()
+                .filter(|r| record_matches(r, name, record_type)),
+        );
+
+        if !response.has_more || fetched == 0 {
+            break;
+        }
+        params.page += 1;
+        params.cursor = response.next_cursor;
+    }
+
+    Ok(records)
 }
@@ -8,6 +8,9 @@ use serde::{Deserialize, Serialize};
 pub struct PaginationParams {
     pub page: u32,
     pub page_size: u32,
+    /// 游标分页的游标（上一页响应的 `next_cursor`），按页码分页的 Provider 忽略
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
 }
 
 impl Default for PaginationParams {
@@ -15,6 +18,7 @@ impl Default for PaginationParams {
         Self {
             page: 1,
             page_size: 20,
+            cursor: None,
         }
     }
 }
@@ -31,6 +35,32 @@ pub struct RecordQueryParams {
     /// 记录类型过滤
     #[serde(skip_serializing_if = "Option::is_none")]
     pub record_type: Option<DnsRecordType>,
+    /// 游标分页的游标（上一页响应的 `next_cursor`），按页码分页的 Provider 忽略
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    /// 只返回该时间（RFC3339）之后修改过的记录
+    ///
+    /// 目前各 Provider 均在本地按 `updated_at` 过滤当前页，`total_count` 仍为过滤前的总数；
+    /// 没有 `updated_at` 的记录总是返回
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified_after: Option<String>,
+    /// 排序字段，未指定时保持 Provider 返回的顺序
+    ///
+    /// 支持的 Provider（Cloudflare）在服务端排序，其余 Provider 只对当前页排序
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_by: Option<RecordSort>,
+    /// 是否降序
+    #[serde(default)]
+    pub sort_desc: bool,
+}
+
+/// DNS 记录排序字段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RecordSort {
+    Name,
+    Type,
+    Ttl,
 }
 
 impl Default for RecordQueryParams {
@@ -40,6 +70,10 @@ impl Default for RecordQueryParams {
             page_size: 20,
             keyword: None,
             record_type: None,
+            cursor: None,
+            modified_after: None,
+            sort_by: None,
+            sort_desc: false,
         }
     }
 }
@@ -50,6 +84,7 @@ impl RecordQueryParams {
         PaginationParams {
             page: self.page,
             page_size: self.page_size,
+            cursor: self.cursor.clone(),
         }
     }
 }
@@ -63,6 +98,9 @@ pub struct PaginatedResponse<T> {
     pub page_size: u32,
     pub total_count: u32,
     pub has_more: bool,
+    /// 下一页的游标（仅游标分页的 Provider 返回，原样传回 `cursor` 即可翻页）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
 }
 
 impl<T> PaginatedResponse<T> {
@@ -74,6 +112,27 @@ impl<T> PaginatedResponse<T> {
             page_size,
             total_count,
             has_more,
+            next_cursor: None,
+        }
+    }
+
+    /// 设置下一页游标，`has_more` 以是否存在游标为准（供游标分页的 Provider 使用）
+    #[must_use]
+    pub fn with_next_cursor(mut self, next_cursor: Option<String>) -> Self {
+        self.has_more = next_cursor.is_some();
+        self.next_cursor = next_cursor;
+        self
+    }
+
+    /// 转换列表项，保留分页信息
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> PaginatedResponse<U> {
+        PaginatedResponse {
+            items: self.items.into_iter().map(f).collect(),
+            page: self.page,
+            page_size: self.page_size,
+            total_count: self.total_count,
+            has_more: self.has_more,
+            next_cursor: self.next_cursor,
         }
     }
 }
@@ -94,17 +153,42 @@ pub enum ProviderType {
     Huaweicloud,
 }
 
+impl ProviderType {
+    /// 提供商标识符（与序列化形式一致）
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            #[cfg(feature = "cloudflare")]
+            Self::Cloudflare => "cloudflare",
+            #[cfg(feature = "aliyun")]
+            Self::Aliyun => "aliyun",
+            #[cfg(feature = "dnspod")]
+            Self::Dnspod => "dnspod",
+            #[cfg(feature = "huaweicloud")]
+            Self::Huaweicloud => "huaweicloud",
+        }
+    }
+}
+
 impl std::fmt::Display for ProviderType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for ProviderType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
             #[cfg(feature = "cloudflare")]
-            Self::Cloudflare => write!(f, "cloudflare"),
+            "cloudflare" => Ok(Self::Cloudflare),
             #[cfg(feature = "aliyun")]
-            Self::Aliyun => write!(f, "aliyun"),
+            "aliyun" => Ok(Self::Aliyun),
             #[cfg(feature = "dnspod")]
-            Self::Dnspod => write!(f, "dnspod"),
+            "dnspod" => Ok(Self::Dnspod),
             #[cfg(feature = "huaweicloud")]
-            Self::Huaweicloud => write!(f, "huaweicloud"),
+            "huaweicloud" => Ok(Self::Huaweicloud),
+            _ => Err(format!("未知的提供商: {s}")),
         }
     }
 }
@@ -133,7 +217,7 @@ pub struct Domain {
 
 // ============ DNS 记录相关类型 ============
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum DnsRecordType {
     A,
@@ -144,6 +228,8 @@ pub enum DnsRecordType {
     Ns,
     Srv,
     Caa,
+    /// 根域 CNAME 的替代类型（ALIAS/ANAME），由各 Provider 映射到原生机制
+    Alias,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -154,16 +240,61 @@ pub struct DnsRecord {
     #[serde(rename = "type")]
     pub record_type: DnsRecordType,
     pub name: String,
+    /// 完整域名（如 `www.example.com`，根域为 zone 名称），列表接口总是填充
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fqdn: Option<String>,
+    /// 记录值，CNAME/MX/NS 的主机名统一为不带末尾点的形式
     pub value: String,
     pub ttl: u32,
     pub priority: Option<u16>,
     pub proxied: Option<bool>,
+    /// 解析线路（阿里云 Line / DNSPod RecordLine），None 为默认线路
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<String>,
+    /// 备注（DNSPod Remark）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remark: Option<String>,
+    /// 是否启用，None 表示提供商不支持暂停记录
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
     #[serde(rename = "createdAt")]
     pub created_at: Option<String>,
     #[serde(rename = "updatedAt")]
     pub updated_at: Option<String>,
 }
 
+/// 区域的 DNSSEC 状态
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DnssecStatus {
+    pub enabled: bool,
+    /// 需要在注册商处配置的 DS 记录
+    pub ds_records: Vec<String>,
+    /// 签名算法
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub algorithm: Option<String>,
+}
+
+/// 解析线路（智能解析按运营商、地域等返回不同结果）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordLine {
+    /// 创建记录时 `line` 字段传入的值（阿里云为线路代码，DNSPod 为线路名称）
+    pub id: String,
+    /// 显示名称
+    pub name: String,
+}
+
+/// 凭证的权限信息
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PermissionInfo {
+    /// 已授予的权限名称（如 Cloudflare 的 "DNS Write"）
+    pub permissions: Vec<String>,
+    /// 是否可以修改 DNS 记录，无法确定时为 None
+    pub can_write_dns: Option<bool>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateDnsRecordRequest {
     #[serde(rename = "domainId")]
@@ -175,6 +306,12 @@ pub struct CreateDnsRecordRequest {
     pub ttl: u32,
     pub priority: Option<u16>,
     pub proxied: Option<bool>,
+    /// 解析线路（阿里云 Line / DNSPod RecordLine），None 为默认线路
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<String>,
+    /// 备注（DNSPod Remark）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remark: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -188,6 +325,274 @@ pub struct UpdateDnsRecordRequest {
     pub ttl: u32,
     pub priority: Option<u16>,
     pub proxied: Option<bool>,
+    /// 解析线路（阿里云 Line / DNSPod RecordLine），None 为默认线路
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<String>,
+    /// 备注（DNSPod Remark）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remark: Option<String>,
+}
+
+/// DNS 记录的部分更新，只包含需要修改的字段（记录类型不可修改）
+///
+/// 可清空的字段为 `Option<Option<T>>`：缺省表示不修改，`null`（`Some(None)`）表示清空
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordPatch {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<u32>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_some",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub priority: Option<Option<u16>>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_some",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub proxied: Option<Option<bool>>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_some",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub line: Option<Option<String>>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_some",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub remark: Option<Option<String>>,
+}
+
+/// 字段存在时（包括 `null`）包一层 `Some`，与字段缺省区分
+fn deserialize_some<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: serde::Deserializer<'de>,
+{
+    T::deserialize(deserializer).map(Some)
+}
+
+impl RecordPatch {
+    /// 合并到现有记录，得到完整的更新请求
+    pub fn apply_to(&self, record: &DnsRecord) -> UpdateDnsRecordRequest {
+        UpdateDnsRecordRequest {
+            domain_id: record.domain_id.clone(),
+            record_type: record.record_type.clone(),
+            name: self.name.clone().unwrap_or_else(|| record.name.clone()),
+            value: self.value.clone().unwrap_or_else(|| record.value.clone()),
+            ttl: self.ttl.unwrap_or(record.ttl),
+            priority: self.priority.unwrap_or(record.priority),
+            proxied: self.proxied.unwrap_or(record.proxied),
+            line: self.line.clone().unwrap_or_else(|| record.line.clone()),
+            remark: self.remark.clone().unwrap_or_else(|| record.remark.clone()),
+        }
+    }
+}
+
+impl CreateDnsRecordRequest {
+    /// 本地校验记录值，避免无效请求消耗 API 配额
+    pub fn validate(&self) -> Result<(), RecordValidationError> {
+        validate_record_name(&self.name)?;
+        validate_record(&self.record_type, &self.value, self.priority)
+    }
+}
+
+impl UpdateDnsRecordRequest {
+    /// 本地校验记录值，避免无效请求消耗 API 配额
+    pub fn validate(&self) -> Result<(), RecordValidationError> {
+        validate_record_name(&self.name)?;
+        validate_record(&self.record_type, &self.value, self.priority)
+    }
+}
+
+/// TXT 记录值的最大长度
+const MAX_TXT_LENGTH: usize = 2048;
+
+/// 记录校验错误
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordValidationError {
+    /// 出错的字段（name / value / priority）
+    pub field: String,
+    pub reason: String,
+}
+
+impl RecordValidationError {
+    fn new(field: &str, reason: impl Into<String>) -> Self {
+        Self {
+            field: field.to_string(),
+            reason: reason.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for RecordValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.reason)
+    }
+}
+
+impl std::error::Error for RecordValidationError {}
+
+/// 检查是否为合法主机名（允许末尾的点和下划线）
+fn is_valid_hostname(value: &str) -> bool {
+    let host = value.strip_suffix('.').unwrap_or(value);
+    !host.is_empty()
+        && host.len() <= 253
+        && host.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        })
+}
+
+/// 校验记录名称中的通配符：`*` 只能作为最左侧的完整标签（如 `*`、`*.sub`）
+pub(crate) fn validate_record_name(name: &str) -> Result<(), RecordValidationError> {
+    let misplaced = name
+        .split('.')
+        .enumerate()
+        .any(|(index, label)| label.contains('*') && (index > 0 || label != "*"));
+    if misplaced {
+        return Err(RecordValidationError::new(
+            "name",
+            format!("'{name}' 中的通配符 * 只能作为最左侧的完整标签，如 * 或 *.sub"),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_record(
+    record_type: &DnsRecordType,
+    value: &str,
+    priority: Option<u16>,
+) -> Result<(), RecordValidationError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(RecordValidationError::new("value", "记录值不能为空"));
+    }
+
+    match record_type {
+        DnsRecordType::A => {
+            if value.parse::<std::net::Ipv4Addr>().is_err() {
+                return Err(RecordValidationError::new(
+                    "value",
+                    format!("'{value}' 不是有效的 IPv4 地址"),
+                ));
+            }
+        }
+        DnsRecordType::Aaaa => {
+            if value.parse::<std::net::Ipv6Addr>().is_err() {
+                return Err(RecordValidationError::new(
+                    "value",
+                    format!("'{value}' 不是有效的 IPv6 地址"),
+                ));
+            }
+        }
+        DnsRecordType::Cname | DnsRecordType::Ns | DnsRecordType::Mx | DnsRecordType::Alias => {
+            if !is_valid_hostname(value) {
+                return Err(RecordValidationError::new(
+                    "value",
+                    format!("'{value}' 不是有效的主机名"),
+                ));
+            }
+        }
+        DnsRecordType::Txt => {
+            if value.len() > MAX_TXT_LENGTH {
+                return Err(RecordValidationError::new(
+                    "value",
+                    format!("TXT 记录长度不能超过 {MAX_TXT_LENGTH} 个字符"),
+                ));
+            }
+        }
+        DnsRecordType::Srv | DnsRecordType::Caa => {}
+    }
+
+    if matches!(record_type, DnsRecordType::Mx | DnsRecordType::Srv) && priority.is_none() {
+        return Err(RecordValidationError::new(
+            "priority",
+            "MX / SRV 记录必须设置优先级",
+        ));
+    }
+
+    Ok(())
+}
+
+// ============ HTTP 客户端配置 ============
+
+/// Provider HTTP 客户端配置
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderClientConfig {
+    /// 代理地址，支持 `http://`、`https://`、`socks5://`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<String>,
+    /// 请求超时（秒）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+    /// 自定义 User-Agent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+}
+
+impl ProviderClientConfig {
+    /// 根据配置创建 HTTP 客户端，代理地址无效时返回 `NetworkError`
+    pub fn build_client(&self, provider: &str) -> crate::Result<reqwest::Client> {
+        self.build(provider, true)
+    }
+
+    /// 创建不经过代理的 HTTP 客户端（如检测本机公网 IP），超时和 User-Agent 仍按配置
+    pub fn build_direct_client(&self, provider: &str) -> crate::Result<reqwest::Client> {
+        self.build(provider, false)
+    }
+
+    /// 返回已应用配置的 `ClientBuilder`，供需要额外设置（如重定向策略）的调用方使用
+    pub fn client_builder(&self, provider: &str) -> crate::Result<reqwest::ClientBuilder> {
+        self.builder(provider, true)
+    }
+
+    fn build(&self, provider: &str, use_proxy: bool) -> crate::Result<reqwest::Client> {
+        self.builder(provider, use_proxy)?
+            .build()
+            .map_err(|e| network_error(provider, format!("Failed to build HTTP client: {e}")))
+    }
+
+    fn builder(&self, provider: &str, use_proxy: bool) -> crate::Result<reqwest::ClientBuilder> {
+        let mut builder = reqwest::Client::builder();
+        if !use_proxy {
+            // 同时忽略系统环境变量中的代理
+            builder = builder.no_proxy();
+        } else if let Some(proxy) = self.proxy.as_deref().filter(|p| !p.trim().is_empty()) {
+            let proxy = reqwest::Proxy::all(proxy.trim())
+                .map_err(|e| network_error(provider, format!("Invalid proxy URL: {e}")))?;
+            builder = builder.proxy(proxy);
+        }
+        if let Some(secs) = self.timeout_secs {
+            builder = builder.timeout(std::time::Duration::from_secs(secs));
+        }
+        if let Some(user_agent) = &self.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+        Ok(builder)
+    }
+}
+
+fn network_error(provider: &str, detail: String) -> crate::ProviderError {
+    crate::ProviderError::NetworkError {
+        provider: provider.to_string(),
+        detail,
+    }
 }
 
 // ============ Provider 元数据类型 ============
@@ -212,6 +617,58 @@ pub struct ProviderCredentialField {
     pub placeholder: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub help_text: Option<String>,
+    /// 是否可留空
+    #[serde(default)]
+    pub optional: bool,
+}
+
+/// TTL 取值范围
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TtlRange {
+    pub min: u32,
+    pub max: u32,
+    /// 表示"自动"的特殊值 (如 Cloudflare 的 1)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto: Option<u32>,
+}
+
+impl TtlRange {
+    /// TTL 是否在允许范围内
+    pub fn contains(&self, ttl: u32) -> bool {
+        self.auto == Some(ttl) || (self.min..=self.max).contains(&ttl)
+    }
+}
+
+impl Default for TtlRange {
+    fn default() -> Self {
+        Self {
+            min: 1,
+            max: u32::MAX,
+            auto: None,
+        }
+    }
+}
+
+/// 单个账号按限流建议的最大并发请求数
+const MAX_SUGGESTED_CONCURRENCY: usize = 5;
+
+/// API 限流：每个时间窗口内允许的请求数
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RateLimit {
+    pub requests: u32,
+    pub window_secs: u32,
+}
+
+impl RateLimit {
+    /// 按每秒请求数估算单个账号的并发数（1 到 `MAX_SUGGESTED_CONCURRENCY`）
+    pub fn suggested_concurrency(&self) -> usize {
+        let per_second = self.requests / self.window_secs.max(1);
+        usize::try_from(per_second)
+            .unwrap_or(usize::MAX)
+            .clamp(1, MAX_SUGGESTED_CONCURRENCY)
+    }
 }
 
 /// 提供商支持的功能
@@ -220,6 +677,15 @@ pub struct ProviderCredentialField {
 pub struct ProviderFeatures {
     /// 是否支持代理功能 (如 Cloudflare 的 CDN 代理)
     pub proxy: bool,
+    /// 允许的 TTL 范围
+    pub ttl_range: TtlRange,
+    /// 支持的记录类型
+    pub supported_record_types: Vec<DnsRecordType>,
+    /// 是否允许在根域（@）创建 CNAME（如 Cloudflare 的 CNAME 展平）
+    pub supports_apex_cname: bool,
+    /// 官方文档中的 API 限流，None 表示未公开
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<RateLimit>,
 }
 
 /// 提供商元数据
@@ -270,6 +736,17 @@ impl std::fmt::Display for CredentialValidationError {
     }
 }
 
+impl CredentialValidationError {
+    /// 出错的凭证字段名（如 `apiToken`）
+    pub fn field(&self) -> &str {
+        match self {
+            Self::MissingField { field, .. }
+            | Self::EmptyField { field, .. }
+            | Self::InvalidFormat { field, .. } => field,
+        }
+    }
+}
+
 impl std::error::Error for CredentialValidationError {}
 
 /// 凭证枚举 - 类型安全的凭证定义
@@ -285,6 +762,9 @@ pub enum ProviderCredentials {
     Aliyun {
         access_key_id: String,
         access_key_secret: String,
+        /// 地域（如 cn-hangzhou、ap-southeast-1）或完整的 endpoint 域名，留空使用 cn-hangzhou
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        region: Option<String>,
     },
 
     #[cfg(feature = "dnspod")]
@@ -299,6 +779,12 @@ pub enum ProviderCredentials {
     Huaweicloud {
         access_key_id: String,
         secret_access_key: String,
+        /// 地域（如 ap-southeast-1）或完整的 endpoint 域名，留空使用全局 endpoint
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        region: Option<String>,
+        /// Zone 类型：public / private（内网 VPC），留空为 public
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        zone_type: Option<String>,
     },
 }
 
@@ -327,6 +813,7 @@ impl ProviderCredentials {
                     "accessKeySecret",
                     "Access Key Secret",
                 )?,
+                region: Self::get_optional_field(map, "region"),
             }),
             #[cfg(feature = "dnspod")]
             ProviderType::Dnspod => Ok(Self::Dnspod {
@@ -334,20 +821,45 @@ impl ProviderCredentials {
                 secret_key: Self::get_required_field(provider, map, "secretKey", "Secret Key")?,
             }),
             #[cfg(feature = "huaweicloud")]
-            ProviderType::Huaweicloud => Ok(Self::Huaweicloud {
-                access_key_id: Self::get_required_field(
-                    provider,
-                    map,
-                    "accessKeyId",
-                    "Access Key ID",
-                )?,
-                secret_access_key: Self::get_required_field(
-                    provider,
-                    map,
-                    "secretAccessKey",
-                    "Secret Access Key",
-                )?,
-            }),
+            ProviderType::Huaweicloud => {
+                let region = Self::get_optional_field(map, "region");
+                let zone_type = match Self::get_optional_field(map, "zoneType") {
+                    Some(t) if t != "public" && t != "private" => {
+                        return Err(CredentialValidationError::InvalidFormat {
+                            provider: provider.clone(),
+                            field: "zoneType".to_string(),
+                            label: "Zone 类型".to_string(),
+                            reason: "只能是 public 或 private".to_string(),
+                        });
+                    }
+                    t => t,
+                };
+                // 内网 Zone 按地域管理，全局 endpoint 只能访问公网 Zone
+                if zone_type.as_deref() == Some("private") && region.is_none() {
+                    return Err(CredentialValidationError::InvalidFormat {
+                        provider: provider.clone(),
+                        field: "region".to_string(),
+                        label: "地域".to_string(),
+                        reason: "内网 Zone 需要填写 VPC 所在地域".to_string(),
+                    });
+                }
+                Ok(Self::Huaweicloud {
+                    access_key_id: Self::get_required_field(
+                        provider,
+                        map,
+                        "accessKeyId",
+                        "Access Key ID",
+                    )?,
+                    secret_access_key: Self::get_required_field(
+                        provider,
+                        map,
+                        "secretAccessKey",
+                        "Secret Access Key",
+                    )?,
+                    region,
+                    zone_type,
+                })
+            }
             #[allow(unreachable_patterns)]
             _ => Err(CredentialValidationError::InvalidFormat {
                 provider: provider.clone(),
@@ -383,6 +895,22 @@ impl ProviderCredentials {
         }
     }
 
+    /// 从 HashMap 中获取可选字段，空字符串视为未填写
+    fn get_optional_field(
+        map: &std::collections::HashMap<String, String>,
+        key: &str,
+    ) -> Option<String> {
+        map.get(key)
+            .map(|v| v.trim())
+            .filter(|v| !v.is_empty())
+            .map(str::to_string)
+    }
+
+    /// 校验必填字段非空（直接构造或反序列化得到的凭证不经过 `from_map` 的校验）
+    pub fn validate(&self) -> Result<(), CredentialValidationError> {
+        Self::from_map(&self.provider_type(), &self.to_map()).map(|_| ())
+    }
+
     /// 转换为 HashMap（保存时用，保持存储格式兼容）
     pub fn to_map(&self) -> std::collections::HashMap<String, String> {
         match self {
@@ -390,11 +918,18 @@ impl ProviderCredentials {
             Self::Aliyun {
                 access_key_id,
                 access_key_secret,
-            } => [
-                ("accessKeyId".to_string(), access_key_id.clone()),
-                ("accessKeySecret".to_string(), access_key_secret.clone()),
-            ]
-            .into(),
+                region,
+            } => {
+                let mut map: std::collections::HashMap<String, String> = [
+                    ("accessKeyId".to_string(), access_key_id.clone()),
+                    ("accessKeySecret".to_string(), access_key_secret.clone()),
+                ]
+                .into();
+                if let Some(region) = region {
+                    map.insert("region".to_string(), region.clone());
+                }
+                map
+            }
             Self::Dnspod {
                 secret_id,
                 secret_key,
@@ -406,11 +941,22 @@ impl ProviderCredentials {
             Self::Huaweicloud {
                 access_key_id,
                 secret_access_key,
-            } => [
-                ("accessKeyId".to_string(), access_key_id.clone()),
-                ("secretAccessKey".to_string(), secret_access_key.clone()),
-            ]
-            .into(),
+                region,
+                zone_type,
+            } => {
+                let mut map: std::collections::HashMap<String, String> = [
+                    ("accessKeyId".to_string(), access_key_id.clone()),
+                    ("secretAccessKey".to_string(), secret_access_key.clone()),
+                ]
+                .into();
+                if let Some(region) = region {
+                    map.insert("region".to_string(), region.clone());
+                }
+                if let Some(zone_type) = zone_type {
+                    map.insert("zoneType".to_string(), zone_type.clone());
+                }
+                map
+            }
         }
     }
 
@@ -424,3 +970,100 @@ impl ProviderCredentials {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ProviderType;
+    use crate::factory::get_all_provider_metadata;
+
+    #[test]
+    fn provider_type_str_round_trip() {
+        for provider in get_all_provider_metadata().into_iter().map(|m| m.id) {
+            let id = provider.as_str();
+            assert_eq!(id.parse::<ProviderType>(), Ok(provider.clone()));
+            // 与 serde 的序列化形式保持一致
+            assert_eq!(serde_json::to_value(&provider).unwrap(), id);
+        }
+        assert!("unknown".parse::<ProviderType>().is_err());
+    }
+
+    #[cfg(feature = "cloudflare")]
+    #[test]
+    fn blank_token_is_rejected_before_building_provider() {
+        let credentials = super::ProviderCredentials::Cloudflare {
+            api_token: "  ".to_string(),
+        };
+        assert!(matches!(
+            credentials.validate(),
+            Err(super::CredentialValidationError::EmptyField { ref field, .. }) if field == "apiToken"
+        ));
+        assert!(matches!(
+            crate::factory::create_provider(credentials),
+            Err(crate::ProviderError::InvalidParameter { ref param, .. }) if param == "apiToken"
+        ));
+    }
+
+    #[cfg(feature = "huaweicloud")]
+    #[test]
+    fn private_huaweicloud_zone_requires_region() {
+        let map = |pairs: &[(&str, &str)]| {
+            [("accessKeyId", "ak"), ("secretAccessKey", "sk")]
+                .iter()
+                .chain(pairs)
+                .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+                .collect::<std::collections::HashMap<_, _>>()
+        };
+        let from_map = |pairs: &[(&str, &str)]| {
+            super::ProviderCredentials::from_map(&ProviderType::Huaweicloud, &map(pairs))
+        };
+        assert!(from_map(&[("zoneType", "public")]).is_ok());
+        assert!(matches!(
+            from_map(&[("zoneType", "private")]),
+            Err(super::CredentialValidationError::InvalidFormat { ref field, .. }) if field == "region"
+        ));
+        assert!(from_map(&[("zoneType", "private"), ("region", "ap-southeast-1")]).is_ok());
+    }
+
+    #[test]
+    fn wildcard_must_be_leftmost_label() {
+        let validate = |name: &str| super::validate_record_name(name).is_ok();
+        assert!(validate("*"));
+        assert!(validate("*.foo"));
+        assert!(validate("@"));
+        assert!(!validate("a*"));
+        assert!(!validate("foo.*"));
+        assert!(!validate("*.*.foo"));
+    }
+
+    #[test]
+    fn record_patch_can_clear_optional_fields() {
+        let record = super::DnsRecord {
+            id: "r1".to_string(),
+            domain_id: "z1".to_string(),
+            record_type: super::DnsRecordType::Mx,
+            name: "@".to_string(),
+            fqdn: None,
+            value: "mail.example.com".to_string(),
+            ttl: 600,
+            priority: Some(10),
+            proxied: None,
+            line: Some("default".to_string()),
+            remark: Some("old".to_string()),
+            enabled: None,
+            created_at: None,
+            updated_at: None,
+        };
+
+        let patch: super::RecordPatch =
+            serde_json::from_value(serde_json::json!({"remark": null, "ttl": 300})).unwrap();
+        assert_eq!(patch.remark, Some(None));
+        assert_eq!(patch.line, None);
+
+        let req = patch.apply_to(&record);
+        assert_eq!(req.remark, None);
+        assert_eq!(req.ttl, 300);
+        // 未出现的字段保持不变
+        assert_eq!(req.line.as_deref(), Some("default"));
+        assert_eq!(req.priority, Some(10));
+    }
+}
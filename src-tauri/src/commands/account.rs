@@ -1,14 +1,23 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::future::join_all;
+use sha2::{Digest, Sha256};
 use tauri::State;
+use tokio::sync::Semaphore;
 
 use crate::crypto;
-use crate::error::DnsError;
-use crate::providers::create_provider;
+use crate::error::{DnsError, ProviderError};
+use crate::providers::{create_provider_with_config, DnsProvider};
 use crate::storage::AccountStore;
 use crate::types::{
-    Account, AccountStatus, ApiResponse, CreateAccountRequest, ExportAccountsRequest,
-    ExportAccountsResponse, ExportFile, ExportFileHeader, ExportedAccount, ImportAccountsRequest,
-    ImportFailure, ImportPreview, ImportPreviewAccount, ImportResult, ProviderCredentials,
-    ProviderMetadata,
+    Account, AccountMetrics, AccountStatus, ApiResponse, CreateAccountRequest,
+    CredentialTestResult, ExportAccountsRequest, ExportAccountsResponse, ExportFile,
+    ExportFileHeader, ExportedAccount, ImportAccountsRequest, ImportAction, ImportFailure,
+    ImportPreview, ImportPreviewAccount, ImportResult, ImportStrategy, KdfParams,
+    ProviderClientConfig, ProviderCredentials, ProviderMetadata, ProviderType,
+    RefreshAccountStatusesResult, RestoreAccountsResult, UpdateAccountRequest,
 };
 use crate::AppState;
 
@@ -23,18 +32,19 @@ pub async fn list_accounts(
 
 /// 创建新账号
 /// 1. 验证凭证
-/// 2. 保存凭证到 Keychain
-/// 3. 注册 Provider 实例
-/// 4. 保存账号元数据
+/// 2. 检查写入权限
+/// 3. 保存凭证到 Keychain
+/// 4. 注册 Provider 实例
+/// 5. 保存账号元数据
 #[tauri::command]
 pub async fn create_account(
     state: State<'_, AppState>,
     request: CreateAccountRequest,
 ) -> Result<ApiResponse<Account>, DnsError> {
     // 1. 转换凭证并创建 provider 实例
-    let credentials = ProviderCredentials::from_map(&request.provider, &request.credentials)
-        .map_err(DnsError::CredentialValidation)?;
-    let provider = create_provider(credentials)?;
+    let credentials = ProviderCredentials::from_map(&request.provider, &request.credentials)?;
+    let client_config = state.client_config.read().await.clone();
+    let provider = create_provider_with_config(credentials, &client_config)?;
 
     // 2. 验证凭证
     let is_valid = provider.validate_credentials().await?;
@@ -44,15 +54,32 @@ pub async fn create_account(
             crate::error::ProviderError::InvalidCredentials {
                 provider: request.provider.to_string(),
                 raw_message: None,
+                request_id: None,
             },
         ));
     }
 
-    // 3. 生成账号 ID
+    // 3. 检查写入权限（无法确定时不阻止创建）
+    let warning = match provider.check_permissions().await {
+        Ok(info) if info.can_write_dns == Some(false) => {
+            log::warn!(
+                "Credentials for new {} account lack DNS write permission",
+                request.provider
+            );
+            Some("凭证缺少 DNS 写入权限，只能查看记录".to_string())
+        }
+        Ok(_) => None,
+        Err(e) => {
+            log::debug!("Skip permission check: {e}");
+            None
+        }
+    };
+
+    // 4. 生成账号 ID
     let account_id = uuid::Uuid::new_v4().to_string();
     let now = chrono::Utc::now().to_rfc3339();
 
-    // 4. 保存凭证到 Keychain
+    // 5. 保存凭证到 Keychain
     log::info!("Saving credentials to Keychain for account: {account_id}");
     state
         .credential_store
@@ -63,10 +90,10 @@ pub async fn create_account(
         })?;
     log::info!("Credentials saved successfully to Keychain");
 
-    // 5. 注册 provider 到 registry
+    // 6. 注册 provider 到 registry
     state.registry.register(account_id.clone(), provider).await;
 
-    // 6. 创建账号元数据
+    // 7. 创建账号元数据
     let account = Account {
         id: account_id,
         name: request.name,
@@ -75,12 +102,13 @@ pub async fn create_account(
         updated_at: now,
         status: Some(crate::types::AccountStatus::Active),
         error: None,
+        warning,
     };
 
-    // 7. 保存账号元数据到内存
+    // 8. 保存账号元数据到内存
     state.accounts.write().await.push(account.clone());
 
-    // 8. 持久化账户元数据到 Store
+    // 9. 持久化账户元数据到 Store
     let accounts = state.accounts.read().await.clone();
     if let Err(e) = AccountStore::save_accounts(&state.app_handle, &accounts) {
         log::error!("Failed to persist account to store: {e}");
@@ -90,6 +118,121 @@ pub async fn create_account(
     Ok(ApiResponse::success(account))
 }
 
+/// 测试凭证是否有效（不保存凭证、不注册 Provider）
+#[tauri::command]
+pub async fn test_credentials(
+    state: State<'_, AppState>,
+    provider: ProviderType,
+    credentials: HashMap<String, String>,
+) -> Result<ApiResponse<CredentialTestResult>, DnsError> {
+    let credentials = ProviderCredentials::from_map(&provider, &credentials)?;
+    let client_config = state.client_config.read().await.clone();
+    let provider = create_provider_with_config(credentials, &client_config)?;
+
+    if !provider.validate_credentials().await? {
+        return Ok(ApiResponse::success(CredentialTestResult {
+            valid: false,
+            permissions: None,
+        }));
+    }
+
+    let permissions = match provider.check_permissions().await {
+        Ok(info) => Some(info),
+        Err(e) => {
+            log::debug!("Skip permission check: {e}");
+            None
+        }
+    };
+
+    Ok(ApiResponse::success(CredentialTestResult {
+        valid: true,
+        permissions,
+    }))
+}
+
+/// 更新账号名称或凭证
+/// 1. 验证新凭证（失败时保留原有 Provider）
+/// 2. 保存凭证到 Keychain
+/// 3. 替换注册的 Provider 实例
+/// 4. 更新账号元数据
+#[tauri::command]
+pub async fn update_account(
+    state: State<'_, AppState>,
+    request: UpdateAccountRequest,
+) -> Result<ApiResponse<Account>, DnsError> {
+    // 1. 检查账号是否存在
+    let provider_type = state
+        .accounts
+        .read()
+        .await
+        .iter()
+        .find(|a| a.id == request.id)
+        .map(|a| a.provider.clone())
+        .ok_or_else(|| DnsError::AccountNotFound(request.id.clone()))?;
+
+    // 2. 验证新凭证并替换 provider
+    let mut warning = None;
+    if let Some(credentials) = &request.credentials {
+        let typed_credentials = ProviderCredentials::from_map(&provider_type, credentials)?;
+        let client_config = state.client_config.read().await.clone();
+        let provider = create_provider_with_config(typed_credentials, &client_config)?;
+
+        if !provider.validate_credentials().await? {
+            return Err(DnsError::Provider(
+                crate::error::ProviderError::InvalidCredentials {
+                    provider: provider_type.to_string(),
+                    raw_message: None,
+                    request_id: None,
+                },
+            ));
+        }
+
+        if let Ok(info) = provider.check_permissions().await {
+            if info.can_write_dns == Some(false) {
+                warning = Some("凭证缺少 DNS 写入权限，只能查看记录".to_string());
+            }
+        }
+
+        state
+            .credential_store
+            .save(&request.id, credentials)
+            .map_err(|e| {
+                log::error!("Failed to save credentials to Keychain: {e}");
+                DnsError::CredentialError(e.to_string())
+            })?;
+
+        state.registry.register(request.id.clone(), provider).await;
+    }
+
+    // 3. 更新账号元数据
+    let (account, accounts) = {
+        let mut accounts = state.accounts.write().await;
+        let account = accounts
+            .iter_mut()
+            .find(|a| a.id == request.id)
+            .ok_or_else(|| DnsError::AccountNotFound(request.id.clone()))?;
+
+        if let Some(name) = request.name.filter(|n| !n.trim().is_empty()) {
+            account.name = name;
+        }
+        if request.credentials.is_some() {
+            account.status = Some(AccountStatus::Active);
+            account.error = None;
+            account.warning = warning;
+        }
+        account.updated_at = chrono::Utc::now().to_rfc3339();
+
+        (account.clone(), accounts.clone())
+    };
+
+    // 4. 持久化账户元数据
+    if let Err(e) = AccountStore::save_accounts(&state.app_handle, &accounts) {
+        log::error!("Failed to persist account to store: {e}");
+    }
+
+    Ok(ApiResponse::success(account))
+}
+
 /// 删除账号
 /// 1. 注销 Provider
 /// 2. 删除凭证
@@ -127,6 +270,68 @@ pub async fn delete_account(
     Ok(ApiResponse::success(()))
 }
 
+/// 重新执行账户恢复：重新读取 Store 和 Keychain，重建并注册所有 Provider
+///
+/// 用于 Keychain 在启动时被锁定或拒绝访问的情况，用户授权后无需重启应用；
+/// Keychain 仍不可用时返回错误，账号状态保持不变。
+/// `validate` 为 true 时恢复后再验证各账号的凭证（较慢）
+#[tauri::command]
+pub async fn reload_credentials(
+    state: State<'_, AppState>,
+    validate: Option<bool>,
+) -> Result<ApiResponse<RestoreAccountsResult>, DnsError> {
+    let result = crate::restore_accounts(&state, validate.unwrap_or(false)).await?;
+    Ok(ApiResponse::success(result))
+}
+
+/// 用已加载的凭证为账号重建 Provider 实例，失败时返回显示在账号上的错误信息
+pub(crate) fn build_account_provider(
+    account: &Account,
+    credentials: Option<&HashMap<String, String>>,
+    client_config: &ProviderClientConfig,
+) -> Result<Arc<dyn DnsProvider>, String> {
+    let Some(credentials) = credentials else {
+        log::warn!(
+            "No credentials found for account {}: credential not in store",
+            account.id
+        );
+        return Err("凭证未找到".to_string());
+    };
+
+    let typed_credentials =
+        ProviderCredentials::from_map(&account.provider, credentials).map_err(|e| {
+            log::warn!(
+                "Failed to parse credentials for account {}: {}",
+                account.id,
+                e
+            );
+            format!("凭证格式错误: {e}")
+        })?;
+
+    create_provider_with_config(typed_credentials, client_config).map_err(|e| {
+        log::warn!(
+            "Failed to create provider for account {}: {}",
+            account.id,
+            e
+        );
+        format!("Provider 创建失败: {e}")
+    })
+}
+
+/// 获取账号的 Provider 调用指标（请求数、错误数、平均耗时）
+#[tauri::command]
+pub async fn get_account_metrics(
+    state: State<'_, AppState>,
+    account_id: String,
+) -> Result<ApiResponse<AccountMetrics>, DnsError> {
+    let metrics = state
+        .registry
+        .metrics(&account_id)
+        .await
+        .ok_or_else(|| DnsError::AccountNotFound(account_id.clone()))?;
+    Ok(ApiResponse::success(metrics.snapshot(&account_id)))
+}
+
 /// 获取所有支持的提供商列表
 #[tauri::command]
 pub async fn list_providers() -> Result<ApiResponse<Vec<ProviderMetadata>>, DnsError> {
@@ -134,26 +339,60 @@ pub async fn list_providers() -> Result<ApiResponse<Vec<ProviderMetadata>>, DnsE
     Ok(ApiResponse::success(providers))
 }
 
-/// 导出账号（准备导出内容，返回 JSON 字符串）
-#[tauri::command]
-pub async fn export_accounts(
-    state: State<'_, AppState>,
-    request: ExportAccountsRequest,
-) -> Result<ApiResponse<ExportAccountsResponse>, DnsError> {
-    // 1. 获取选中账号的元数据
-    let accounts = state.accounts.read().await;
-    let selected_accounts: Vec<&Account> = accounts
-        .iter()
-        .filter(|a| request.account_ids.contains(&a.id))
-        .collect();
+/// 导入时同时验证凭证的最大请求数
+const IMPORT_VALIDATION_CONCURRENCY: usize = 5;
 
-    if selected_accounts.is_empty() {
-        return Err(DnsError::NoAccountsSelected);
+/// 当前写入的导出文件格式版本
+///
+/// - v1：加密固定使用 PBKDF2，头部不参与认证
+/// - v2：头部记录 KDF 参数（作为 AES-GCM 附加认证数据）和 `data` 的 SHA-256 校验和
+const EXPORT_FILE_VERSION: u32 = 2;
+
+/// 可读取的最低导出文件格式版本
+const MIN_EXPORT_FILE_VERSION: u32 = 1;
+
+/// 检查导出文件版本是否可读取
+///
+/// 新格式需要在此处声明兼容的版本范围，读取逻辑再按版本分支处理
+fn check_export_version(header: &ExportFileHeader) -> Result<(), DnsError> {
+    if (MIN_EXPORT_FILE_VERSION..=EXPORT_FILE_VERSION).contains(&header.version) {
+        Ok(())
+    } else {
+        Err(DnsError::UnsupportedFileVersion)
     }
+}
+
+/// 生成加密时的附加认证数据（AAD），使头部被篡改时解密失败
+///
+/// salt 和 nonce 由加密过程生成，且已分别参与密钥派生和 GCM 校验；
+/// 校验和在加密后才能计算，且密文本身已受 GCM 保护，因此均不计入
+fn header_aad(header: &ExportFileHeader) -> Result<Vec<u8>, DnsError> {
+    let header = ExportFileHeader {
+        salt: None,
+        nonce: None,
+        checksum: None,
+        ..header.clone()
+    };
+    serde_json::to_vec(&header).map_err(|e| DnsError::SerializationError(e.to_string()))
+}
+
+/// 计算导出文件 `data` 字段的 SHA-256 校验和
+fn data_checksum(data: &serde_json::Value) -> Result<String, DnsError> {
+    let bytes =
+        serde_json::to_vec(data).map_err(|e| DnsError::SerializationError(e.to_string()))?;
+    Ok(hex::encode(Sha256::digest(&bytes)))
+}
 
-    // 2. 加载凭证并构建导出数据
+/// 加载账号凭证并生成导出文件内容
+fn build_export(
+    state: &AppState,
+    accounts: &[Account],
+    encrypt: bool,
+    password: Option<&str>,
+) -> Result<ExportAccountsResponse, DnsError> {
+    // 1. 加载凭证并构建导出数据
     let mut exported_accounts = Vec::new();
-    for account in selected_accounts {
+    for account in accounts {
         let credentials = match state.credential_store.load(&account.id) {
             Ok(creds) => creds,
             Err(e) => {
@@ -172,52 +411,60 @@ pub async fn export_accounts(
         });
     }
 
-    // 3. 序列化账号数据
+    // 2. 序列化账号数据
     let accounts_json = serde_json::to_value(&exported_accounts)
         .map_err(|e| DnsError::SerializationError(e.to_string()))?;
 
-    // 4. 构建导出文件
+    // 3. 构建导出文件
     let now = chrono::Utc::now().to_rfc3339();
     let app_version = env!("CARGO_PKG_VERSION").to_string();
 
-    let export_file = if request.encrypt {
-        let password = request
-            .password
-            .as_ref()
+    let mut export_file = if encrypt {
+        let password = password
             .ok_or_else(|| DnsError::ValidationError("加密导出需要提供密码".to_string()))?;
 
         let plaintext = serde_json::to_vec(&accounts_json)
             .map_err(|e| DnsError::SerializationError(e.to_string()))?;
 
-        let (salt, nonce, ciphertext) = crypto::encrypt(&plaintext, password)
+        let kdf = KdfParams::recommended();
+        let mut header = ExportFileHeader {
+            version: EXPORT_FILE_VERSION,
+            encrypted: true,
+            salt: None,
+            nonce: None,
+            kdf: Some(kdf.clone()),
+            checksum: None,
+            exported_at: now,
+            app_version,
+        };
+        let aad = header_aad(&header)?;
+        let (salt, nonce, ciphertext) = crypto::encrypt(&plaintext, password, &kdf, &aad)
             .map_err(|e| DnsError::ImportExportError(e.to_string()))?;
+        header.salt = Some(salt);
+        header.nonce = Some(nonce);
 
         ExportFile {
-            header: ExportFileHeader {
-                version: 1,
-                encrypted: true,
-                salt: Some(salt),
-                nonce: Some(nonce),
-                exported_at: now,
-                app_version,
-            },
+            header,
             data: serde_json::Value::String(ciphertext),
         }
     } else {
         ExportFile {
             header: ExportFileHeader {
-                version: 1,
+                version: EXPORT_FILE_VERSION,
                 encrypted: false,
                 salt: None,
                 nonce: None,
+                kdf: None,
+                checksum: None,
                 exported_at: now,
                 app_version,
             },
             data: accounts_json,
         }
     };
+    export_file.header.checksum = Some(data_checksum(&export_file.data)?);
 
-    // 5. 生成文件内容
+    // 4. 生成文件内容
     let content = serde_json::to_string_pretty(&export_file)
         .map_err(|e| DnsError::SerializationError(e.to_string()))?;
 
@@ -226,10 +473,238 @@ pub async fn export_accounts(
         chrono::Local::now().format("%Y%m%d-%H%M%S")
     );
 
-    Ok(ApiResponse::success(ExportAccountsResponse {
+    Ok(ExportAccountsResponse {
         content,
         suggested_filename,
-    }))
+    })
+}
+
+/// 导出账号（准备导出内容，返回 JSON 字符串）
+#[tauri::command]
+pub async fn export_accounts(
+    state: State<'_, AppState>,
+    request: ExportAccountsRequest,
+) -> Result<ApiResponse<ExportAccountsResponse>, DnsError> {
+    let selected_accounts: Vec<Account> = state
+        .accounts
+        .read()
+        .await
+        .iter()
+        .filter(|a| request.account_ids.contains(&a.id))
+        .cloned()
+        .collect();
+
+    if selected_accounts.is_empty() {
+        return Err(DnsError::NoAccountsSelected);
+    }
+
+    let response = build_export(
+        &state,
+        &selected_accounts,
+        request.encrypt,
+        request.password.as_deref(),
+    )?;
+    Ok(ApiResponse::success(response))
+}
+
+/// 导出全部账号（完整备份）
+#[tauri::command]
+pub async fn export_all_accounts(
+    state: State<'_, AppState>,
+    encrypt: bool,
+    password: Option<String>,
+) -> Result<ApiResponse<ExportAccountsResponse>, DnsError> {
+    let accounts = state.accounts.read().await.clone();
+
+    if accounts.is_empty() {
+        return Err(DnsError::NoAccountsSelected);
+    }
+
+    let response = build_export(&state, &accounts, encrypt, password.as_deref())?;
+    Ok(ApiResponse::success(response))
+}
+
+/// 解析导入文件并检查版本，v2 起同时核对校验和
+fn parse_export_file(content: &str) -> Result<ExportFile, DnsError> {
+    let export_file: ExportFile = serde_json::from_str(content)
+        .map_err(|e| DnsError::ImportExportError(format!("无效的导入文件: {e}")))?;
+    check_export_version(&export_file.header)?;
+
+    if export_file.header.version >= 2 {
+        let expected = export_file
+            .header
+            .checksum
+            .as_deref()
+            .ok_or_else(|| DnsError::ImportExportError("缺少数据校验和".to_string()))?;
+        if !data_checksum(&export_file.data)?.eq_ignore_ascii_case(expected) {
+            return Err(DnsError::ImportExportError(
+                "文件校验失败，内容可能已损坏或被修改".to_string(),
+            ));
+        }
+    }
+    Ok(export_file)
+}
+
+/// 逐个解析账号数据，提供商未知或凭证字段不匹配的账号单独列出，不影响其他账号
+fn parse_exported_accounts(
+    data: serde_json::Value,
+) -> Result<(Vec<ExportedAccount>, Vec<ImportFailure>), DnsError> {
+    let items: Vec<serde_json::Value> = serde_json::from_value(data)
+        .map_err(|e| DnsError::ImportExportError(format!("解析账号数据失败: {e}")))?;
+
+    let mut accounts = Vec::new();
+    let mut invalid = Vec::new();
+    for (index, item) in items.into_iter().enumerate() {
+        let name = item
+            .get("name")
+            .and_then(serde_json::Value::as_str)
+            .map_or_else(|| format!("#{}", index + 1), str::to_string);
+        let checked = serde_json::from_value::<ExportedAccount>(item)
+            .map_err(|e| format!("账号数据无效: {e}"))
+            .and_then(|account| {
+                ProviderCredentials::from_map(&account.provider, &account.credentials)
+                    .map(|_| account)
+                    .map_err(|e| format!("凭证格式错误: {e}"))
+            });
+        match checked {
+            Ok(account) => accounts.push(account),
+            Err(reason) => invalid.push(ImportFailure { name, reason }),
+        }
+    }
+    Ok((accounts, invalid))
+}
+
+/// 解密（或直接解析）导入文件中的账号数据，返回可导入的账号和无效的账号
+fn read_exported_accounts(
+    export_file: ExportFile,
+    password: Option<&str>,
+) -> Result<(Vec<ExportedAccount>, Vec<ImportFailure>), DnsError> {
+    if !export_file.header.encrypted {
+        return parse_exported_accounts(export_file.data);
+    }
+
+    let password =
+        password.ok_or_else(|| DnsError::ImportExportError("加密文件需要提供密码".to_string()))?;
+    let ciphertext = export_file
+        .data
+        .as_str()
+        .ok_or_else(|| DnsError::ImportExportError("无效的加密数据".to_string()))?;
+    let salt = export_file
+        .header
+        .salt
+        .as_ref()
+        .ok_or_else(|| DnsError::ImportExportError("缺少加密盐值".to_string()))?;
+    let nonce = export_file
+        .header
+        .nonce
+        .as_ref()
+        .ok_or_else(|| DnsError::ImportExportError("缺少加密 nonce".to_string()))?;
+
+    // v1 固定使用 PBKDF2，且未绑定头部；v2 起 KDF 参数记录在头部
+    let (kdf, aad) = if export_file.header.version == 1 {
+        (KdfParams::legacy(), Vec::new())
+    } else {
+        let kdf = export_file
+            .header
+            .kdf
+            .clone()
+            .ok_or_else(|| DnsError::ImportExportError("缺少密钥派生参数".to_string()))?;
+        (kdf, header_aad(&export_file.header)?)
+    };
+
+    let plaintext = crypto::decrypt(ciphertext, password, salt, nonce, &kdf, &aad)
+        .map_err(|_| DnsError::ImportExportError("解密失败，请检查密码是否正确".to_string()))?;
+
+    let data = serde_json::from_slice(&plaintext)
+        .map_err(|e| DnsError::ImportExportError(format!("解析账号数据失败: {e}")))?;
+    parse_exported_accounts(data)
+}
+
+/// 单个账号的导入计划
+struct ImportPlan {
+    action: ImportAction,
+    /// 导入后的账号名称
+    name: String,
+    /// 覆盖更新时的目标账号 ID
+    existing_id: Option<String>,
+    has_conflict: bool,
+}
+
+/// 按冲突处理方式为每个待导入账号生成导入计划（预览与导入共用）
+fn plan_import(
+    existing: &[Account],
+    accounts: &[ExportedAccount],
+    strategy: ImportStrategy,
+) -> Vec<ImportPlan> {
+    // 已占用的 (提供商, 名称)，包含本次导入中排在前面的账号
+    let mut taken: Vec<(ProviderType, String)> = existing
+        .iter()
+        .map(|a| (a.provider.clone(), a.name.clone()))
+        .collect();
+    let is_taken = |taken: &[(ProviderType, String)], provider: &ProviderType, name: &str| {
+        taken.iter().any(|(p, n)| p == provider && n == name)
+    };
+    // 已被本次导入覆盖的现有账号 ID
+    let mut overwritten: Vec<String> = Vec::new();
+
+    accounts
+        .iter()
+        .map(|exported| {
+            let provider = &exported.provider;
+            if !is_taken(&taken, provider, &exported.name) {
+                taken.push((provider.clone(), exported.name.clone()));
+                return ImportPlan {
+                    action: ImportAction::Create,
+                    name: exported.name.clone(),
+                    existing_id: None,
+                    has_conflict: false,
+                };
+            }
+
+            let skip = ImportPlan {
+                action: ImportAction::Skip,
+                name: exported.name.clone(),
+                existing_id: None,
+                has_conflict: true,
+            };
+            match strategy {
+                ImportStrategy::Skip => skip,
+                ImportStrategy::Overwrite => {
+                    // 文件内重复的账号只覆盖一次，之后的重复项跳过
+                    let Some(target) = existing
+                        .iter()
+                        .find(|a| &a.provider == provider && a.name == exported.name)
+                    else {
+                        return skip;
+                    };
+                    if overwritten.contains(&target.id) {
+                        return skip;
+                    }
+                    overwritten.push(target.id.clone());
+                    ImportPlan {
+                        action: ImportAction::Update,
+                        name: target.name.clone(),
+                        existing_id: Some(target.id.clone()),
+                        has_conflict: true,
+                    }
+                }
+                ImportStrategy::Rename => {
+                    // 候选数多于已占用名称数，必然能找到可用名称
+                    let name = (2..=taken.len() + 2)
+                        .map(|n| format!("{} ({n})", exported.name))
+                        .find(|name| !is_taken(&taken, provider, name))
+                        .unwrap_or_else(|| exported.name.clone());
+                    taken.push((provider.clone(), name.clone()));
+                    ImportPlan {
+                        action: ImportAction::Create,
+                        name,
+                        existing_id: None,
+                        has_conflict: true,
+                    }
+                }
+            }
+        })
+        .collect()
 }
 
 /// 预览导入文件
@@ -238,71 +713,49 @@ pub async fn preview_import(
     state: State<'_, AppState>,
     content: String,
     password: Option<String>,
+    strategy: Option<ImportStrategy>,
 ) -> Result<ApiResponse<ImportPreview>, DnsError> {
-    // 1. 解析文件
-    let export_file: ExportFile = serde_json::from_str(&content)
-        .map_err(|e| DnsError::ImportExportError(format!("无效的导入文件: {e}")))?;
-
-    // 2. 检查版本
-    if export_file.header.version > 1 {
-        return Err(DnsError::UnsupportedFileVersion);
-    }
+    // 1. 解析文件并检查版本
+    let export_file = parse_export_file(&content)?;
+    let encrypted = export_file.header.encrypted;
 
-    // 3. 如果加密但未提供密码，返回需要密码的提示
-    if export_file.header.encrypted && password.is_none() {
+    // 2. 加密但未提供密码，返回需要密码的提示
+    if encrypted && password.is_none() {
         return Ok(ApiResponse::success(ImportPreview {
             encrypted: true,
             account_count: 0,
             accounts: None,
+            invalid_accounts: Vec::new(),
         }));
     }
 
-    // 4. 解密或直接解析账号数据
-    let accounts: Vec<ExportedAccount> = if export_file.header.encrypted {
-        let password = password.as_ref().unwrap();
-        let ciphertext = export_file
-            .data
-            .as_str()
-            .ok_or_else(|| DnsError::ImportExportError("无效的加密数据".to_string()))?;
-        let salt = export_file
-            .header
-            .salt
-            .as_ref()
-            .ok_or_else(|| DnsError::ImportExportError("缺少加密盐值".to_string()))?;
-        let nonce = export_file
-            .header
-            .nonce
-            .as_ref()
-            .ok_or_else(|| DnsError::ImportExportError("缺少加密 nonce".to_string()))?;
-
-        let plaintext = crypto::decrypt(ciphertext, password, salt, nonce)
-            .map_err(|_| DnsError::ImportExportError("解密失败，请检查密码是否正确".to_string()))?;
+    // 3. 解密或直接解析账号数据
+    let (accounts, invalid_accounts) = read_exported_accounts(export_file, password.as_deref())?;
 
-        serde_json::from_slice(&plaintext)
-            .map_err(|e| DnsError::ImportExportError(format!("解析账号数据失败: {e}")))?
-    } else {
-        serde_json::from_value(export_file.data)
-            .map_err(|e| DnsError::ImportExportError(format!("解析账号数据失败: {e}")))?
-    };
-
-    // 5. 检查与现有账号的冲突
-    let existing_accounts = state.accounts.read().await;
-    let existing_names: std::collections::HashSet<_> =
-        existing_accounts.iter().map(|a| a.name.as_str()).collect();
+    // 4. 检查与现有账号的冲突及将执行的动作
+    let plans = plan_import(
+        &state.accounts.read().await,
+        &accounts,
+        strategy.unwrap_or_default(),
+    );
 
     let preview_accounts: Vec<ImportPreviewAccount> = accounts
         .iter()
-        .map(|a| ImportPreviewAccount {
+        .zip(plans)
+        .map(|(a, plan)| ImportPreviewAccount {
             name: a.name.clone(),
             provider: a.provider.clone(),
-            has_conflict: existing_names.contains(a.name.as_str()),
+            has_conflict: plan.has_conflict,
+            action: plan.action,
+            target_name: plan.name,
         })
         .collect();
 
     Ok(ApiResponse::success(ImportPreview {
-        encrypted: export_file.header.encrypted,
+        encrypted,
         account_count: accounts.len(),
         accounts: Some(preview_accounts),
+        invalid_accounts,
     }))
 }
 
@@ -312,47 +765,24 @@ pub async fn import_accounts(
     state: State<'_, AppState>,
     request: ImportAccountsRequest,
 ) -> Result<ApiResponse<ImportResult>, DnsError> {
-    // 1. 解析和解密（逻辑与 preview_import 类似）
-    let export_file: ExportFile = serde_json::from_str(&request.content)
-        .map_err(|e| DnsError::ImportExportError(format!("无效的导入文件: {e}")))?;
+    // 1. 解析和解密（与 preview_import 共用）
+    let export_file = parse_export_file(&request.content)?;
+    let (accounts, mut failures) =
+        read_exported_accounts(export_file, request.password.as_deref())?;
+    let plans = plan_import(&state.accounts.read().await, &accounts, request.strategy);
 
-    let accounts: Vec<ExportedAccount> = if export_file.header.encrypted {
-        let password = request
-            .password
-            .as_ref()
-            .ok_or_else(|| DnsError::ImportExportError("加密文件需要提供密码".to_string()))?;
-        let ciphertext = export_file
-            .data
-            .as_str()
-            .ok_or_else(|| DnsError::ImportExportError("无效的加密数据".to_string()))?;
-        let salt = export_file
-            .header
-            .salt
-            .as_ref()
-            .ok_or_else(|| DnsError::ImportExportError("缺少加密盐值".to_string()))?;
-        let nonce = export_file
-            .header
-            .nonce
-            .as_ref()
-            .ok_or_else(|| DnsError::ImportExportError("缺少加密 nonce".to_string()))?;
-
-        let plaintext = crypto::decrypt(ciphertext, password, salt, nonce)
-            .map_err(|_| DnsError::ImportExportError("解密失败，请检查密码是否正确".to_string()))?;
-
-        serde_json::from_slice(&plaintext)
-            .map_err(|e| DnsError::ImportExportError(format!("解析账号数据失败: {e}")))?
-    } else {
-        serde_json::from_value(export_file.data)
-            .map_err(|e| DnsError::ImportExportError(format!("解析账号数据失败: {e}")))?
-    };
-
-    // 2. 逐个导入账号
-    let mut success_count = 0;
-    let mut failures = Vec::new();
     let now = chrono::Utc::now().to_rfc3339();
+    let client_config = state.client_config.read().await.clone();
+    let mut skipped_count = 0;
+
+    // 2. 转换凭证并创建 provider 实例
+    let mut prepared = Vec::new();
+    for (exported, plan) in accounts.into_iter().zip(plans) {
+        if plan.action == ImportAction::Skip {
+            skipped_count += 1;
+            continue;
+        }
 
-    for exported in accounts {
-        // 2.1 转换凭证并创建 provider 实例
         let credentials =
             match ProviderCredentials::from_map(&exported.provider, &exported.credentials) {
                 Ok(c) => c,
@@ -364,21 +794,49 @@ pub async fn import_accounts(
                     continue;
                 }
             };
-        let provider = match create_provider(credentials) {
-            Ok(p) => p,
-            Err(e) => {
-                failures.push(ImportFailure {
-                    name: exported.name.clone(),
-                    reason: format!("创建 Provider 失败: {e}"),
-                });
-                continue;
-            }
+        match create_provider_with_config(credentials, &client_config) {
+            Ok(provider) => prepared.push((exported, plan, provider)),
+            Err(e) => failures.push(ImportFailure {
+                name: exported.name.clone(),
+                reason: format!("创建 Provider 失败: {e}"),
+            }),
+        }
+    }
+
+    // 3. 并行验证凭证（限制并发），全部完成后再写入
+    let semaphore = Arc::new(Semaphore::new(IMPORT_VALIDATION_CONCURRENCY));
+    let checks = prepared.iter().map(|(_, _, provider)| {
+        let semaphore = semaphore.clone();
+        async move {
+            let _permit = semaphore.acquire().await;
+            provider.validate_credentials().await
+        }
+    });
+    let results = join_all(checks).await;
+
+    // 4. 逐个保存通过验证的账号
+    let mut created_count = 0;
+    let mut updated_count = 0;
+    for ((exported, plan, provider), result) in prepared.into_iter().zip(results) {
+        let reason = match result {
+            Ok(true) => None,
+            Ok(false) => Some("凭证无效".to_string()),
+            Err(e) => Some(format!("凭证验证失败: {e}")),
         };
+        if let Some(reason) = reason {
+            failures.push(ImportFailure {
+                name: exported.name,
+                reason,
+            });
+            continue;
+        }
 
-        // 2.2 生成新的账号 ID
-        let account_id = uuid::Uuid::new_v4().to_string();
+        // 4.1 覆盖时沿用现有账号 ID，否则生成新的 ID
+        let account_id = plan
+            .existing_id
+            .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
 
-        // 2.3 保存凭证到 Keychain
+        // 4.2 保存凭证到 Keychain
         if let Err(e) = state
             .credential_store
             .save(&account_id, &exported.credentials)
@@ -390,33 +848,309 @@ pub async fn import_accounts(
             continue;
         }
 
-        // 2.4 注册 provider
+        // 4.3 注册 provider
         state.registry.register(account_id.clone(), provider).await;
 
-        // 2.5 创建账号元数据
-        let account = Account {
-            id: account_id,
-            name: exported.name,
-            provider: exported.provider,
-            created_at: now.clone(),
-            updated_at: now.clone(),
-            status: Some(AccountStatus::Active),
-            error: None,
-        };
-
-        // 2.6 保存到内存
-        state.accounts.write().await.push(account);
-        success_count += 1;
+        // 4.4 更新或创建账号元数据
+        let mut accounts = state.accounts.write().await;
+        if let Some(existing) = accounts.iter_mut().find(|a| a.id == account_id) {
+            existing.updated_at.clone_from(&now);
+            existing.status = Some(AccountStatus::Active);
+            existing.error = None;
+            existing.warning = None;
+            updated_count += 1;
+        } else {
+            accounts.push(Account {
+                id: account_id,
+                name: plan.name,
+                provider: exported.provider,
+                created_at: now.clone(),
+                updated_at: now.clone(),
+                status: Some(AccountStatus::Active),
+                error: None,
+                warning: None,
+            });
+            created_count += 1;
+        }
     }
 
-    // 3. 持久化账户元数据
+    // 5. 持久化账户元数据
     let accounts = state.accounts.read().await.clone();
     if let Err(e) = AccountStore::save_accounts(&state.app_handle, &accounts) {
         log::error!("Failed to persist accounts after import: {e}");
     }
 
     Ok(ApiResponse::success(ImportResult {
-        success_count,
+        success_count: created_count + updated_count,
+        created_count,
+        updated_count,
+        skipped_count,
         failures,
     }))
 }
+
+/// 单个账号验证凭证的超时时间，避免个别 Provider 响应缓慢拖住整体
+const VALIDATE_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// 并行验证所有已注册账号的凭证，更新内存中的账号状态并返回更新后的列表
+///
+/// 未注册 Provider、验证超时或验证出错但并非鉴权失败的账号保持原状态
+pub(crate) async fn validate_registered_accounts(state: &AppState) -> Vec<Account> {
+    // 1. 并行验证所有已注册的 Provider
+    let account_ids = state.registry.list_account_ids().await;
+    let checks = account_ids.into_iter().map(|account_id| async {
+        let provider = state.registry.get(&account_id).await;
+        let result = match provider {
+            Some(provider) => {
+                tokio::time::timeout(VALIDATE_TIMEOUT, provider.validate_credentials())
+                    .await
+                    .inspect_err(|_| {
+                        log::warn!("Credential validation timed out for account {account_id}");
+                    })
+                    .ok()
+            }
+            None => None,
+        };
+        (account_id, result)
+    });
+    let results = join_all(checks).await;
+
+    // 2. 更新内存中的账号状态
+    let mut accounts = state.accounts.write().await;
+    for (account_id, result) in results {
+        let Some(result) = result else {
+            continue;
+        };
+        if let Some(account) = accounts.iter_mut().find(|a| a.id == account_id) {
+            apply_validation_result(account, result);
+        }
+    }
+    accounts.clone()
+}
+
+/// 根据凭证验证结果更新账号状态
+///
+/// 只有确认的鉴权失败才标记为错误；网络故障、超时、5xx 等错误不代表凭证有问题，保持原状态
+fn apply_validation_result(account: &mut Account, result: Result<bool, ProviderError>) {
+    match result {
+        Ok(true) => {
+            account.status = Some(AccountStatus::Active);
+            account.error = None;
+        }
+        Ok(false) => {
+            account.status = Some(AccountStatus::Error);
+            account.error = Some("凭证已失效".to_string());
+        }
+        Err(e @ ProviderError::InvalidCredentials { .. }) => {
+            account.status = Some(AccountStatus::Error);
+            account.error = Some(e.to_string());
+        }
+        Err(e) => {
+            let kind = if e.is_retryable() {
+                "transiently"
+            } else {
+                "with a non-auth error"
+            };
+            log::warn!(
+                "Credential validation failed {kind} for account {}: {e}",
+                account.id
+            );
+        }
+    }
+}
+
+/// 重新验证所有账号的凭证并刷新状态
+///
+/// 并行调用各 Provider 的 `validate_credentials`，未注册 Provider 的账号保持原状态
+#[tauri::command]
+pub async fn refresh_account_statuses(
+    state: State<'_, AppState>,
+) -> Result<ApiResponse<RefreshAccountStatusesResult>, DnsError> {
+    let accounts = validate_registered_accounts(&state).await;
+
+    // 持久化账户元数据
+    if let Err(e) = AccountStore::save_accounts(&state.app_handle, &accounts) {
+        log::error!("Failed to persist accounts after status refresh: {e}");
+    }
+
+    let active_count = accounts
+        .iter()
+        .filter(|a| matches!(a.status, Some(AccountStatus::Active)))
+        .count();
+
+    Ok(ApiResponse::success(RefreshAccountStatusesResult {
+        active_count,
+        error_count: accounts.len() - active_count,
+        accounts,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type TestResult = std::result::Result<(), Box<dyn std::error::Error>>;
+
+    fn active_account() -> Account {
+        Account {
+            id: "acc-1".to_string(),
+            name: "test".to_string(),
+            provider: ProviderType::Cloudflare,
+            created_at: String::new(),
+            updated_at: String::new(),
+            status: Some(AccountStatus::Active),
+            error: None,
+            warning: None,
+        }
+    }
+
+    fn exported(name: &str) -> ExportedAccount {
+        ExportedAccount {
+            id: "x".to_string(),
+            name: name.to_string(),
+            provider: ProviderType::Cloudflare,
+            created_at: String::new(),
+            updated_at: String::new(),
+            credentials: HashMap::new(),
+        }
+    }
+
+    /// 每个导入账号的 (动作, 目标名称, 目标账号 ID)
+    fn planned(strategy: ImportStrategy) -> Vec<(ImportAction, String, Option<String>)> {
+        let accounts = [exported("test"), exported("new"), exported("test")];
+        plan_import(&[active_account()], &accounts, strategy)
+            .into_iter()
+            .map(|plan| (plan.action, plan.name, plan.existing_id))
+            .collect()
+    }
+
+    #[test]
+    fn plan_import_skip() {
+        assert_eq!(
+            planned(ImportStrategy::Skip),
+            vec![
+                (ImportAction::Skip, "test".to_string(), None),
+                (ImportAction::Create, "new".to_string(), None),
+                (ImportAction::Skip, "test".to_string(), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn plan_import_overwrite_updates_target_once() {
+        assert_eq!(
+            planned(ImportStrategy::Overwrite),
+            vec![
+                (
+                    ImportAction::Update,
+                    "test".to_string(),
+                    Some("acc-1".to_string())
+                ),
+                (ImportAction::Create, "new".to_string(), None),
+                (ImportAction::Skip, "test".to_string(), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn plan_import_rename() {
+        assert_eq!(
+            planned(ImportStrategy::Rename),
+            vec![
+                (ImportAction::Create, "test (2)".to_string(), None),
+                (ImportAction::Create, "new".to_string(), None),
+                (ImportAction::Create, "test (3)".to_string(), None),
+            ]
+        );
+    }
+
+    fn export_content(version: u32, data: &serde_json::Value, checksum: Option<&str>) -> String {
+        serde_json::json!({
+            "header": {
+                "version": version,
+                "encrypted": false,
+                "checksum": checksum,
+                "exportedAt": "2024-01-01T00:00:00Z",
+                "appVersion": "1.0.0"
+            },
+            "data": data
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn v2_checksum_mismatch_is_rejected() -> TestResult {
+        let data = serde_json::json!([]);
+        let content = export_content(2, &data, Some(&data_checksum(&data)?));
+        assert!(parse_export_file(&content).is_ok());
+
+        let tampered = export_content(2, &serde_json::json!([{}]), Some(&data_checksum(&data)?));
+        assert!(parse_export_file(&tampered).is_err());
+        assert!(parse_export_file(&export_content(2, &data, None)).is_err());
+
+        // v1 文件没有校验和
+        assert!(parse_export_file(&export_content(1, &data, None)).is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_accounts_are_reported_individually() -> TestResult {
+        let account = |name: &str, provider: &str| {
+            serde_json::json!({
+                "id": "x",
+                "name": name,
+                "provider": provider,
+                "createdAt": "",
+                "updatedAt": "",
+                "credentials": {"apiToken": "token"}
+            })
+        };
+        let data = serde_json::json!([
+            account("cf", "cloudflare"),
+            account("unknown", "no-such-provider"),
+            account("wrong-fields", "aliyun")
+        ]);
+
+        let (accounts, invalid) = parse_exported_accounts(data)?;
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].name, "cf");
+        let names: Vec<_> = invalid.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["unknown", "wrong-fields"]);
+        Ok(())
+    }
+
+    #[test]
+    fn network_error_keeps_account_status() {
+        let mut account = active_account();
+        apply_validation_result(
+            &mut account,
+            Err(ProviderError::NetworkError {
+                provider: "cloudflare".to_string(),
+                detail: "connection timed out".to_string(),
+            }),
+        );
+        assert!(matches!(account.status, Some(AccountStatus::Active)));
+        assert!(account.error.is_none());
+
+        apply_validation_result(
+            &mut account,
+            Err(ProviderError::Unknown {
+                provider: "cloudflare".to_string(),
+                raw_code: None,
+                raw_message: "HTTP 502 Bad Gateway".to_string(),
+                request_id: None,
+            }),
+        );
+        assert!(matches!(account.status, Some(AccountStatus::Active)));
+
+        apply_validation_result(
+            &mut account,
+            Err(ProviderError::InvalidCredentials {
+                provider: "cloudflare".to_string(),
+                raw_message: None,
+                request_id: None,
+            }),
+        );
+        assert!(matches!(account.status, Some(AccountStatus::Error)));
+    }
+}
@@ -1,14 +1,45 @@
+use std::sync::Arc;
+
+use futures::future::join_all;
 use tauri::State;
+use tokio::sync::Semaphore;
+use tokio_util::sync::CancellationToken;
 
-use crate::error::DnsError;
+use crate::commands::toolbox::detect_public_ip;
+use crate::error::{DnsError, ProviderError};
+use crate::providers::{domain_to_ascii, get_provider_features, DnsProvider};
 use crate::types::{
-    ApiResponse, BatchDeleteFailure, BatchDeleteRequest, BatchDeleteResult, CreateDnsRecordRequest,
-    DnsRecord, DnsRecordType, PaginatedResponse, RecordQueryParams, UpdateDnsRecordRequest,
+    AccountRecordsResult, ApiResponse, BatchDeleteRequest, BatchDeleteResult, BatchFailure,
+    BatchUpdateRequest, BatchUpdateResult, CreateDnsRecordRequest, DdnsUpdateResult, DnsRecord,
+    DnsRecordType, GlobalSearchFailure, GlobalSearchMatch, GlobalSearchResult, PaginatedResponse,
+    ProviderType, RecordPatch, RecordQueryParams, RecordSort, UpdateDnsRecordRequest,
 };
 use crate::AppState;
 
+/// 提供商未声明 API 限流时单个账号的并发数
+const DEFAULT_PROVIDER_CONCURRENCY: usize = 3;
+
+/// 按提供商声明的 API 限流计算单个账号的并发数
+pub(crate) fn provider_concurrency(provider: &dyn DnsProvider) -> usize {
+    provider
+        .id()
+        .parse::<ProviderType>()
+        .ok()
+        .and_then(|p| get_provider_features(&p).rate_limit)
+        .map_or(DEFAULT_PROVIDER_CONCURRENCY, |limit| {
+            limit.suggested_concurrency()
+        })
+}
+
+/// 将用户输入的记录名称编码为 ASCII（punycode），与 API 返回的名称保持一致
+fn ascii_record_name(name: &str) -> Result<String, DnsError> {
+    domain_to_ascii(name)
+        .ok_or_else(|| DnsError::ValidationError(format!("无效的国际化域名: {name}")))
+}
+
 /// 列出域名下的所有 DNS 记录（分页 + 搜索）
 #[tauri::command]
+#[allow(clippy::too_many_arguments)] // 参数与前端传入的字段一一对应
 pub async fn list_dns_records(
     state: State<'_, AppState>,
     account_id: String,
@@ -17,6 +48,10 @@ pub async fn list_dns_records(
     page_size: Option<u32>,
     keyword: Option<String>,
     record_type: Option<DnsRecordType>,
+    cursor: Option<String>,
+    modified_after: Option<String>,
+    sort_by: Option<RecordSort>,
+    sort_desc: Option<bool>,
 ) -> Result<ApiResponse<PaginatedResponse<DnsRecord>>, DnsError> {
     // 获取 provider
     let provider = state
@@ -31,6 +66,10 @@ pub async fn list_dns_records(
         page_size: page_size.unwrap_or(20),
         keyword,
         record_type,
+        cursor,
+        modified_after,
+        sort_by,
+        sort_desc: sort_desc.unwrap_or(false),
     };
 
     // 调用 provider 获取 DNS 记录列表
@@ -44,7 +83,7 @@ pub async fn list_dns_records(
 pub async fn create_dns_record(
     state: State<'_, AppState>,
     account_id: String,
-    request: CreateDnsRecordRequest,
+    mut request: CreateDnsRecordRequest,
 ) -> Result<ApiResponse<DnsRecord>, DnsError> {
     // 获取 provider
     let provider = state
@@ -53,6 +92,20 @@ pub async fn create_dns_record(
         .await
         .ok_or_else(|| DnsError::AccountNotFound(account_id.clone()))?;
 
+    request.name = ascii_record_name(&request.name)?;
+
+    // 本地校验记录值
+    request
+        .validate()
+        .map_err(|e| DnsError::ValidationError(e.to_string()))?;
+
+    // 预检同名冲突，给出现有记录的值；预检本身失败时不阻止创建
+    match provider.can_create_record(&request).await {
+        Err(e @ ProviderError::RecordExists { .. }) => return Err(e.into()),
+        Err(e) => log::warn!("创建记录前的冲突检查失败，继续创建: {e}"),
+        Ok(()) => {}
+    }
+
     // 调用 provider 创建记录
     let record = provider.create_record(&request).await?;
 
@@ -65,7 +118,7 @@ pub async fn update_dns_record(
     state: State<'_, AppState>,
     account_id: String,
     record_id: String,
-    request: UpdateDnsRecordRequest,
+    mut request: UpdateDnsRecordRequest,
 ) -> Result<ApiResponse<DnsRecord>, DnsError> {
     // 获取 provider
     let provider = state
@@ -74,12 +127,47 @@ pub async fn update_dns_record(
         .await
         .ok_or_else(|| DnsError::AccountNotFound(account_id.clone()))?;
 
+    request.name = ascii_record_name(&request.name)?;
+
+    // 本地校验记录值
+    request
+        .validate()
+        .map_err(|e| DnsError::ValidationError(e.to_string()))?;
+
     // 调用 provider 更新记录
     let record = provider.update_record(&record_id, &request).await?;
 
     Ok(ApiResponse::success(record))
 }
 
+/// 只修改 DNS 记录的指定字段（如单独切换代理状态）
+#[tauri::command]
+pub async fn patch_dns_record(
+    state: State<'_, AppState>,
+    account_id: String,
+    domain_id: String,
+    record_id: String,
+    mut changes: RecordPatch,
+) -> Result<ApiResponse<DnsRecord>, DnsError> {
+    // 获取 provider
+    let provider = state
+        .registry
+        .get(&account_id)
+        .await
+        .ok_or_else(|| DnsError::AccountNotFound(account_id.clone()))?;
+
+    if let Some(name) = &changes.name {
+        changes.name = Some(ascii_record_name(name)?);
+    }
+
+    // 调用 provider 修改记录（合并后的记录值由 provider 校验）
+    let record = provider
+        .patch_record(&domain_id, &record_id, &changes)
+        .await?;
+
+    Ok(ApiResponse::success(record))
+}
+
 /// 删除 DNS 记录
 #[tauri::command]
 pub async fn delete_dns_record(
@@ -101,7 +189,33 @@ pub async fn delete_dns_record(
     Ok(ApiResponse::success(()))
 }
 
+/// 启用或暂停 DNS 记录
+#[tauri::command]
+pub async fn toggle_dns_record(
+    state: State<'_, AppState>,
+    account_id: String,
+    domain_id: String,
+    record_id: String,
+    enabled: bool,
+) -> Result<ApiResponse<()>, DnsError> {
+    // 获取 provider
+    let provider = state
+        .registry
+        .get(&account_id)
+        .await
+        .ok_or_else(|| DnsError::AccountNotFound(account_id.clone()))?;
+
+    // 不支持暂停的 provider 返回 NotSupported
+    provider
+        .set_record_enabled(&domain_id, &record_id, enabled)
+        .await?;
+
+    Ok(ApiResponse::success(()))
+}
+
 /// 批量删除 DNS 记录
+///
+/// 请求带 `operation_id` 时可通过 `cancel_operation` 中途取消，返回已删除部分的结果
 #[tauri::command]
 pub async fn batch_delete_dns_records(
     state: State<'_, AppState>,
@@ -114,41 +228,418 @@ pub async fn batch_delete_dns_records(
         .get(&account_id)
         .await
         .ok_or_else(|| DnsError::AccountNotFound(account_id.clone()))?;
+    let operation = state.operations.start(request.operation_id.clone())?;
+    let token = operation.token();
 
     let mut success_count = 0;
+    let mut cancelled_count = 0;
     let mut failures = Vec::new();
 
-    // 并行删除所有记录
+    // 并行删除，限制同时进行的请求数，避免触发 API 限流
+    let concurrency = request
+        .concurrency
+        .unwrap_or_else(|| provider_concurrency(provider.as_ref()))
+        .max(1);
+    let semaphore = Arc::new(Semaphore::new(concurrency));
+
     let delete_futures: Vec<_> = request
         .record_ids
         .iter()
         .map(|record_id| {
             let provider = provider.clone();
+            let semaphore = semaphore.clone();
             let domain_id = request.domain_id.clone();
             let record_id = record_id.clone();
             async move {
-                match provider.delete_record(&record_id, &domain_id).await {
+                let _permit = semaphore.acquire().await;
+                if token.is_cancelled() {
+                    return None;
+                }
+                Some(match provider.delete_record(&record_id, &domain_id).await {
                     Ok(()) => Ok(record_id),
                     Err(e) => Err((record_id, e.to_string())),
-                }
+                })
             }
         })
         .collect();
 
-    let results = futures::future::join_all(delete_futures).await;
+    let results = join_all(delete_futures).await;
 
     for result in results {
         match result {
-            Ok(_) => success_count += 1,
-            Err((record_id, reason)) => {
-                failures.push(BatchDeleteFailure { record_id, reason });
+            Some(Ok(_)) => success_count += 1,
+            Some(Err((record_id, reason))) => {
+                failures.push(BatchFailure { record_id, reason });
             }
+            None => cancelled_count += 1,
         }
     }
 
     Ok(ApiResponse::success(BatchDeleteResult {
         success_count,
         failed_count: failures.len(),
+        cancelled_count,
         failures,
     }))
 }
+
+/// 批量更新 DNS 记录（如选中多条记录统一修改 TTL）
+///
+/// 每条记录单独校验，校验或更新失败的记录计入 `failures`，不影响其他记录；
+/// 请求带 `operation_id` 时可通过 `cancel_operation` 中途取消，返回已更新部分的结果
+#[tauri::command]
+pub async fn batch_update_dns_records(
+    state: State<'_, AppState>,
+    account_id: String,
+    request: BatchUpdateRequest,
+) -> Result<ApiResponse<BatchUpdateResult>, DnsError> {
+    // 获取 provider
+    let provider = state
+        .registry
+        .get(&account_id)
+        .await
+        .ok_or_else(|| DnsError::AccountNotFound(account_id.clone()))?;
+    let operation = state.operations.start(request.operation_id.clone())?;
+    let token = operation.token();
+
+    let mut records = Vec::new();
+    let mut cancelled_count = 0;
+    let mut failures = Vec::new();
+
+    // 并行更新，限制同时进行的请求数，避免触发 API 限流
+    let concurrency = request
+        .concurrency
+        .unwrap_or_else(|| provider_concurrency(provider.as_ref()))
+        .max(1);
+    let semaphore = Arc::new(Semaphore::new(concurrency));
+
+    let update_futures: Vec<_> = request
+        .updates
+        .into_iter()
+        .map(|item| {
+            let provider = provider.clone();
+            let semaphore = semaphore.clone();
+            async move {
+                let record_id = item.record_id;
+                let mut req = item.request;
+                match ascii_record_name(&req.name) {
+                    Ok(name) => req.name = name,
+                    Err(e) => return Some(Err((record_id, e.to_string()))),
+                }
+                if let Err(e) = req.validate() {
+                    return Some(Err((record_id, e.to_string())));
+                }
+
+                let _permit = semaphore.acquire().await;
+                if token.is_cancelled() {
+                    return None;
+                }
+                Some(
+                    provider
+                        .update_record(&record_id, &req)
+                        .await
+                        .map_err(|e| (record_id, e.to_string())),
+                )
+            }
+        })
+        .collect();
+
+    let results = join_all(update_futures).await;
+
+    for result in results {
+        match result {
+            Some(Ok(record)) => records.push(record),
+            Some(Err((record_id, reason))) => {
+                failures.push(BatchFailure { record_id, reason });
+            }
+            None => cancelled_count += 1,
+        }
+    }
+
+    Ok(ApiResponse::success(BatchUpdateResult {
+        success_count: records.len(),
+        failed_count: failures.len(),
+        cancelled_count,
+        records,
+        failures,
+    }))
+}
+
+/// 取消进行中的批量操作，返回是否找到该操作
+#[tauri::command]
+pub async fn cancel_operation(
+    state: State<'_, AppState>,
+    operation_id: String,
+) -> Result<ApiResponse<bool>, DnsError> {
+    Ok(ApiResponse::success(state.operations.cancel(&operation_id)))
+}
+
+/// 动态 DNS：将 A/AAAA 记录指向本机当前的公网 IP
+/// 记录值已是当前 IP 时不调用更新接口；同名记录有多条时需要通过 `record_id` 指定
+#[tauri::command]
+pub async fn ddns_update(
+    state: State<'_, AppState>,
+    account_id: String,
+    domain_id: String,
+    record_name: String,
+    record_type: DnsRecordType,
+    record_id: Option<String>,
+) -> Result<ApiResponse<DdnsUpdateResult>, DnsError> {
+    let ipv6 = match record_type {
+        DnsRecordType::A => false,
+        DnsRecordType::Aaaa => true,
+        _ => {
+            return Err(DnsError::ValidationError(
+                "DDNS 仅支持 A 或 AAAA 记录".to_string(),
+            ))
+        }
+    };
+
+    let provider = state
+        .registry
+        .get(&account_id)
+        .await
+        .ok_or_else(|| DnsError::AccountNotFound(account_id.clone()))?;
+
+    // 必须直连检测，经过代理时得到的是代理出口 IP
+    let client = state
+        .client_config
+        .read()
+        .await
+        .build_direct_client("toolbox")?;
+    let ip = detect_public_ip(&client, ipv6)
+        .await
+        .map_err(|message| DnsError::ApiError {
+            provider: "ipify".to_string(),
+            message,
+        })?;
+
+    let record_name = ascii_record_name(&record_name)?;
+    let mut records = provider
+        .find_records(&domain_id, &record_name, Some(record_type))
+        .await?;
+    if let Some(record_id) = &record_id {
+        records.retain(|r| &r.id == record_id);
+    }
+    if records.len() > 1 {
+        return Err(DnsError::ValidationError(format!(
+            "{record_name} 有 {} 条同类型记录，请指定要更新的记录",
+            records.len()
+        )));
+    }
+    let record = records.pop().ok_or_else(|| {
+        DnsError::RecordNotFound(record_id.unwrap_or_else(|| record_name.clone()))
+    })?;
+
+    // 按地址比较，避免 IPv6 写法不同（如是否压缩零段）导致误判
+    let previous_value = record.value.clone();
+    if previous_value.trim().parse::<std::net::IpAddr>().ok() == Some(ip) {
+        return Ok(ApiResponse::success(DdnsUpdateResult {
+            updated: false,
+            ip: ip.to_string(),
+            previous_value,
+            record,
+        }));
+    }
+
+    let request = RecordPatch {
+        value: Some(ip.to_string()),
+        ..Default::default()
+    }
+    .apply_to(&record);
+    let record = provider.update_record(&record.id, &request).await?;
+    log::info!("DDNS 已将 {record_name} 从 {previous_value} 更新为 {ip}");
+
+    Ok(ApiResponse::success(DdnsUpdateResult {
+        updated: true,
+        ip: ip.to_string(),
+        previous_value,
+        record,
+    }))
+}
+
+/// 在所有账号的所有域名中搜索 DNS 记录（匹配记录名称或值）
+///
+/// 单个账号或域名失败时记录在结果中，不影响其他账号
+#[tauri::command]
+pub async fn search_records_global(
+    state: State<'_, AppState>,
+    keyword: String,
+    record_type: Option<DnsRecordType>,
+    operation_id: Option<String>,
+) -> Result<ApiResponse<GlobalSearchResult>, DnsError> {
+    let keyword = keyword.trim().to_lowercase();
+    if keyword.is_empty() && record_type.is_none() {
+        return Err(DnsError::ValidationError(
+            "请输入搜索关键词或选择记录类型".to_string(),
+        ));
+    }
+
+    let operation = state.operations.start(operation_id)?;
+    let token = operation.token();
+
+    let mut providers = Vec::new();
+    for account_id in state.registry.list_account_ids().await {
+        if let Some(provider) = state.registry.get(&account_id).await {
+            providers.push((account_id, provider));
+        }
+    }
+
+    // 限流按账号（凭证）计算，每个账号使用独立的并发限制
+    let account_futures = providers.into_iter().map(|(account_id, provider)| {
+        let concurrency = provider_concurrency(provider.as_ref());
+        search_account(
+            account_id,
+            provider,
+            Arc::new(Semaphore::new(concurrency)),
+            &keyword,
+            record_type.as_ref(),
+            token,
+        )
+    });
+
+    let mut result = GlobalSearchResult {
+        matches: Vec::new(),
+        failures: Vec::new(),
+        cancelled: false,
+    };
+    for (matches, failures) in join_all(account_futures).await {
+        result.matches.extend(matches);
+        result.failures.extend(failures);
+    }
+    result.cancelled = token.is_cancelled();
+
+    Ok(ApiResponse::success(result))
+}
+
+/// 列出单个账号下所有域名的记录（可按关键词和类型过滤），合并后分页返回
+///
+/// 每次调用都会重新读取全部域名的记录，结果按域名、记录名称排序以保证翻页稳定；
+/// 单个域名读取失败时记录在 `failures` 中，不影响其他域名
+#[tauri::command]
+pub async fn list_account_records(
+    state: State<'_, AppState>,
+    account_id: String,
+    keyword: Option<String>,
+    record_type: Option<DnsRecordType>,
+    page: Option<u32>,
+    page_size: Option<u32>,
+) -> Result<ApiResponse<AccountRecordsResult>, DnsError> {
+    let provider = state
+        .registry
+        .get(&account_id)
+        .await
+        .ok_or_else(|| DnsError::AccountNotFound(account_id.clone()))?;
+
+    let keyword = keyword.unwrap_or_default().trim().to_lowercase();
+    let concurrency = provider_concurrency(provider.as_ref());
+    let (mut matches, failures) = search_account(
+        account_id,
+        provider,
+        Arc::new(Semaphore::new(concurrency)),
+        &keyword,
+        record_type.as_ref(),
+        &CancellationToken::new(),
+    )
+    .await;
+    matches.sort_by(|a, b| {
+        a.domain_name
+            .cmp(&b.domain_name)
+            .then_with(|| a.record.name.cmp(&b.record.name))
+    });
+
+    let page = page.unwrap_or(1).max(1);
+    let page_size = page_size.unwrap_or(20).max(1);
+    let total_count = u32::try_from(matches.len()).unwrap_or(u32::MAX);
+    let items = matches
+        .into_iter()
+        .skip((page - 1).saturating_mul(page_size) as usize)
+        .take(page_size as usize)
+        .collect();
+
+    Ok(ApiResponse::success(AccountRecordsResult {
+        page: PaginatedResponse::new(items, page, page_size, total_count),
+        failures,
+    }))
+}
+
+/// 搜索单个账号下的所有域名，取消后跳过尚未开始搜索的域名
+async fn search_account(
+    account_id: String,
+    provider: Arc<dyn DnsProvider>,
+    semaphore: Arc<Semaphore>,
+    keyword: &str,
+    record_type: Option<&DnsRecordType>,
+    token: &CancellationToken,
+) -> (Vec<GlobalSearchMatch>, Vec<GlobalSearchFailure>) {
+    let domains = {
+        let _permit = semaphore.acquire().await;
+        if token.is_cancelled() {
+            return (Vec::new(), Vec::new());
+        }
+        provider.list_all_domains().await
+    };
+    let domains = match domains {
+        Ok(domains) => domains,
+        Err(e) => {
+            let failure = GlobalSearchFailure {
+                account_id,
+                domain_id: None,
+                reason: e.to_string(),
+            };
+            return (Vec::new(), vec![failure]);
+        }
+    };
+
+    let record_futures = domains.into_iter().map(|domain| {
+        let provider = provider.clone();
+        let semaphore = semaphore.clone();
+        async move {
+            let _permit = semaphore.acquire().await;
+            if token.is_cancelled() {
+                return (domain, None);
+            }
+            let records = provider.list_all_records(&domain.id).await;
+            (domain, Some(records))
+        }
+    });
+
+    let mut matches = Vec::new();
+    let mut failures = Vec::new();
+    for (domain, records) in join_all(record_futures).await {
+        match records {
+            None => {}
+            Some(Ok(records)) => {
+                matches.extend(
+                    records
+                        .into_iter()
+                        .filter(|r| record_matches(r, keyword, record_type))
+                        .map(|record| GlobalSearchMatch {
+                            account_id: account_id.clone(),
+                            provider: provider.id().to_string(),
+                            domain_id: domain.id.clone(),
+                            domain_name: domain.name.clone(),
+                            record,
+                        }),
+                );
+            }
+            Some(Err(e)) => failures.push(GlobalSearchFailure {
+                account_id: account_id.clone(),
+                domain_id: Some(domain.id),
+                reason: e.to_string(),
+            }),
+        }
+    }
+
+    (matches, failures)
+}
+
+/// 记录名称或值包含关键词（忽略大小写），且类型匹配
+fn record_matches(record: &DnsRecord, keyword: &str, record_type: Option<&DnsRecordType>) -> bool {
+    if record_type.is_some_and(|t| *t != record.record_type) {
+        return false;
+    }
+    keyword.is_empty()
+        || record.name.to_lowercase().contains(keyword)
+        || record.value.to_lowercase().contains(keyword)
+}
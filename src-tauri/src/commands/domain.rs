@@ -1,10 +1,45 @@
+use std::sync::Arc;
+
+use futures::future::join_all;
 use tauri::State;
+use tokio::sync::Semaphore;
 
 use crate::error::{DnsError, ProviderError};
-use crate::types::{AccountStatus, ApiResponse, Domain, PaginatedResponse, PaginationParams};
+use crate::providers::DnsProvider;
+use crate::types::{
+    AccountStatus, ApiResponse, DnssecStatus, Domain, LibDomain, PaginatedResponse,
+    PaginationParams, RecordLine,
+};
 use crate::AppState;
 
-/// 更新账户状态（凭证失效时调用）
+/// 补全记录数时同时进行的最大请求数
+const RECORD_COUNT_CONCURRENCY: usize = 4;
+
+/// 为列表接口未返回记录数的域名（如 Cloudflare）单独查询记录数
+///
+/// 查询失败时保持为空，不影响域名列表
+async fn fill_record_counts(provider: &dyn DnsProvider, domains: &mut [LibDomain]) {
+    let semaphore = Arc::new(Semaphore::new(RECORD_COUNT_CONCURRENCY));
+    let counts = domains.iter().map(|domain| {
+        let semaphore = semaphore.clone();
+        async move {
+            if domain.record_count.is_some() {
+                return domain.record_count;
+            }
+            let _permit = semaphore.acquire().await;
+            provider.count_records(&domain.id).await.ok()
+        }
+    });
+    let counts = join_all(counts).await;
+
+    for (domain, count) in domains.iter_mut().zip(counts) {
+        domain.record_count = count;
+    }
+}
+
+/// 更新账户状态（仅在确认凭证失效时调用）
+///
+/// 网络故障、超时、5xx 等可重试错误不代表凭证有问题，不应走到这里
 async fn mark_account_invalid(state: &AppState, account_id: &str, error_msg: &str) {
     let mut accounts = state.accounts.write().await;
     if let Some(account) = accounts.iter_mut().find(|a| a.id == account_id) {
@@ -21,6 +56,7 @@ pub async fn list_domains(
     account_id: String,
     page: Option<u32>,
     page_size: Option<u32>,
+    cursor: Option<String>,
 ) -> Result<ApiResponse<PaginatedResponse<Domain>>, DnsError> {
     // 获取 provider
     let provider = state
@@ -33,24 +69,16 @@ pub async fn list_domains(
     let params = PaginationParams {
         page: page.unwrap_or(1),
         page_size: page_size.unwrap_or(20),
+        cursor,
     };
 
     // 调用 provider 获取域名列表
     match provider.list_domains(&params).await {
-        Ok(lib_response) => {
+        Ok(mut lib_response) => {
+            fill_record_counts(provider.as_ref(), &mut lib_response.items).await;
+
             // 将库的 Domain 转换为应用层的 Domain（添加 account_id）
-            let domains: Vec<Domain> = lib_response
-                .items
-                .into_iter()
-                .map(|d| Domain::from_lib(d, account_id.clone()))
-                .collect();
-
-            let response = PaginatedResponse::new(
-                domains,
-                lib_response.page,
-                lib_response.page_size,
-                lib_response.total_count,
-            );
+            let response = lib_response.map(|d| Domain::from_lib(d, account_id.clone()));
             Ok(ApiResponse::success(response))
         }
         Err(ProviderError::InvalidCredentials { provider, .. }) => {
@@ -59,9 +87,10 @@ pub async fn list_domains(
             Err(DnsError::Provider(ProviderError::InvalidCredentials {
                 provider,
                 raw_message: None,
+                request_id: None,
             }))
         }
-        Err(e) => Err(DnsError::Provider(e)),
+        Err(e) => Err(e.into()),
     }
 }
 
@@ -87,3 +116,58 @@ pub async fn get_domain(
 
     Ok(ApiResponse::success(domain))
 }
+
+/// 获取域名的 DNSSEC 状态
+#[tauri::command]
+pub async fn get_dnssec(
+    state: State<'_, AppState>,
+    account_id: String,
+    domain_id: String,
+) -> Result<ApiResponse<DnssecStatus>, DnsError> {
+    // 获取 provider
+    let provider = state
+        .registry
+        .get(&account_id)
+        .await
+        .ok_or_else(|| DnsError::AccountNotFound(account_id.clone()))?;
+
+    let status = provider.get_dnssec_status(&domain_id).await?;
+
+    Ok(ApiResponse::success(status))
+}
+
+/// 获取域名应在注册商处配置的 NS 服务器
+#[tauri::command]
+pub async fn get_domain_nameservers(
+    state: State<'_, AppState>,
+    account_id: String,
+    domain_id: String,
+) -> Result<ApiResponse<Vec<String>>, DnsError> {
+    let provider = state
+        .registry
+        .get(&account_id)
+        .await
+        .ok_or_else(|| DnsError::AccountNotFound(account_id.clone()))?;
+
+    let nameservers = provider.get_domain_nameservers(&domain_id).await?;
+
+    Ok(ApiResponse::success(nameservers))
+}
+
+/// 获取域名可用的解析线路
+#[tauri::command]
+pub async fn list_record_lines(
+    state: State<'_, AppState>,
+    account_id: String,
+    domain_id: String,
+) -> Result<ApiResponse<Vec<RecordLine>>, DnsError> {
+    let provider = state
+        .registry
+        .get(&account_id)
+        .await
+        .ok_or_else(|| DnsError::AccountNotFound(account_id.clone()))?;
+
+    let lines = provider.list_record_lines(&domain_id).await?;
+
+    Ok(ApiResponse::success(lines))
+}
@@ -1,7 +1,9 @@
 pub mod account;
 pub mod dns;
 pub mod domain;
+pub mod settings;
 pub mod toolbox;
+pub mod zone;
 
 #[cfg(target_os = "android")]
 pub mod updater;
@@ -0,0 +1,99 @@
+use tauri::State;
+
+use crate::credentials::{IPINFO_TOKEN_FIELD, IPINFO_TOKEN_KEY};
+use crate::error::DnsError;
+use crate::providers::create_provider_with_config;
+use crate::storage::SettingsStore;
+use crate::types::{ApiResponse, IpGeoConfig, ProviderClientConfig, ProviderCredentials};
+use crate::AppState;
+
+/// 获取网络设置（代理、超时等）
+#[tauri::command]
+pub async fn get_network_config(
+    state: State<'_, AppState>,
+) -> Result<ApiResponse<ProviderClientConfig>, DnsError> {
+    let config = state.client_config.read().await.clone();
+    Ok(ApiResponse::success(config))
+}
+
+/// 更新网络设置
+///
+/// 保存后使用新配置重建所有已注册的 Provider，工具箱请求也会立即生效
+#[tauri::command]
+pub async fn set_network_config(
+    state: State<'_, AppState>,
+    config: ProviderClientConfig,
+) -> Result<ApiResponse<ProviderClientConfig>, DnsError> {
+    // 1. 校验配置（代理地址无效时返回 NetworkError）
+    config.build_client("settings")?;
+
+    // 2. 持久化
+    SettingsStore::save_client_config(&state.app_handle, &config)?;
+    *state.client_config.write().await = config.clone();
+
+    // 3. 使用新配置重建已注册的 Provider
+    let all_credentials = state.credential_store.load_all()?;
+    let accounts = state.accounts.read().await.clone();
+    for account_id in state.registry.list_account_ids().await {
+        let Some(account) = accounts.iter().find(|a| a.id == account_id) else {
+            continue;
+        };
+        let Some(credentials) = all_credentials.get(&account_id) else {
+            continue;
+        };
+        let provider = ProviderCredentials::from_map(&account.provider, credentials)
+            .map_err(DnsError::from)
+            .and_then(|c| create_provider_with_config(c, &config).map_err(DnsError::from));
+        match provider {
+            Ok(provider) => state.registry.register(account_id, provider).await,
+            Err(e) => log::warn!("Failed to rebuild provider for account {account_id}: {e}"),
+        }
+    }
+
+    Ok(ApiResponse::success(config))
+}
+
+/// 获取 IP 地理位置查询设置（不返回 ipinfo Token，只返回是否已配置）
+#[tauri::command]
+pub async fn get_ip_geo_config(
+    state: State<'_, AppState>,
+) -> Result<ApiResponse<IpGeoConfig>, DnsError> {
+    let config = state.ip_geo_config.read().await.clone();
+    Ok(ApiResponse::success(config))
+}
+
+/// 更新 IP 地理位置查询设置
+///
+/// ipinfo Token 保存在凭证存储中：未传入时保持不变，传入空字符串时清除
+#[tauri::command]
+pub async fn set_ip_geo_config(
+    state: State<'_, AppState>,
+    mut config: IpGeoConfig,
+) -> Result<ApiResponse<IpGeoConfig>, DnsError> {
+    // 备用服务与首选相同时没有意义
+    if config.fallback == Some(config.provider) {
+        config.fallback = None;
+    }
+
+    let token = match config.ipinfo_token.take().map(|t| t.trim().to_string()) {
+        None => state.ip_geo_config.read().await.ipinfo_token.clone(),
+        Some(token) if token.is_empty() => {
+            if state.credential_store.exists(IPINFO_TOKEN_KEY) {
+                state.credential_store.delete(IPINFO_TOKEN_KEY)?;
+            }
+            None
+        }
+        Some(token) => {
+            let record = [(IPINFO_TOKEN_FIELD.to_string(), token.clone())].into();
+            state.credential_store.save(IPINFO_TOKEN_KEY, &record)?;
+            Some(token)
+        }
+    };
+    config.ipinfo_token_configured = token.is_some();
+
+    SettingsStore::save_ip_geo_config(&state.app_handle, &config)?;
+    config.ipinfo_token = token;
+    *state.ip_geo_config.write().await = config.clone();
+
+    Ok(ApiResponse::success(config))
+}
@@ -2,28 +2,196 @@ use futures::future::join_all;
 use hickory_resolver::{
     config::{NameServerConfigGroup, ResolverConfig, ResolverOpts},
     name_server::TokioConnectionProvider,
+    proto::{
+        op::{Edns, Message, Query, ResponseCode},
+        rr::{Name, RData, Record, RecordType},
+        ProtoErrorKind,
+    },
     TokioResolver,
 };
 use regex::Regex;
-use std::net::IpAddr;
-use whois_rust::{WhoIs, WhoIsLookupOptions};
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, LazyLock};
+use std::time::{Duration, Instant};
+use tauri::State;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpStream, UdpSocket};
+use tokio::sync::Semaphore;
+use whois_rust::{WhoIs, WhoIsLookupOptions, WhoIsServerValue};
+
+use crate::providers::domain_to_ascii;
 
 use crate::types::{
-    ApiResponse, CertChainItem, DnsLookupRecord, DnsLookupResult, IpGeoInfo, IpLookupResult,
-    SslCertInfo, SslCheckResult, WhoisResult,
+    ApiResponse, AxfrResult, CertChainItem, CertExpiryItem, DkimCheck, DmarcCheck, DnsLookupRecord,
+    DnsLookupResult, DnsLookupTypeStatus, DnsRecord, DnsRecordType, DnsTrace, DnsTraceHop,
+    DnssecValidation, DomainExpiryItem, EmailAuthResult, HttpHeader, HttpInspectResult,
+    HttpRedirectHop, IpGeoConfig, IpGeoInfo, IpGeoProvider, IpLookupFailure, IpLookupResult,
+    IpWhoisResult, PortCheckAddress, PortCheckResult, PortStatus, PropagationResolverResult,
+    PropagationResult, RdapEntity, RdapResult, SpfCheck, SslCertInfo, SslCheckResult, WhoisResult,
 };
+use crate::AppState;
 
 /// 嵌入 WHOIS 服务器配置
 const WHOIS_SERVERS: &str = include_str!("../resources/whois_servers.json");
 
+/// WHOIS 客户端（服务器配置只在首次使用时解析一次）
+static WHOIS: LazyLock<Result<WhoIs, String>> =
+    LazyLock::new(|| WhoIs::from_string(WHOIS_SERVERS).map_err(|e| e.to_string()));
+
+/// 编译一组正则（模式均为常量，编译失败的直接忽略）
+fn compile_patterns(patterns: &[&str]) -> Vec<Regex> {
+    patterns.iter().filter_map(|p| Regex::new(p).ok()).collect()
+}
+
+// 域名 WHOIS 字段
+static REGISTRAR_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+    compile_patterns(&[
+        r"(?i)Registrar:\s*(.+)",
+        r"(?i)Registrar Name:\s*(.+)",
+        r"(?i)Sponsoring Registrar:\s*(.+)",
+    ])
+});
+
+static CREATION_DATE_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+    compile_patterns(&[
+        r"(?i)Creation Date:\s*(.+)",
+        r"(?i)Created Date:\s*(.+)",
+        r"(?i)Created:\s*(.+)",
+        r"(?i)Registration Time:\s*(.+)",
+        r"(?i)Registration Date:\s*(.+)",
+    ])
+});
+
+static EXPIRATION_DATE_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+    compile_patterns(&[
+        r"(?i)Expir(?:y|ation) Date:\s*(.+)",
+        r"(?i)Registry Expiry Date:\s*(.+)",
+        r"(?i)Expiration Time:\s*(.+)",
+        r"(?i)paid-till:\s*(.+)",
+    ])
+});
+
+static UPDATED_DATE_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+    compile_patterns(&[
+        r"(?i)Updated Date:\s*(.+)",
+        r"(?i)Last Updated:\s*(.+)",
+        r"(?i)Last Modified:\s*(.+)",
+    ])
+});
+
+static REGISTRANT_ORG_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+    compile_patterns(&[
+        r"(?i)Registrant Organization:\s*(.+)",
+        r"(?i)Registrant Organisation:\s*(.+)",
+        r"(?i)Registrant:\s*(.+)",
+    ])
+});
+
+static REGISTRANT_COUNTRY_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+    compile_patterns(&[
+        r"(?i)Registrant Country:\s*(.+)",
+        r"(?i)Registrant Country Code:\s*(.+)",
+    ])
+});
+
+static REGISTRAR_ABUSE_EMAIL_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+    compile_patterns(&[
+        r"(?i)Registrar Abuse Contact Email:\s*(.+)",
+        r"(?i)Abuse Contact Email:\s*(.+)",
+    ])
+});
+
+static NAME_SERVER_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+    compile_patterns(&[
+        r"(?i)Name Server:\s*(.+)",
+        r"(?i)nserver:\s*(.+)",
+        r"(?i)DNS:\s*(.+)",
+    ])
+});
+
+static STATUS_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+    compile_patterns(&[
+        r"(?i)Domain Status:\s*(.+)",
+        r"(?i)Status:\s*(.+)",
+        r"(?i)state:\s*(.+)",
+    ])
+});
+
+// RIR WHOIS（IP 分配信息）字段
+static RIR_REFER_PATTERNS: LazyLock<Vec<Regex>> =
+    LazyLock::new(|| compile_patterns(&[r"(?im)^refer:\s*(.+)", r"(?im)^whois:\s*(.+)"]));
+
+static NET_RANGE_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+    compile_patterns(&[
+        r"(?im)^NetRange:\s*(.+)",
+        r"(?im)^inetnum:\s*(.+)",
+        r"(?im)^inet6num:\s*(.+)",
+    ])
+});
+
+static CIDR_PATTERNS: LazyLock<Vec<Regex>> =
+    LazyLock::new(|| compile_patterns(&[r"(?im)^CIDR:\s*(.+)", r"(?im)^route6?:\s*(.+)"]));
+
+static NET_NAME_PATTERNS: LazyLock<Vec<Regex>> =
+    LazyLock::new(|| compile_patterns(&[r"(?im)^NetName:\s*(.+)"]));
+
+static ORG_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+    compile_patterns(&[
+        r"(?im)^OrgName:\s*(.+)",
+        r"(?im)^org-name:\s*(.+)",
+        r"(?im)^owner:\s*(.+)",
+        r"(?im)^descr:\s*(.+)",
+    ])
+});
+
+static COUNTRY_PATTERNS: LazyLock<Vec<Regex>> =
+    LazyLock::new(|| compile_patterns(&[r"(?im)^Country:\s*(.+)"]));
+
+static ABUSE_EMAIL_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+    compile_patterns(&[
+        r"(?im)^OrgAbuseEmail:\s*(.+)",
+        r"(?im)^abuse-mailbox:\s*(.+)",
+        r"(?i)Abuse contact for .+ is '([^']+)'",
+        r"(?im)^e-mail:\s*(.+)",
+    ])
+});
+
+/// IANA RDAP 域名引导注册表
+const RDAP_BOOTSTRAP_URL: &str = "https://data.iana.org/rdap/dns.json";
+
+/// RDAP 请求超时时间
+const RDAP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 创建工具箱使用的 HTTP 客户端，与 Provider 使用相同的代理配置
+async fn toolbox_client(state: &AppState) -> Result<reqwest::Client, String> {
+    state
+        .client_config
+        .read()
+        .await
+        .build_client("toolbox")
+        .map_err(|e| e.to_string())
+}
+
 /// WHOIS 查询
 #[tauri::command]
 pub async fn whois_lookup(domain: String) -> Result<ApiResponse<WhoisResult>, String> {
-    let whois =
-        WhoIs::from_string(WHOIS_SERVERS).map_err(|e| format!("初始化 WHOIS 客户端失败: {e}"))?;
+    let result = query_whois(&domain).await?;
+    Ok(ApiResponse::success(result))
+}
 
-    let options =
-        WhoIsLookupOptions::from_string(&domain).map_err(|e| format!("无效的域名: {e}"))?;
+/// 将国际化域名编码为 ASCII（punycode）后再查询
+fn ascii_domain(domain: &str) -> Result<String, String> {
+    domain_to_ascii(domain.trim()).ok_or_else(|| format!("无效的国际化域名: {domain}"))
+}
+
+/// 查询并解析 WHOIS 数据
+async fn query_whois(domain: &str) -> Result<WhoisResult, String> {
+    let whois = WHOIS
+        .as_ref()
+        .map_err(|e| format!("初始化 WHOIS 客户端失败: {e}"))?;
+
+    let options = WhoIsLookupOptions::from_string(ascii_domain(domain)?)
+        .map_err(|e| format!("无效的域名: {e}"))?;
 
     let raw = whois
         .lookup_async(options)
@@ -31,66 +199,310 @@ pub async fn whois_lookup(domain: String) -> Result<ApiResponse<WhoisResult>, St
         .map_err(|e| format!("WHOIS 查询失败: {e}"))?;
 
     // 解析原始 WHOIS 数据
-    let result = parse_whois_response(&domain, &raw);
+    Ok(parse_whois_response(domain, &raw))
+}
 
+/// RDAP 查询
+///
+/// 通过 IANA 引导注册表找到 TLD 的权威 RDAP 服务器，
+/// TLD 没有 RDAP 服务器时回退到 WHOIS
+#[tauri::command]
+pub async fn rdap_lookup(
+    state: State<'_, AppState>,
+    domain: String,
+) -> Result<ApiResponse<RdapResult>, String> {
+    let client = toolbox_client(&state).await?;
+    let result = query_rdap(&client, &domain).await?;
     Ok(ApiResponse::success(result))
 }
 
+/// 执行 RDAP 查询
+async fn query_rdap(client: &reqwest::Client, domain: &str) -> Result<RdapResult, String> {
+    let domain = ascii_domain(domain)?.trim_end_matches('.').to_lowercase();
+    if domain.is_empty() {
+        return Err("请输入域名".to_string());
+    }
+
+    let Some(base_url) = find_rdap_server(client, &domain).await? else {
+        let whois = query_whois(&domain).await?;
+        return Ok(RdapResult {
+            domain,
+            source: "whois".to_string(),
+            registrar: whois.registrar,
+            creation_date: whois.creation_date,
+            expiration_date: whois.expiration_date,
+            updated_date: whois.updated_date,
+            status: whois.status,
+            name_servers: whois.name_servers,
+            entities: Vec::new(),
+            raw: whois.raw,
+        });
+    };
+
+    let url = format!("{}/domain/{domain}", base_url.trim_end_matches('/'));
+    let response = client
+        .get(&url)
+        .timeout(RDAP_TIMEOUT)
+        .header("Accept", "application/rdap+json")
+        .send()
+        .await
+        .map_err(|e| format!("RDAP 查询失败: {e}"))?;
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Err(format!("RDAP 未找到域名: {domain}"));
+    }
+    if !response.status().is_success() {
+        return Err(format!("RDAP 查询失败: HTTP {}", response.status()));
+    }
+
+    let raw = response
+        .text()
+        .await
+        .map_err(|e| format!("RDAP 查询失败: {e}"))?;
+    let data: serde_json::Value =
+        serde_json::from_str(&raw).map_err(|e| format!("RDAP 响应解析失败: {e}"))?;
+
+    Ok(parse_rdap_response(domain, &data, raw))
+}
+
+/// 从 IANA 引导注册表中查找 TLD 对应的 RDAP 服务器
+async fn find_rdap_server(
+    client: &reqwest::Client,
+    domain: &str,
+) -> Result<Option<String>, String> {
+    let bootstrap: serde_json::Value = client
+        .get(RDAP_BOOTSTRAP_URL)
+        .timeout(RDAP_TIMEOUT)
+        .send()
+        .await
+        .map_err(|e| format!("获取 RDAP 引导数据失败: {e}"))?
+        .json()
+        .await
+        .map_err(|e| format!("解析 RDAP 引导数据失败: {e}"))?;
+
+    let tld = domain.rsplit('.').next().unwrap_or(domain);
+
+    // services: [[["com", "net"], ["https://rdap.verisign.com/com/v1/"]], ...]
+    let server = bootstrap["services"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .find(|service| {
+            service[0]
+                .as_array()
+                .is_some_and(|tlds| tlds.iter().any(|t| t.as_str() == Some(tld)))
+        })
+        .and_then(|service| {
+            let urls = service[1].as_array()?;
+            // 优先使用 HTTPS 地址
+            urls.iter()
+                .filter_map(serde_json::Value::as_str)
+                .find(|url| url.starts_with("https://"))
+                .or_else(|| urls.iter().find_map(serde_json::Value::as_str))
+                .map(str::to_string)
+        });
+
+    Ok(server)
+}
+
+/// 解析 RDAP 域名响应
+fn parse_rdap_response(domain: String, data: &serde_json::Value, raw: String) -> RdapResult {
+    let event_date = |action: &str| {
+        data["events"].as_array().and_then(|events| {
+            events
+                .iter()
+                .find(|e| e["eventAction"].as_str() == Some(action))
+                .and_then(|e| e["eventDate"].as_str())
+                .map(str::to_string)
+        })
+    };
+
+    let strings = |value: &serde_json::Value| -> Vec<String> {
+        value
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(serde_json::Value::as_str)
+            .map(str::to_string)
+            .collect()
+    };
+
+    let name_servers = data["nameservers"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|ns| ns["ldhName"].as_str())
+        .map(|ns| ns.trim_end_matches('.').to_lowercase())
+        .collect();
+
+    let entities: Vec<RdapEntity> = data["entities"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|entity| RdapEntity {
+            handle: entity["handle"].as_str().map(str::to_string),
+            roles: strings(&entity["roles"]),
+            name: vcard_name(&entity["vcardArray"]),
+        })
+        .collect();
+
+    let registrar = entities
+        .iter()
+        .find(|e| e.roles.iter().any(|r| r == "registrar"))
+        .and_then(|e| e.name.clone());
+
+    RdapResult {
+        domain,
+        source: "rdap".to_string(),
+        registrar,
+        creation_date: event_date("registration"),
+        expiration_date: event_date("expiration"),
+        updated_date: event_date("last changed"),
+        status: strings(&data["status"]),
+        name_servers,
+        entities,
+        raw,
+    }
+}
+
+/// 从 jCard 中提取 fn（显示名称）
+///
+/// `["vcard", [["version", {}, "text", "4.0"], ["fn", {}, "text", "Example Inc."]]]`
+fn vcard_name(vcard: &serde_json::Value) -> Option<String> {
+    vcard[1]
+        .as_array()?
+        .iter()
+        .find(|prop| prop[0].as_str() == Some("fn"))
+        .and_then(|prop| prop[3].as_str())
+        .map(str::to_string)
+}
+
 /// 解析 WHOIS 原始响应
 fn parse_whois_response(domain: &str, raw: &str) -> WhoisResult {
+    let redacted_fields = extract_redacted_fields(raw);
+    let privacy_protected = !redacted_fields.is_empty();
+
+    let creation_date = extract_field(raw, &CREATION_DATE_PATTERNS);
+    let expiration_date = extract_field(raw, &EXPIRATION_DATE_PATTERNS);
+
     WhoisResult {
         domain: domain.to_string(),
-        registrar: extract_field(
-            raw,
-            &[
-                r"(?i)Registrar:\s*(.+)",
-                r"(?i)Registrar Name:\s*(.+)",
-                r"(?i)Sponsoring Registrar:\s*(.+)",
-            ],
-        ),
-        creation_date: extract_field(
-            raw,
-            &[
-                r"(?i)Creation Date:\s*(.+)",
-                r"(?i)Created Date:\s*(.+)",
-                r"(?i)Created:\s*(.+)",
-                r"(?i)Registration Time:\s*(.+)",
-                r"(?i)Registration Date:\s*(.+)",
-            ],
-        ),
-        expiration_date: extract_field(
-            raw,
-            &[
-                r"(?i)Expir(?:y|ation) Date:\s*(.+)",
-                r"(?i)Registry Expiry Date:\s*(.+)",
-                r"(?i)Expiration Time:\s*(.+)",
-                r"(?i)paid-till:\s*(.+)",
-            ],
-        ),
-        updated_date: extract_field(
-            raw,
-            &[
-                r"(?i)Updated Date:\s*(.+)",
-                r"(?i)Last Updated:\s*(.+)",
-                r"(?i)Last Modified:\s*(.+)",
-            ],
-        ),
+        registrar: extract_field(raw, &REGISTRAR_PATTERNS),
+        creation_date_parsed: creation_date.as_deref().and_then(parse_whois_date),
+        expiration_date_parsed: expiration_date.as_deref().and_then(parse_whois_date),
+        creation_date,
+        expiration_date,
+        updated_date: extract_field(raw, &UPDATED_DATE_PATTERNS),
+        registrant_org: extract_contact_field(raw, &REGISTRANT_ORG_PATTERNS),
+        registrant_country: extract_contact_field(raw, &REGISTRANT_COUNTRY_PATTERNS),
+        abuse_email: extract_contact_field(raw, &REGISTRAR_ABUSE_EMAIL_PATTERNS),
         name_servers: extract_name_servers(raw),
         status: extract_status(raw),
+        privacy_protected,
+        redacted_fields,
         raw: raw.to_string(),
     }
 }
 
+/// WHOIS 隐私保护 / GDPR 脱敏标记（小写匹配）
+const PRIVACY_MARKERS: &[&str] = &[
+    "redacted",
+    "data protected",
+    "gdpr masked",
+    "whois privacy",
+    "privacy protect",
+    "privacy service",
+    "contact privacy",
+    "domains by proxy",
+    "not disclosed",
+];
+
+/// 找出被隐私保护或脱敏的字段名
+fn extract_redacted_fields(text: &str) -> Vec<String> {
+    let mut fields: Vec<String> = Vec::new();
+
+    for line in text.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let key = key.trim();
+        if key.is_empty() || key.starts_with('%') || key.starts_with('>') {
+            continue;
+        }
+        if is_redacted(value) && !fields.iter().any(|f| f == key) {
+            fields.push(key.to_string());
+        }
+    }
+
+    fields
+}
+
+/// WHOIS 中常见的带时间格式（无时区的按 UTC 处理）
+const WHOIS_DATETIME_FORMATS: &[&str] = &[
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y.%m.%d %H:%M:%S",
+    "%Y/%m/%d %H:%M:%S",
+    "%d-%b-%Y %H:%M:%S",
+    "%d.%m.%Y %H:%M:%S",
+];
+
+/// WHOIS 中常见的纯日期格式
+const WHOIS_DATE_FORMATS: &[&str] = &[
+    "%Y-%m-%d", "%Y.%m.%d", "%Y/%m/%d", "%d-%b-%Y", "%d.%m.%Y", "%d/%m/%Y", "%Y%m%d",
+];
+
+/// 将各种格式的 WHOIS 日期规范化为 RFC3339（UTC），无法识别时返回 None
+fn parse_whois_date(value: &str) -> Option<String> {
+    use chrono::{DateTime, NaiveDate, NaiveDateTime, SecondsFormat, Utc};
+
+    let value = value.trim();
+    let format = |dt: DateTime<Utc>| dt.to_rfc3339_opts(SecondsFormat::Secs, true);
+
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(format(dt.with_timezone(&Utc)));
+    }
+    if let Ok(dt) = DateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S %z") {
+        return Some(format(dt.with_timezone(&Utc)));
+    }
+
+    // 去掉 "(UTC)" / "UTC" / "GMT" / "Z" 后缀后按无时区格式解析
+    let value = ["(UTC)", "UTC", "GMT", "Z"]
+        .iter()
+        .find_map(|suffix| value.strip_suffix(suffix))
+        .unwrap_or(value)
+        .trim_end();
+    if let Some(dt) = WHOIS_DATETIME_FORMATS
+        .iter()
+        .find_map(|f| NaiveDateTime::parse_from_str(value, f).ok())
+    {
+        return Some(format(dt.and_utc()));
+    }
+    WHOIS_DATE_FORMATS
+        .iter()
+        .find_map(|f| NaiveDate::parse_from_str(value, f).ok())
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|dt| format(dt.and_utc()))
+}
+
+/// 字段值是否为隐私保护或脱敏占位
+fn is_redacted(value: &str) -> bool {
+    let value = value.to_lowercase();
+    PRIVACY_MARKERS.iter().any(|m| value.contains(m))
+}
+
+/// 提取联系人字段，被脱敏的值视为未公开
+fn extract_contact_field(text: &str, patterns: &[Regex]) -> Option<String> {
+    extract_field(text, patterns).filter(|v| !is_redacted(v))
+}
+
 /// 使用多个正则模式提取字段
-fn extract_field(text: &str, patterns: &[&str]) -> Option<String> {
-    for pattern in patterns {
-        if let Ok(re) = Regex::new(pattern) {
-            if let Some(caps) = re.captures(text) {
-                if let Some(m) = caps.get(1) {
-                    let value = m.as_str().trim().to_string();
-                    if !value.is_empty() {
-                        return Some(value);
-                    }
+fn extract_field(text: &str, patterns: &[Regex]) -> Option<String> {
+    for re in patterns {
+        if let Some(caps) = re.captures(text) {
+            if let Some(m) = caps.get(1) {
+                let value = m.as_str().trim().to_string();
+                if !value.is_empty() {
+                    return Some(value);
                 }
             }
         }
@@ -101,20 +513,13 @@ fn extract_field(text: &str, patterns: &[&str]) -> Option<String> {
 /// 提取域名服务器
 fn extract_name_servers(text: &str) -> Vec<String> {
     let mut servers = Vec::new();
-    let patterns = [
-        r"(?i)Name Server:\s*(.+)",
-        r"(?i)nserver:\s*(.+)",
-        r"(?i)DNS:\s*(.+)",
-    ];
-
-    for pattern in patterns {
-        if let Ok(re) = Regex::new(pattern) {
-            for caps in re.captures_iter(text) {
-                if let Some(m) = caps.get(1) {
-                    let server = m.as_str().trim().to_lowercase();
-                    if !server.is_empty() && !servers.contains(&server) {
-                        servers.push(server);
-                    }
+
+    for re in NAME_SERVER_PATTERNS.iter() {
+        for caps in re.captures_iter(text) {
+            if let Some(m) = caps.get(1) {
+                let server = m.as_str().trim().to_lowercase();
+                if !server.is_empty() && !servers.contains(&server) {
+                    servers.push(server);
                 }
             }
         }
@@ -126,26 +531,19 @@ fn extract_name_servers(text: &str) -> Vec<String> {
 /// 提取域名状态
 fn extract_status(text: &str) -> Vec<String> {
     let mut statuses = Vec::new();
-    let patterns = [
-        r"(?i)Domain Status:\s*(.+)",
-        r"(?i)Status:\s*(.+)",
-        r"(?i)state:\s*(.+)",
-    ];
-
-    for pattern in patterns {
-        if let Ok(re) = Regex::new(pattern) {
-            for caps in re.captures_iter(text) {
-                if let Some(m) = caps.get(1) {
-                    let status = m.as_str().trim().to_string();
-                    // 只取状态名，去掉后面的 URL
-                    let status = status
-                        .split_whitespace()
-                        .next()
-                        .unwrap_or(&status)
-                        .to_string();
-                    if !status.is_empty() && !statuses.contains(&status) {
-                        statuses.push(status);
-                    }
+
+    for re in STATUS_PATTERNS.iter() {
+        for caps in re.captures_iter(text) {
+            if let Some(m) = caps.get(1) {
+                let status = m.as_str().trim().to_string();
+                // 只取状态名，去掉后面的 URL
+                let status = status
+                    .split_whitespace()
+                    .next()
+                    .unwrap_or(&status)
+                    .to_string();
+                if !status.is_empty() && !statuses.contains(&status) {
+                    statuses.push(status);
                 }
             }
         }
@@ -154,71 +552,409 @@ fn extract_status(text: &str) -> Vec<String> {
     statuses
 }
 
-/// DNS 查询
-#[tauri::command]
-pub async fn dns_lookup(
-    domain: String,
-    record_type: String,
-    nameserver: Option<String>,
-) -> Result<ApiResponse<DnsLookupResult>, String> {
-    // 获取系统默认 DNS 服务器地址的辅助函数
-    fn get_system_dns() -> String {
-        let config = ResolverConfig::default();
-        let servers: Vec<String> = config
-            .name_servers()
+/// 加密 DNS 服务器的 TLS 证书名称（未知服务器使用 IP 本身）
+fn encrypted_dns_name(ip: IpAddr) -> String {
+    match ip.to_string().as_str() {
+        "1.1.1.1" | "1.0.0.1" => "cloudflare-dns.com",
+        "8.8.8.8" | "8.8.4.4" => "dns.google",
+        "9.9.9.9" | "149.112.112.112" => "dns.quad9.net",
+        "223.5.5.5" | "223.6.6.6" => "dns.alidns.com",
+        "1.12.12.12" | "120.53.53.53" => "dot.pub",
+        other => return other.to_string(),
+    }
+    .to_string()
+}
+
+/// 使用验证型解析器检查 DNSSEC 状态
+///
+/// 验证失败（签名或否定应答证明无效）视为 Bogus；
+/// 否定应答证明为 Insecure，或上游不可达、不支持验证时视为 Insecure
+async fn check_dnssec(config: ResolverConfig, domain: &str, record_type: &str) -> DnssecValidation {
+    let Ok(record_type) = record_type.parse::<hickory_resolver::proto::rr::RecordType>() else {
+        return DnssecValidation::Insecure;
+    };
+
+    let mut opts = ResolverOpts::default();
+    opts.validate = true;
+    let resolver = TokioResolver::builder_with_config(config, TokioConnectionProvider::default())
+        .with_options(opts)
+        .build();
+
+    let lookup = match resolver.lookup(domain, record_type).await {
+        Ok(lookup) => lookup,
+        Err(e) => return validation_error_state(&e),
+    };
+
+    let proofs: Vec<_> = lookup
+        .record_iter()
+        .map(hickory_resolver::proto::rr::Record::proof)
+        .collect();
+    if proofs
+        .iter()
+        .any(hickory_resolver::proto::dnssec::Proof::is_bogus)
+    {
+        DnssecValidation::Bogus
+    } else if !proofs.is_empty()
+        && proofs
             .iter()
-            .map(|ns| ns.socket_addr.ip().to_string())
-            .collect();
-        if servers.is_empty() {
-            "系统默认".to_string()
-        } else {
-            servers.join(", ")
+            .all(hickory_resolver::proto::dnssec::Proof::is_secure)
+    {
+        DnssecValidation::Secure
+    } else {
+        DnssecValidation::Insecure
+    }
+}
+
+/// 验证型查询失败时的 DNSSEC 状态
+fn validation_error_state(error: &hickory_resolver::ResolveError) -> DnssecValidation {
+    let Some(error) = error.proto() else {
+        return DnssecValidation::Insecure;
+    };
+    match error.kind() {
+        // 否定应答的 NSEC/NSEC3 证明
+        ProtoErrorKind::Nsec { proof, .. } if proof.is_insecure() => DnssecValidation::Insecure,
+        ProtoErrorKind::NoRecordsFound { authorities, .. } => {
+            if authorities
+                .iter()
+                .flat_map(|records| records.iter())
+                .any(|r| r.proof().is_bogus())
+            {
+                DnssecValidation::Bogus
+            } else {
+                DnssecValidation::Insecure
+            }
+        }
+        // 否定应答证明无效，或验证器以消息形式报告的失败（如否定应答缺少 SOA）
+        ProtoErrorKind::Nsec { .. } | ProtoErrorKind::Message(_) | ProtoErrorKind::Msg(_) => {
+            DnssecValidation::Bogus
         }
+        // 超时、连接失败等，上游不可达或不支持验证
+        _ => DnssecValidation::Insecure,
     }
+}
 
-    // 根据 nameserver 参数决定使用自定义还是系统默认
-    let (resolver, used_nameserver) = if let Some(ref ns) = nameserver {
-        if ns.is_empty() {
-            // 空字符串视为系统默认
-            let system_dns = get_system_dns();
-            let provider = TokioConnectionProvider::default();
-            let resolver = TokioResolver::builder_with_config(ResolverConfig::default(), provider)
-                .with_options(ResolverOpts::default())
-                .build();
-            (resolver, system_dns)
-        } else {
-            // 解析自定义 nameserver 地址
-            let ns_ip: IpAddr = ns
-                .parse()
-                .map_err(|_| format!("无效的 DNS 服务器地址: {ns}"))?;
+/// 将解析错误转换为错误信息，"没有记录"不视为错误
+fn lookup_error_message(error: &hickory_resolver::ResolveError) -> Option<String> {
+    if error.is_no_records_found() || error.is_nx_domain() {
+        None
+    } else {
+        Some(error.to_string())
+    }
+}
 
-            let config = ResolverConfig::from_parts(
-                None,
-                vec![],
-                NameServerConfigGroup::from_ips_clear(&[ns_ip], 53, true),
-            );
-            let provider = TokioConnectionProvider::default();
-            let resolver = TokioResolver::builder_with_config(config, provider)
-                .with_options(ResolverOpts::default())
-                .build();
-            (resolver, ns.clone())
+/// 根服务器（a-m.root-servers.net）
+const ROOT_SERVERS: &[(&str, &str)] = &[
+    ("a.root-servers.net", "198.41.0.4"),
+    ("b.root-servers.net", "170.247.170.2"),
+    ("c.root-servers.net", "192.33.4.12"),
+    ("d.root-servers.net", "199.7.91.13"),
+    ("e.root-servers.net", "192.203.230.10"),
+    ("f.root-servers.net", "192.5.5.241"),
+    ("g.root-servers.net", "192.112.36.4"),
+    ("h.root-servers.net", "198.97.190.53"),
+    ("i.root-servers.net", "192.36.148.17"),
+    ("j.root-servers.net", "192.58.128.30"),
+    ("k.root-servers.net", "193.0.14.129"),
+    ("l.root-servers.net", "199.7.83.42"),
+    ("m.root-servers.net", "202.12.27.33"),
+];
+/// trace 最多跟随的委派层数
+const TRACE_MAX_HOPS: usize = 16;
+/// 每层最多尝试的 nameserver 数量
+const TRACE_SERVER_ATTEMPTS: usize = 3;
+/// 单次查询超时
+const TRACE_QUERY_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// 向权威服务器发送不递归的查询，响应被截断时改用 TCP 重试
+async fn query_authoritative(
+    ip: IpAddr,
+    name: &Name,
+    record_type: RecordType,
+) -> Result<Message, String> {
+    let mut message = Message::new();
+    message
+        .set_id(rand::random())
+        .set_recursion_desired(false)
+        .add_query(Query::query(name.clone(), record_type));
+    let mut edns = Edns::new();
+    edns.set_max_payload(1232);
+    message.set_edns(edns);
+    let request = message.to_vec().map_err(|e| e.to_string())?;
+    let addr = SocketAddr::new(ip, 53);
+
+    let bind_addr = if ip.is_ipv4() { "0.0.0.0:0" } else { "[::]:0" };
+    let socket = UdpSocket::bind(bind_addr)
+        .await
+        .map_err(|e| e.to_string())?;
+    socket
+        .send_to(&request, addr)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut buf = vec![0u8; 4096];
+    let response = loop {
+        let (len, from) = tokio::time::timeout(TRACE_QUERY_TIMEOUT, socket.recv_from(&mut buf))
+            .await
+            .map_err(|_| "查询超时".to_string())?
+            .map_err(|e| e.to_string())?;
+        if from != addr {
+            continue;
         }
-    } else {
-        // 使用系统默认
-        let system_dns = get_system_dns();
-        let provider = TokioConnectionProvider::default();
-        let resolver = TokioResolver::builder_with_config(ResolverConfig::default(), provider)
-            .with_options(ResolverOpts::default())
-            .build();
-        (resolver, system_dns)
+        let response = Message::from_vec(&buf[..len]).map_err(|e| e.to_string())?;
+        if response.id() == message.id() {
+            break response;
+        }
+    };
+    if !response.truncated() {
+        return Ok(response);
+    }
+
+    // TCP 报文带 2 字节长度前缀
+    let exchange = async {
+        let mut stream = TcpStream::connect(addr).await?;
+        let len = u16::try_from(request.len()).unwrap_or(u16::MAX);
+        stream.write_all(&len.to_be_bytes()).await?;
+        stream.write_all(&request).await?;
+
+        let mut len_buf = [0u8; 2];
+        stream.read_exact(&mut len_buf).await?;
+        let mut body = vec![0u8; usize::from(u16::from_be_bytes(len_buf))];
+        stream.read_exact(&mut body).await?;
+        Ok::<_, std::io::Error>(body)
+    };
+    let body = tokio::time::timeout(TRACE_QUERY_TIMEOUT, exchange)
+        .await
+        .map_err(|_| "TCP 查询超时".to_string())?
+        .map_err(|e| e.to_string())?;
+    Message::from_vec(&body).map_err(|e| e.to_string())
+}
+
+/// 将应答记录转换为查询结果格式
+fn trace_record(record: &Record) -> DnsLookupRecord {
+    let (value, priority) = match record.data() {
+        RData::MX(mx) => (mx.exchange().to_string(), Some(mx.preference())),
+        RData::SRV(srv) => (
+            format!("{} {} {}", srv.weight(), srv.port(), srv.target()),
+            Some(srv.priority()),
+        ),
+        data => (data.to_string(), None),
     };
+    DnsLookupRecord {
+        record_type: record.record_type().to_string(),
+        name: record.name().to_string().trim_end_matches('.').to_string(),
+        value: value.trim_end_matches('.').to_string(),
+        ttl: record.ttl(),
+        priority,
+    }
+}
+
+/// 显示用的 zone 名称，根区为 "."
+fn zone_label(zone: &Name) -> String {
+    if zone.is_root() {
+        ".".to_string()
+    } else {
+        zone.to_string().trim_end_matches('.').to_string()
+    }
+}
+
+/// 从根服务器开始逐级跟随 NS 委派，直到权威服务器给出应答（类似 `dig +trace`）
+async fn trace_lookup(domain: &str, record_type: &str) -> Result<DnsTrace, String> {
+    let mut name = Name::from_utf8(domain).map_err(|e| format!("无效的域名: {e}"))?;
+    name.set_fqdn(true);
+    let record_type: RecordType = record_type
+        .parse()
+        .map_err(|_| format!("不支持的记录类型: {record_type}"))?;
+
+    // 委派中没有 glue 记录时，用系统解析器解析 nameserver 地址
+    let fallback = TokioResolver::builder_with_config(
+        ResolverConfig::default(),
+        TokioConnectionProvider::default(),
+    )
+    .build();
+
+    let mut zone = Name::root();
+    let mut servers: Vec<(String, Option<IpAddr>)> = ROOT_SERVERS
+        .iter()
+        .map(|(ns, ip)| ((*ns).to_string(), ip.parse().ok()))
+        .collect();
+    let mut hops = Vec::new();
+
+    for _ in 0..TRACE_MAX_HOPS {
+        let mut hop = DnsTraceHop {
+            zone: zone_label(&zone),
+            nameservers: servers.iter().map(|(ns, _)| ns.clone()).collect(),
+            server: None,
+            server_ip: None,
+            response_code: None,
+            authoritative: false,
+            records: Vec::new(),
+            referral: Vec::new(),
+            elapsed_ms: 0,
+            error: None,
+        };
+
+        // 依次尝试该层的 nameserver，直到拿到有效响应
+        let mut failures = Vec::new();
+        let mut response = None;
+        for (ns, ip) in servers.iter().take(TRACE_SERVER_ATTEMPTS) {
+            let ip = match ip {
+                Some(ip) => *ip,
+                None => match fallback
+                    .ipv4_lookup(format!("{ns}."))
+                    .await
+                    .map(|lookup| lookup.iter().next().map(|a| IpAddr::V4(a.0)))
+                {
+                    Ok(Some(ip)) => ip,
+                    Ok(None) => {
+                        failures.push(format!("{ns}: 没有 glue 记录且无法解析地址"));
+                        continue;
+                    }
+                    Err(e) => {
+                        failures.push(format!("{ns}: 无法解析地址 ({e})"));
+                        continue;
+                    }
+                },
+            };
+
+            let started = Instant::now();
+            match query_authoritative(ip, &name, record_type).await {
+                Ok(message)
+                    if matches!(
+                        message.response_code(),
+                        ResponseCode::NoError | ResponseCode::NXDomain
+                    ) =>
+                {
+                    hop.server = Some(ns.clone());
+                    hop.server_ip = Some(ip.to_string());
+                    hop.elapsed_ms =
+                        u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+                    response = Some(message);
+                    break;
+                }
+                Ok(message) => failures.push(format!("{ns} ({ip}): {}", message.response_code())),
+                Err(e) => failures.push(format!("{ns} ({ip}): {e}")),
+            }
+        }
+        if !failures.is_empty() {
+            hop.error = Some(failures.join("; "));
+        }
+
+        let Some(response) = response else {
+            let error = format!("{} 的 nameserver 均无有效响应（lame delegation）", hop.zone);
+            hops.push(hop);
+            return Ok(DnsTrace {
+                hops,
+                completed: false,
+                error: Some(error),
+            });
+        };
+
+        hop.response_code = Some(response.response_code().to_string());
+        hop.authoritative = response.authoritative();
+        hop.records = response.answers().iter().map(trace_record).collect();
+        let server = hop.server.clone().unwrap_or_default();
+
+        // NXDOMAIN 或有应答记录：解析结束
+        if response.response_code() == ResponseCode::NXDomain || !hop.records.is_empty() {
+            hops.push(hop);
+            return Ok(DnsTrace {
+                hops,
+                completed: true,
+                error: None,
+            });
+        }
+
+        let referral: Vec<&Record> = response
+            .name_servers()
+            .iter()
+            .filter(|r| r.record_type() == RecordType::NS)
+            .collect();
+        let next_zone = referral.first().map(|r| r.name().clone());
+
+        match next_zone {
+            // 委派到更下一级的 zone，继续查询
+            Some(next) if next.num_labels() > zone.num_labels() && next.zone_of(&name) => {
+                servers = referral
+                    .iter()
+                    .filter_map(|r| r.data().as_ns())
+                    .map(|ns| {
+                        let glue = response
+                            .additionals()
+                            .iter()
+                            .filter(|a| a.name() == &ns.0)
+                            .find_map(|a| a.data().as_a())
+                            .map(|a| IpAddr::V4(a.0));
+                        (ns.0.to_string().trim_end_matches('.').to_string(), glue)
+                    })
+                    .collect();
+                hop.referral = servers.iter().map(|(ns, _)| ns.clone()).collect();
+                hops.push(hop);
+                zone = next;
+            }
+            Some(next) => {
+                let error = format!(
+                    "{server} 未对 {} 给出权威应答，而是委派到了 {}（lame delegation）",
+                    zone_label(&zone),
+                    zone_label(&next)
+                );
+                hops.push(hop);
+                return Ok(DnsTrace {
+                    hops,
+                    completed: false,
+                    error: Some(error),
+                });
+            }
+            // 权威应答但没有记录（NODATA）
+            None if response.authoritative() => {
+                hops.push(hop);
+                return Ok(DnsTrace {
+                    hops,
+                    completed: true,
+                    error: None,
+                });
+            }
+            None => {
+                let error = format!(
+                    "{server} 既不是 {} 的权威服务器，也没有给出委派（lame delegation）",
+                    zone_label(&zone)
+                );
+                hops.push(hop);
+                return Ok(DnsTrace {
+                    hops,
+                    completed: false,
+                    error: Some(error),
+                });
+            }
+        }
+    }
+
+    Ok(DnsTrace {
+        hops,
+        completed: false,
+        error: Some(format!("委派超过 {TRACE_MAX_HOPS} 层，可能存在循环委派")),
+    })
+}
 
+/// `dns_lookup` 支持的记录类型（"ALL" 时全部查询）
+const LOOKUP_RECORD_TYPES: &[&str] = &[
+    "A", "AAAA", "CNAME", "MX", "TXT", "NS", "SOA", "SRV", "CAA", "PTR",
+];
+
+/// 使用指定解析器查询单个记录类型
+///
+/// 返回查询到的记录和查询错误（"没有记录"不视为错误），不支持的类型返回 Err
+async fn query_records(
+    resolver: &TokioResolver,
+    domain: &str,
+    record_type: &str,
+) -> Result<(Vec<DnsLookupRecord>, Option<String>), String> {
+    let domain = domain.to_string();
     let mut records: Vec<DnsLookupRecord> = Vec::new();
-    let record_type_upper = record_type.to_uppercase();
+    let mut lookup_error: Option<String> = None;
 
-    match record_type_upper.as_str() {
-        "A" => {
-            if let Ok(response) = resolver.ipv4_lookup(&domain).await {
+    match record_type {
+        "A" => match resolver.ipv4_lookup(&domain).await {
+            Ok(response) => {
                 for ip in response.iter() {
                     records.push(DnsLookupRecord {
                         record_type: "A".to_string(),
@@ -233,9 +969,10 @@ pub async fn dns_lookup(
                     });
                 }
             }
-        }
-        "AAAA" => {
-            if let Ok(response) = resolver.ipv6_lookup(&domain).await {
+            Err(e) => lookup_error = lookup_error_message(&e),
+        },
+        "AAAA" => match resolver.ipv6_lookup(&domain).await {
+            Ok(response) => {
                 for ip in response.iter() {
                     records.push(DnsLookupRecord {
                         record_type: "AAAA".to_string(),
@@ -250,9 +987,10 @@ pub async fn dns_lookup(
                     });
                 }
             }
-        }
-        "MX" => {
-            if let Ok(response) = resolver.mx_lookup(&domain).await {
+            Err(e) => lookup_error = lookup_error_message(&e),
+        },
+        "MX" => match resolver.mx_lookup(&domain).await {
+            Ok(response) => {
                 for mx in response.iter() {
                     records.push(DnsLookupRecord {
                         record_type: "MX".to_string(),
@@ -267,9 +1005,10 @@ pub async fn dns_lookup(
                     });
                 }
             }
-        }
-        "TXT" => {
-            if let Ok(response) = resolver.txt_lookup(&domain).await {
+            Err(e) => lookup_error = lookup_error_message(&e),
+        },
+        "TXT" => match resolver.txt_lookup(&domain).await {
+            Ok(response) => {
                 for txt in response.iter() {
                     let txt_data: String = txt
                         .iter()
@@ -288,9 +1027,10 @@ pub async fn dns_lookup(
                     });
                 }
             }
-        }
-        "NS" => {
-            if let Ok(response) = resolver.ns_lookup(&domain).await {
+            Err(e) => lookup_error = lookup_error_message(&e),
+        },
+        "NS" => match resolver.ns_lookup(&domain).await {
+            Ok(response) => {
                 for ns in response.iter() {
                     records.push(DnsLookupRecord {
                         record_type: "NS".to_string(),
@@ -305,27 +1045,31 @@ pub async fn dns_lookup(
                     });
                 }
             }
-        }
+            Err(e) => lookup_error = lookup_error_message(&e),
+        },
         "CNAME" => {
-            if let Ok(response) = resolver
+            match resolver
                 .lookup(&domain, hickory_resolver::proto::rr::RecordType::CNAME)
                 .await
             {
-                for record in response.record_iter() {
-                    if let Some(cname) = record.data().as_cname() {
-                        records.push(DnsLookupRecord {
-                            record_type: "CNAME".to_string(),
-                            name: domain.clone(),
-                            value: cname.0.to_string().trim_end_matches('.').to_string(),
-                            ttl: record.ttl(),
-                            priority: None,
-                        });
+                Ok(response) => {
+                    for record in response.record_iter() {
+                        if let Some(cname) = record.data().as_cname() {
+                            records.push(DnsLookupRecord {
+                                record_type: "CNAME".to_string(),
+                                name: domain.clone(),
+                                value: cname.0.to_string().trim_end_matches('.').to_string(),
+                                ttl: record.ttl(),
+                                priority: None,
+                            });
+                        }
                     }
                 }
+                Err(e) => lookup_error = lookup_error_message(&e),
             }
         }
-        "SOA" => {
-            if let Ok(response) = resolver.soa_lookup(&domain).await {
+        "SOA" => match resolver.soa_lookup(&domain).await {
+            Ok(response) => {
                 if let Some(soa) = response.iter().next() {
                     let value = format!(
                         "{} {} {} {} {} {} {}",
@@ -350,9 +1094,10 @@ pub async fn dns_lookup(
                     });
                 }
             }
-        }
-        "SRV" => {
-            if let Ok(response) = resolver.srv_lookup(&domain).await {
+            Err(e) => lookup_error = lookup_error_message(&e),
+        },
+        "SRV" => match resolver.srv_lookup(&domain).await {
+            Ok(response) => {
                 for srv in response.iter() {
                     let value = format!(
                         "{} {} {}",
@@ -373,100 +1118,908 @@ pub async fn dns_lookup(
                     });
                 }
             }
-        }
+            Err(e) => lookup_error = lookup_error_message(&e),
+        },
         "CAA" => {
-            if let Ok(response) = resolver
+            match resolver
                 .lookup(&domain, hickory_resolver::proto::rr::RecordType::CAA)
                 .await
             {
-                for record in response.record_iter() {
-                    if let Some(caa) = record.data().as_caa() {
-                        let value = format!(
-                            "{} {} \"{}\"",
-                            if caa.issuer_critical() { 128 } else { 0 },
-                            caa.tag().as_str(),
-                            String::from_utf8_lossy(caa.raw_value())
-                        );
-                        records.push(DnsLookupRecord {
-                            record_type: "CAA".to_string(),
-                            name: domain.clone(),
-                            value,
-                            ttl: record.ttl(),
-                            priority: None,
-                        });
+                Ok(response) => {
+                    for record in response.record_iter() {
+                        if let Some(caa) = record.data().as_caa() {
+                            let value = format!(
+                                "{} {} \"{}\"",
+                                if caa.issuer_critical() { 128 } else { 0 },
+                                caa.tag().as_str(),
+                                String::from_utf8_lossy(caa.raw_value())
+                            );
+                            records.push(DnsLookupRecord {
+                                record_type: "CAA".to_string(),
+                                name: domain.clone(),
+                                value,
+                                ttl: record.ttl(),
+                                priority: None,
+                            });
+                        }
                     }
                 }
+                Err(e) => lookup_error = lookup_error_message(&e),
             }
         }
         "PTR" => {
-            if let Ok(response) = resolver
+            match resolver
                 .lookup(&domain, hickory_resolver::proto::rr::RecordType::PTR)
                 .await
             {
-                for record in response.record_iter() {
-                    if let Some(ptr) = record.data().as_ptr() {
-                        records.push(DnsLookupRecord {
-                            record_type: "PTR".to_string(),
-                            name: domain.clone(),
-                            value: ptr.0.to_string().trim_end_matches('.').to_string(),
-                            ttl: record.ttl(),
-                            priority: None,
-                        });
+                Ok(response) => {
+                    for record in response.record_iter() {
+                        if let Some(ptr) = record.data().as_ptr() {
+                            records.push(DnsLookupRecord {
+                                record_type: "PTR".to_string(),
+                                name: domain.clone(),
+                                value: ptr.0.to_string().trim_end_matches('.').to_string(),
+                                ttl: record.ttl(),
+                                priority: None,
+                            });
+                        }
                     }
                 }
+                Err(e) => lookup_error = lookup_error_message(&e),
+            }
+        }
+        _ => return Err(format!("不支持的记录类型: {record_type}")),
+    }
+
+    Ok((records, lookup_error))
+}
+
+/// DNS 查询
+///
+/// `protocol` 可选 "udp"（默认）、"doh"（DNS-over-HTTPS）、"dot"（DNS-over-TLS），
+/// 未指定 nameserver 时加密协议使用 Cloudflare 公共 DNS。
+/// `trace` 为 true 时忽略 nameserver 和 protocol，从根服务器开始逐级查询
+#[tauri::command]
+pub async fn dns_lookup(
+    domain: String,
+    record_type: String,
+    nameserver: Option<String>,
+    protocol: Option<String>,
+    trace: Option<bool>,
+) -> Result<ApiResponse<DnsLookupResult>, String> {
+    // 获取配置中 DNS 服务器地址的辅助函数
+    fn describe_nameservers(config: &ResolverConfig) -> String {
+        let mut servers: Vec<String> = Vec::new();
+        for ns in config.name_servers() {
+            let ip = ns.socket_addr.ip().to_string();
+            if !servers.contains(&ip) {
+                servers.push(ip);
             }
         }
+        if servers.is_empty() {
+            "系统默认".to_string()
+        } else {
+            servers.join(", ")
+        }
+    }
+
+    let domain = ascii_domain(&domain)?;
+
+    if trace.unwrap_or(false) {
+        let record_type = record_type.to_uppercase();
+        if record_type == "ALL" {
+            return Err("trace 模式需要指定记录类型".to_string());
+        }
+
+        let trace = trace_lookup(&domain, &record_type).await?;
+        let last_hop = trace.hops.last();
+        let nameserver = last_hop.and_then(|hop| hop.server.clone()).map_or_else(
+            || "根服务器 (trace)".to_string(),
+            |ns| format!("{ns} (trace)"),
+        );
+        let records = last_hop.map(|hop| hop.records.clone()).unwrap_or_default();
+
+        return Ok(ApiResponse::success(DnsLookupResult {
+            nameserver,
+            records,
+            dnssec: DnssecValidation::Insecure,
+            type_statuses: vec![DnsLookupTypeStatus {
+                record_type,
+                error: trace.error.clone(),
+            }],
+            trace: Some(trace),
+        }));
+    }
+
+    // 空字符串视为系统默认
+    let custom_ns = nameserver.as_deref().filter(|ns| !ns.is_empty());
+    let custom_ip: Option<IpAddr> = custom_ns
+        .map(|ns| {
+            ns.parse()
+                .map_err(|_| format!("无效的 DNS 服务器地址: {ns}"))
+        })
+        .transpose()?;
+
+    // 根据协议和 nameserver 参数构造解析器配置
+    let protocol = protocol.as_deref().unwrap_or("udp").to_lowercase();
+    let (config, protocol_label) = match protocol.as_str() {
+        "udp" => (
+            custom_ip.map_or_else(ResolverConfig::default, |ip| {
+                ResolverConfig::from_parts(
+                    None,
+                    vec![],
+                    NameServerConfigGroup::from_ips_clear(&[ip], 53, true),
+                )
+            }),
+            None,
+        ),
+        "doh" => (
+            custom_ip.map_or_else(ResolverConfig::cloudflare_https, |ip| {
+                ResolverConfig::from_parts(
+                    None,
+                    vec![],
+                    NameServerConfigGroup::from_ips_https(&[ip], 443, encrypted_dns_name(ip), true),
+                )
+            }),
+            Some("DoH"),
+        ),
+        "dot" => (
+            custom_ip.map_or_else(ResolverConfig::cloudflare_tls, |ip| {
+                ResolverConfig::from_parts(
+                    None,
+                    vec![],
+                    NameServerConfigGroup::from_ips_tls(&[ip], 853, encrypted_dns_name(ip), true),
+                )
+            }),
+            Some("DoT"),
+        ),
+        _ => return Err(format!("不支持的查询协议: {protocol}")),
+    };
+
+    let servers = custom_ns.map_or_else(|| describe_nameservers(&config), str::to_string);
+    let used_nameserver = match protocol_label {
+        Some(label) => format!("{servers} ({label})"),
+        None => servers,
+    };
+
+    let validation_config = config.clone();
+    let resolver = TokioResolver::builder_with_config(config, TokioConnectionProvider::default())
+        .with_options(ResolverOpts::default())
+        .build();
+
+    let mut records: Vec<DnsLookupRecord> = Vec::new();
+    let mut all_dnssec: Option<DnssecValidation> = None;
+    let mut all_statuses: Option<Vec<DnsLookupTypeStatus>> = None;
+    let mut lookup_error: Option<String> = None;
+    let record_type_upper = record_type.to_uppercase();
+
+    match record_type_upper.as_str() {
         "ALL" => {
             // 并发查询所有记录类型
-            let types = vec![
-                "A", "AAAA", "CNAME", "MX", "TXT", "NS", "SOA", "SRV", "CAA", "PTR",
-            ];
+            let types = LOOKUP_RECORD_TYPES;
             let ns = nameserver.clone();
             let futures: Vec<_> = types
-                .into_iter()
-                .map(|t| Box::pin(dns_lookup(domain.clone(), t.to_string(), ns.clone())))
+                .iter()
+                .map(|t| {
+                    Box::pin(dns_lookup(
+                        domain.clone(),
+                        t.to_string(),
+                        ns.clone(),
+                        Some(protocol.clone()),
+                        None,
+                    ))
+                })
                 .collect();
 
             let results = join_all(futures).await;
-            for result in results {
-                if let Ok(ApiResponse {
-                    data: Some(lookup_result),
-                    ..
-                }) = result
-                {
-                    records.extend(lookup_result.records);
+            let mut states = Vec::new();
+            let mut statuses = Vec::new();
+            for (t, result) in types.iter().zip(results) {
+                match result {
+                    Ok(ApiResponse {
+                        data: Some(lookup_result),
+                        ..
+                    }) => {
+                        // 没有记录的类型不参与 DNSSEC 状态汇总
+                        if !lookup_result.records.is_empty() {
+                            states.push(lookup_result.dnssec);
+                        }
+                        records.extend(lookup_result.records);
+                        statuses.extend(lookup_result.type_statuses);
+                    }
+                    Ok(_) => statuses.push(DnsLookupTypeStatus {
+                        record_type: (*t).to_string(),
+                        error: None,
+                    }),
+                    Err(e) => statuses.push(DnsLookupTypeStatus {
+                        record_type: (*t).to_string(),
+                        error: Some(e),
+                    }),
                 }
             }
+            all_statuses = Some(statuses);
+
+            all_dnssec = Some(if states.contains(&DnssecValidation::Bogus) {
+                DnssecValidation::Bogus
+            } else if !states.is_empty() && states.iter().all(|s| *s == DnssecValidation::Secure) {
+                DnssecValidation::Secure
+            } else {
+                DnssecValidation::Insecure
+            });
         }
         _ => {
-            return Err(format!("不支持的记录类型: {record_type}"));
+            (records, lookup_error) = query_records(&resolver, &domain, &record_type_upper).await?;
         }
     }
 
+    let dnssec = match all_dnssec {
+        Some(state) => state,
+        None if records.is_empty() => DnssecValidation::Insecure,
+        None => check_dnssec(validation_config, &domain, &record_type_upper).await,
+    };
+
+    let type_statuses = all_statuses.unwrap_or_else(|| {
+        vec![DnsLookupTypeStatus {
+            record_type: record_type_upper,
+            error: lookup_error,
+        }]
+    });
+
     Ok(ApiResponse::success(DnsLookupResult {
         nameserver: used_nameserver,
         records,
+        dnssec,
+        type_statuses,
+        trace: None,
     }))
 }
 
-/// ipwhois.io 响应结构
-#[derive(serde::Deserialize)]
-struct IpWhoisResponse {
-    ip: String,
-    success: bool,
-    message: Option<String>,
-    #[serde(rename = "type")]
-    ip_type: Option<String>,
-    country: Option<String>,
-    country_code: Option<String>,
-    region: Option<String>,
-    city: Option<String>,
-    latitude: Option<f64>,
-    longitude: Option<f64>,
-    timezone: Option<IpWhoisTimezone>,
-    connection: Option<IpWhoisConnection>,
-}
-
+/// 通过提供商 API 读取记录（而非 DNS 查询）
+///
+/// 返回提供商中配置的记录，即尚未完成传播时的"预期"解析结果
+#[tauri::command]
+pub async fn resolve_via_provider(
+    state: State<'_, AppState>,
+    account_id: String,
+    domain_id: String,
+    name: String,
+    record_type: Option<DnsRecordType>,
+) -> Result<ApiResponse<Vec<DnsRecord>>, String> {
+    let provider = state
+        .registry
+        .get(&account_id)
+        .await
+        .ok_or_else(|| format!("账号不存在: {account_id}"))?;
+
+    let records = provider
+        .find_records(&domain_id, name.trim(), record_type)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(ApiResponse::success(records))
+}
+
+// ============ 区域传送 (AXFR) ============
+
+/// 连接和读取单个响应报文的超时
+const AXFR_TIMEOUT: Duration = Duration::from_secs(10);
+/// 单次传送最多接收的记录数，防止异常服务器耗尽内存
+const AXFR_MAX_RECORDS: usize = 100_000;
+
+/// 解析 nameserver 地址，支持 IP、`IP:端口` 和主机名
+async fn resolve_nameserver_addr(nameserver: &str) -> Result<SocketAddr, String> {
+    if let Ok(addr) = nameserver.parse::<SocketAddr>() {
+        return Ok(addr);
+    }
+    if let Ok(ip) = nameserver.parse::<IpAddr>() {
+        return Ok(SocketAddr::new(ip, 53));
+    }
+    tokio::net::lookup_host((nameserver, 53))
+        .await
+        .map_err(|e| format!("无法解析 nameserver {nameserver}: {e}"))?
+        .next()
+        .ok_or_else(|| format!("无法解析 nameserver {nameserver}"))
+}
+
+/// 读取一个 TCP DNS 报文（带 2 字节长度前缀）
+async fn read_tcp_message(stream: &mut TcpStream) -> std::io::Result<Vec<u8>> {
+    let mut len_buf = [0u8; 2];
+    stream.read_exact(&mut len_buf).await?;
+    let mut body = vec![0u8; usize::from(u16::from_be_bytes(len_buf))];
+    stream.read_exact(&mut body).await?;
+    Ok(body)
+}
+
+/// 通过 TCP 发起 AXFR，读取响应直到再次收到 SOA 记录
+async fn zone_transfer(addr: SocketAddr, zone: &Name) -> Result<Vec<Record>, String> {
+    let mut message = Message::new();
+    message
+        .set_id(rand::random())
+        .set_recursion_desired(false)
+        .add_query(Query::query(zone.clone(), RecordType::AXFR));
+    let request = message.to_vec().map_err(|e| e.to_string())?;
+
+    let mut stream = tokio::time::timeout(AXFR_TIMEOUT, TcpStream::connect(addr))
+        .await
+        .map_err(|_| format!("连接 {addr} 超时"))?
+        .map_err(|e| format!("无法连接 {addr}: {e}"))?;
+    let len = u16::try_from(request.len()).unwrap_or(u16::MAX);
+    stream
+        .write_all(&len.to_be_bytes())
+        .await
+        .map_err(|e| e.to_string())?;
+    stream
+        .write_all(&request)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut records: Vec<Record> = Vec::new();
+    loop {
+        let body = tokio::time::timeout(AXFR_TIMEOUT, read_tcp_message(&mut stream))
+            .await
+            .map_err(|_| "区域传送超时".to_string())?
+            .map_err(|e| {
+                // 部分服务器拒绝传送时直接关闭连接
+                if e.kind() == std::io::ErrorKind::UnexpectedEof {
+                    "服务器关闭了连接，区域传送被拒绝或未完成".to_string()
+                } else {
+                    e.to_string()
+                }
+            })?;
+        let response = Message::from_vec(&body).map_err(|e| e.to_string())?;
+        if response.id() != message.id() {
+            continue;
+        }
+
+        match response.response_code() {
+            ResponseCode::NoError => {}
+            ResponseCode::Refused | ResponseCode::NotAuth => {
+                return Err("服务器拒绝了区域传送（未允许本机进行 AXFR）".to_string());
+            }
+            code => return Err(format!("区域传送失败: {code}")),
+        }
+        if response.answers().is_empty() {
+            return Err("服务器未返回记录，区域传送被拒绝".to_string());
+        }
+
+        for record in response.answers() {
+            let is_soa = record.record_type() == RecordType::SOA;
+            if records.is_empty() && !is_soa {
+                return Err("响应不是有效的区域传送（首条记录不是 SOA）".to_string());
+            }
+            // 结尾重复的 SOA 标志传送结束
+            if is_soa && !records.is_empty() {
+                return Ok(records);
+            }
+            if records.len() >= AXFR_MAX_RECORDS {
+                return Err(format!("记录数超过上限 {AXFR_MAX_RECORDS}"));
+            }
+            records.push(record.clone());
+        }
+    }
+}
+
+/// 向权威服务器发起区域传送（AXFR），导出 zone 中的全部记录
+///
+/// 只读诊断工具，常用于迁移前从旧 DNS 服务器导出 zone
+#[tauri::command]
+pub async fn axfr_transfer(
+    nameserver: String,
+    zone: String,
+) -> Result<ApiResponse<AxfrResult>, String> {
+    let mut zone_name = Name::from_utf8(zone.trim()).map_err(|e| format!("无效的域名: {e}"))?;
+    zone_name.set_fqdn(true);
+    let addr = resolve_nameserver_addr(nameserver.trim()).await?;
+
+    let started = Instant::now();
+    let records = zone_transfer(addr, &zone_name).await?;
+
+    Ok(ApiResponse::success(AxfrResult {
+        zone: zone_label(&zone_name),
+        nameserver: addr.to_string(),
+        records: records.iter().map(trace_record).collect(),
+        elapsed_ms: u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX),
+    }))
+}
+
+/// 查询 TXT 记录，每条记录的多个字符串拼接为一条，"没有记录"返回空列表
+async fn lookup_txt_records(resolver: &TokioResolver, name: &str) -> Result<Vec<String>, String> {
+    match resolver.txt_lookup(name).await {
+        Ok(response) => Ok(response
+            .iter()
+            .map(|txt| {
+                txt.iter()
+                    .map(|data| String::from_utf8_lossy(data).to_string())
+                    .collect::<String>()
+            })
+            .collect()),
+        Err(e) => lookup_error_message(&e).map_or_else(|| Ok(Vec::new()), Err),
+    }
+}
+
+/// 解析 `k=v; k=v` 形式的标签列表（DMARC、DKIM 使用）
+fn parse_tag_list(record: &str) -> Vec<(String, String)> {
+    record
+        .split(';')
+        .filter_map(|tag| tag.split_once('='))
+        .map(|(k, v)| (k.trim().to_lowercase(), v.trim().to_string()))
+        .collect()
+}
+
+/// 分析 SPF 记录
+fn analyze_spf(records: &[String]) -> SpfCheck {
+    let spf_records: Vec<&String> = records
+        .iter()
+        .filter(|r| {
+            let lower = r.to_lowercase();
+            lower == "v=spf1" || lower.starts_with("v=spf1 ")
+        })
+        .collect();
+
+    let mut check = SpfCheck {
+        record: spf_records.first().map(|r| (*r).clone()),
+        mechanisms: Vec::new(),
+        all_qualifier: None,
+        hard_fail: false,
+        dns_lookups: 0,
+        issues: Vec::new(),
+    };
+
+    let Some(record) = check.record.clone() else {
+        check.issues.push("未找到 SPF 记录".to_string());
+        return check;
+    };
+    if spf_records.len() > 1 {
+        check.issues.push(format!(
+            "存在 {} 条 SPF 记录，接收方会将其视为错误（permerror）",
+            spf_records.len()
+        ));
+    }
+
+    check.mechanisms = record
+        .split_whitespace()
+        .skip(1)
+        .map(str::to_string)
+        .collect();
+
+    let mut has_redirect = false;
+    for term in &check.mechanisms {
+        let lower = term.to_lowercase();
+        let name = lower.trim_start_matches(['+', '-', '~', '?']);
+        let name = name.split([':', '/', '=']).next().unwrap_or_default();
+        match name {
+            "all" => check.all_qualifier = Some(lower.clone()),
+            "include" | "a" | "mx" | "exists" => check.dns_lookups += 1,
+            "ptr" => {
+                check.dns_lookups += 1;
+                check
+                    .issues
+                    .push("使用了不推荐的 ptr 机制（RFC 7208）".to_string());
+            }
+            "redirect" => {
+                check.dns_lookups += 1;
+                has_redirect = true;
+            }
+            _ => {}
+        }
+    }
+
+    match check.all_qualifier.as_deref() {
+        Some("-all") => check.hard_fail = true,
+        Some("all" | "+all") => check
+            .issues
+            .push("使用了 +all，任何服务器都可以冒用该域名发信".to_string()),
+        Some(qualifier) => check.issues.push(format!(
+            "未以 -all 结尾（当前为 {qualifier}），伪造的邮件不会被直接拒绝"
+        )),
+        None if has_redirect => {}
+        None => check
+            .issues
+            .push("缺少 all 机制，未匹配的发件服务器结果为 neutral".to_string()),
+    }
+
+    if check.dns_lookups > 10 {
+        check.issues.push(format!(
+            "需要 {} 次 DNS 查询，超过 10 次的限制会导致验证失败",
+            check.dns_lookups
+        ));
+    }
+
+    check
+}
+
+/// 分析 DMARC 记录
+fn analyze_dmarc(records: &[String]) -> DmarcCheck {
+    let dmarc_records: Vec<&String> = records
+        .iter()
+        .filter(|r| r.trim_start().to_lowercase().starts_with("v=dmarc1"))
+        .collect();
+
+    let mut check = DmarcCheck {
+        record: dmarc_records.first().map(|r| (*r).clone()),
+        policy: None,
+        subdomain_policy: None,
+        pct: None,
+        rua: Vec::new(),
+        issues: Vec::new(),
+    };
+
+    let Some(record) = check.record.clone() else {
+        check.issues.push("未找到 DMARC 记录".to_string());
+        return check;
+    };
+    if dmarc_records.len() > 1 {
+        check.issues.push(format!(
+            "存在 {} 条 DMARC 记录，接收方会忽略 DMARC 策略",
+            dmarc_records.len()
+        ));
+    }
+
+    for (key, value) in parse_tag_list(&record) {
+        match key.as_str() {
+            "p" => check.policy = Some(value.to_lowercase()),
+            "sp" => check.subdomain_policy = Some(value.to_lowercase()),
+            "pct" => check.pct = value.parse().ok(),
+            "rua" => {
+                check.rua = value
+                    .split(',')
+                    .map(|uri| uri.trim().to_string())
+                    .filter(|uri| !uri.is_empty())
+                    .collect();
+            }
+            _ => {}
+        }
+    }
+
+    match check.policy.as_deref() {
+        None => check.issues.push("缺少 p= 策略标签".to_string()),
+        Some("none") => check
+            .issues
+            .push("策略为 p=none，仅监控，不会拦截伪造的邮件".to_string()),
+        Some("quarantine" | "reject") => {}
+        Some(policy) => check.issues.push(format!("无效的策略: p={policy}")),
+    }
+    if let Some(pct) = check.pct.filter(|pct| *pct < 100) {
+        check
+            .issues
+            .push(format!("pct={pct}，策略只对 {pct}% 的邮件生效"));
+    }
+    if check.rua.is_empty() {
+        check
+            .issues
+            .push("未配置 rua 聚合报告地址，无法收到认证结果报告".to_string());
+    }
+
+    check
+}
+
+/// 分析 DKIM 记录
+fn analyze_dkim(selector: String, records: &[String]) -> DkimCheck {
+    use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+    use x509_parser::prelude::FromDer;
+    use x509_parser::x509::SubjectPublicKeyInfo;
+
+    let record = records
+        .iter()
+        .find(|r| parse_tag_list(r).iter().any(|(k, _)| k == "p"))
+        .cloned();
+
+    let mut check = DkimCheck {
+        selector,
+        record,
+        key_type: None,
+        key_present: false,
+        key_valid: false,
+        key_bits: None,
+        issues: Vec::new(),
+    };
+
+    let Some(record) = check.record.clone() else {
+        check.issues.push(format!(
+            "未找到 selector \"{}\" 的 DKIM 记录",
+            check.selector
+        ));
+        return check;
+    };
+
+    let tags = parse_tag_list(&record);
+    let key_type = tags
+        .iter()
+        .find(|(k, _)| k == "k")
+        .map_or_else(|| "rsa".to_string(), |(_, v)| v.to_lowercase());
+    let public_key: String = tags
+        .iter()
+        .find(|(k, _)| k == "p")
+        .map(|(_, v)| v.chars().filter(|c| !c.is_whitespace()).collect())
+        .unwrap_or_default();
+    check.key_type = Some(key_type.clone());
+
+    if public_key.is_empty() {
+        check
+            .issues
+            .push("公钥为空（p=），该密钥已被吊销".to_string());
+        return check;
+    }
+    check.key_present = true;
+
+    let Ok(key) = BASE64.decode(&public_key) else {
+        check.issues.push("公钥不是有效的 Base64".to_string());
+        return check;
+    };
+
+    match key_type.as_str() {
+        "rsa" => match SubjectPublicKeyInfo::from_der(&key)
+            .ok()
+            .and_then(|(_, spki)| spki.parsed().ok().map(|parsed| parsed.key_size()))
+        {
+            Some(key_size) => {
+                let bits = u32::try_from(key_size).unwrap_or(u32::MAX);
+                check.key_valid = true;
+                check.key_bits = Some(bits);
+                if bits < 1024 {
+                    check
+                        .issues
+                        .push(format!("RSA 密钥仅 {bits} 位，接收方可能拒绝验证"));
+                }
+            }
+            None => check.issues.push("无法解析 RSA 公钥".to_string()),
+        },
+        "ed25519" => {
+            if key.len() == 32 {
+                check.key_valid = true;
+                check.key_bits = Some(256);
+            } else {
+                check
+                    .issues
+                    .push("Ed25519 公钥长度应为 32 字节".to_string());
+            }
+        }
+        other => check.issues.push(format!("不支持的密钥类型: k={other}")),
+    }
+
+    check
+}
+
+/// 邮件认证检查
+///
+/// 查询根域名的 SPF、`_dmarc` 下的 DMARC，以及指定 selector 的 DKIM 记录，
+/// 并标记常见的配置问题
+#[tauri::command]
+pub async fn email_auth_check(
+    domain: String,
+    dkim_selector: Option<String>,
+) -> Result<ApiResponse<EmailAuthResult>, String> {
+    let domain = domain.trim().trim_end_matches('.').to_lowercase();
+    if domain.is_empty() {
+        return Err("域名不能为空".to_string());
+    }
+
+    let resolver = TokioResolver::builder_with_config(
+        ResolverConfig::default(),
+        TokioConnectionProvider::default(),
+    )
+    .with_options(ResolverOpts::default())
+    .build();
+
+    let selector = dkim_selector
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty());
+    let dmarc_name = format!("_dmarc.{domain}");
+    let dkim_name = selector
+        .as_ref()
+        .map(|s| format!("{s}._domainkey.{domain}"));
+
+    let (spf_records, dmarc_records, dkim_records) = futures::join!(
+        lookup_txt_records(&resolver, &domain),
+        lookup_txt_records(&resolver, &dmarc_name),
+        async {
+            match &dkim_name {
+                Some(name) => Some(lookup_txt_records(&resolver, name).await),
+                None => None,
+            }
+        }
+    );
+
+    let spf = match spf_records {
+        Ok(records) => analyze_spf(&records),
+        Err(e) => {
+            let mut check = analyze_spf(&[]);
+            check.issues = vec![format!("SPF 查询失败: {e}")];
+            check
+        }
+    };
+    let dmarc = match dmarc_records {
+        Ok(records) => analyze_dmarc(&records),
+        Err(e) => {
+            let mut check = analyze_dmarc(&[]);
+            check.issues = vec![format!("DMARC 查询失败: {e}")];
+            check
+        }
+    };
+    let dkim = selector
+        .zip(dkim_records)
+        .map(|(selector, records)| match records {
+            Ok(records) => analyze_dkim(selector, &records),
+            Err(e) => {
+                let mut check = analyze_dkim(selector, &[]);
+                check.issues = vec![format!("DKIM 查询失败: {e}")];
+                check
+            }
+        });
+
+    Ok(ApiResponse::success(EmailAuthResult {
+        domain,
+        spf,
+        dmarc,
+        dkim,
+    }))
+}
+
+/// 传播检查使用的公共 DNS（名称, IP）
+const PUBLIC_RESOLVERS: &[(&str, &str)] = &[
+    ("Cloudflare", "1.1.1.1"),
+    ("Google", "8.8.8.8"),
+    ("Quad9", "9.9.9.9"),
+    ("OpenDNS", "208.67.222.222"),
+    ("AliDNS", "223.5.5.5"),
+    ("DNSPod", "119.29.29.29"),
+    ("114DNS", "114.114.114.114"),
+];
+
+/// 比较记录值时忽略大小写、首尾引号和末尾的 "."
+fn normalize_record_value(value: &str) -> String {
+    value
+        .trim()
+        .trim_matches('"')
+        .trim_end_matches('.')
+        .to_lowercase()
+}
+
+/// 向单个公共 DNS 查询
+async fn query_public_resolver(
+    name: &str,
+    ip: IpAddr,
+    domain: &str,
+    record_type: &str,
+    expected: Option<&str>,
+) -> PropagationResolverResult {
+    let config = ResolverConfig::from_parts(
+        None,
+        vec![],
+        NameServerConfigGroup::from_ips_clear(&[ip], 53, true),
+    );
+    // 每个服务器只查一次，避免个别服务器超时拖慢整体结果
+    let mut opts = ResolverOpts::default();
+    opts.timeout = std::time::Duration::from_secs(3);
+    opts.attempts = 1;
+    opts.cache_size = 0;
+    let resolver = TokioResolver::builder_with_config(config, TokioConnectionProvider::default())
+        .with_options(opts)
+        .build();
+
+    let started = std::time::Instant::now();
+    let (records, error) = match query_records(&resolver, domain, record_type).await {
+        Ok(result) => result,
+        Err(e) => (Vec::new(), Some(e)),
+    };
+    let elapsed_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+    let matched = match (expected, &error) {
+        (Some(expected), None) => Some(
+            records
+                .iter()
+                .any(|r| normalize_record_value(&r.value) == expected),
+        ),
+        _ => None,
+    };
+
+    PropagationResolverResult {
+        name: name.to_string(),
+        ip: ip.to_string(),
+        ttl: records.first().map(|r| r.ttl),
+        values: records.into_iter().map(|r| r.value).collect(),
+        matched,
+        error,
+        elapsed_ms,
+    }
+}
+
+/// DNS 传播检查
+///
+/// 并发向多个公共 DNS 查询同一记录，`expected_value` 不为空时比较各服务器的结果是否包含该值
+#[tauri::command]
+pub async fn check_propagation(
+    domain: String,
+    record_type: String,
+    expected_value: Option<String>,
+) -> Result<ApiResponse<PropagationResult>, String> {
+    let domain = domain.trim().trim_end_matches('.').to_string();
+    if domain.is_empty() {
+        return Err("域名不能为空".to_string());
+    }
+
+    let record_type = record_type.to_uppercase();
+    if !LOOKUP_RECORD_TYPES.contains(&record_type.as_str()) {
+        return Err(format!("不支持的记录类型: {record_type}"));
+    }
+
+    let expected_value = expected_value.filter(|v| !v.trim().is_empty());
+    let expected = expected_value.as_deref().map(normalize_record_value);
+
+    let futures: Vec<_> = PUBLIC_RESOLVERS
+        .iter()
+        .filter_map(|(name, ip)| Some((*name, ip.parse::<IpAddr>().ok()?)))
+        .map(|(name, ip)| {
+            query_public_resolver(name, ip, &domain, &record_type, expected.as_deref())
+        })
+        .collect();
+    let resolvers = join_all(futures).await;
+
+    let matched_count = resolvers.iter().filter(|r| r.matched == Some(true)).count();
+
+    Ok(ApiResponse::success(PropagationResult {
+        domain,
+        record_type,
+        expected_value,
+        resolvers,
+        matched_count,
+    }))
+}
+
+/// 地理位置查询失败
+enum GeoLookupError {
+    /// 服务已达限额，可切换到备用服务
+    RateLimited(String),
+    /// 其他错误
+    Failed(String),
+}
+
+impl GeoLookupError {
+    fn into_message(self) -> String {
+        match self {
+            Self::RateLimited(msg) | Self::Failed(msg) => msg,
+        }
+    }
+}
+
+/// 根据地址格式判断 IP 版本
+fn ip_version_of(ip: &str) -> String {
+    if ip.contains(':') { "IPv6" } else { "IPv4" }.to_string()
+}
+
+/// 拆分 "AS15169 Google LLC" 形式的组织字段为 (ASN, 名称)
+fn split_asn_org(value: &str) -> (Option<String>, Option<String>) {
+    let (head, rest) = value.split_once(' ').unwrap_or((value, ""));
+    let is_asn =
+        head.len() > 2 && head.starts_with("AS") && head[2..].chars().all(|c| c.is_ascii_digit());
+    if !is_asn {
+        return (None, Some(value.to_string()));
+    }
+    let name = rest.trim();
+    (
+        Some(head.to_string()),
+        (!name.is_empty()).then(|| name.to_string()),
+    )
+}
+
+/// ipwhois.io 响应结构
+#[derive(serde::Deserialize)]
+struct IpWhoisResponse {
+    ip: String,
+    success: bool,
+    message: Option<String>,
+    #[serde(rename = "type")]
+    ip_type: Option<String>,
+    country: Option<String>,
+    country_code: Option<String>,
+    region: Option<String>,
+    city: Option<String>,
+    latitude: Option<f64>,
+    longitude: Option<f64>,
+    timezone: Option<IpWhoisTimezone>,
+    connection: Option<IpWhoisConnection>,
+}
+
 #[derive(serde::Deserialize)]
 struct IpWhoisTimezone {
     id: Option<String>,
@@ -479,8 +2032,8 @@ struct IpWhoisConnection {
     isp: Option<String>,
 }
 
-/// 查询单个 IP 的地理位置
-async fn lookup_single_ip(ip: &str, client: &reqwest::Client) -> Result<IpGeoInfo, String> {
+/// 通过 ipwho.is 查询
+async fn lookup_ipwhois(ip: &str, client: &reqwest::Client) -> Result<IpGeoInfo, GeoLookupError> {
     let url = format!(
         "https://ipwho.is/{ip}?fields=ip,success,message,type,country,country_code,region,city,latitude,longitude,timezone,connection"
     );
@@ -489,32 +2042,29 @@ async fn lookup_single_ip(ip: &str, client: &reqwest::Client) -> Result<IpGeoInf
         .get(&url)
         .send()
         .await
-        .map_err(|e| format!("请求失败: {e}"))?
+        .map_err(|e| GeoLookupError::Failed(format!("请求失败: {e}")))?
         .json()
         .await
-        .map_err(|e| format!("解析失败: {e}"))?;
+        .map_err(|e| GeoLookupError::Failed(format!("解析失败: {e}")))?;
 
     if !response.success {
-        let error_msg = match response.message.as_deref() {
+        let error = match response.message.as_deref() {
             Some("You've hit the monthly limit") => {
-                "IP 查询服务已达本月限额，请稍后再试".to_string()
+                GeoLookupError::RateLimited("IP 查询服务已达本月限额，请稍后再试".to_string())
             }
-            Some("Invalid IP address") => "无效的 IP 地址".to_string(),
-            Some("Reserved range") => "该 IP 属于保留地址段，无法查询".to_string(),
-            Some(msg) => format!("查询失败: {msg}"),
-            None => "查询失败".to_string(),
+            Some("Invalid IP address") => GeoLookupError::Failed("无效的 IP 地址".to_string()),
+            Some("Reserved range") => {
+                GeoLookupError::Failed("该 IP 属于保留地址段，无法查询".to_string())
+            }
+            Some(msg) => GeoLookupError::Failed(format!("查询失败: {msg}")),
+            None => GeoLookupError::Failed("查询失败".to_string()),
         };
-        return Err(error_msg);
+        return Err(error);
     }
 
-    let ip_version = response.ip_type.unwrap_or_else(|| {
-        if response.ip.contains(':') {
-            "IPv6"
-        } else {
-            "IPv4"
-        }
-        .to_string()
-    });
+    let ip_version = response
+        .ip_type
+        .unwrap_or_else(|| ip_version_of(&response.ip));
 
     let (isp, org, asn) = response.connection.map_or((None, None, None), |conn| {
         (
@@ -543,25 +2093,244 @@ async fn lookup_single_ip(ip: &str, client: &reqwest::Client) -> Result<IpGeoInf
     })
 }
 
+/// ip-api.com 响应结构
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IpApiResponse {
+    status: String,
+    message: Option<String>,
+    query: Option<String>,
+    country: Option<String>,
+    country_code: Option<String>,
+    region_name: Option<String>,
+    city: Option<String>,
+    lat: Option<f64>,
+    lon: Option<f64>,
+    timezone: Option<String>,
+    isp: Option<String>,
+    org: Option<String>,
+    #[serde(rename = "as")]
+    as_field: Option<String>,
+    asname: Option<String>,
+}
+
+/// 通过 ip-api.com 查询（免费版不支持 HTTPS）
+async fn lookup_ipapi(ip: &str, client: &reqwest::Client) -> Result<IpGeoInfo, GeoLookupError> {
+    let url = format!(
+        "http://ip-api.com/json/{ip}?fields=status,message,query,country,countryCode,regionName,city,lat,lon,timezone,isp,org,as,asname"
+    );
+
+    let response = client
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| GeoLookupError::Failed(format!("请求失败: {e}")))?;
+
+    if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return Err(GeoLookupError::RateLimited(
+            "ip-api.com 请求过于频繁，请稍后再试".to_string(),
+        ));
+    }
+
+    let response: IpApiResponse = response
+        .json()
+        .await
+        .map_err(|e| GeoLookupError::Failed(format!("解析失败: {e}")))?;
+
+    if response.status != "success" {
+        let error = match response.message.as_deref() {
+            Some("invalid query") => "无效的 IP 地址".to_string(),
+            Some("private range" | "reserved range") => {
+                "该 IP 属于保留地址段，无法查询".to_string()
+            }
+            Some(msg) => format!("查询失败: {msg}"),
+            None => "查询失败".to_string(),
+        };
+        return Err(GeoLookupError::Failed(error));
+    }
+
+    let ip = response.query.unwrap_or_else(|| ip.to_string());
+    let asn = response
+        .as_field
+        .as_deref()
+        .and_then(|value| split_asn_org(value).0);
+
+    Ok(IpGeoInfo {
+        ip_version: ip_version_of(&ip),
+        ip,
+        country: response.country,
+        country_code: response.country_code,
+        region: response.region_name,
+        city: response.city,
+        latitude: response.lat,
+        longitude: response.lon,
+        timezone: response.timezone,
+        isp: response.isp,
+        org: response.org,
+        asn,
+        as_name: response.asname,
+    })
+}
+
+/// ipinfo.io 响应结构
+#[derive(serde::Deserialize)]
+struct IpInfoResponse {
+    ip: Option<String>,
+    #[serde(default)]
+    bogon: bool,
+    city: Option<String>,
+    region: Option<String>,
+    /// 国家代码
+    country: Option<String>,
+    /// "纬度,经度"
+    loc: Option<String>,
+    /// "AS15169 Google LLC"
+    org: Option<String>,
+    timezone: Option<String>,
+    error: Option<IpInfoError>,
+}
+
+#[derive(serde::Deserialize)]
+struct IpInfoError {
+    title: Option<String>,
+    message: Option<String>,
+}
+
+/// 通过 ipinfo.io 查询，配置 Token 时使用账号额度
+async fn lookup_ipinfo(
+    ip: &str,
+    client: &reqwest::Client,
+    token: Option<&str>,
+) -> Result<IpGeoInfo, GeoLookupError> {
+    let mut request = client.get(format!("https://ipinfo.io/{ip}/json"));
+    if let Some(token) = token {
+        request = request.bearer_auth(token);
+    }
+
+    let response = request
+        .send()
+        .await
+        .map_err(|e| GeoLookupError::Failed(format!("请求失败: {e}")))?;
+
+    match response.status() {
+        reqwest::StatusCode::TOO_MANY_REQUESTS => {
+            return Err(GeoLookupError::RateLimited(
+                "ipinfo.io 已达请求限额，请稍后再试".to_string(),
+            ));
+        }
+        reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN => {
+            return Err(GeoLookupError::Failed("ipinfo.io Token 无效".to_string()));
+        }
+        _ => {}
+    }
+
+    let response: IpInfoResponse = response
+        .json()
+        .await
+        .map_err(|e| GeoLookupError::Failed(format!("解析失败: {e}")))?;
+
+    if let Some(error) = response.error {
+        let msg = error.message.or(error.title).unwrap_or_default();
+        return Err(GeoLookupError::Failed(format!("查询失败: {msg}")));
+    }
+    if response.bogon {
+        return Err(GeoLookupError::Failed(
+            "该 IP 属于保留地址段，无法查询".to_string(),
+        ));
+    }
+
+    let ip = response.ip.unwrap_or_else(|| ip.to_string());
+    let (latitude, longitude) = response
+        .loc
+        .as_deref()
+        .and_then(|loc| loc.split_once(','))
+        .map_or((None, None), |(lat, lon)| {
+            (lat.trim().parse().ok(), lon.trim().parse().ok())
+        });
+    let (asn, as_name) = response.org.as_deref().map_or((None, None), split_asn_org);
+
+    Ok(IpGeoInfo {
+        ip_version: ip_version_of(&ip),
+        ip,
+        country: None,
+        country_code: response.country,
+        region: response.region,
+        city: response.city,
+        latitude,
+        longitude,
+        timezone: response.timezone,
+        isp: as_name.clone(),
+        org: as_name.clone(),
+        asn,
+        as_name,
+    })
+}
+
+/// 使用指定服务查询
+async fn lookup_with_provider(
+    provider: IpGeoProvider,
+    ip: &str,
+    client: &reqwest::Client,
+    config: &IpGeoConfig,
+) -> Result<IpGeoInfo, GeoLookupError> {
+    match provider {
+        IpGeoProvider::Ipwhois => lookup_ipwhois(ip, client).await,
+        IpGeoProvider::Ipapi => lookup_ipapi(ip, client).await,
+        IpGeoProvider::Ipinfo => lookup_ipinfo(ip, client, config.ipinfo_token.as_deref()).await,
+    }
+}
+
+/// 域名解析出多个 IP 时的最大并发查询数
+const IP_LOOKUP_CONCURRENCY: usize = 5;
+
+/// 查询单个 IP 的地理位置，首选服务达到限额时切换到备用服务
+async fn lookup_single_ip(
+    ip: &str,
+    client: &reqwest::Client,
+    config: &IpGeoConfig,
+) -> Result<IpGeoInfo, String> {
+    match lookup_with_provider(config.provider, ip, client, config).await {
+        Ok(info) => Ok(info),
+        Err(GeoLookupError::RateLimited(msg)) => {
+            let Some(fallback) = config.fallback else {
+                return Err(msg);
+            };
+            log::warn!(
+                "IP 查询服务 {:?} 已达限额，切换到 {fallback:?}",
+                config.provider
+            );
+            lookup_with_provider(fallback, ip, client, config)
+                .await
+                .map_err(GeoLookupError::into_message)
+        }
+        Err(GeoLookupError::Failed(msg)) => Err(msg),
+    }
+}
+
 /// IP/域名 地理位置查询
 /// 支持直接输入 IP 地址或域名，域名会解析出所有 IPv4/IPv6 地址
 #[tauri::command]
-pub async fn ip_lookup(query: String) -> Result<ApiResponse<IpLookupResult>, String> {
+pub async fn ip_lookup(
+    state: State<'_, AppState>,
+    query: String,
+) -> Result<ApiResponse<IpLookupResult>, String> {
     let query = query.trim().to_string();
     if query.is_empty() {
         return Err("请输入 IP 地址或域名".to_string());
     }
 
-    let client = reqwest::Client::new();
+    let client = toolbox_client(&state).await?;
+    let geo_config = state.ip_geo_config.read().await.clone();
 
     // 检查是否为 IP 地址
     if let Ok(_ip_addr) = query.parse::<std::net::IpAddr>() {
         // 直接查询 IP
-        let result = lookup_single_ip(&query, &client).await?;
+        let result = lookup_single_ip(&query, &client, &geo_config).await?;
         return Ok(ApiResponse::success(IpLookupResult {
             query,
             is_domain: false,
             results: vec![result],
+            failures: Vec::new(),
         }));
     }
 
@@ -591,356 +2360,618 @@ pub async fn ip_lookup(query: String) -> Result<ApiResponse<IpLookupResult>, Str
         return Err(format!("无法解析域名: {query}"));
     }
 
-    // 查询每个 IP 的地理位置（并行）
+    // 并行查询每个 IP 的地理位置，限制并发避免触发免费接口的频率限制
+    let semaphore = Arc::new(Semaphore::new(IP_LOOKUP_CONCURRENCY));
+    let lookups = ips.iter().map(|ip| {
+        let semaphore = Arc::clone(&semaphore);
+        let client = &client;
+        let geo_config = &geo_config;
+        async move {
+            let _permit = semaphore.acquire().await.map_err(|e| e.to_string())?;
+            lookup_single_ip(ip, client, geo_config).await
+        }
+    });
+    let outcomes = join_all(lookups).await;
+
+    // 单个 IP 失败不影响其他结果
     let mut results = Vec::new();
-    for ip in ips {
-        match lookup_single_ip(&ip, &client).await {
+    let mut failures = Vec::new();
+    for (ip, outcome) in ips.into_iter().zip(outcomes) {
+        match outcome {
             Ok(info) => results.push(info),
-            Err(e) => {
-                // 记录错误但继续处理其他 IP
-                eprintln!("查询 IP {ip} 失败: {e}");
+            Err(error) => {
+                log::warn!("查询 IP {ip} 失败: {error}");
+                failures.push(IpLookupFailure { ip, error });
             }
         }
     }
 
     if results.is_empty() {
-        return Err("所有 IP 地址查询均失败".to_string());
+        let detail = failures
+            .first()
+            .map(|f| format!(": {}", f.error))
+            .unwrap_or_default();
+        return Err(format!("所有 IP 地址查询均失败{detail}"));
     }
 
     Ok(ApiResponse::success(IpLookupResult {
         query,
         is_domain: true,
         results,
+        failures,
     }))
 }
 
-/// 检查 HTTP 连接是否可用
-fn check_http_connection(domain: &str, port: u16) -> bool {
-    use std::io::{Read, Write};
-    use std::net::TcpStream;
+// ============ 公网 IP 检测 ============
 
-    if let Ok(mut stream) = TcpStream::connect(format!("{domain}:{port}")) {
-        stream
-            .set_read_timeout(Some(std::time::Duration::from_secs(5)))
-            .ok();
-        stream
-            .set_write_timeout(Some(std::time::Duration::from_secs(5)))
-            .ok();
+/// 返回调用方公网 IP 的回显服务
+const PUBLIC_IPV4_ECHO_URL: &str = "https://api.ipify.org";
+const PUBLIC_IPV6_ECHO_URL: &str = "https://api6.ipify.org";
 
-        let request = format!("HEAD / HTTP/1.1\r\nHost: {domain}\r\nConnection: close\r\n\r\n");
+/// 通过 IP 回显服务检测本机当前的公网 IP（供 DDNS 使用）
+pub(crate) async fn detect_public_ip(
+    client: &reqwest::Client,
+    ipv6: bool,
+) -> Result<IpAddr, String> {
+    let url = if ipv6 {
+        PUBLIC_IPV6_ECHO_URL
+    } else {
+        PUBLIC_IPV4_ECHO_URL
+    };
 
-        if stream.write_all(request.as_bytes()).is_ok() {
-            let mut response = vec![0u8; 128];
-            if stream.read(&mut response).is_ok() {
-                let response_str = String::from_utf8_lossy(&response);
-                // 检查是否是 HTTP 响应
-                return response_str.starts_with("HTTP/");
-            }
-        }
+    let text = client
+        .get(url)
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        .map_err(|e| format!("获取公网 IP 失败: {e}"))?
+        .text()
+        .await
+        .map_err(|e| format!("读取公网 IP 失败: {e}"))?;
+
+    let ip: IpAddr = text
+        .trim()
+        .parse()
+        .map_err(|_| format!("IP 回显服务返回了无效的地址: {}", text.trim()))?;
+    if ip.is_ipv6() != ipv6 {
+        let version = if ipv6 { "IPv6" } else { "IPv4" };
+        return Err(format!("未检测到公网 {version} 地址"));
     }
-    false
+    Ok(ip)
 }
 
-/// SSL 证书检查（桌面端使用 native-tls）
-/// 支持自定义端口，如果 HTTPS 连接失败会回退检测 HTTP
-#[cfg(not(target_os = "android"))]
+/// 查询 IP 所属的 RIR WHOIS 服务器（由 IANA 返回 refer 字段）
+async fn find_rir_server(whois: &WhoIs, ip: &str) -> Option<String> {
+    let mut options = WhoIsLookupOptions::from_string(ip).ok()?;
+    options.server = Some(WhoIsServerValue::from_string("whois.iana.org").ok()?);
+    options.follow = 0;
+
+    let raw = whois.lookup_async(options).await.ok()?;
+    extract_field(&raw, &RIR_REFER_PATTERNS)
+}
+
+/// 根据 WHOIS 服务器主机名得到 RIR 名称
+fn rir_name(server: &str) -> String {
+    match server {
+        "whois.arin.net" => "ARIN",
+        "whois.ripe.net" => "RIPE NCC",
+        "whois.apnic.net" => "APNIC",
+        "whois.lacnic.net" => "LACNIC",
+        "whois.afrinic.net" => "AFRINIC",
+        other => other,
+    }
+    .to_string()
+}
+
+/// IP WHOIS 查询
+///
+/// 先通过 IANA 找到分配该地址的 RIR，再查询 RIR 的 WHOIS 服务器，
+/// 返回地址段、所属组织和滥用举报联系方式
 #[tauri::command]
-pub async fn ssl_check(
-    domain: String,
-    port: Option<u16>,
-) -> Result<ApiResponse<SslCheckResult>, String> {
-    use native_tls::TlsConnector;
-    use std::io::{Read, Write};
-    use std::net::TcpStream;
-    use x509_parser::prelude::*;
+pub async fn ip_whois(ip: String) -> Result<ApiResponse<IpWhoisResult>, String> {
+    let ip = ip.trim().to_string();
+    if ip.parse::<IpAddr>().is_err() {
+        return Err("无效的 IP 地址".to_string());
+    }
 
-    let port = port.unwrap_or(443);
-    let domain_clone = domain.clone();
+    let whois = WHOIS
+        .as_ref()
+        .map_err(|e| format!("初始化 WHOIS 客户端失败: {e}"))?;
 
-    tokio::task::spawn_blocking(move || {
-        // 尝试建立 TCP 连接
-        let stream = match TcpStream::connect(format!("{domain_clone}:{port}")) {
-            Ok(s) => s,
-            Err(e) => {
-                // 连接失败
-                return Ok(ApiResponse::success(SslCheckResult {
-                    domain: domain_clone,
-                    port,
-                    connection_status: "failed".to_string(),
-                    cert_info: None,
-                    error: Some(format!("连接失败: {e}")),
-                }));
-            }
-        };
-        stream
-            .set_read_timeout(Some(std::time::Duration::from_secs(10)))
-            .ok();
+    let server = find_rir_server(whois, &ip)
+        .await
+        .unwrap_or_else(|| "whois.arin.net".to_string());
 
-        // 尝试建立 TLS 连接
-        let connector = match TlsConnector::builder()
-            .danger_accept_invalid_certs(true)
-            .build()
-        {
-            Ok(c) => c,
-            Err(e) => {
-                return Ok(ApiResponse::success(SslCheckResult {
-                    domain: domain_clone,
-                    port,
-                    connection_status: "failed".to_string(),
-                    cert_info: None,
-                    error: Some(format!("TLS 初始化失败: {e}")),
-                }));
+    // ARIN 需要 "n + " 前缀才会返回网络信息而非组织列表
+    let server_value = if server == "whois.arin.net" {
+        WhoIsServerValue::from_value(&serde_json::json!({
+            "host": server,
+            "query": "n + $addr\r\n",
+        }))
+    } else {
+        WhoIsServerValue::from_string(&server)
+    }
+    .map_err(|e| format!("无效的 WHOIS 服务器: {e}"))?;
+
+    let mut options =
+        WhoIsLookupOptions::from_string(&ip).map_err(|e| format!("无效的 IP 地址: {e}"))?;
+    options.server = Some(server_value);
+
+    let raw = whois
+        .lookup_async(options)
+        .await
+        .map_err(|e| format!("WHOIS 查询失败: {e}"))?;
+
+    Ok(ApiResponse::success(parse_ip_whois_response(
+        &ip, &server, &raw,
+    )))
+}
+
+/// 解析 RIR WHOIS 原始响应（各 RIR 字段名不同）
+fn parse_ip_whois_response(ip: &str, server: &str, raw: &str) -> IpWhoisResult {
+    IpWhoisResult {
+        ip: ip.to_string(),
+        rir: rir_name(server),
+        whois_server: server.to_string(),
+        net_range: extract_field(raw, &NET_RANGE_PATTERNS),
+        cidr: extract_field(raw, &CIDR_PATTERNS),
+        net_name: extract_field(raw, &NET_NAME_PATTERNS),
+        org: extract_field(raw, &ORG_PATTERNS),
+        country: extract_field(raw, &COUNTRY_PATTERNS),
+        abuse_email: extract_field(raw, &ABUSE_EMAIL_PATTERNS),
+        raw: raw.to_string(),
+    }
+}
+
+/// 端口检查默认超时
+const PORT_CHECK_DEFAULT_TIMEOUT_MS: u64 = 5_000;
+/// 端口检查允许的最大超时
+const PORT_CHECK_MAX_TIMEOUT_MS: u64 = 30_000;
+
+/// 检查单个地址的端口是否可连接
+async fn check_port_address(addr: SocketAddr, timeout: Duration) -> PortCheckAddress {
+    let started = Instant::now();
+    let (status, latency_ms, error) =
+        match tokio::time::timeout(timeout, TcpStream::connect(addr)).await {
+            Ok(Ok(_)) => (
+                PortStatus::Open,
+                Some(u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX)),
+                None,
+            ),
+            Ok(Err(e)) if e.kind() == std::io::ErrorKind::ConnectionRefused => {
+                (PortStatus::Closed, None, Some(e.to_string()))
             }
+            Ok(Err(e)) => (PortStatus::Filtered, None, Some(e.to_string())),
+            Err(_) => (PortStatus::Filtered, None, Some("连接超时".to_string())),
         };
+    PortCheckAddress {
+        address: addr.ip().to_string(),
+        status,
+        latency_ms,
+        error,
+    }
+}
 
-        let Ok(mut tls_stream) = connector.connect(&domain_clone, stream) else {
-            // TLS 握手失败，检测是否是 HTTP 连接
-            if check_http_connection(&domain_clone, port) {
-                return Ok(ApiResponse::success(SslCheckResult {
-                    domain: domain_clone,
-                    port,
-                    connection_status: "http".to_string(),
-                    cert_info: None,
-                    error: None,
-                }));
+/// TCP 端口连通性检查
+///
+/// 先解析主机名，再并发检查每个 IPv4/IPv6 地址，用于排查记录生效后服务不可达的问题
+#[tauri::command]
+pub async fn port_check(
+    host: String,
+    port: u16,
+    timeout_ms: Option<u64>,
+) -> Result<ApiResponse<PortCheckResult>, String> {
+    let host = host.trim().to_string();
+    let timeout = Duration::from_millis(
+        timeout_ms
+            .unwrap_or(PORT_CHECK_DEFAULT_TIMEOUT_MS)
+            .clamp(1, PORT_CHECK_MAX_TIMEOUT_MS),
+    );
+
+    // IPv6 地址可能带方括号
+    let bare_host = host.trim_start_matches('[').trim_end_matches(']');
+    let addrs: Vec<SocketAddr> = if let Ok(ip) = bare_host.parse::<IpAddr>() {
+        vec![SocketAddr::new(ip, port)]
+    } else {
+        let mut addrs: Vec<SocketAddr> = Vec::new();
+        let resolved = tokio::net::lookup_host((bare_host, port))
+            .await
+            .map_err(|e| format!("无法解析主机 {host}: {e}"))?;
+        for addr in resolved {
+            if !addrs.contains(&addr) {
+                addrs.push(addr);
             }
-            return Ok(ApiResponse::success(SslCheckResult {
-                domain: domain_clone,
-                port,
-                connection_status: "failed".to_string(),
-                cert_info: None,
-                error: Some("TLS 握手失败，且非 HTTP 连接".to_string()),
-            }));
+        }
+        addrs
+    };
+    if addrs.is_empty() {
+        return Err(format!("无法解析主机 {host}"));
+    }
+
+    let addresses = join_all(addrs.into_iter().map(|a| check_port_address(a, timeout))).await;
+    let status = [PortStatus::Open, PortStatus::Closed]
+        .into_iter()
+        .find(|s| addresses.iter().any(|a| a.status == *s))
+        .unwrap_or(PortStatus::Filtered);
+
+    Ok(ApiResponse::success(PortCheckResult {
+        host,
+        port,
+        status,
+        addresses,
+    }))
+}
+
+/// HTTP 检查最多跟随的重定向次数
+const HTTP_INSPECT_MAX_REDIRECTS: usize = 10;
+
+/// 检查 URL 的 HTTP 响应：逐跳跟随重定向，返回重定向链、最终响应头和 HSTS 配置
+///
+/// 用于确认 DNS 修改后请求是否到达预期后端，未指定协议时使用 https
+#[tauri::command]
+pub async fn http_inspect(
+    state: State<'_, AppState>,
+    url: String,
+) -> Result<ApiResponse<HttpInspectResult>, String> {
+    let input = url.trim();
+    if input.is_empty() {
+        return Err("请输入 URL".to_string());
+    }
+    let input = if input.contains("://") {
+        input.to_string()
+    } else {
+        format!("https://{input}")
+    };
+    let mut current = reqwest::Url::parse(&input).map_err(|e| format!("无效的 URL: {e}"))?;
+
+    // 手动跟随重定向以记录每一跳，代理与 Provider 相同
+    let client = state
+        .client_config
+        .read()
+        .await
+        .client_builder("toolbox")
+        .map_err(|e| e.to_string())?
+        .timeout(std::time::Duration::from_secs(10))
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .map_err(|e| format!("初始化 HTTP 客户端失败: {e}"))?;
+
+    let started = Instant::now();
+    let mut redirects = Vec::new();
+    let response = loop {
+        let response = client
+            .get(current.clone())
+            .send()
+            .await
+            .map_err(|e| format!("请求 {current} 失败: {e}"))?;
+        if !response.status().is_redirection() {
+            break response;
+        }
+
+        let location = response
+            .headers()
+            .get(reqwest::header::LOCATION)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let Some(location) = location else {
+            break response;
         };
+        if redirects.len() >= HTTP_INSPECT_MAX_REDIRECTS {
+            return Err(format!("重定向次数超过 {HTTP_INSPECT_MAX_REDIRECTS} 次"));
+        }
+        let next = current
+            .join(&location)
+            .map_err(|e| format!("无效的重定向地址 {location}: {e}"))?;
+        redirects.push(HttpRedirectHop {
+            url: current.to_string(),
+            status: response.status().as_u16(),
+            location: next.to_string(),
+        });
+        current = next;
+    };
 
-        // 发送 HTTP 请求
-        let request =
-            format!("HEAD / HTTP/1.1\r\nHost: {domain_clone}\r\nConnection: close\r\n\r\n");
-        tls_stream.write_all(request.as_bytes()).ok();
-        let mut response = vec![0u8; 1024];
-        tls_stream.read(&mut response).ok();
+    let headers = response.headers();
+    let header = |name: reqwest::header::HeaderName| {
+        headers
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string)
+    };
 
-        // 获取证书
-        let Ok(Some(cert_chain)) = tls_stream.peer_certificate() else {
-            return Ok(ApiResponse::success(SslCheckResult {
-                domain: domain_clone,
-                port,
-                connection_status: "https".to_string(),
-                cert_info: None,
-                error: Some("未找到证书".to_string()),
-            }));
-        };
+    Ok(ApiResponse::success(HttpInspectResult {
+        url: input,
+        final_url: current.to_string(),
+        status: response.status().as_u16(),
+        redirects,
+        server: header(reqwest::header::SERVER),
+        hsts: header(reqwest::header::STRICT_TRANSPORT_SECURITY),
+        headers: headers
+            .iter()
+            .map(|(name, value)| HttpHeader {
+                name: name.to_string(),
+                value: String::from_utf8_lossy(value.as_bytes()).into_owned(),
+            })
+            .collect(),
+        elapsed_ms: u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX),
+    }))
+}
 
-        let cert_der = match cert_chain.to_der() {
-            Ok(d) => d,
-            Err(e) => {
-                return Ok(ApiResponse::success(SslCheckResult {
-                    domain: domain_clone,
-                    port,
-                    connection_status: "https".to_string(),
-                    cert_info: None,
-                    error: Some(format!("证书编码失败: {e}")),
-                }));
-            }
-        };
+/// 检查 HTTP 连接是否可用
+fn check_http_connection(connect_addr: &str, host: &str, port: u16) -> bool {
+    use std::io::{Read, Write};
+    use std::net::TcpStream;
 
-        // 解析证书
-        let (_, cert) = match X509Certificate::from_der(&cert_der) {
-            Ok(c) => c,
-            Err(e) => {
-                return Ok(ApiResponse::success(SslCheckResult {
-                    domain: domain_clone,
-                    port,
-                    connection_status: "https".to_string(),
-                    cert_info: None,
-                    error: Some(format!("证书解析失败: {e}")),
-                }));
+    if let Ok(mut stream) = TcpStream::connect((connect_addr, port)) {
+        stream
+            .set_read_timeout(Some(std::time::Duration::from_secs(5)))
+            .ok();
+        stream
+            .set_write_timeout(Some(std::time::Duration::from_secs(5)))
+            .ok();
+
+        let request = format!("HEAD / HTTP/1.1\r\nHost: {host}\r\nConnection: close\r\n\r\n");
+
+        if stream.write_all(request.as_bytes()).is_ok() {
+            let mut response = vec![0u8; 128];
+            if stream.read(&mut response).is_ok() {
+                let response_str = String::from_utf8_lossy(&response);
+                // 检查是否是 HTTP 响应
+                return response_str.starts_with("HTTP/");
             }
-        };
+        }
+    }
+    false
+}
 
-        // 提取信息
-        let subject = cert.subject().to_string();
-        let issuer = cert.issuer().to_string();
-        let valid_from = cert.validity().not_before.to_rfc2822().unwrap_or_default();
-        let valid_to = cert.validity().not_after.to_rfc2822().unwrap_or_default();
+/// SSL 检查使用的根证书
+///
+/// 桌面端使用系统信任的根证书（包括企业内部 CA），与浏览器的判断一致；
+/// 读取不到系统证书时以及 Android 端使用内置的 Mozilla 根证书
+static ROOT_CERTS: LazyLock<Arc<rustls::RootCertStore>> = LazyLock::new(|| {
+    let mut store = rustls::RootCertStore::empty();
+
+    #[cfg(not(target_os = "android"))]
+    {
+        let native = rustls_native_certs::load_native_certs();
+        for e in &native.errors {
+            log::warn!("Failed to load system root certificate: {e}");
+        }
+        let (added, ignored) = store.add_parsable_certificates(native.certs);
+        if ignored > 0 {
+            log::warn!("Ignored {ignored} unparsable system root certificates");
+        }
+        if added > 0 {
+            return Arc::new(store);
+        }
+    }
 
-        // 计算剩余天数
-        let now = chrono::Utc::now();
-        let not_after = chrono::DateTime::parse_from_rfc2822(&valid_to)
-            .map(|dt| dt.with_timezone(&chrono::Utc))
-            .unwrap_or(now);
-        let days_remaining = (not_after - now).num_days();
-        let is_expired = days_remaining < 0;
+    store.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+    Arc::new(store)
+});
+
+/// 记录证书验证结果但始终接受证书的验证器
+///
+/// 证书无效时仍需完成握手以获取证书信息，真实的验证结果保存在 `errors` 中
+#[derive(Debug)]
+struct RecordingVerifier {
+    inner: std::sync::Arc<rustls::client::WebPkiServerVerifier>,
+    errors: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+}
 
-        // 验证证书是否有效
-        let is_valid = TlsConnector::new()
-            .map(|c| {
-                TcpStream::connect(format!("{domain_clone}:{port}"))
-                    .ok()
-                    .and_then(|s| c.connect(&domain_clone, s).ok())
-                    .is_some()
-            })
-            .unwrap_or(false);
+impl rustls::client::danger::ServerCertVerifier for RecordingVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &rustls::pki_types::CertificateDer<'_>,
+        intermediates: &[rustls::pki_types::CertificateDer<'_>],
+        server_name: &rustls::pki_types::ServerName<'_>,
+        ocsp_response: &[u8],
+        now: rustls::pki_types::UnixTime,
+    ) -> Result<rustls::client::danger::ServerCertVerified, rustls::Error> {
+        if let Err(e) = self.inner.verify_server_cert(
+            end_entity,
+            intermediates,
+            server_name,
+            ocsp_response,
+            now,
+        ) {
+            if let Ok(mut errors) = self.errors.lock() {
+                errors.push(describe_cert_error(&e));
+            }
+        }
+        Ok(rustls::client::danger::ServerCertVerified::assertion())
+    }
 
-        // 提取 SAN
-        let san: Vec<String> = cert
-            .subject_alternative_name()
-            .ok()
-            .flatten()
-            .map(|ext| {
-                ext.value
-                    .general_names
-                    .iter()
-                    .filter_map(|name| match name {
-                        x509_parser::extensions::GeneralName::DNSName(dns) => {
-                            Some((*dns).to_string())
-                        }
-                        _ => None,
-                    })
-                    .collect()
-            })
-            .unwrap_or_default();
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &rustls::pki_types::CertificateDer<'_>,
+        dss: &rustls::DigitallySignedStruct,
+    ) -> Result<rustls::client::danger::HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls12_signature(message, cert, dss)
+    }
 
-        let serial_number = cert.serial.to_str_radix(16).to_uppercase();
-        let signature_algorithm = cert.signature_algorithm.algorithm.to_string();
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &rustls::pki_types::CertificateDer<'_>,
+        dss: &rustls::DigitallySignedStruct,
+    ) -> Result<rustls::client::danger::HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls13_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<rustls::SignatureScheme> {
+        self.inner.supported_verify_schemes()
+    }
+}
 
-        let certificate_chain = vec![CertChainItem {
-            subject: subject.clone(),
-            issuer: issuer.clone(),
-            is_ca: cert.is_ca(),
-        }];
+/// 将证书验证错误转换为可读信息
+fn describe_cert_error(error: &rustls::Error) -> String {
+    use rustls::CertificateError;
 
-        Ok(ApiResponse::success(SslCheckResult {
-            domain: domain_clone.clone(),
-            port,
-            connection_status: "https".to_string(),
-            cert_info: Some(SslCertInfo {
-                domain: domain_clone,
-                issuer,
-                subject,
-                valid_from,
-                valid_to,
-                days_remaining,
-                is_expired,
-                is_valid,
-                san,
-                serial_number,
-                signature_algorithm,
-                certificate_chain,
-            }),
-            error: None,
-        }))
-    })
-    .await
-    .map_err(|e| format!("任务执行失败: {e}"))?
+    let rustls::Error::InvalidCertificate(cert_error) = error else {
+        return format!("证书验证失败: {error}");
+    };
+
+    match cert_error {
+        CertificateError::Expired | CertificateError::ExpiredContext { .. } => {
+            "证书已过期".to_string()
+        }
+        CertificateError::NotValidYet | CertificateError::NotValidYetContext { .. } => {
+            "证书尚未生效".to_string()
+        }
+        CertificateError::NotValidForName | CertificateError::NotValidForNameContext { .. } => {
+            "证书与域名不匹配".to_string()
+        }
+        CertificateError::UnknownIssuer => "证书颁发机构不受信任".to_string(),
+        CertificateError::Revoked => "证书已被吊销".to_string(),
+        CertificateError::BadSignature => "证书签名无效".to_string(),
+        CertificateError::BadEncoding => "证书编码无效".to_string(),
+        CertificateError::InvalidPurpose | CertificateError::InvalidPurposeContext { .. } => {
+            "证书用途不适用于服务器认证".to_string()
+        }
+        other => format!("证书验证失败: {other:?}"),
+    }
 }
 
-/// SSL 证书检查（Android 使用 rustls）
-#[cfg(target_os = "android")]
+/// SSL 证书检查
+/// 支持自定义端口，如果 HTTPS 连接失败会回退检测 HTTP
+///
+/// 桌面端和 Android 均使用 rustls，因此都能获取协商的 TLS 版本和密码套件
+///
+/// `connect_addr` 指定实际连接的地址，`server_name` 指定 SNI 和证书校验使用的域名，
+/// 均默认为 `domain`，用于在切换 DNS 前检查负载均衡后某个节点的证书
 #[tauri::command]
 pub async fn ssl_check(
+    state: State<'_, AppState>,
+    domain: String,
+    port: Option<u16>,
+    server_name: Option<String>,
+    connect_addr: Option<String>,
+) -> Result<ApiResponse<SslCheckResult>, String> {
+    let client = toolbox_client(&state).await?;
+    check_ssl(client, domain, port, server_name, connect_addr).await
+}
+
+/// 执行 SSL 检查，`client` 用于查询 OCSP
+async fn check_ssl(
+    client: reqwest::Client,
     domain: String,
     port: Option<u16>,
+    server_name: Option<String>,
+    connect_addr: Option<String>,
 ) -> Result<ApiResponse<SslCheckResult>, String> {
-    use rustls::{ClientConfig, ClientConnection, RootCertStore, StreamOwned};
+    use rustls::{ClientConfig, ClientConnection, StreamOwned};
     use std::io::{Read, Write};
     use std::net::TcpStream;
-    use std::sync::Arc;
+    use std::sync::{Arc, Mutex};
     use x509_parser::prelude::*;
 
     let port = port.unwrap_or(443);
+    let runtime = tokio::runtime::Handle::current();
     let domain_clone = domain.clone();
+    let server_name = server_name
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| domain.clone());
+    let connect_addr = connect_addr
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| domain.clone());
 
     tokio::task::spawn_blocking(move || {
+        // 连接失败时的结果
+        let failed = |domain: String, error: String| {
+            Ok(ApiResponse::success(SslCheckResult {
+                domain,
+                port,
+                connection_status: "failed".to_string(),
+                cert_info: None,
+                error: Some(error),
+                tls_version: None,
+                cipher_suite: None,
+            }))
+        };
+
         // 尝试建立 TCP 连接
-        let stream = match TcpStream::connect(format!("{}:{}", domain_clone, port)) {
+        let stream = match TcpStream::connect((connect_addr.as_str(), port)) {
             Ok(s) => s,
-            Err(e) => {
-                return Ok(ApiResponse::success(SslCheckResult {
-                    domain: domain_clone,
-                    port,
-                    connection_status: "failed".to_string(),
-                    cert_info: None,
-                    error: Some(format!("连接失败: {}", e)),
-                }));
-            }
+            Err(e) => return failed(domain_clone, format!("连接失败: {e}")),
         };
         stream
             .set_read_timeout(Some(std::time::Duration::from_secs(10)))
             .ok();
 
-        // 配置 rustls
-        let mut root_store = RootCertStore::empty();
-        root_store.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+        // 配置 rustls：使用根证书验证，但记录错误而不中断握手
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
 
-        let config = ClientConfig::builder()
-            .with_root_certificates(root_store)
-            .with_no_client_auth();
+        let inner = match rustls::client::WebPkiServerVerifier::builder_with_provider(
+            Arc::clone(&ROOT_CERTS),
+            provider.clone(),
+        )
+        .build()
+        {
+            Ok(v) => v,
+            Err(e) => return failed(domain_clone, format!("TLS 初始化失败: {e}")),
+        };
+        let validation_errors = Arc::new(Mutex::new(Vec::new()));
+        let verifier = Arc::new(RecordingVerifier {
+            inner,
+            errors: validation_errors.clone(),
+        });
+
+        let config = match ClientConfig::builder_with_provider(provider)
+            .with_safe_default_protocol_versions()
+        {
+            Ok(builder) => builder
+                .dangerous()
+                .with_custom_certificate_verifier(verifier)
+                .with_no_client_auth(),
+            Err(e) => return failed(domain_clone, format!("TLS 初始化失败: {e}")),
+        };
 
-        let server_name = match domain_clone.clone().try_into() {
-            Ok(n) => n,
-            Err(_) => {
-                return Ok(ApiResponse::success(SslCheckResult {
-                    domain: domain_clone,
-                    port,
-                    connection_status: "failed".to_string(),
-                    cert_info: None,
-                    error: Some("无效的域名".to_string()),
-                }));
-            }
+        let Ok(sni) = server_name.clone().try_into() else {
+            return failed(domain_clone, "无效的域名".to_string());
         };
 
-        let conn = match ClientConnection::new(Arc::new(config), server_name) {
+        let conn = match ClientConnection::new(Arc::new(config), sni) {
             Ok(c) => c,
-            Err(e) => {
-                // TLS 连接失败，检测是否是 HTTP 连接
-                if check_http_connection(&domain_clone, port) {
-                    return Ok(ApiResponse::success(SslCheckResult {
-                        domain: domain_clone,
-                        port,
-                        connection_status: "http".to_string(),
-                        cert_info: None,
-                        error: None,
-                    }));
-                }
-                return Ok(ApiResponse::success(SslCheckResult {
-                    domain: domain_clone,
-                    port,
-                    connection_status: "failed".to_string(),
-                    cert_info: None,
-                    error: Some(format!("TLS 初始化失败: {}", e)),
-                }));
-            }
+            Err(e) => return failed(domain_clone, format!("TLS 初始化失败: {e}")),
         };
 
         let mut tls_stream = StreamOwned::new(conn, stream);
 
         // 发送请求触发握手
-        let request = format!(
-            "HEAD / HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n\r\n",
-            domain_clone
-        );
+        let request =
+            format!("HEAD / HTTP/1.1\r\nHost: {server_name}\r\nConnection: close\r\n\r\n");
         if tls_stream.write_all(request.as_bytes()).is_err() {
-            // 写入失败，检测是否是 HTTP 连接
-            if check_http_connection(&domain_clone, port) {
+            // TLS 握手失败，检测是否是 HTTP 连接
+            if check_http_connection(&connect_addr, &server_name, port) {
                 return Ok(ApiResponse::success(SslCheckResult {
                     domain: domain_clone,
                     port,
                     connection_status: "http".to_string(),
                     cert_info: None,
                     error: None,
+                    tls_version: None,
+                    cipher_suite: None,
                 }));
             }
-            return Ok(ApiResponse::success(SslCheckResult {
-                domain: domain_clone,
-                port,
-                connection_status: "failed".to_string(),
-                cert_info: None,
-                error: Some("TLS 握手失败".to_string()),
-            }));
+            return failed(domain_clone, "TLS 握手失败，且非 HTTP 连接".to_string());
         }
         let mut response = vec![0u8; 1024];
-        tls_stream.read(&mut response).ok();
+        let _ = tls_stream.read(&mut response);
+
+        // 握手完成后读取协商结果
+        let tls_version = tls_stream
+            .conn
+            .protocol_version()
+            .map(|version| match version {
+                rustls::ProtocolVersion::TLSv1_3 => "TLS 1.3".to_string(),
+                rustls::ProtocolVersion::TLSv1_2 => "TLS 1.2".to_string(),
+                other => format!("{other:?}"),
+            });
+        let cipher_suite = tls_stream
+            .conn
+            .negotiated_cipher_suite()
+            .map(|suite| format!("{:?}", suite.suite()));
 
         // 获取证书
         let certs = match tls_stream.conn.peer_certificates() {
@@ -952,14 +2983,14 @@ pub async fn ssl_check(
                     connection_status: "https".to_string(),
                     cert_info: None,
                     error: Some("未找到证书".to_string()),
+                    tls_version,
+                    cipher_suite,
                 }));
             }
         };
 
-        let cert_der = certs[0].as_ref();
-
         // 解析证书
-        let (_, cert) = match X509Certificate::from_der(cert_der) {
+        let (_, cert) = match X509Certificate::from_der(certs[0].as_ref()) {
             Ok(c) => c,
             Err(e) => {
                 return Ok(ApiResponse::success(SslCheckResult {
@@ -967,7 +2998,9 @@ pub async fn ssl_check(
                     port,
                     connection_status: "https".to_string(),
                     cert_info: None,
-                    error: Some(format!("证书解析失败: {}", e)),
+                    error: Some(format!("证书解析失败: {e}")),
+                    tls_version,
+                    cipher_suite,
                 }));
             }
         };
@@ -981,11 +3014,16 @@ pub async fn ssl_check(
         // 计算剩余天数
         let now = chrono::Utc::now();
         let not_after = chrono::DateTime::parse_from_rfc2822(&valid_to)
-            .map(|dt| dt.with_timezone(&chrono::Utc))
-            .unwrap_or(now);
+            .map_or(now, |dt| dt.with_timezone(&chrono::Utc));
         let days_remaining = (not_after - now).num_days();
         let is_expired = days_remaining < 0;
-        let is_valid = !is_expired;
+
+        // 握手过程中记录的验证结果
+        let validation_errors = validation_errors
+            .lock()
+            .map(|errors| errors.clone())
+            .unwrap_or_default();
+        let is_valid = validation_errors.is_empty();
 
         // 提取 SAN
         let san: Vec<String> = cert
@@ -1022,6 +3060,14 @@ pub async fn ssl_check(
             })
             .collect();
 
+        // OCSP 查询失败不影响检查结果
+        let ocsp_status = certs
+            .get(1)
+            .and_then(|issuer| X509Certificate::from_der(issuer.as_ref()).ok())
+            .and_then(|(_, issuer)| {
+                runtime.block_on(crate::ocsp::check_status(&client, &cert, &issuer))
+            });
+
         Ok(ApiResponse::success(SslCheckResult {
             domain: domain_clone.clone(),
             port,
@@ -1035,14 +3081,325 @@ pub async fn ssl_check(
                 days_remaining,
                 is_expired,
                 is_valid,
+                validation_errors,
                 san,
                 serial_number,
                 signature_algorithm,
                 certificate_chain,
+                ocsp_status,
             }),
             error: None,
+            tls_version,
+            cipher_suite,
         }))
     })
     .await
-    .map_err(|e| format!("任务执行失败: {}", e))?
+    .map_err(|e| format!("任务执行失败: {e}"))?
+}
+
+/// 批量证书检查的最大并发数
+const CERT_BULK_CONCURRENCY: usize = 8;
+
+/// 批量检查多个域名的证书有效期，按剩余天数从少到多排序
+///
+/// 单个域名失败不影响其他结果，失败和未启用 HTTPS 的域名排在最后
+#[tauri::command]
+pub async fn check_certs_bulk(
+    state: State<'_, AppState>,
+    domains: Vec<String>,
+    port: Option<u16>,
+) -> Result<ApiResponse<Vec<CertExpiryItem>>, String> {
+    let port = port.unwrap_or(443);
+    let unique = unique_domains(domains)?;
+    let client = toolbox_client(&state).await?;
+
+    let semaphore = Arc::new(Semaphore::new(CERT_BULK_CONCURRENCY));
+    let checks = unique.into_iter().map(|domain| {
+        let semaphore = Arc::clone(&semaphore);
+        let client = client.clone();
+        async move {
+            let outcome = match semaphore.acquire().await {
+                Ok(_permit) => check_ssl(client, domain.clone(), Some(port), None, None).await,
+                Err(e) => Err(e.to_string()),
+            };
+            cert_expiry_item(&domain, port, outcome)
+        }
+    });
+    let mut items = join_all(checks).await;
+
+    items.sort_by_key(|item| (item.days_remaining.is_none(), item.days_remaining));
+    Ok(ApiResponse::success(items))
+}
+
+/// 规范化并去重批量检查的域名列表，列表为空时报错
+fn unique_domains(domains: Vec<String>) -> Result<Vec<String>, String> {
+    let mut unique: Vec<String> = Vec::new();
+    for domain in domains {
+        let domain = domain.trim().trim_end_matches('.').to_lowercase();
+        if !domain.is_empty() && !unique.contains(&domain) {
+            unique.push(domain);
+        }
+    }
+    if unique.is_empty() {
+        return Err("请输入域名".to_string());
+    }
+    Ok(unique)
+}
+
+/// 批量域名到期检查的最大并发数（WHOIS 服务器普遍限制查询频率）
+const DOMAIN_EXPIRY_CONCURRENCY: usize = 4;
+
+/// 默认的即将到期提醒阈值（天）
+const DEFAULT_EXPIRY_THRESHOLD_DAYS: i64 = 30;
+
+/// 批量检查多个域名的注册到期时间，按剩余天数从少到多排序
+///
+/// 优先使用 WHOIS，WHOIS 失败或到期时间无法识别时改用 RDAP；
+/// 单个域名失败不影响其他结果，失败的域名排在最后
+#[tauri::command]
+pub async fn domain_expiry_report(
+    state: State<'_, AppState>,
+    domains: Vec<String>,
+    threshold_days: Option<i64>,
+) -> Result<ApiResponse<Vec<DomainExpiryItem>>, String> {
+    let threshold_days = threshold_days.unwrap_or(DEFAULT_EXPIRY_THRESHOLD_DAYS);
+    let unique = unique_domains(domains)?;
+    let client = toolbox_client(&state).await?;
+
+    let semaphore = Arc::new(Semaphore::new(DOMAIN_EXPIRY_CONCURRENCY));
+    let checks = unique.into_iter().map(|domain| {
+        let semaphore = Arc::clone(&semaphore);
+        let client = &client;
+        async move {
+            let outcome = match semaphore.acquire().await {
+                Ok(_permit) => lookup_domain_expiry(client, &domain).await,
+                Err(e) => Err(e.to_string()),
+            };
+            domain_expiry_item(domain, threshold_days, outcome)
+        }
+    });
+    let mut items = join_all(checks).await;
+
+    items.sort_by_key(|item| (item.days_remaining.is_none(), item.days_remaining));
+    Ok(ApiResponse::success(items))
+}
+
+/// 查询域名的注册商和到期时间（RFC3339）
+async fn lookup_domain_expiry(
+    client: &reqwest::Client,
+    domain: &str,
+) -> Result<(Option<String>, String), String> {
+    let whois_error = match query_whois(domain).await {
+        Ok(whois) => match whois.expiration_date_parsed {
+            Some(expiration) => return Ok((whois.registrar, expiration)),
+            None => "WHOIS 中没有可识别的到期时间".to_string(),
+        },
+        Err(e) => e,
+    };
+
+    let rdap = query_rdap(client, domain)
+        .await
+        .map_err(|e| format!("{whois_error}; {e}"))?;
+    let expiration = rdap
+        .expiration_date
+        .as_deref()
+        .and_then(parse_whois_date)
+        .ok_or(whois_error)?;
+    Ok((rdap.registrar, expiration))
+}
+
+/// 将单个域名的查询结果转换为到期条目
+fn domain_expiry_item(
+    domain: String,
+    threshold_days: i64,
+    outcome: Result<(Option<String>, String), String>,
+) -> DomainExpiryItem {
+    let (registrar, expiration_date) = match outcome {
+        Ok(found) => found,
+        Err(e) => {
+            return DomainExpiryItem {
+                domain,
+                registrar: None,
+                expiration_date: None,
+                days_remaining: None,
+                expiring_soon: false,
+                error: Some(e),
+            };
+        }
+    };
+
+    let days_remaining = chrono::DateTime::parse_from_rfc3339(&expiration_date)
+        .ok()
+        .map(|dt| (dt.with_timezone(&chrono::Utc) - chrono::Utc::now()).num_days());
+    DomainExpiryItem {
+        domain,
+        registrar,
+        expiration_date: Some(expiration_date),
+        days_remaining,
+        expiring_soon: days_remaining.is_some_and(|days| days <= threshold_days),
+        error: None,
+    }
+}
+
+/// 将单个域名的 SSL 检查结果转换为有效期条目
+fn cert_expiry_item(
+    domain: &str,
+    port: u16,
+    outcome: Result<ApiResponse<SslCheckResult>, String>,
+) -> CertExpiryItem {
+    let failed = |error: String| CertExpiryItem {
+        domain: domain.to_string(),
+        port,
+        days_remaining: None,
+        valid_to: None,
+        is_valid: None,
+        error: Some(error),
+    };
+
+    let result = match outcome.map(|r| r.data) {
+        Ok(Some(result)) => result,
+        Ok(None) => return failed("检查失败".to_string()),
+        Err(e) => return failed(e),
+    };
+    match result.cert_info {
+        Some(cert) => CertExpiryItem {
+            domain: domain.to_string(),
+            port,
+            days_remaining: Some(cert.days_remaining),
+            valid_to: Some(cert.valid_to),
+            is_valid: Some(cert.is_valid),
+            error: cert.validation_errors.first().cloned(),
+        },
+        None => failed(result.error.unwrap_or_else(|| "未启用 HTTPS".to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn txt(records: &[&str]) -> Vec<String> {
+        records.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn spf_hard_fail() {
+        let check = analyze_spf(&txt(&["v=spf1 include:_spf.google.com -all"]));
+        assert!(check.hard_fail);
+        assert_eq!(check.all_qualifier.as_deref(), Some("-all"));
+        assert_eq!(check.dns_lookups, 1);
+        assert!(check.issues.is_empty());
+    }
+
+    #[test]
+    fn spf_soft_fail_and_pass_all() {
+        let soft = analyze_spf(&txt(&["v=spf1 mx ~all"]));
+        assert!(!soft.hard_fail);
+        assert!(soft.issues.iter().any(|i| i.contains("~all")));
+
+        let pass = analyze_spf(&txt(&["v=spf1 +all"]));
+        assert!(!pass.hard_fail);
+        assert!(pass.issues.iter().any(|i| i.contains("+all")));
+    }
+
+    #[test]
+    fn spf_redirect_without_all() {
+        let check = analyze_spf(&txt(&["v=spf1 redirect=_spf.example.com"]));
+        assert_eq!(check.all_qualifier, None);
+        assert_eq!(check.dns_lookups, 1);
+        assert!(check.issues.is_empty());
+    }
+
+    #[test]
+    fn spf_too_many_lookups() {
+        let includes: Vec<String> = (0..11)
+            .map(|i| format!("include:s{i}.example.com"))
+            .collect();
+        let record = format!("v=spf1 {} -all", includes.join(" "));
+        let check = analyze_spf(&[record]);
+        assert_eq!(check.dns_lookups, 11);
+        assert!(check.issues.iter().any(|i| i.contains("超过 10 次")));
+    }
+
+    #[test]
+    fn dmarc_policy_none() {
+        let check = analyze_dmarc(&txt(&["v=DMARC1; p=none; rua=mailto:dmarc@example.com"]));
+        assert_eq!(check.policy.as_deref(), Some("none"));
+        assert_eq!(check.rua, vec!["mailto:dmarc@example.com"]);
+        assert_eq!(check.issues.len(), 1);
+        assert!(check.issues[0].contains("p=none"));
+    }
+
+    #[test]
+    fn dmarc_partial_pct() {
+        let check = analyze_dmarc(&txt(&[
+            "v=DMARC1; p=reject; pct=50; rua=mailto:dmarc@example.com",
+        ]));
+        assert_eq!(check.policy.as_deref(), Some("reject"));
+        assert_eq!(check.pct, Some(50));
+        assert!(check.issues.iter().any(|i| i.contains("pct=50")));
+    }
+
+    #[test]
+    fn dkim_revoked_key() {
+        let check = analyze_dkim("default".to_string(), &txt(&["v=DKIM1; k=rsa; p="]));
+        assert!(check.record.is_some());
+        assert!(!check.key_present);
+        assert!(!check.key_valid);
+        assert!(check.issues.iter().any(|i| i.contains("吊销")));
+    }
+
+    #[test]
+    fn dkim_invalid_base64() {
+        let check = analyze_dkim("default".to_string(), &txt(&["v=DKIM1; p=not*base64"]));
+        assert!(check.key_present);
+        assert!(!check.key_valid);
+        assert!(check.issues.iter().any(|i| i.contains("Base64")));
+    }
+
+    #[test]
+    fn dkim_ed25519_key() {
+        // RFC 8463 示例公钥
+        let check = analyze_dkim(
+            "brisbane".to_string(),
+            &txt(&["v=DKIM1; k=ed25519; p=11qYAYKxCrfVS/7TyWQHOg7hcvPapiMlrwIaaPcHURo="]),
+        );
+        assert!(check.key_valid);
+        assert_eq!(check.key_bits, Some(256));
+        assert!(check.issues.is_empty());
+    }
+
+    fn nsec_error(proof: hickory_resolver::proto::dnssec::Proof) -> hickory_resolver::ResolveError {
+        hickory_resolver::proto::ProtoError::from(ProtoErrorKind::Nsec {
+            query: Box::new(Query::query(Name::root(), RecordType::A)),
+            proof,
+        })
+        .into()
+    }
+
+    #[test]
+    fn dnssec_validation_failure_is_bogus() {
+        use hickory_resolver::proto::dnssec::Proof;
+
+        assert_eq!(
+            validation_error_state(&nsec_error(Proof::Bogus)),
+            DnssecValidation::Bogus
+        );
+        assert_eq!(
+            validation_error_state(&nsec_error(Proof::Insecure)),
+            DnssecValidation::Insecure
+        );
+        let missing_soa = hickory_resolver::proto::ProtoError::from(
+            "could not validate negative response missing SOA",
+        );
+        assert_eq!(
+            validation_error_state(&missing_soa.into()),
+            DnssecValidation::Bogus
+        );
+        let timeout = hickory_resolver::proto::ProtoError::from(ProtoErrorKind::Timeout);
+        assert_eq!(
+            validation_error_state(&timeout.into()),
+            DnssecValidation::Insecure
+        );
+    }
 }
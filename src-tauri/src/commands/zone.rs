@@ -0,0 +1,712 @@
+use std::sync::{Arc, LazyLock};
+
+use futures::future::join_all;
+use tauri::State;
+use tokio::sync::Semaphore;
+
+use crate::commands::dns::provider_concurrency;
+use crate::error::DnsError;
+use crate::providers::{find_conflict, get_provider_features, normalize_record_value};
+use crate::types::{
+    ApiResponse, ApplyTemplateResult, ApplyZoneDiffResult, CloneRecordStatus,
+    CloneZoneRecordResult, CloneZoneResult, CreateDnsRecordRequest, DnsRecord, DnsRecordType,
+    ProviderType, RecordTemplate, TemplateRecordResult, TemplateRecordStatus,
+    UpdateDnsRecordRequest, ZoneDiff, ZoneDiffFailure, ZoneRecordUpdate,
+};
+use crate::AppState;
+
+/// 嵌入的内置记录模板
+const RECORD_TEMPLATES: &str = include_str!("../resources/record_templates.json");
+
+/// 内置记录模板（只在首次使用时解析一次）
+static TEMPLATES: LazyLock<Result<Vec<RecordTemplate>, String>> =
+    LazyLock::new(|| serde_json::from_str(RECORD_TEMPLATES).map_err(|e| e.to_string()));
+
+/// 记录差异计算结果（不含账号信息）
+struct RecordDiff {
+    to_add: Vec<DnsRecord>,
+    to_update: Vec<ZoneRecordUpdate>,
+    to_delete: Vec<DnsRecord>,
+    unchanged_count: usize,
+}
+
+/// 规范化记录名称："" / "WWW." -> "@" / "www"
+fn normalize_name(name: &str) -> String {
+    let name = name.trim().trim_end_matches('.').to_lowercase();
+    if name.is_empty() {
+        "@".to_string()
+    } else {
+        name
+    }
+}
+
+/// 记录值的比较形式：Provider 的规范形式，忽略大小写（与 Provider 的冲突判断一致）
+fn comparable_value(record_type: &DnsRecordType, value: &str) -> String {
+    normalize_record_value(record_type, value).to_ascii_lowercase()
+}
+
+/// 根域 NS 记录由各 Provider 自行分配，不参与同步
+fn is_apex_ns(record: &DnsRecord) -> bool {
+    record.record_type == DnsRecordType::Ns && normalize_name(&record.name) == "@"
+}
+
+fn record_key(record: &DnsRecord) -> (String, DnsRecordType) {
+    (normalize_name(&record.name), record.record_type.clone())
+}
+
+fn record_identity(record: &DnsRecord) -> (String, DnsRecordType, String) {
+    (
+        normalize_name(&record.name),
+        record.record_type.clone(),
+        comparable_value(&record.record_type, &record.value),
+    )
+}
+
+/// 从剩余的目标记录中取出第一条满足条件的记录
+fn take_matching(
+    remaining: &mut [Option<DnsRecord>],
+    predicate: impl Fn(&DnsRecord) -> bool,
+) -> Option<DnsRecord> {
+    remaining
+        .iter_mut()
+        .find(|slot| slot.as_ref().is_some_and(&predicate))
+        .and_then(Option::take)
+}
+
+/// 计算源记录与目标记录的差异
+///
+/// 1. 名称、类型、值都相同的记录视为同一条，TTL / 优先级不同时需要更新
+/// 2. 剩余记录中名称和类型相同的配对为更新
+/// 3. 仍未匹配的源记录需要新增，目标记录需要删除
+///
+/// `proxied` 等 Provider 特有字段不参与比较
+fn compute_diff(source: Vec<DnsRecord>, target: Vec<DnsRecord>) -> RecordDiff {
+    let mut remaining: Vec<Option<DnsRecord>> = target
+        .into_iter()
+        .filter(|r| !is_apex_ns(r))
+        .map(Some)
+        .collect();
+
+    let mut to_update = Vec::new();
+    let mut unmatched = Vec::new();
+    let mut unchanged_count = 0;
+
+    for desired in source.into_iter().filter(|r| !is_apex_ns(r)) {
+        let identity = record_identity(&desired);
+        match take_matching(&mut remaining, |r| record_identity(r) == identity) {
+            Some(current) if current.ttl == desired.ttl && current.priority == desired.priority => {
+                unchanged_count += 1;
+            }
+            Some(current) => to_update.push(ZoneRecordUpdate { current, desired }),
+            None => unmatched.push(desired),
+        }
+    }
+
+    let mut to_add = Vec::new();
+    for desired in unmatched {
+        let key = record_key(&desired);
+        match take_matching(&mut remaining, |r| record_key(r) == key) {
+            Some(current) => to_update.push(ZoneRecordUpdate { current, desired }),
+            None => to_add.push(desired),
+        }
+    }
+
+    RecordDiff {
+        to_add,
+        to_update,
+        to_delete: remaining.into_iter().flatten().collect(),
+        unchanged_count,
+    }
+}
+
+/// 比较两个账号下区域的 DNS 记录差异
+///
+/// 以源区域为准，计算目标区域需要新增、更新、删除的记录
+#[tauri::command]
+pub async fn diff_zones(
+    state: State<'_, AppState>,
+    source_account_id: String,
+    source_domain_id: String,
+    target_account_id: String,
+    target_domain_id: String,
+) -> Result<ApiResponse<ZoneDiff>, DnsError> {
+    let source_provider = state
+        .registry
+        .get(&source_account_id)
+        .await
+        .ok_or_else(|| DnsError::AccountNotFound(source_account_id.clone()))?;
+    let target_provider = state
+        .registry
+        .get(&target_account_id)
+        .await
+        .ok_or_else(|| DnsError::AccountNotFound(target_account_id.clone()))?;
+
+    // 并行拉取两侧的全部记录
+    let (source_records, target_records) = futures::future::try_join(
+        source_provider.list_all_records(&source_domain_id),
+        target_provider.list_all_records(&target_domain_id),
+    )
+    .await?;
+
+    let diff = compute_diff(source_records, target_records);
+
+    Ok(ApiResponse::success(ZoneDiff {
+        source_account_id,
+        source_domain_id,
+        target_account_id,
+        target_domain_id,
+        to_add: diff.to_add,
+        to_update: diff.to_update,
+        to_delete: diff.to_delete,
+        unchanged_count: diff.unchanged_count,
+    }))
+}
+
+/// 将区域差异应用到目标区域
+///
+/// `dry_run` 为 true 时只返回将要执行的操作数量，不修改任何记录。
+/// 先删除再更新、新增，避免同名 CNAME 与其他类型记录冲突
+#[tauri::command]
+pub async fn apply_zone_diff(
+    state: State<'_, AppState>,
+    diff: ZoneDiff,
+    dry_run: bool,
+) -> Result<ApiResponse<ApplyZoneDiffResult>, DnsError> {
+    if dry_run {
+        return Ok(ApiResponse::success(ApplyZoneDiffResult {
+            dry_run,
+            created_count: diff.to_add.len(),
+            updated_count: diff.to_update.len(),
+            deleted_count: diff.to_delete.len(),
+            failures: Vec::new(),
+        }));
+    }
+
+    let provider = state
+        .registry
+        .get(&diff.target_account_id)
+        .await
+        .ok_or_else(|| DnsError::AccountNotFound(diff.target_account_id.clone()))?;
+
+    let domain_id = diff.target_domain_id;
+    let mut failures = Vec::new();
+    let mut deleted_count = 0;
+    let mut updated_count = 0;
+    let mut created_count = 0;
+
+    // 1. 删除目标区域多出的记录
+    for record in diff.to_delete {
+        match provider.delete_record(&record.id, &domain_id).await {
+            Ok(()) => deleted_count += 1,
+            Err(e) => failures.push(ZoneDiffFailure {
+                action: "delete".to_string(),
+                name: record.name,
+                record_type: record.record_type,
+                reason: e.to_string(),
+            }),
+        }
+    }
+
+    // 2. 更新内容不同的记录（保留目标记录的代理状态和解析线路）
+    for ZoneRecordUpdate { current, desired } in diff.to_update {
+        let request = UpdateDnsRecordRequest {
+            domain_id: domain_id.clone(),
+            record_type: desired.record_type.clone(),
+            name: desired.name.clone(),
+            value: desired.value,
+            ttl: desired.ttl,
+            priority: desired.priority,
+            proxied: current.proxied,
+            line: current.line,
+            remark: current.remark,
+        };
+        match provider.update_record(&current.id, &request).await {
+            Ok(_) => updated_count += 1,
+            Err(e) => failures.push(ZoneDiffFailure {
+                action: "update".to_string(),
+                name: desired.name,
+                record_type: desired.record_type,
+                reason: e.to_string(),
+            }),
+        }
+    }
+
+    // 3. 新增目标区域缺少的记录
+    for record in diff.to_add {
+        let request = CreateDnsRecordRequest {
+            domain_id: domain_id.clone(),
+            record_type: record.record_type.clone(),
+            name: record.name.clone(),
+            value: record.value,
+            ttl: record.ttl,
+            priority: record.priority,
+            proxied: None,
+            line: None,
+            remark: None,
+        };
+        match provider.create_record(&request).await {
+            Ok(_) => created_count += 1,
+            Err(e) => failures.push(ZoneDiffFailure {
+                action: "create".to_string(),
+                name: record.name,
+                record_type: record.record_type,
+                reason: e.to_string(),
+            }),
+        }
+    }
+
+    Ok(ApiResponse::success(ApplyZoneDiffResult {
+        dry_run,
+        created_count,
+        updated_count,
+        deleted_count,
+        failures,
+    }))
+}
+
+/// 与已有记录的冲突
+enum Conflict {
+    /// 已存在完全相同的记录
+    Identical,
+    /// 与已有记录冲突的说明
+    Other(String),
+}
+
+/// 用 Provider 的冲突判断查找与待创建记录冲突的已有记录
+///
+/// 相同记录，或 CNAME 与同名的其他记录共存，视为冲突
+fn check_conflict(request: &CreateDnsRecordRequest, target: &[DnsRecord]) -> Option<Conflict> {
+    find_conflict(target, request).map(|existing| {
+        if existing.record_type == request.record_type
+            && comparable_value(&existing.record_type, &existing.value)
+                == comparable_value(&request.record_type, &request.value)
+        {
+            Conflict::Identical
+        } else {
+            Conflict::Other(format!(
+                "与目标区域的 {:?} 记录冲突: {}",
+                existing.record_type, existing.value
+            ))
+        }
+    })
+}
+
+/// 目标区域中与待复制记录冲突的记录说明，没有冲突时返回 None
+fn clone_conflict(request: &CreateDnsRecordRequest, target: &[DnsRecord]) -> Option<String> {
+    check_conflict(request, target).map(|conflict| match conflict {
+        Conflict::Identical => "目标区域已存在相同记录".to_string(),
+        Conflict::Other(reason) => reason,
+    })
+}
+
+/// 将源区域的全部记录复制到另一个账号下的目标区域，用于跨提供商迁移
+///
+/// 跳过根域 NS、目标不支持的记录类型和与目标已有记录冲突的记录；
+/// 目标不支持代理时丢弃代理状态，TTL 超出目标允许范围时调整到最近的边界值
+#[tauri::command]
+pub async fn clone_zone(
+    state: State<'_, AppState>,
+    source_account_id: String,
+    source_domain_id: String,
+    target_account_id: String,
+    target_domain_id: String,
+    operation_id: Option<String>,
+) -> Result<ApiResponse<CloneZoneResult>, DnsError> {
+    let operation = state.operations.start(operation_id)?;
+    let source_provider = state
+        .registry
+        .get(&source_account_id)
+        .await
+        .ok_or_else(|| DnsError::AccountNotFound(source_account_id.clone()))?;
+    let target_provider = state
+        .registry
+        .get(&target_account_id)
+        .await
+        .ok_or_else(|| DnsError::AccountNotFound(target_account_id.clone()))?;
+
+    let (source_records, target_records) = futures::future::try_join(
+        source_provider.list_all_records(&source_domain_id),
+        target_provider.list_all_records(&target_domain_id),
+    )
+    .await?;
+
+    let features = target_provider
+        .id()
+        .parse::<ProviderType>()
+        .map(|p| get_provider_features(&p))
+        .map_err(DnsError::ProviderNotFound)?;
+
+    let mut results = Vec::new();
+    let mut requests = Vec::new();
+    for record in source_records.into_iter().filter(|r| !is_apex_ns(r)) {
+        let range = &features.ttl_range;
+        let ttl = if range.contains(record.ttl) {
+            record.ttl
+        } else {
+            record.ttl.clamp(range.min, range.max)
+        };
+        let request = CreateDnsRecordRequest {
+            domain_id: target_domain_id.clone(),
+            record_type: record.record_type,
+            name: record.name,
+            value: record.value,
+            ttl,
+            priority: record.priority,
+            proxied: record.proxied.filter(|_| features.proxy),
+            line: None,
+            remark: None,
+        };
+
+        let skip_reason = if features
+            .supported_record_types
+            .contains(&request.record_type)
+        {
+            clone_conflict(&request, &target_records)
+        } else {
+            Some("目标提供商不支持该记录类型".to_string())
+        };
+        if let Some(reason) = skip_reason {
+            results.push(CloneZoneRecordResult {
+                name: request.name,
+                record_type: request.record_type,
+                value: request.value,
+                status: CloneRecordStatus::Skipped,
+                reason: Some(reason),
+            });
+            continue;
+        }
+        requests.push(request);
+    }
+
+    // 按目标提供商的限流并发创建
+    let semaphore = Arc::new(Semaphore::new(provider_concurrency(
+        target_provider.as_ref(),
+    )));
+    let token = operation.token();
+    let creates = requests.into_iter().map(|request| {
+        let semaphore = Arc::clone(&semaphore);
+        let provider = Arc::clone(&target_provider);
+        async move {
+            let outcome = match semaphore.acquire().await {
+                // 取消后不再发起新的创建请求，已发出的请求照常完成
+                Ok(_permit) if token.is_cancelled() => None,
+                Ok(_permit) => Some(
+                    provider
+                        .create_record(&request)
+                        .await
+                        .map_err(|e| e.to_string()),
+                ),
+                Err(e) => Some(Err(e.to_string())),
+            };
+            let (status, reason) = match outcome {
+                Some(Ok(_)) => (CloneRecordStatus::Created, None),
+                Some(Err(e)) => (CloneRecordStatus::Failed, Some(e)),
+                None => (CloneRecordStatus::Cancelled, None),
+            };
+            CloneZoneRecordResult {
+                name: request.name,
+                record_type: request.record_type,
+                value: request.value,
+                status,
+                reason,
+            }
+        }
+    });
+    results.extend(join_all(creates).await);
+
+    let count = |status: CloneRecordStatus| results.iter().filter(|r| r.status == status).count();
+    Ok(ApiResponse::success(CloneZoneResult {
+        created_count: count(CloneRecordStatus::Created),
+        skipped_count: count(CloneRecordStatus::Skipped),
+        failed_count: count(CloneRecordStatus::Failed),
+        cancelled_count: count(CloneRecordStatus::Cancelled),
+        records: results,
+    }))
+}
+
+/// 列出内置的记录模板
+#[tauri::command]
+pub async fn list_record_templates() -> Result<ApiResponse<Vec<RecordTemplate>>, DnsError> {
+    let templates = TEMPLATES
+        .as_ref()
+        .map_err(|e| DnsError::SerializationError(e.clone()))?;
+    Ok(ApiResponse::success(templates.clone()))
+}
+
+/// 同名位置已有其他 SPF 记录时返回冲突说明（一个名称只能有一条 SPF 记录）
+fn spf_conflict(name: &str, value: &str, target: &[DnsRecord]) -> Option<String> {
+    let is_spf = |v: &str| normalize_record_value(&DnsRecordType::Txt, v).starts_with("v=spf1");
+    if !is_spf(value) {
+        return None;
+    }
+    let name = normalize_name(name);
+    target
+        .iter()
+        .find(|r| {
+            r.record_type == DnsRecordType::Txt
+                && normalize_name(&r.name) == name
+                && is_spf(&r.value)
+        })
+        .map(|r| format!("已存在其他 SPF 记录，请手动合并: {}", r.value))
+}
+
+/// 将内置模板的记录添加到域名下
+///
+/// 已存在相同记录的跳过，可重复执行；与已有记录冲突的不创建，需用户手动处理
+#[tauri::command]
+pub async fn apply_record_template(
+    state: State<'_, AppState>,
+    account_id: String,
+    domain_id: String,
+    template_id: String,
+) -> Result<ApiResponse<ApplyTemplateResult>, DnsError> {
+    let templates = TEMPLATES
+        .as_ref()
+        .map_err(|e| DnsError::SerializationError(e.clone()))?;
+    let template = templates
+        .iter()
+        .find(|t| t.id == template_id)
+        .ok_or_else(|| DnsError::ValidationError(format!("未知的记录模板: {template_id}")))?;
+
+    let provider = state
+        .registry
+        .get(&account_id)
+        .await
+        .ok_or_else(|| DnsError::AccountNotFound(account_id.clone()))?;
+    let domain = provider.get_domain(&domain_id).await?;
+    let mut existing = provider.list_all_records(&domain_id).await?;
+
+    let features = provider
+        .id()
+        .parse::<ProviderType>()
+        .map(|p| get_provider_features(&p))
+        .map_err(DnsError::ProviderNotFound)?;
+
+    // 逐条创建，模板记录很少，顺序执行便于后续记录看到前面刚创建的记录
+    let mut results = Vec::new();
+    for entry in &template.records {
+        let value = entry
+            .value
+            .replace("{domain_dashed}", &domain.name.replace('.', "-"))
+            .replace("{domain}", &domain.name);
+
+        let range = &features.ttl_range;
+        let request = CreateDnsRecordRequest {
+            domain_id: domain_id.clone(),
+            record_type: entry.record_type.clone(),
+            name: entry.name.clone(),
+            value: value.clone(),
+            ttl: entry.ttl.clamp(range.min, range.max),
+            priority: entry.priority,
+            proxied: None,
+            line: None,
+            remark: None,
+        };
+
+        let conflict = match check_conflict(&request, &existing) {
+            Some(Conflict::Identical) => Some((TemplateRecordStatus::Existing, None)),
+            Some(Conflict::Other(reason)) => Some((TemplateRecordStatus::Conflict, Some(reason))),
+            None => spf_conflict(&entry.name, &value, &existing)
+                .map(|reason| (TemplateRecordStatus::Conflict, Some(reason))),
+        };
+        let (status, reason) = if let Some(outcome) = conflict {
+            outcome
+        } else {
+            match provider.create_record(&request).await {
+                Ok(record) => {
+                    existing.push(record);
+                    (TemplateRecordStatus::Created, None)
+                }
+                Err(e) => (TemplateRecordStatus::Failed, Some(e.to_string())),
+            }
+        };
+        results.push(TemplateRecordResult {
+            name: entry.name.clone(),
+            record_type: entry.record_type.clone(),
+            value,
+            status,
+            reason,
+        });
+    }
+
+    let count =
+        |status: TemplateRecordStatus| results.iter().filter(|r| r.status == status).count();
+    Ok(ApiResponse::success(ApplyTemplateResult {
+        created_count: count(TemplateRecordStatus::Created),
+        existing_count: count(TemplateRecordStatus::Existing),
+        conflict_count: count(TemplateRecordStatus::Conflict),
+        failed_count: count(TemplateRecordStatus::Failed),
+        records: results,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type TestResult = std::result::Result<(), Box<dyn std::error::Error>>;
+
+    #[test]
+    fn builtin_templates_parse() -> TestResult {
+        let templates = TEMPLATES.as_ref().map_err(Clone::clone)?;
+        assert!(templates.iter().any(|t| t.id == "google-workspace"));
+        assert!(templates.iter().all(|t| !t.records.is_empty()));
+        Ok(())
+    }
+
+    fn record(
+        id: &str,
+        record_type: DnsRecordType,
+        name: &str,
+        value: &str,
+        ttl: u32,
+    ) -> DnsRecord {
+        DnsRecord {
+            id: id.to_string(),
+            domain_id: "zone".to_string(),
+            record_type,
+            name: name.to_string(),
+            fqdn: None,
+            value: value.to_string(),
+            ttl,
+            priority: None,
+            proxied: None,
+            line: None,
+            remark: None,
+            enabled: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    fn ids(records: &[DnsRecord]) -> Vec<&str> {
+        records.iter().map(|r| r.id.as_str()).collect()
+    }
+
+    #[test]
+    fn diff_adds_missing_and_deletes_extra_records() {
+        let source = vec![record("s1", DnsRecordType::A, "www", "192.0.2.1", 600)];
+        let target = vec![record("t1", DnsRecordType::Txt, "www", "hello", 600)];
+
+        let diff = compute_diff(source, target);
+        assert_eq!(ids(&diff.to_add), ["s1"]);
+        assert_eq!(ids(&diff.to_delete), ["t1"]);
+        assert!(diff.to_update.is_empty());
+        assert_eq!(diff.unchanged_count, 0);
+    }
+
+    #[test]
+    fn diff_updates_changed_value_and_ttl() {
+        let source = vec![
+            record("s1", DnsRecordType::A, "www", "192.0.2.1", 600),
+            record("s2", DnsRecordType::Cname, "blog", "a.example.net", 300),
+        ];
+        let target = vec![
+            record("t1", DnsRecordType::A, "www", "192.0.2.9", 600),
+            record("t2", DnsRecordType::Cname, "blog", "a.example.net", 600),
+        ];
+
+        let diff = compute_diff(source, target);
+        let updates: Vec<(&str, &str)> = diff
+            .to_update
+            .iter()
+            .map(|u| (u.current.id.as_str(), u.desired.id.as_str()))
+            .collect();
+        assert_eq!(updates, [("t2", "s2"), ("t1", "s1")]);
+        assert!(diff.to_add.is_empty());
+        assert!(diff.to_delete.is_empty());
+    }
+
+    #[test]
+    fn diff_pairs_duplicate_values_one_to_one() {
+        // 同名同类型的多条记录（轮询）逐条配对，多出的一条需要新增
+        let source = vec![
+            record("s1", DnsRecordType::A, "@", "192.0.2.1", 600),
+            record("s2", DnsRecordType::A, "@", "192.0.2.2", 600),
+            record("s3", DnsRecordType::A, "@", "192.0.2.2", 600),
+        ];
+        let target = vec![
+            record("t1", DnsRecordType::A, "@", "192.0.2.2", 600),
+            record("t2", DnsRecordType::A, "@", "192.0.2.1", 600),
+        ];
+
+        let diff = compute_diff(source, target);
+        assert_eq!(diff.unchanged_count, 2);
+        assert_eq!(ids(&diff.to_add), ["s3"]);
+        assert!(diff.to_update.is_empty());
+        assert!(diff.to_delete.is_empty());
+    }
+
+    #[test]
+    fn diff_ignores_provider_format_differences() {
+        let source = vec![
+            record(
+                "s1",
+                DnsRecordType::Cname,
+                "WWW.",
+                "Target.Example.NET.",
+                600,
+            ),
+            record(
+                "s2",
+                DnsRecordType::Txt,
+                "dkim",
+                "\"v=DKIM1; \" \"p=abc\"",
+                600,
+            ),
+            record("s3", DnsRecordType::Ns, "@", "ns1.example.net", 600),
+        ];
+        let target = vec![
+            record("t1", DnsRecordType::Cname, "www", "target.example.net", 600),
+            record("t2", DnsRecordType::Txt, "dkim", "v=DKIM1; p=abc", 600),
+        ];
+
+        let diff = compute_diff(source, target);
+        assert_eq!(diff.unchanged_count, 2);
+        assert!(diff.to_add.is_empty());
+        assert!(diff.to_update.is_empty());
+        assert!(diff.to_delete.is_empty());
+    }
+
+    fn create_request(
+        record_type: DnsRecordType,
+        name: &str,
+        value: &str,
+    ) -> CreateDnsRecordRequest {
+        CreateDnsRecordRequest {
+            domain_id: "zone".to_string(),
+            record_type,
+            name: name.to_string(),
+            value: value.to_string(),
+            ttl: 600,
+            priority: None,
+            proxied: None,
+            line: None,
+            remark: None,
+        }
+    }
+
+    #[test]
+    fn check_conflict_uses_provider_rules() {
+        let target = vec![
+            record("t1", DnsRecordType::A, "www", "192.0.2.1", 600),
+            record("t2", DnsRecordType::Cname, "blog", "a.example.net", 600),
+        ];
+
+        let identical = create_request(DnsRecordType::A, "WWW", "192.0.2.1");
+        assert!(matches!(
+            check_conflict(&identical, &target),
+            Some(Conflict::Identical)
+        ));
+        let cname = create_request(DnsRecordType::Txt, "blog", "hello");
+        assert!(matches!(
+            check_conflict(&cname, &target),
+            Some(Conflict::Other(_))
+        ));
+        // 同名同类型的其他值（轮询）和其他名称都不冲突
+        let round_robin = create_request(DnsRecordType::A, "www", "192.0.2.2");
+        assert!(check_conflict(&round_robin, &target).is_none());
+        let other_name = create_request(DnsRecordType::Cname, "api", "a.example.net");
+        assert!(check_conflict(&other_name, &target).is_none());
+    }
+}
@@ -1,74 +1,247 @@
 //! Android 凭证存储实现
 //!
-//! 使用 tauri-plugin-store 持久化凭证到应用私有目录
+//! 使用 Stronghold 加密快照持久化凭证，每个账户一条记录（key 为 `account_id`）。
+//! 快照密钥由 Android Keystore 中不可导出的密钥加密后保存，不以明文落盘
 
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::RwLock;
 
-use tauri::AppHandle;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use rand::RngCore;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_android_keystore::KeystoreExt;
 use tauri_plugin_store::StoreExt;
+use tauri_plugin_stronghold::stronghold::Stronghold;
 
 use super::{CredentialStore, CredentialsMap};
 use crate::error::{DnsError, Result};
 
+/// 应用私有 Store 文件（保存加密后的快照密钥，以及旧版本的明文凭证）
 const STORE_FILE_NAME: &str = "credentials.json";
-const CREDENTIALS_KEY: &str = "all-credentials";
+/// 旧版本明文凭证的 key，启动时迁移到 Stronghold 后删除
+const LEGACY_CREDENTIALS_KEY: &str = "all-credentials";
+/// 旧版本明文保存的快照密钥，启动时加密后删除
+const LEGACY_SNAPSHOT_KEY_NAME: &str = "stronghold-key";
+/// 经 Android Keystore 加密的快照密钥
+const WRAPPED_SNAPSHOT_KEY_NAME: &str = "stronghold-key-wrapped";
+const SNAPSHOT_FILE_NAME: &str = "credentials.stronghold";
+const CLIENT_PATH: &[u8] = b"dns-orchestrator";
 
 /// Android 凭证存储实现
 ///
-/// 使用 tauri-plugin-store 将凭证持久化到应用私有目录
-/// Android 的应用沙箱提供基本的数据保护
+/// 凭证加密保存在应用私有目录的 Stronghold 快照中，
+/// 快照密钥首次启动时随机生成，经 Android Keystore 加密后保存在应用私有 Store
 pub struct AndroidCredentialStore {
     app_handle: AppHandle,
-    /// 内存缓存，减少磁盘 I/O
+    /// 快照打开失败时保留错误信息，在读写时返回
+    stronghold: std::result::Result<Stronghold, String>,
+    /// 内存缓存，减少快照读写
     credentials: RwLock<CredentialsMap>,
 }
 
 impl AndroidCredentialStore {
     pub fn new(app_handle: AppHandle) -> Self {
+        let stronghold = Self::open_stronghold(&app_handle);
+        if let Err(e) = &stronghold {
+            log::error!("Failed to open Stronghold snapshot: {e}");
+        }
+
         let store = Self {
             app_handle,
+            stronghold,
             credentials: RwLock::new(HashMap::new()),
         };
-        // 启动时从持久化存储加载
-        if let Ok(creds) = store.read_from_store() {
-            if let Ok(mut cache) = store.credentials.write() {
-                *cache = creds;
+
+        // 启动时从快照加载，并迁移旧版本的明文凭证
+        match store.read_all_records() {
+            Ok(mut creds) => {
+                store.migrate_legacy(&mut creds);
+                if let Ok(mut cache) = store.credentials.write() {
+                    *cache = creds;
+                }
             }
+            Err(e) => log::error!("Failed to load credentials from Stronghold: {e}"),
         }
         store
     }
 
-    /// 从 Store 读取所有凭证
-    fn read_from_store(&self) -> Result<CredentialsMap> {
-        let store = self
-            .app_handle
+    /// 快照文件路径
+    fn snapshot_path(app_handle: &AppHandle) -> std::result::Result<PathBuf, String> {
+        let dir = app_handle
+            .path()
+            .app_local_data_dir()
+            .map_err(|e| e.to_string())?;
+        std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+        Ok(dir.join(SNAPSHOT_FILE_NAME))
+    }
+
+    /// 读取快照密钥，不存在时随机生成；密钥只以 Keystore 加密后的形式保存
+    fn snapshot_key(app_handle: &AppHandle) -> std::result::Result<Vec<u8>, String> {
+        let store = app_handle
             .store(STORE_FILE_NAME)
-            .map_err(|e| DnsError::CredentialError(format!("Failed to access store: {}", e)))?;
+            .map_err(|e| e.to_string())?;
+        let keystore = app_handle.keystore();
 
-        match store.get(CREDENTIALS_KEY) {
-            Some(value) => serde_json::from_value(value.clone())
-                .map_err(|e| DnsError::SerializationError(e.to_string())),
-            None => Ok(HashMap::new()),
+        if let Some(wrapped) = store.get(WRAPPED_SNAPSHOT_KEY_NAME) {
+            let wrapped = wrapped
+                .as_str()
+                .ok_or_else(|| "Invalid wrapped Stronghold key".to_string())?
+                .to_string();
+            return keystore
+                .unwrap_key(wrapped)
+                .map_err(|e| e.to_string())
+                .and_then(|k| BASE64.decode(k).map_err(|e| e.to_string()))
+                .and_then(|k| {
+                    (k.len() == 32)
+                        .then_some(k)
+                        .ok_or_else(|| "Invalid Stronghold key".to_string())
+                });
         }
+
+        // 旧版本明文保存的密钥：沿用原密钥（快照由它加密），加密保存后删除明文
+        let key = match store.get(LEGACY_SNAPSHOT_KEY_NAME) {
+            Some(key) => key
+                .as_str()
+                .and_then(|k| BASE64.decode(k).ok())
+                .filter(|k| k.len() == 32)
+                .ok_or_else(|| "Invalid Stronghold key".to_string())?,
+            None => {
+                let mut key = vec![0u8; 32];
+                rand::thread_rng().fill_bytes(&mut key);
+                key
+            }
+        };
+
+        let wrapped = keystore
+            .wrap_key(BASE64.encode(&key))
+            .map_err(|e| e.to_string())?;
+        store.set(WRAPPED_SNAPSHOT_KEY_NAME.to_string(), wrapped);
+        store.delete(LEGACY_SNAPSHOT_KEY_NAME);
+        store.save().map_err(|e| e.to_string())?;
+        Ok(key)
     }
 
-    /// 将所有凭证写入 Store
-    fn write_to_store(&self, credentials: &CredentialsMap) -> Result<()> {
-        let store = self
-            .app_handle
-            .store(STORE_FILE_NAME)
-            .map_err(|e| DnsError::CredentialError(format!("Failed to access store: {}", e)))?;
+    /// 打开（或创建）Stronghold 快照
+    fn open_stronghold(app_handle: &AppHandle) -> std::result::Result<Stronghold, String> {
+        let path = Self::snapshot_path(app_handle)?;
+        let key = Self::snapshot_key(app_handle)?;
+        let stronghold = Stronghold::new(path, key).map_err(|e| e.to_string())?;
+
+        // 快照中已有 client 时加载，否则新建
+        if stronghold.load_client(CLIENT_PATH).is_err() {
+            stronghold
+                .create_client(CLIENT_PATH)
+                .map_err(|e| e.to_string())?;
+        }
+        Ok(stronghold)
+    }
+
+    fn stronghold(&self) -> Result<&Stronghold> {
+        self.stronghold
+            .as_ref()
+            .map_err(|e| DnsError::CredentialError(format!("Stronghold unavailable: {e}")))
+    }
+
+    /// 从快照读取所有账户的凭证
+    fn read_all_records(&self) -> Result<CredentialsMap> {
+        let stronghold = self.stronghold()?;
+        let client = stronghold
+            .get_client(CLIENT_PATH)
+            .map_err(|e| DnsError::CredentialError(e.to_string()))?;
+        let store = client.store();
 
-        let json = serde_json::to_value(credentials)
+        let mut credentials = HashMap::new();
+        for key in store
+            .keys()
+            .map_err(|e| DnsError::CredentialError(e.to_string()))?
+        {
+            let Ok(account_id) = String::from_utf8(key.clone()) else {
+                continue;
+            };
+            let Some(value) = store
+                .get(&key)
+                .map_err(|e| DnsError::CredentialError(e.to_string()))?
+            else {
+                continue;
+            };
+            let creds: HashMap<String, String> = serde_json::from_slice(&value)
+                .map_err(|e| DnsError::SerializationError(e.to_string()))?;
+            credentials.insert(account_id, creds);
+        }
+        Ok(credentials)
+    }
+
+    /// 写入单个账户的凭证记录并保存快照
+    fn write_record(&self, account_id: &str, credentials: &HashMap<String, String>) -> Result<()> {
+        let stronghold = self.stronghold()?;
+        let client = stronghold
+            .get_client(CLIENT_PATH)
+            .map_err(|e| DnsError::CredentialError(e.to_string()))?;
+
+        let value = serde_json::to_vec(credentials)
             .map_err(|e| DnsError::SerializationError(e.to_string()))?;
+        client
+            .store()
+            .insert(account_id.as_bytes().to_vec(), value, None)
+            .map_err(|e| DnsError::CredentialError(e.to_string()))?;
 
-        store.set(CREDENTIALS_KEY.to_string(), json);
-        store
+        stronghold
             .save()
-            .map_err(|e| DnsError::CredentialError(format!("Failed to save store: {}", e)))?;
+            .map_err(|e| DnsError::CredentialError(format!("Failed to save snapshot: {e}")))
+    }
 
-        Ok(())
+    /// 删除单个账户的凭证记录并保存快照
+    fn delete_record(&self, account_id: &str) -> Result<()> {
+        let stronghold = self.stronghold()?;
+        let client = stronghold
+            .get_client(CLIENT_PATH)
+            .map_err(|e| DnsError::CredentialError(e.to_string()))?;
+
+        client
+            .store()
+            .delete(account_id.as_bytes())
+            .map_err(|e| DnsError::CredentialError(e.to_string()))?;
+
+        stronghold
+            .save()
+            .map_err(|e| DnsError::CredentialError(format!("Failed to save snapshot: {e}")))
+    }
+
+    /// 将旧版本保存在 Store 中的明文凭证迁移到 Stronghold
+    fn migrate_legacy(&self, credentials: &mut CredentialsMap) {
+        let Ok(store) = self.app_handle.store(STORE_FILE_NAME) else {
+            return;
+        };
+        let Some(value) = store.get(LEGACY_CREDENTIALS_KEY) else {
+            return;
+        };
+
+        let legacy: CredentialsMap = match serde_json::from_value(value.clone()) {
+            Ok(legacy) => legacy,
+            Err(e) => {
+                log::warn!("Failed to parse legacy credentials: {e}");
+                return;
+            }
+        };
+
+        for (account_id, creds) in legacy {
+            if credentials.contains_key(&account_id) {
+                continue;
+            }
+            if let Err(e) = self.write_record(&account_id, &creds) {
+                // 保留明文数据，下次启动重试
+                log::error!("Failed to migrate credentials for account {account_id}: {e}");
+                return;
+            }
+            credentials.insert(account_id, creds);
+        }
+
+        store.delete(LEGACY_CREDENTIALS_KEY);
+        if let Err(e) = store.save() {
+            log::warn!("Failed to remove legacy credentials: {e}");
+        }
+        log::info!("Migrated legacy credentials to Stronghold");
     }
 }
 
@@ -86,6 +259,9 @@ impl CredentialStore for AndroidCredentialStore {
     fn save(&self, account_id: &str, credentials: &HashMap<String, String>) -> Result<()> {
         log::debug!("Saving credentials for account: {}", account_id);
 
+        // 持久化到 Stronghold
+        self.write_record(account_id, credentials)?;
+
         // 更新内存缓存
         let mut cache = self
             .credentials
@@ -93,9 +269,6 @@ impl CredentialStore for AndroidCredentialStore {
             .map_err(|e| DnsError::CredentialError(format!("Lock poisoned: {}", e)))?;
         cache.insert(account_id.to_string(), credentials.clone());
 
-        // 持久化到 Store
-        self.write_to_store(&cache)?;
-
         log::info!("Credentials saved for account: {}", account_id);
         Ok(())
     }
@@ -113,6 +286,9 @@ impl CredentialStore for AndroidCredentialStore {
     fn delete(&self, account_id: &str) -> Result<()> {
         log::debug!("Deleting credentials for account: {}", account_id);
 
+        // 从 Stronghold 删除
+        self.delete_record(account_id)?;
+
         // 更新内存缓存
         let mut cache = self
             .credentials
@@ -120,9 +296,6 @@ impl CredentialStore for AndroidCredentialStore {
             .map_err(|e| DnsError::CredentialError(format!("Lock poisoned: {}", e)))?;
         cache.remove(account_id);
 
-        // 持久化到 Store
-        self.write_to_store(&cache)?;
-
         log::info!("Credentials deleted for account: {}", account_id);
         Ok(())
     }
@@ -1,8 +1,11 @@
 use keyring::Entry;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::AppHandle;
 
 use super::{CredentialStore, CredentialsMap};
 use crate::error::{DnsError, Result};
+use crate::storage::AccountStore;
 
 const SERVICE_NAME: &str = "dns-orchestrator";
 const CREDENTIALS_KEY: &str = "all-credentials";
@@ -10,11 +13,19 @@ const CREDENTIALS_KEY: &str = "all-credentials";
 /// 系统 Keychain 凭证存储实现
 ///
 /// 使用单个 Keychain 条目存储所有账户凭证，避免多次 Keychain 访问
-pub struct KeychainStore;
+pub struct KeychainStore {
+    /// 用于读取账号列表（迁移旧版本凭证时需要 `account_id`）
+    app_handle: AppHandle,
+    /// 旧版本凭证迁移只在首次加载时检查一次
+    migration_checked: AtomicBool,
+}
 
 impl KeychainStore {
-    pub fn new() -> Self {
-        Self
+    pub fn new(app_handle: AppHandle) -> Self {
+        Self {
+            app_handle,
+            migration_checked: AtomicBool::new(false),
+        }
     }
 
     /// 获取 Keychain Entry
@@ -24,7 +35,7 @@ impl KeychainStore {
     }
 
     /// 从 Keychain 读取整个凭证存储
-    fn read_all_internal(&self) -> Result<CredentialsMap> {
+    fn read_all_internal() -> Result<CredentialsMap> {
         let entry = Self::get_entry()?;
 
         match entry.get_password() {
@@ -40,7 +51,7 @@ impl KeychainStore {
     }
 
     /// 将整个凭证存储写入 Keychain
-    fn write_all_internal(&self, credentials: &CredentialsMap) -> Result<()> {
+    fn write_all_internal(credentials: &CredentialsMap) -> Result<()> {
         let entry = Self::get_entry()?;
 
         let json = serde_json::to_string(credentials)
@@ -52,18 +63,68 @@ impl KeychainStore {
 
         Ok(())
     }
-}
 
-impl Default for KeychainStore {
-    fn default() -> Self {
-        Self::new()
+    /// 迁移旧版本的凭证存储
+    ///
+    /// 旧版本每个账户一个 Keychain 条目（用户名为 `account_id`），
+    /// Keychain 无法枚举条目，因此按账号列表逐个读取，写入单条目后删除旧条目
+    fn migrate_legacy_entries(&self) -> Result<CredentialsMap> {
+        let accounts = AccountStore::load_accounts(&self.app_handle)?;
+
+        let mut migrated = HashMap::new();
+        let mut legacy_entries = Vec::new();
+        for account in accounts {
+            let Ok(entry) = Entry::new(SERVICE_NAME, &account.id) else {
+                continue;
+            };
+            match entry.get_password() {
+                Ok(json) => match serde_json::from_str::<HashMap<String, String>>(&json) {
+                    Ok(credentials) => {
+                        migrated.insert(account.id, credentials);
+                        legacy_entries.push(entry);
+                    }
+                    Err(e) => {
+                        log::warn!("Failed to parse legacy credentials for {}: {e}", account.id);
+                    }
+                },
+                Err(keyring::Error::NoEntry) => {}
+                Err(e) => log::warn!("Failed to read legacy credentials for {}: {e}", account.id),
+            }
+        }
+
+        if migrated.is_empty() {
+            return Ok(migrated);
+        }
+
+        // 先写入新条目，成功后再删除旧条目，避免中途失败丢失凭证
+        Self::write_all_internal(&migrated)?;
+        for entry in legacy_entries {
+            if let Err(e) = entry.delete_credential() {
+                log::warn!("Failed to delete legacy Keychain entry: {e}");
+            }
+        }
+
+        log::info!(
+            "Migrated {} accounts from legacy Keychain entries",
+            migrated.len()
+        );
+        Ok(migrated)
     }
 }
 
 impl CredentialStore for KeychainStore {
     fn load_all(&self) -> Result<CredentialsMap> {
         log::debug!("Loading all credentials from Keychain");
-        let credentials = self.read_all_internal()?;
+        let mut credentials = Self::read_all_internal()?;
+
+        // 单条目为空时检查是否有旧版本的逐账户条目
+        if credentials.is_empty() && !self.migration_checked.swap(true, Ordering::SeqCst) {
+            match self.migrate_legacy_entries() {
+                Ok(migrated) => credentials = migrated,
+                Err(e) => log::error!("Failed to migrate legacy credentials: {e}"),
+            }
+        }
+
         log::info!("Loaded {} accounts from Keychain", credentials.len());
         Ok(credentials)
     }
@@ -72,20 +133,20 @@ impl CredentialStore for KeychainStore {
         log::debug!("Saving credentials for account: {account_id}");
 
         // 读取现有凭证
-        let mut all_credentials = self.read_all_internal()?;
+        let mut all_credentials = Self::read_all_internal()?;
 
         // 更新指定账户的凭证
         all_credentials.insert(account_id.to_string(), credentials.clone());
 
         // 写回 Keychain
-        self.write_all_internal(&all_credentials)?;
+        Self::write_all_internal(&all_credentials)?;
 
         log::info!("Credentials saved for account: {account_id}");
         Ok(())
     }
 
     fn load(&self, account_id: &str) -> Result<HashMap<String, String>> {
-        let all_credentials = self.read_all_internal()?;
+        let all_credentials = Self::read_all_internal()?;
 
         all_credentials.get(account_id).cloned().ok_or_else(|| {
             DnsError::CredentialError(format!("No credentials found for account: {account_id}"))
@@ -96,21 +157,19 @@ impl CredentialStore for KeychainStore {
         log::debug!("Deleting credentials for account: {account_id}");
 
         // 读取现有凭证
-        let mut all_credentials = self.read_all_internal()?;
+        let mut all_credentials = Self::read_all_internal()?;
 
         // 删除指定账户的凭证
         all_credentials.remove(account_id);
 
         // 写回 Keychain
-        self.write_all_internal(&all_credentials)?;
+        Self::write_all_internal(&all_credentials)?;
 
         log::info!("Credentials deleted for account: {account_id}");
         Ok(())
     }
 
     fn exists(&self, account_id: &str) -> bool {
-        self.read_all_internal()
-            .map(|creds| creds.contains_key(account_id))
-            .unwrap_or(false)
+        Self::read_all_internal().is_ok_and(|creds| creds.contains_key(account_id))
     }
 }
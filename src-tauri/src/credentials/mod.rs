@@ -4,7 +4,7 @@ mod keychain;
 #[cfg(not(target_os = "android"))]
 pub use keychain::KeychainStore;
 
-// Android 端使用内存存储（临时方案，后续可接入 Stronghold）
+// Android 端使用 Stronghold 加密存储
 #[cfg(target_os = "android")]
 mod android;
 #[cfg(target_os = "android")]
@@ -21,6 +21,11 @@ use std::collections::HashMap;
 /// - 涉及文件：credentials/*.rs, commands/account.rs, lib.rs, types.rs
 pub type CredentialsMap = HashMap<String, HashMap<String, String>>;
 
+/// ipinfo.io Token 在凭证存储中的 key（不会与 UUID 形式的 `account_id` 冲突）
+pub const IPINFO_TOKEN_KEY: &str = "settings:ipinfo";
+/// ipinfo.io Token 记录中的字段名
+pub const IPINFO_TOKEN_FIELD: &str = "token";
+
 /// 凭证存储 Trait
 pub trait CredentialStore: Send + Sync {
     /// 一次性加载所有凭证（启动时使用，只访问一次 Keychain）
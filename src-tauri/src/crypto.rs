@@ -1,5 +1,5 @@
 use aes_gcm::{
-    aead::{Aead, KeyInit},
+    aead::{Aead, KeyInit, Payload},
     Aes256Gcm, Nonce,
 };
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
@@ -8,21 +8,104 @@ use rand::RngCore;
 use sha2::Sha256;
 
 use crate::error::{DnsError, Result};
+use crate::types::KdfParams;
 
-const PBKDF2_ITERATIONS: u32 = 100_000;
+/// 旧版导出文件使用的 PBKDF2 迭代次数
+const LEGACY_PBKDF2_ITERATIONS: u32 = 100_000;
+/// Argon2id 默认参数（OWASP 推荐：19 MiB 内存，2 次迭代，单线程）
+const ARGON2_MEMORY_KIB: u32 = 19_456;
+const ARGON2_ITERATIONS: u32 = 2;
+const ARGON2_PARALLELISM: u32 = 1;
+/// 解密时允许的 KDF 参数上限，防止恶意文件耗尽内存或长时间占用 CPU
+const ARGON2_MAX_MEMORY_KIB: u32 = 1_048_576; // 1 GiB
+const ARGON2_MAX_ITERATIONS: u32 = 64;
+const ARGON2_MAX_PARALLELISM: u32 = 16;
+const PBKDF2_MAX_ITERATIONS: u32 = 10_000_000;
 const SALT_LENGTH: usize = 16;
 const NONCE_LENGTH: usize = 12;
 const KEY_LENGTH: usize = 32; // AES-256
 
+impl KdfParams {
+    /// 新导出文件使用的默认参数
+    pub fn recommended() -> Self {
+        Self::Argon2id {
+            memory_kib: ARGON2_MEMORY_KIB,
+            iterations: ARGON2_ITERATIONS,
+            parallelism: ARGON2_PARALLELISM,
+        }
+    }
+
+    /// 未记录 KDF 的旧版文件使用的参数
+    pub fn legacy() -> Self {
+        Self::Pbkdf2Sha256 {
+            iterations: LEGACY_PBKDF2_ITERATIONS,
+        }
+    }
+}
+
 /// 从密码派生加密密钥
-fn derive_key(password: &str, salt: &[u8]) -> [u8; KEY_LENGTH] {
-    pbkdf2_hmac_array::<Sha256, KEY_LENGTH>(password.as_bytes(), salt, PBKDF2_ITERATIONS)
+fn derive_key(password: &str, salt: &[u8], kdf: &KdfParams) -> Result<[u8; KEY_LENGTH]> {
+    match *kdf {
+        KdfParams::Pbkdf2Sha256 { iterations } => {
+            if iterations == 0 || iterations > PBKDF2_MAX_ITERATIONS {
+                return Err(DnsError::ValidationError(format!(
+                    "Invalid PBKDF2 iterations: {iterations}"
+                )));
+            }
+            Ok(pbkdf2_hmac_array::<Sha256, KEY_LENGTH>(
+                password.as_bytes(),
+                salt,
+                iterations,
+            ))
+        }
+        KdfParams::Argon2id {
+            memory_kib,
+            iterations,
+            parallelism,
+        } => {
+            if memory_kib > ARGON2_MAX_MEMORY_KIB {
+                return Err(DnsError::ValidationError(format!(
+                    "Argon2 memory cost too large: {memory_kib} KiB"
+                )));
+            }
+            if iterations > ARGON2_MAX_ITERATIONS {
+                return Err(DnsError::ValidationError(format!(
+                    "Argon2 iterations too large: {iterations}"
+                )));
+            }
+            if parallelism > ARGON2_MAX_PARALLELISM {
+                return Err(DnsError::ValidationError(format!(
+                    "Argon2 parallelism too large: {parallelism}"
+                )));
+            }
+            let config = argon2::Config {
+                variant: argon2::Variant::Argon2id,
+                version: argon2::Version::Version13,
+                mem_cost: memory_kib,
+                time_cost: iterations,
+                lanes: parallelism,
+                hash_length: u32::try_from(KEY_LENGTH).unwrap_or(u32::MAX),
+                ..argon2::Config::default()
+            };
+            let hash = argon2::hash_raw(password.as_bytes(), salt, &config)
+                .map_err(|e| DnsError::SerializationError(format!("Key derivation failed: {e}")))?;
+
+            let mut key = [0u8; KEY_LENGTH];
+            key.copy_from_slice(&hash);
+            Ok(key)
+        }
+    }
 }
 
-/// 加密数据
+/// 加密数据，`aad` 为附加认证数据（不加密，但被篡改时解密失败）
 ///
 /// 返回: (`salt_base64`, `nonce_base64`, `ciphertext_base64`)
-pub fn encrypt(plaintext: &[u8], password: &str) -> Result<(String, String, String)> {
+pub fn encrypt(
+    plaintext: &[u8],
+    password: &str,
+    kdf: &KdfParams,
+    aad: &[u8],
+) -> Result<(String, String, String)> {
     // 生成随机盐和 nonce
     let mut salt = [0u8; SALT_LENGTH];
     let mut nonce_bytes = [0u8; NONCE_LENGTH];
@@ -30,7 +113,7 @@ pub fn encrypt(plaintext: &[u8], password: &str) -> Result<(String, String, Stri
     rand::thread_rng().fill_bytes(&mut nonce_bytes);
 
     // 派生密钥
-    let key = derive_key(password, &salt);
+    let key = derive_key(password, &salt, kdf)?;
 
     // 创建加密器
     let cipher = Aes256Gcm::new_from_slice(&key)
@@ -39,7 +122,13 @@ pub fn encrypt(plaintext: &[u8], password: &str) -> Result<(String, String, Stri
 
     // 加密
     let ciphertext = cipher
-        .encrypt(nonce, plaintext)
+        .encrypt(
+            nonce,
+            Payload {
+                msg: plaintext,
+                aad,
+            },
+        )
         .map_err(|e| DnsError::SerializationError(format!("Encryption failed: {e}")))?;
 
     Ok((
@@ -49,12 +138,14 @@ pub fn encrypt(plaintext: &[u8], password: &str) -> Result<(String, String, Stri
     ))
 }
 
-/// 解密数据
+/// 解密数据，`aad` 须与加密时一致
 pub fn decrypt(
     ciphertext_b64: &str,
     password: &str,
     salt_b64: &str,
     nonce_b64: &str,
+    kdf: &KdfParams,
+    aad: &[u8],
 ) -> Result<Vec<u8>> {
     // 解码 Base64
     let salt = BASE64
@@ -66,9 +157,14 @@ pub fn decrypt(
     let ciphertext = BASE64
         .decode(ciphertext_b64)
         .map_err(|e| DnsError::SerializationError(format!("Invalid ciphertext: {e}")))?;
+    if nonce_bytes.len() != NONCE_LENGTH {
+        return Err(DnsError::SerializationError(
+            "Invalid nonce length".to_string(),
+        ));
+    }
 
     // 派生密钥
-    let key = derive_key(password, &salt);
+    let key = derive_key(password, &salt, kdf)?;
 
     // 创建解密器
     let cipher = Aes256Gcm::new_from_slice(&key)
@@ -76,9 +172,131 @@ pub fn decrypt(
     let nonce = Nonce::from_slice(&nonce_bytes);
 
     // 解密
-    cipher.decrypt(nonce, ciphertext.as_ref()).map_err(|_| {
+    let payload = Payload {
+        msg: ciphertext.as_ref(),
+        aad,
+    };
+    cipher.decrypt(nonce, payload).map_err(|_| {
         DnsError::SerializationError(
             "Decryption failed: invalid password or corrupted data".to_string(),
         )
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type TestResult = std::result::Result<(), Box<dyn std::error::Error>>;
+
+    /// 测试用的低开销 Argon2id 参数
+    fn fast_argon2() -> KdfParams {
+        KdfParams::Argon2id {
+            memory_kib: 64,
+            iterations: 1,
+            parallelism: 1,
+        }
+    }
+
+    #[test]
+    fn pbkdf2_round_trip() -> TestResult {
+        let kdf = KdfParams::legacy();
+        let (salt, nonce, ciphertext) = encrypt(b"secret data", "password", &kdf, b"header")?;
+
+        let plaintext = decrypt(&ciphertext, "password", &salt, &nonce, &kdf, b"header")?;
+        assert_eq!(plaintext, b"secret data");
+        Ok(())
+    }
+
+    #[test]
+    fn argon2id_round_trip() -> TestResult {
+        let kdf = fast_argon2();
+        let (salt, nonce, ciphertext) = encrypt(b"secret data", "password", &kdf, b"header")?;
+
+        let plaintext = decrypt(&ciphertext, "password", &salt, &nonce, &kdf, b"header")?;
+        assert_eq!(plaintext, b"secret data");
+        Ok(())
+    }
+
+    #[test]
+    fn wrong_password_or_kdf_fails() -> TestResult {
+        let kdf = fast_argon2();
+        let (salt, nonce, ciphertext) = encrypt(b"secret data", "password", &kdf, b"header")?;
+
+        assert!(decrypt(&ciphertext, "wrong", &salt, &nonce, &kdf, b"header").is_err());
+        assert!(decrypt(
+            &ciphertext,
+            "password",
+            &salt,
+            &nonce,
+            &KdfParams::legacy(),
+            b"header"
+        )
+        .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn tampered_aad_fails() -> TestResult {
+        let kdf = fast_argon2();
+        let (salt, nonce, ciphertext) = encrypt(b"secret data", "password", &kdf, b"header")?;
+
+        assert!(decrypt(&ciphertext, "password", &salt, &nonce, &kdf, b"tampered").is_err());
+        assert!(decrypt(&ciphertext, "password", &salt, &nonce, &kdf, b"").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_excessive_argon2_memory() {
+        let kdf = KdfParams::Argon2id {
+            memory_kib: ARGON2_MAX_MEMORY_KIB + 1,
+            iterations: 1,
+            parallelism: 1,
+        };
+        assert!(encrypt(b"secret data", "password", &kdf, b"header").is_err());
+    }
+
+    #[test]
+    fn rejects_excessive_kdf_iterations() {
+        let kdfs = [
+            KdfParams::Argon2id {
+                memory_kib: 64,
+                iterations: u32::MAX,
+                parallelism: 1,
+            },
+            KdfParams::Argon2id {
+                memory_kib: 64,
+                iterations: 1,
+                parallelism: u32::MAX,
+            },
+            KdfParams::Pbkdf2Sha256 {
+                iterations: u32::MAX,
+            },
+        ];
+        for kdf in &kdfs {
+            assert!(matches!(
+                decrypt("", "password", "", "AAAAAAAAAAAAAAAA", kdf, b"header"),
+                Err(DnsError::ValidationError(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn kdf_params_serialization() -> TestResult {
+        let json = serde_json::to_value(KdfParams::recommended())?;
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "algorithm": "argon2id",
+                "memoryKib": ARGON2_MEMORY_KIB,
+                "iterations": ARGON2_ITERATIONS,
+                "parallelism": ARGON2_PARALLELISM,
+            })
+        );
+
+        let legacy: KdfParams =
+            serde_json::from_str(r#"{"algorithm":"pbkdf2-sha256","iterations":100000}"#)?;
+        assert_eq!(legacy, KdfParams::legacy());
+        Ok(())
+    }
+}
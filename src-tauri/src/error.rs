@@ -57,9 +57,37 @@ pub enum DnsError {
     #[error("Unsupported file version")]
     UnsupportedFileVersion,
 
+    /// Provider 不支持该功能（前端据此禁用对应操作）
+    #[error("{provider} does not support {capability}")]
+    NotSupported {
+        provider: String,
+        capability: String,
+    },
+
     /// Provider 错误（从库转换）
     #[error("{0}")]
-    Provider(#[from] ProviderError),
+    Provider(ProviderError),
+}
+
+impl From<ProviderError> for DnsError {
+    fn from(err: ProviderError) -> Self {
+        match err {
+            ProviderError::NotSupported {
+                provider,
+                capability,
+            } => Self::NotSupported {
+                provider,
+                capability,
+            },
+            other => Self::Provider(other),
+        }
+    }
+}
+
+impl From<CredentialValidationError> for DnsError {
+    fn from(err: CredentialValidationError) -> Self {
+        Self::CredentialValidation(err)
+    }
 }
 
 pub type Result<T> = std::result::Result<T, DnsError>;
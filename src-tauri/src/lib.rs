@@ -2,6 +2,8 @@ mod commands;
 mod credentials;
 mod crypto;
 mod error;
+mod ocsp;
+mod operations;
 mod providers;
 mod storage;
 mod types;
@@ -10,47 +12,113 @@ use std::sync::Arc;
 
 #[cfg(target_os = "android")]
 use commands::updater;
-use commands::{account, dns, domain, toolbox};
+use commands::{account, dns, domain, settings, toolbox, zone};
 #[cfg(target_os = "android")]
 use credentials::AndroidCredentialStore;
-use credentials::CredentialStore;
 #[cfg(not(target_os = "android"))]
 use credentials::KeychainStore;
+use credentials::{CredentialStore, CredentialsMap, IPINFO_TOKEN_FIELD, IPINFO_TOKEN_KEY};
+use error::DnsError;
+use operations::OperationRegistry;
 use providers::ProviderRegistry;
-use storage::AccountStore;
+use storage::{AccountStore, SettingsStore};
 use tauri::Manager;
 use tokio::sync::RwLock;
-use types::Account;
+use types::{Account, AccountStatus, IpGeoConfig, ProviderClientConfig, RestoreAccountsResult};
 
 /// 应用全局状态
 pub struct AppState {
     /// Provider 注册表
     pub registry: ProviderRegistry,
+    /// 可取消的进行中操作
+    pub operations: OperationRegistry,
     /// 凭证存储
     pub credential_store: Arc<dyn CredentialStore>,
     /// 账号元数据 (不含凭证)
     pub accounts: RwLock<Vec<Account>>,
+    /// HTTP 客户端配置（代理、超时等）
+    pub client_config: RwLock<ProviderClientConfig>,
+    /// IP 地理位置查询设置
+    pub ip_geo_config: RwLock<IpGeoConfig>,
     /// App Handle (用于访问 Store)
     pub app_handle: tauri::AppHandle,
 }
 
+/// 加载网络设置，失败时使用默认配置
+fn load_client_config(app_handle: &tauri::AppHandle) -> ProviderClientConfig {
+    SettingsStore::load_client_config(app_handle).unwrap_or_else(|e| {
+        log::warn!("Failed to load network settings: {e}");
+        ProviderClientConfig::default()
+    })
+}
+
+/// 加载 IP 地理位置查询设置，失败时使用默认配置
+///
+/// ipinfo Token 从凭证存储读取；旧版本明文保存在设置中的 Token 迁移到凭证存储
+fn load_ip_geo_config(
+    app_handle: &tauri::AppHandle,
+    credential_store: &dyn CredentialStore,
+) -> IpGeoConfig {
+    let mut config = SettingsStore::load_ip_geo_config(app_handle).unwrap_or_else(|e| {
+        log::warn!("Failed to load IP geo settings: {e}");
+        IpGeoConfig::default()
+    });
+
+    if let Some(token) = config.ipinfo_token.clone() {
+        let record = [(IPINFO_TOKEN_FIELD.to_string(), token)].into();
+        match credential_store.save(IPINFO_TOKEN_KEY, &record) {
+            Ok(()) => {
+                config.ipinfo_token_configured = true;
+                // 重新保存设置以去掉明文 Token
+                if let Err(e) = SettingsStore::save_ip_geo_config(app_handle, &config) {
+                    log::warn!("Failed to remove plaintext ipinfo token: {e}");
+                }
+                log::info!("Migrated ipinfo token to credential store");
+            }
+            Err(e) => log::warn!("Failed to migrate ipinfo token: {e}"),
+        }
+        return config;
+    }
+
+    // 未配置时不访问凭证存储，避免启动时触发 Keychain 授权
+    if config.ipinfo_token_configured {
+        config.ipinfo_token = credential_store
+            .load(IPINFO_TOKEN_KEY)
+            .map_err(|e| log::warn!("Failed to load ipinfo token: {e}"))
+            .ok()
+            .and_then(|mut record| record.remove(IPINFO_TOKEN_FIELD));
+        config.ipinfo_token_configured = config.ipinfo_token.is_some();
+    }
+    config
+}
+
 impl AppState {
     #[cfg(not(target_os = "android"))]
     pub fn new(app_handle: tauri::AppHandle) -> Self {
+        let credential_store: Arc<dyn CredentialStore> =
+            Arc::new(KeychainStore::new(app_handle.clone()));
         Self {
             registry: ProviderRegistry::new(),
-            credential_store: Arc::new(KeychainStore::new()),
+            operations: OperationRegistry::new(),
+            ip_geo_config: RwLock::new(load_ip_geo_config(&app_handle, credential_store.as_ref())),
+            credential_store,
             accounts: RwLock::new(Vec::new()),
+            client_config: RwLock::new(load_client_config(&app_handle)),
             app_handle,
         }
     }
 
     #[cfg(target_os = "android")]
     pub fn new(app_handle: tauri::AppHandle) -> Self {
+        let credential_store: Arc<dyn CredentialStore> =
+            Arc::new(AndroidCredentialStore::new(app_handle.clone()));
         Self {
             registry: ProviderRegistry::new(),
-            credential_store: Arc::new(AndroidCredentialStore::new(app_handle.clone())),
+            operations: OperationRegistry::new(),
+            ip_geo_config: RwLock::new(load_ip_geo_config(&app_handle, credential_store.as_ref())),
+            credential_store,
             accounts: RwLock::new(Vec::new()),
+            client_config: RwLock::new(load_client_config(&app_handle)),
             app_handle,
         }
     }
@@ -77,7 +145,7 @@ pub fn run() {
         builder = builder.plugin(tauri_plugin_updater::Builder::new().build());
     }
 
-    // Android 启用 Stronghold 和 APK Installer
+    // Android 启用 Stronghold、Keystore 和 APK Installer
     #[cfg(target_os = "android")]
     {
         builder = builder
@@ -87,6 +155,7 @@ pub fn run() {
                 ))
                 .build(),
             )
+            .plugin(tauri_plugin_android_keystore::init())
             .plugin(tauri_plugin_apk_installer::init());
     }
 
@@ -94,10 +163,13 @@ pub fn run() {
         // 创建 AppState（需要 AppHandle）
         let state = AppState::new(app.handle().clone());
 
-        // 从持久化存储恢复账户
-        if let Err(e) = restore_accounts(&state) {
+        // 从持久化存储恢复账户（setup 阶段同步执行）
+        if let Err(e) = futures::executor::block_on(restore_accounts(&state, false)) {
             log::error!("Failed to restore accounts: {e}");
-            // 不阻止应用启动，只记录错误
+            // 不阻止应用启动，凭证不可用时账户显示为错误状态
+            if matches!(e, DnsError::CredentialError(_)) {
+                mark_credentials_unavailable(&state, &e);
+            }
         }
 
         app.manage(state);
@@ -109,25 +181,61 @@ pub fn run() {
         // Account commands
         account::list_accounts,
         account::create_account,
+        account::update_account,
         account::delete_account,
         account::list_providers,
+        account::test_credentials,
         account::export_accounts,
+        account::export_all_accounts,
         account::preview_import,
         account::import_accounts,
+        account::refresh_account_statuses,
+        account::get_account_metrics,
+        account::reload_credentials,
+        settings::get_network_config,
+        settings::set_network_config,
+        settings::get_ip_geo_config,
+        settings::set_ip_geo_config,
         // Domain commands
         domain::list_domains,
         domain::get_domain,
+        domain::get_dnssec,
+        domain::get_domain_nameservers,
+        domain::list_record_lines,
         // DNS commands
         dns::list_dns_records,
         dns::create_dns_record,
         dns::update_dns_record,
+        dns::patch_dns_record,
         dns::delete_dns_record,
+        dns::toggle_dns_record,
         dns::batch_delete_dns_records,
+        dns::batch_update_dns_records,
+        dns::cancel_operation,
+        dns::search_records_global,
+        dns::list_account_records,
+        dns::ddns_update,
         // Toolbox commands
         toolbox::whois_lookup,
+        toolbox::rdap_lookup,
         toolbox::dns_lookup,
+        toolbox::resolve_via_provider,
+        toolbox::axfr_transfer,
+        toolbox::email_auth_check,
+        toolbox::check_propagation,
         toolbox::ip_lookup,
+        toolbox::ip_whois,
         toolbox::ssl_check,
+        toolbox::check_certs_bulk,
+        toolbox::domain_expiry_report,
+        toolbox::port_check,
+        toolbox::http_inspect,
+        // Zone sync commands
+        zone::diff_zones,
+        zone::apply_zone_diff,
+        zone::clone_zone,
+        zone::list_record_templates,
+        zone::apply_record_template,
     ]);
 
     #[cfg(target_os = "android")]
@@ -135,145 +243,141 @@ pub fn run() {
         // Account commands
         account::list_accounts,
         account::create_account,
+        account::update_account,
         account::delete_account,
         account::list_providers,
+        account::test_credentials,
         account::export_accounts,
+        account::export_all_accounts,
         account::preview_import,
         account::import_accounts,
+        account::refresh_account_statuses,
+        account::get_account_metrics,
+        account::reload_credentials,
+        settings::get_network_config,
+        settings::set_network_config,
+        settings::get_ip_geo_config,
+        settings::set_ip_geo_config,
         // Domain commands
         domain::list_domains,
         domain::get_domain,
+        domain::get_dnssec,
+        domain::get_domain_nameservers,
+        domain::list_record_lines,
         // DNS commands
         dns::list_dns_records,
         dns::create_dns_record,
         dns::update_dns_record,
+        dns::patch_dns_record,
         dns::delete_dns_record,
+        dns::toggle_dns_record,
         dns::batch_delete_dns_records,
+        dns::batch_update_dns_records,
+        dns::cancel_operation,
+        dns::search_records_global,
+        dns::list_account_records,
+        dns::ddns_update,
         // Toolbox commands
         toolbox::whois_lookup,
+        toolbox::rdap_lookup,
         toolbox::dns_lookup,
+        toolbox::resolve_via_provider,
+        toolbox::axfr_transfer,
+        toolbox::email_auth_check,
+        toolbox::check_propagation,
         toolbox::ip_lookup,
+        toolbox::ip_whois,
         toolbox::ssl_check,
+        toolbox::check_certs_bulk,
+        toolbox::domain_expiry_report,
+        toolbox::port_check,
+        toolbox::http_inspect,
+        // Zone sync commands
+        zone::diff_zones,
+        zone::apply_zone_diff,
+        zone::clone_zone,
+        zone::list_record_templates,
+        zone::apply_record_template,
         // Android updater commands
         updater::check_android_update,
         updater::download_apk,
         updater::install_apk,
     ]);
 
+    #[allow(clippy::expect_used)]
     builder
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
 
-/// 从持久化存储恢复账户
+/// 从持久化存储恢复账户（启动时调用，`reload_credentials` 命令复用）
 ///
 /// 流程：
 /// 1. 从 Store 加载账户元数据
 /// 2. 一次性从 Keychain 加载所有凭证（只访问一次 Keychain）
-/// 3. 重建 Provider 实例
-/// 4. 注册到 `ProviderRegistry`
-fn restore_accounts(state: &AppState) -> crate::error::Result<()> {
-    use crate::providers::create_provider;
-    use crate::types::{AccountStatus, ProviderCredentials};
-
+/// 3. 重建 Provider 实例并注册到 `ProviderRegistry`，失败的账户注销
+/// 4. 注销已不在 Store 中的账户
+/// 5. `validate` 为 true 时验证各账号凭证（启动时跳过，避免拖慢启动）
+///
+/// Keychain 不可用时返回错误，不修改已有的账户状态和注册表
+async fn restore_accounts(
+    state: &AppState,
+    validate: bool,
+) -> crate::error::Result<RestoreAccountsResult> {
     // 1. 加载账户元数据
     let mut accounts = AccountStore::load_accounts(&state.app_handle)?;
-
-    if accounts.is_empty() {
-        log::info!("No accounts to restore");
-        return Ok(());
-    }
-
     log::info!("Restoring {} accounts...", accounts.len());
 
     // 2. 一次性加载所有凭证（只访问一次 Keychain）
-    let all_credentials = match state.credential_store.load_all() {
-        Ok(creds) => creds,
-        Err(e) => {
-            log::error!("Failed to load credentials from Keychain: {e}");
-            // 标记所有账户为错误状态
-            for account in &mut accounts {
-                account.status = Some(AccountStatus::Error);
-                account.error = Some(format!("凭证加载失败: {e}"));
-            }
-            futures::executor::block_on(async {
-                let mut accounts_guard = state.accounts.write().await;
-                *accounts_guard = accounts;
-            });
-            return Ok(());
-        }
+    let all_credentials = if accounts.is_empty() {
+        CredentialsMap::new()
+    } else {
+        state.credential_store.load_all()?
     };
 
     // 3. 遍历账户，恢复 Provider 实例
+    let client_config = state.client_config.read().await.clone();
     let mut restored_count = 0;
     let mut failed_count = 0;
 
     for account in &mut accounts {
-        // 3.1 从已加载的凭证中获取该账户的凭证
-        let credentials = if let Some(creds) = all_credentials.get(&account.id) {
-            creds.clone()
-        } else {
-            log::warn!(
-                "No credentials found for account {}: credential not in store",
-                account.id
-            );
-            account.status = Some(AccountStatus::Error);
-            account.error = Some("凭证未找到".to_string());
-            failed_count += 1;
-            continue;
-        };
-
-        // 3.2 转换凭证格式
-        let typed_credentials = match ProviderCredentials::from_map(&account.provider, &credentials)
-        {
-            Ok(c) => c,
-            Err(e) => {
-                log::warn!(
-                    "Failed to parse credentials for account {}: {}",
-                    account.id,
-                    e
-                );
-                account.status = Some(AccountStatus::Error);
-                account.error = Some(format!("凭证格式错误: {e}"));
-                failed_count += 1;
-                continue;
+        match account::build_account_provider(
+            account,
+            all_credentials.get(&account.id),
+            &client_config,
+        ) {
+            Ok(provider) => {
+                state.registry.register(account.id.clone(), provider).await;
+                account.status = Some(AccountStatus::Active);
+                account.error = None;
+                restored_count += 1;
             }
-        };
-
-        // 3.3 重建 Provider 实例
-        let provider = match create_provider(typed_credentials) {
-            Ok(p) => p,
             Err(e) => {
-                log::warn!(
-                    "Failed to create provider for account {}: {}",
-                    account.id,
-                    e
-                );
+                state.registry.unregister(&account.id).await;
                 account.status = Some(AccountStatus::Error);
-                account.error = Some(format!("Provider 创建失败: {e}"));
+                account.error = Some(e);
                 failed_count += 1;
-                continue;
             }
-        };
-
-        // 3.4 注册到 ProviderRegistry（同步执行，因为在 setup 阶段）
-        let registry = state.registry.clone();
-        let account_id = account.id.clone();
-
-        // 使用 futures 的 block_on 来同步执行异步注册
-        futures::executor::block_on(async {
-            registry.register(account_id, provider).await;
-        });
+        }
+    }
 
-        account.status = Some(AccountStatus::Active);
-        restored_count += 1;
+    // 4. 注销已不在 Store 中的账户
+    for account_id in state.registry.list_account_ids().await {
+        if !accounts.iter().any(|a| a.id == account_id) {
+            state.registry.unregister(&account_id).await;
+        }
     }
 
-    // 4. 更新内存中的账户列表（包含失败的账户）
-    futures::executor::block_on(async {
-        let mut accounts_guard = state.accounts.write().await;
-        *accounts_guard = accounts;
-    });
+    // 5. 更新内存中的账户列表（包含失败的账户）
+    *state.accounts.write().await = accounts.clone();
+    if validate {
+        accounts = account::validate_registered_accounts(state).await;
+        restored_count = accounts
+            .iter()
+            .filter(|a| matches!(a.status, Some(AccountStatus::Active)))
+            .count();
+        failed_count = accounts.len() - restored_count;
+    }
 
     log::info!("Account restoration complete: {restored_count} succeeded, {failed_count} failed");
 
@@ -281,5 +385,28 @@ fn restore_accounts(state: &AppState) -> crate::error::Result<()> {
         log::error!("All accounts failed to restore. Please check Keychain access permissions.");
     }
 
-    Ok(())
+    Ok(RestoreAccountsResult {
+        restored_count,
+        failed_count,
+        accounts,
+    })
+}
+
+/// 启动时 Keychain 不可用：将所有账户标记为错误状态，应用照常启动，
+/// 解锁 Keychain 后可通过 `reload_credentials` 重试
+fn mark_credentials_unavailable(state: &AppState, error: &DnsError) {
+    let mut accounts = match AccountStore::load_accounts(&state.app_handle) {
+        Ok(accounts) => accounts,
+        Err(e) => {
+            log::error!("Failed to load accounts: {e}");
+            return;
+        }
+    };
+    for account in &mut accounts {
+        account.status = Some(AccountStatus::Error);
+        account.error = Some(format!("凭证加载失败: {error}"));
+    }
+    futures::executor::block_on(async {
+        *state.accounts.write().await = accounts;
+    });
 }
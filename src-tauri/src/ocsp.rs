@@ -0,0 +1,531 @@
+//! OCSP 吊销状态查询
+//!
+//! 只实现 SSL 检查所需的最小 DER 编解码。OCSP 经明文 HTTP 传输，
+//! 响应签名须由签发者或其授权的 OCSP 签名证书验证通过，否则视为无结果
+
+use sha1::{Digest, Sha1};
+use x509_parser::der_parser::asn1_rs::BitString;
+use x509_parser::extensions::{GeneralName, ParsedExtension};
+use x509_parser::oid_registry::OID_PKIX_ACCESS_DESCRIPTOR_OCSP;
+use x509_parser::prelude::{FromDer, X509Certificate};
+use x509_parser::time::ASN1Time;
+use x509_parser::verify::verify_signature;
+use x509_parser::x509::{AlgorithmIdentifier, SubjectPublicKeyInfo};
+
+/// OCSP 请求超时时间
+const OCSP_TIMEOUT_SECS: u64 = 5;
+
+/// sha1 的 OID（1.3.14.3.2.26）
+const SHA1_OID: &[u8] = &[0x2b, 0x0e, 0x03, 0x02, 0x1a];
+
+/// 从证书的 Authority Information Access 扩展中提取 OCSP 响应器地址
+pub fn responder_url(cert: &X509Certificate) -> Option<String> {
+    cert.iter_extensions()
+        .find_map(|ext| match ext.parsed_extension() {
+            ParsedExtension::AuthorityInfoAccess(aia) => Some(aia),
+            _ => None,
+        })?
+        .accessdescs
+        .iter()
+        .filter(|desc| desc.access_method == OID_PKIX_ACCESS_DESCRIPTOR_OCSP)
+        .find_map(|desc| match desc.access_location {
+            GeneralName::URI(uri) => Some(uri.to_string()),
+            _ => None,
+        })
+}
+
+/// 查询证书的 OCSP 状态，返回 "good" / "revoked" / "unknown"
+///
+/// 缺少响应器地址、签发者证书、响应器不可达、响应签名校验失败，
+/// 或响应不在有效期内（thisUpdate 晚于当前时间、nextUpdate 已过）时返回 None
+pub async fn check_status(
+    client: &reqwest::Client,
+    cert: &X509Certificate<'_>,
+    issuer: &X509Certificate<'_>,
+) -> Option<String> {
+    let url = responder_url(cert)?;
+    let name_hash = Sha1::digest(cert.issuer().as_raw());
+    let key_hash = Sha1::digest(&issuer.public_key().subject_public_key.data);
+    let cert_id = CertId {
+        name_hash: &name_hash,
+        key_hash: &key_hash,
+        serial: cert.raw_serial(),
+    };
+    let request = build_request(&cert_id);
+
+    let response = client
+        .post(&url)
+        .timeout(std::time::Duration::from_secs(OCSP_TIMEOUT_SECS))
+        .header("Content-Type", "application/ocsp-request")
+        .body(request)
+        .send()
+        .await
+        .ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+    let body = response.bytes().await.ok()?;
+
+    let basic = parse_response(&body)?;
+    if !verify_response(&basic, issuer) {
+        log::warn!("OCSP response from {url} failed signature verification");
+        return None;
+    }
+    single_status(basic.responses, &cert_id, ASN1Time::now().timestamp()).map(str::to_string)
+}
+
+/// 编码一个 DER TLV（OCSP 请求很小，长度最多占两个字节）
+fn der(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut out = vec![tag];
+    match u8::try_from(content.len()) {
+        Ok(len) if len < 0x80 => out.push(len),
+        Ok(len) => out.extend([0x81, len]),
+        Err(_) => {
+            let len = u16::try_from(content.len()).unwrap_or(u16::MAX);
+            out.push(0x82);
+            out.extend(len.to_be_bytes());
+        }
+    }
+    out.extend_from_slice(content);
+    out
+}
+
+/// `CertID` 中用于匹配证书的字段（哈希算法固定为 sha1）
+#[derive(Debug, PartialEq, Eq)]
+struct CertId<'a> {
+    /// 签发者名称的哈希
+    name_hash: &'a [u8],
+    /// 签发者公钥的哈希
+    key_hash: &'a [u8],
+    /// 证书序列号
+    serial: &'a [u8],
+}
+
+/// 构造 `CertID` 内容（不含外层 SEQUENCE）
+fn encode_cert_id(cert_id: &CertId) -> Vec<u8> {
+    let algorithm = der(0x30, &[der(0x06, SHA1_OID), der(0x05, &[])].concat());
+    [
+        algorithm,
+        der(0x04, cert_id.name_hash),
+        der(0x04, cert_id.key_hash),
+        der(0x02, cert_id.serial),
+    ]
+    .concat()
+}
+
+/// 解析 `CertID` 内容（不含外层 SEQUENCE）
+///
+/// `CertID ::= SEQUENCE { hashAlgorithm, issuerNameHash OCTET STRING, issuerKeyHash OCTET STRING, serialNumber INTEGER }`
+fn parse_cert_id(content: &[u8]) -> Option<CertId<'_>> {
+    let (_, _, rest) = read_tlv(content)?;
+    let (0x04, name_hash, rest) = read_tlv(rest)? else {
+        return None;
+    };
+    let (0x04, key_hash, rest) = read_tlv(rest)? else {
+        return None;
+    };
+    let (0x02, serial, _) = read_tlv(rest)? else {
+        return None;
+    };
+    Some(CertId {
+        name_hash,
+        key_hash,
+        serial,
+    })
+}
+
+/// 构造 `OCSPRequest`
+///
+/// `OCSPRequest ::= SEQUENCE { tbsRequest SEQUENCE { requestList SEQUENCE OF Request } }`
+fn build_request(cert_id: &CertId) -> Vec<u8> {
+    let request = der(0x30, &der(0x30, &encode_cert_id(cert_id)));
+    let request_list = der(0x30, &request);
+    let tbs_request = der(0x30, &request_list);
+    der(0x30, &tbs_request)
+}
+
+/// 读取一个 DER TLV，返回 (tag, content, 剩余数据)
+fn read_tlv(input: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+    let (&tag, rest) = input.split_first()?;
+    let (&first, rest) = rest.split_first()?;
+
+    let (len, rest) = if first < 0x80 {
+        (first as usize, rest)
+    } else {
+        let count = (first & 0x7f) as usize;
+        if count == 0 || count > std::mem::size_of::<usize>() || rest.len() < count {
+            return None;
+        }
+        let (len_bytes, rest) = rest.split_at(count);
+        let len = len_bytes
+            .iter()
+            .fold(0usize, |acc, b| (acc << 8) | *b as usize);
+        (len, rest)
+    };
+
+    if rest.len() < len {
+        return None;
+    }
+    let (content, rest) = rest.split_at(len);
+    Some((tag, content, rest))
+}
+
+/// `BasicOCSPResponse` 中校验签名和读取状态所需的部分
+struct BasicResponse<'a> {
+    /// `tbsResponseData` 的完整 DER（签名覆盖的数据）
+    tbs: &'a [u8],
+    /// `signatureAlgorithm` 的完整 DER
+    signature_algorithm: &'a [u8],
+    /// `signature` BIT STRING 的内容（首字节为未使用位数）
+    signature: &'a [u8],
+    /// 响应中附带的证书（授权的 OCSP 签名证书）
+    certs: Vec<&'a [u8]>,
+    /// `responses` SEQUENCE OF `SingleResponse` 的内容
+    responses: &'a [u8],
+}
+
+/// 解析 `OCSPResponse`，响应状态不是 successful 或结构不完整时返回 None
+fn parse_response(data: &[u8]) -> Option<BasicResponse<'_>> {
+    // OCSPResponse ::= SEQUENCE { responseStatus ENUMERATED, responseBytes [0] EXPLICIT }
+    let (_, response, _) = read_tlv(data)?;
+    let (_, status, rest) = read_tlv(response)?;
+    if status != [0] {
+        return None;
+    }
+    let (_, response_bytes, _) = read_tlv(rest)?;
+
+    // ResponseBytes ::= SEQUENCE { responseType OID, response OCTET STRING }
+    let (_, response_bytes, _) = read_tlv(response_bytes)?;
+    let (_, _, rest) = read_tlv(response_bytes)?;
+    let (_, basic, _) = read_tlv(rest)?;
+
+    // BasicOCSPResponse ::= SEQUENCE { tbsResponseData, signatureAlgorithm, signature, certs [0] OPTIONAL }
+    let (_, basic, _) = read_tlv(basic)?;
+    let (_, data, rest) = read_tlv(basic)?;
+    let tbs = &basic[..basic.len() - rest.len()];
+    let (_, _, after_algorithm) = read_tlv(rest)?;
+    let signature_algorithm = &rest[..rest.len() - after_algorithm.len()];
+    let (_, signature, rest) = read_tlv(after_algorithm)?;
+
+    let mut certs = Vec::new();
+    if let Some((0xa0, explicit, _)) = read_tlv(rest) {
+        let (_, mut list, _) = read_tlv(explicit)?;
+        while !list.is_empty() {
+            let (_, _, next) = read_tlv(list)?;
+            certs.push(&list[..list.len() - next.len()]);
+            list = next;
+        }
+    }
+
+    // ResponseData ::= SEQUENCE { version [0] OPTIONAL, responderID, producedAt, responses }
+    let (tag, _, mut rest) = read_tlv(data)?;
+    if tag == 0xa0 {
+        (_, _, rest) = read_tlv(rest)?;
+    }
+    let (_, _, rest) = read_tlv(rest)?;
+    let (_, responses, _) = read_tlv(rest)?;
+
+    Some(BasicResponse {
+        tbs,
+        signature_algorithm,
+        signature,
+        certs,
+        responses,
+    })
+}
+
+/// 用给定公钥校验响应签名
+fn verify_with_key(basic: &BasicResponse, public_key: &SubjectPublicKeyInfo) -> bool {
+    let Some((&unused_bits, signature)) = basic.signature.split_first() else {
+        return false;
+    };
+    let Ok((_, algorithm)) = AlgorithmIdentifier::from_der(basic.signature_algorithm) else {
+        return false;
+    };
+    verify_signature(
+        public_key,
+        &algorithm,
+        &BitString::new(unused_bits, signature),
+        basic.tbs,
+    )
+    .is_ok()
+}
+
+/// 校验响应由签发者直接签名，或由签发者签发、带 OCSP Signing 用途的证书签名
+fn verify_response(basic: &BasicResponse, issuer: &X509Certificate) -> bool {
+    if verify_with_key(basic, issuer.public_key()) {
+        return true;
+    }
+    basic.certs.iter().any(|der| {
+        let Ok((_, responder)) = X509Certificate::from_der(der) else {
+            return false;
+        };
+        let authorized = responder
+            .extended_key_usage()
+            .ok()
+            .flatten()
+            .is_some_and(|eku| eku.value.ocsp_signing);
+        authorized
+            && responder.validity().is_valid()
+            && responder
+                .verify_signature(Some(issuer.public_key()))
+                .is_ok()
+            && verify_with_key(basic, responder.public_key())
+    })
+}
+
+/// 解析完整 DER 编码的时间，返回 Unix 时间戳
+fn parse_time(data: &[u8]) -> Option<i64> {
+    ASN1Time::from_der(data)
+        .ok()
+        .map(|(_, time)| time.timestamp())
+}
+
+/// 在 `responses` 中找到与 `CertID` 匹配、且在 `now` 时刻有效的 `SingleResponse` 并返回其状态
+fn single_status(mut responses: &[u8], cert_id: &CertId, now: i64) -> Option<&'static str> {
+    while !responses.is_empty() {
+        let (_, single, next) = read_tlv(responses)?;
+        responses = next;
+
+        // SingleResponse ::= SEQUENCE { certID, certStatus, thisUpdate, nextUpdate [0] EXPLICIT OPTIONAL, ... }
+        let (_, response_cert_id, rest) = read_tlv(single)?;
+        if parse_cert_id(response_cert_id).as_ref() != Some(cert_id) {
+            continue;
+        }
+        let (status_tag, _, rest) = read_tlv(rest)?;
+        let (_, _, after_this_update) = read_tlv(rest)?;
+        let this_update = parse_time(&rest[..rest.len() - after_this_update.len()])?;
+        let next_update = match read_tlv(after_this_update) {
+            Some((0xa0, explicit, _)) => Some(parse_time(explicit)?),
+            _ => None,
+        };
+        if this_update > now || next_update.is_some_and(|next| next < now) {
+            continue;
+        }
+
+        return Some(match status_tag {
+            0x80 => "good",
+            0xa1 => "revoked",
+            _ => "unknown",
+        });
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ring::signature::{Ed25519KeyPair, KeyPair};
+
+    /// Ed25519 的 OID（1.3.101.112）
+    const ED25519_OID: &[u8] = &[0x2b, 0x65, 0x70];
+    /// id-pkix-ocsp-basic 的 OID（1.3.6.1.5.5.7.48.1.1）
+    const OCSP_BASIC_OID: &[u8] = &[0x2b, 0x06, 0x01, 0x05, 0x05, 0x07, 0x30, 0x01, 0x01];
+    const SERIAL: &[u8] = &[0x01, 0x02, 0x03];
+    const THIS_UPDATE: &str = "20240101000000Z";
+    /// 2024-01-02 00:00:00 UTC
+    const NOW: i64 = 1_704_153_600;
+
+    type TestResult = std::result::Result<(), Box<dyn std::error::Error>>;
+
+    fn key_pair(seed: u8) -> std::result::Result<Ed25519KeyPair, String> {
+        Ed25519KeyPair::from_seed_unchecked(&[seed; 32]).map_err(|e| e.to_string())
+    }
+
+    fn spki(key: &Ed25519KeyPair) -> Vec<u8> {
+        let algorithm = der(0x30, &der(0x06, ED25519_OID));
+        let public_key = der(0x03, &[&[0u8][..], key.public_key().as_ref()].concat());
+        der(0x30, &[algorithm, public_key].concat())
+    }
+
+    fn time(value: &str) -> Vec<u8> {
+        der(0x18, value.as_bytes())
+    }
+
+    fn cert_id(serial: &[u8]) -> CertId<'_> {
+        CertId {
+            name_hash: &[1; 20],
+            key_hash: &[2; 20],
+            serial,
+        }
+    }
+
+    /// 构造一个 `SingleResponse`
+    fn single(
+        cert_id: &CertId,
+        cert_status: &[u8],
+        this_update: &str,
+        next_update: Option<&str>,
+    ) -> Vec<u8> {
+        let mut fields = vec![
+            der(0x30, &encode_cert_id(cert_id)),
+            cert_status.to_vec(),
+            time(this_update),
+        ];
+        if let Some(next_update) = next_update {
+            fields.push(der(0xa0, &time(next_update)));
+        }
+        der(0x30, &fields.concat())
+    }
+
+    /// 构造一个由 `key` 签名、包含单个 `SingleResponse` 的 OCSP 响应
+    fn fixture(single: &[u8], key: &Ed25519KeyPair) -> Vec<u8> {
+        let tbs = der(
+            0x30,
+            &[
+                der(0xa2, &der(0x04, &[0u8; 20])),
+                time(THIS_UPDATE),
+                der(0x30, single),
+            ]
+            .concat(),
+        );
+        let signature_algorithm = der(0x30, &der(0x06, ED25519_OID));
+        let signature = der(0x03, &[&[0u8][..], key.sign(&tbs).as_ref()].concat());
+        let basic = der(0x30, &[tbs, signature_algorithm, signature].concat());
+        let response_bytes = der(
+            0x30,
+            &[der(0x06, OCSP_BASIC_OID), der(0x04, &basic)].concat(),
+        );
+        der(
+            0x30,
+            &[der(0x0a, &[0]), der(0xa0, &response_bytes)].concat(),
+        )
+    }
+
+    /// 证书序列号为 `SERIAL` 的 good 响应
+    fn good(key: &Ed25519KeyPair) -> Vec<u8> {
+        fixture(
+            &single(&cert_id(SERIAL), &[0x80, 0x00], THIS_UPDATE, None),
+            key,
+        )
+    }
+
+    fn verified_status(data: &[u8], key: &Ed25519KeyPair) -> Option<&'static str> {
+        let basic = parse_response(data)?;
+        let spki = spki(key);
+        let (_, public_key) = SubjectPublicKeyInfo::from_der(&spki).ok()?;
+        if !verify_with_key(&basic, &public_key) {
+            return None;
+        }
+        single_status(basic.responses, &cert_id(SERIAL), NOW)
+    }
+
+    #[test]
+    fn good_and_revoked_statuses_are_parsed() -> TestResult {
+        let key = key_pair(1)?;
+        assert_eq!(verified_status(&good(&key), &key), Some("good"));
+
+        let revoked = single(
+            &cert_id(SERIAL),
+            &der(0xa1, &time(THIS_UPDATE)),
+            THIS_UPDATE,
+            None,
+        );
+        assert_eq!(
+            verified_status(&fixture(&revoked, &key), &key),
+            Some("revoked")
+        );
+
+        let unknown = single(&cert_id(SERIAL), &[0x82, 0x00], THIS_UPDATE, None);
+        assert_eq!(
+            verified_status(&fixture(&unknown, &key), &key),
+            Some("unknown")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn other_certificate_has_no_status() -> TestResult {
+        let key = key_pair(1)?;
+        let other_serial = single(&cert_id(&[0x09]), &[0x80, 0x00], THIS_UPDATE, None);
+        assert_eq!(verified_status(&fixture(&other_serial, &key), &key), None);
+
+        // 序列号相同但签发者不同
+        let other_issuer = CertId {
+            key_hash: &[3; 20],
+            ..cert_id(SERIAL)
+        };
+        let other_issuer = single(&other_issuer, &[0x80, 0x00], THIS_UPDATE, None);
+        assert_eq!(verified_status(&fixture(&other_issuer, &key), &key), None);
+
+        let other_name = CertId {
+            name_hash: &[3; 20],
+            ..cert_id(SERIAL)
+        };
+        let other_name = single(&other_name, &[0x80, 0x00], THIS_UPDATE, None);
+        assert_eq!(verified_status(&fixture(&other_name, &key), &key), None);
+        Ok(())
+    }
+
+    #[test]
+    fn response_from_the_future_is_rejected() -> TestResult {
+        let key = key_pair(1)?;
+        let future = single(&cert_id(SERIAL), &[0x80, 0x00], "20240103000000Z", None);
+        assert_eq!(verified_status(&fixture(&future, &key), &key), None);
+        Ok(())
+    }
+
+    #[test]
+    fn expired_response_is_rejected() -> TestResult {
+        let key = key_pair(1)?;
+        let expired = single(
+            &cert_id(SERIAL),
+            &[0x80, 0x00],
+            THIS_UPDATE,
+            Some("20240101120000Z"),
+        );
+        assert_eq!(verified_status(&fixture(&expired, &key), &key), None);
+
+        let fresh = single(
+            &cert_id(SERIAL),
+            &[0x80, 0x00],
+            THIS_UPDATE,
+            Some("20240108000000Z"),
+        );
+        assert_eq!(verified_status(&fixture(&fresh, &key), &key), Some("good"));
+        Ok(())
+    }
+
+    #[test]
+    fn response_signed_by_other_key_is_rejected() -> TestResult {
+        let forged = good(&key_pair(2)?);
+        assert!(parse_response(&forged).is_some());
+        assert_eq!(verified_status(&forged, &key_pair(1)?), None);
+        Ok(())
+    }
+
+    #[test]
+    fn unsuccessful_or_truncated_response_is_ignored() -> TestResult {
+        // responseStatus = tryLater(3)，没有 responseBytes
+        assert!(parse_response(&der(0x30, &der(0x0a, &[3]))).is_none());
+
+        let good = good(&key_pair(1)?);
+        assert!(parse_response(&good[..good.len() - 8]).is_none());
+        assert!(parse_response(&[]).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn request_wraps_cert_id_in_nested_sequences() -> TestResult {
+        let expected = cert_id(SERIAL);
+        let request = build_request(&expected);
+
+        // OCSPRequest -> tbsRequest -> requestList -> Request -> CertID
+        let mut content = request.as_slice();
+        for _ in 0..5 {
+            let (tag, inner, rest) = read_tlv(content).ok_or("truncated request")?;
+            assert_eq!((tag, rest.len()), (0x30, 0));
+            content = inner;
+        }
+        assert_eq!(parse_cert_id(content), Some(expected));
+        Ok(())
+    }
+
+    #[test]
+    fn der_uses_long_form_lengths() {
+        let encoded = der(0x30, &der(0x04, &[0u8; 200]));
+        assert_eq!(&encoded[..3], &[0x30, 0x81, 203]);
+        assert_eq!(&encoded[3..6], &[0x04, 0x81, 200]);
+    }
+}
@@ -0,0 +1,108 @@
+//! 可取消的长时间操作
+//!
+//! 批量命令接收前端生成的 `operation_id`，执行期间在 `OperationRegistry` 中登记取消令牌，
+//! `cancel_operation` 命令按 ID 取消后，命令停止发起新的请求并返回已完成部分的结果。
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use tokio_util::sync::CancellationToken;
+
+use crate::error::{DnsError, Result};
+
+/// 进行中操作的取消令牌注册表
+#[derive(Clone, Default)]
+pub struct OperationRegistry {
+    tokens: Arc<Mutex<HashMap<String, CancellationToken>>>,
+}
+
+impl OperationRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 登记一个操作，返回的 guard 被 drop 时自动注销
+    ///
+    /// 未提供 ID 的操作不登记，其令牌永远不会被取消
+    pub fn start(&self, operation_id: Option<String>) -> Result<OperationGuard> {
+        let token = CancellationToken::new();
+        if let Some(id) = &operation_id {
+            let mut tokens = self.tokens.lock().unwrap_or_else(PoisonError::into_inner);
+            if tokens.contains_key(id) {
+                return Err(DnsError::ValidationError(format!("操作 {id} 已在进行中")));
+            }
+            tokens.insert(id.clone(), token.clone());
+        }
+        Ok(OperationGuard {
+            operation_id,
+            token,
+            tokens: Arc::clone(&self.tokens),
+        })
+    }
+
+    /// 取消进行中的操作，操作不存在（未登记或已结束）时返回 false
+    pub fn cancel(&self, operation_id: &str) -> bool {
+        let tokens = self.tokens.lock().unwrap_or_else(PoisonError::into_inner);
+        tokens
+            .get(operation_id)
+            .map(CancellationToken::cancel)
+            .is_some()
+    }
+}
+
+/// 进行中操作的句柄
+pub struct OperationGuard {
+    operation_id: Option<String>,
+    token: CancellationToken,
+    tokens: Arc<Mutex<HashMap<String, CancellationToken>>>,
+}
+
+impl OperationGuard {
+    /// 操作的取消令牌
+    pub fn token(&self) -> &CancellationToken {
+        &self.token
+    }
+}
+
+impl Drop for OperationGuard {
+    fn drop(&mut self) {
+        if let Some(id) = &self.operation_id {
+            self.tokens
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .remove(id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type TestResult = std::result::Result<(), Box<dyn std::error::Error>>;
+
+    #[test]
+    fn cancel_running_operation() -> TestResult {
+        let registry = OperationRegistry::new();
+        let guard = registry.start(Some("op-1".to_string()))?;
+
+        assert!(registry.start(Some("op-1".to_string())).is_err());
+        assert!(registry.cancel("op-1"));
+        assert!(guard.token().is_cancelled());
+
+        drop(guard);
+        assert!(!registry.cancel("op-1"));
+        Ok(())
+    }
+
+    #[test]
+    fn anonymous_operation_is_not_registered() -> TestResult {
+        let registry = OperationRegistry::new();
+        let guard = registry.start(None)?;
+        let other = registry.start(None)?;
+
+        assert!(!guard.token().is_cancelled());
+        assert!(!other.token().is_cancelled());
+        Ok(())
+    }
+}
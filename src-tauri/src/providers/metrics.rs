@@ -0,0 +1,181 @@
+//! Provider 调用指标
+//!
+//! 注册时用 `MeteredProvider` 包装 Provider 实例，统计每个账号的请求数、错误数和耗时。
+
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use dns_orchestrator_provider::{
+    CreateDnsRecordRequest, DnsProvider, DnsRecord, DnsRecordType, DnssecStatus, Domain,
+    PaginatedResponse, PaginationParams, PermissionInfo, RecordLine, RecordPatch,
+    RecordQueryParams, Result, UpdateDnsRecordRequest,
+};
+
+use crate::types::AccountMetrics;
+
+/// 单个账号的调用计数
+#[derive(Debug, Default)]
+pub struct ProviderMetrics {
+    request_count: AtomicU64,
+    error_count: AtomicU64,
+    total_latency_ms: AtomicU64,
+}
+
+impl ProviderMetrics {
+    fn record(&self, elapsed_ms: u64, is_error: bool) {
+        self.request_count.fetch_add(1, Ordering::Relaxed);
+        self.total_latency_ms
+            .fetch_add(elapsed_ms, Ordering::Relaxed);
+        if is_error {
+            self.error_count.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// 生成当前指标的快照
+    pub fn snapshot(&self, account_id: &str) -> AccountMetrics {
+        let request_count = self.request_count.load(Ordering::Relaxed);
+        let total_latency_ms = self.total_latency_ms.load(Ordering::Relaxed);
+        AccountMetrics {
+            account_id: account_id.to_string(),
+            request_count,
+            error_count: self.error_count.load(Ordering::Relaxed),
+            average_latency_ms: total_latency_ms.checked_div(request_count).unwrap_or(0),
+        }
+    }
+}
+
+/// 统计调用指标的 Provider 包装，所有方法都直接转发给内部 Provider
+pub struct MeteredProvider {
+    inner: Arc<dyn DnsProvider>,
+    metrics: Arc<ProviderMetrics>,
+}
+
+impl MeteredProvider {
+    pub fn new(inner: Arc<dyn DnsProvider>, metrics: Arc<ProviderMetrics>) -> Self {
+        Self { inner, metrics }
+    }
+
+    /// 执行一次调用并记录耗时和结果
+    async fn timed<T>(&self, call: impl Future<Output = Result<T>>) -> Result<T> {
+        let started = Instant::now();
+        let result = call.await;
+        let elapsed_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+        self.metrics.record(elapsed_ms, result.is_err());
+        result
+    }
+}
+
+#[async_trait]
+impl DnsProvider for MeteredProvider {
+    fn id(&self) -> &'static str {
+        self.inner.id()
+    }
+
+    async fn validate_credentials(&self) -> Result<bool> {
+        self.timed(self.inner.validate_credentials()).await
+    }
+
+    async fn check_permissions(&self) -> Result<PermissionInfo> {
+        self.timed(self.inner.check_permissions()).await
+    }
+
+    async fn list_domains(&self, params: &PaginationParams) -> Result<PaginatedResponse<Domain>> {
+        self.timed(self.inner.list_domains(params)).await
+    }
+
+    async fn get_domain(&self, domain_id: &str) -> Result<Domain> {
+        self.timed(self.inner.get_domain(domain_id)).await
+    }
+
+    async fn list_records(
+        &self,
+        domain_id: &str,
+        params: &RecordQueryParams,
+    ) -> Result<PaginatedResponse<DnsRecord>> {
+        self.timed(self.inner.list_records(domain_id, params)).await
+    }
+
+    async fn get_record(&self, domain_id: &str, record_id: &str) -> Result<DnsRecord> {
+        self.timed(self.inner.get_record(domain_id, record_id))
+            .await
+    }
+
+    async fn count_records(&self, domain_id: &str) -> Result<u32> {
+        self.timed(self.inner.count_records(domain_id)).await
+    }
+
+    async fn find_records(
+        &self,
+        domain_id: &str,
+        name: &str,
+        record_type: Option<DnsRecordType>,
+    ) -> Result<Vec<DnsRecord>> {
+        self.timed(self.inner.find_records(domain_id, name, record_type))
+            .await
+    }
+
+    async fn can_create_record(&self, req: &CreateDnsRecordRequest) -> Result<()> {
+        self.timed(self.inner.can_create_record(req)).await
+    }
+
+    async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
+        self.timed(self.inner.create_record(req)).await
+    }
+
+    async fn update_record(
+        &self,
+        record_id: &str,
+        req: &UpdateDnsRecordRequest,
+    ) -> Result<DnsRecord> {
+        self.timed(self.inner.update_record(record_id, req)).await
+    }
+
+    async fn patch_record(
+        &self,
+        domain_id: &str,
+        record_id: &str,
+        changes: &RecordPatch,
+    ) -> Result<DnsRecord> {
+        self.timed(self.inner.patch_record(domain_id, record_id, changes))
+            .await
+    }
+
+    async fn delete_record(&self, record_id: &str, domain_id: &str) -> Result<()> {
+        self.timed(self.inner.delete_record(record_id, domain_id))
+            .await
+    }
+
+    async fn set_record_enabled(
+        &self,
+        domain_id: &str,
+        record_id: &str,
+        enabled: bool,
+    ) -> Result<()> {
+        self.timed(self.inner.set_record_enabled(domain_id, record_id, enabled))
+            .await
+    }
+
+    async fn get_dnssec_status(&self, domain_id: &str) -> Result<DnssecStatus> {
+        self.timed(self.inner.get_dnssec_status(domain_id)).await
+    }
+
+    async fn get_domain_nameservers(&self, domain_id: &str) -> Result<Vec<String>> {
+        self.timed(self.inner.get_domain_nameservers(domain_id))
+            .await
+    }
+
+    async fn list_record_lines(&self, domain_id: &str) -> Result<Vec<RecordLine>> {
+        self.timed(self.inner.list_record_lines(domain_id)).await
+    }
+
+    async fn list_all_domains(&self) -> Result<Vec<Domain>> {
+        self.timed(self.inner.list_all_domains()).await
+    }
+
+    async fn list_all_records(&self, domain_id: &str) -> Result<Vec<DnsRecord>> {
+        self.timed(self.inner.list_all_records(domain_id)).await
+    }
+}
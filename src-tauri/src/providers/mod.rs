@@ -6,31 +6,56 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
+mod metrics;
+
+pub use metrics::{MeteredProvider, ProviderMetrics};
+
 // Re-export from library
-pub use dns_orchestrator_provider::{create_provider, get_all_provider_metadata, DnsProvider};
+pub use dns_orchestrator_provider::{
+    create_provider_with_config, domain_to_ascii, domain_to_unicode, find_conflict,
+    get_all_provider_metadata, get_provider_features, normalize_record_value, DnsProvider,
+};
 
 /// Provider 注册表 - 管理所有已注册的 Provider 实例
 /// 按 `account_id` 索引 Provider 实例
 #[derive(Clone)]
 pub struct ProviderRegistry {
     providers: Arc<RwLock<HashMap<String, Arc<dyn DnsProvider>>>>,
+    metrics: Arc<RwLock<HashMap<String, Arc<ProviderMetrics>>>>,
 }
 
 impl ProviderRegistry {
     pub fn new() -> Self {
         Self {
             providers: Arc::new(RwLock::new(HashMap::new())),
+            metrics: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
     /// 注册提供商实例 (按 `account_id`)
+    ///
+    /// 实例会被包装为 `MeteredProvider`，重新注册同一账号时沿用已有的指标
     pub async fn register(&self, account_id: String, provider: Arc<dyn DnsProvider>) {
+        let metrics = Arc::clone(
+            self.metrics
+                .write()
+                .await
+                .entry(account_id.clone())
+                .or_default(),
+        );
+        let provider: Arc<dyn DnsProvider> = Arc::new(MeteredProvider::new(provider, metrics));
         self.providers.write().await.insert(account_id, provider);
     }
 
     /// 注销提供商
     pub async fn unregister(&self, account_id: &str) {
         self.providers.write().await.remove(account_id);
+        self.metrics.write().await.remove(account_id);
+    }
+
+    /// 获取账号的调用指标
+    pub async fn metrics(&self, account_id: &str) -> Option<Arc<ProviderMetrics>> {
+        self.metrics.read().await.get(account_id).cloned()
     }
 
     /// 获取提供商实例
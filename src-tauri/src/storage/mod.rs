@@ -1,3 +1,5 @@
 mod account_store;
+mod settings_store;
 
 pub use account_store::AccountStore;
+pub use settings_store::SettingsStore;
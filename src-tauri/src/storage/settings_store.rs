@@ -0,0 +1,80 @@
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+use crate::error::{DnsError, Result};
+use crate::types::{IpGeoConfig, ProviderClientConfig};
+
+const STORE_FILE_NAME: &str = "settings.json";
+const NETWORK_KEY: &str = "network";
+const IP_GEO_KEY: &str = "ipGeo";
+
+/// 应用设置存储
+///
+/// 保存需要在 Rust 端生效的设置（如 Provider 请求使用的代理）
+pub struct SettingsStore;
+
+impl SettingsStore {
+    /// 保存网络设置
+    pub fn save_client_config(app: &AppHandle, config: &ProviderClientConfig) -> Result<()> {
+        let store = app
+            .store(STORE_FILE_NAME)
+            .map_err(|e| DnsError::SerializationError(format!("Failed to access store: {e}")))?;
+
+        let config_json = serde_json::to_value(config)
+            .map_err(|e| DnsError::SerializationError(e.to_string()))?;
+        store.set(NETWORK_KEY.to_string(), config_json);
+
+        store
+            .save()
+            .map_err(|e| DnsError::SerializationError(format!("Failed to save store: {e}")))?;
+
+        log::info!("Saved network settings to store");
+        Ok(())
+    }
+
+    /// 加载网络设置，未保存过时返回默认配置
+    pub fn load_client_config(app: &AppHandle) -> Result<ProviderClientConfig> {
+        let store = app
+            .store(STORE_FILE_NAME)
+            .map_err(|e| DnsError::SerializationError(format!("Failed to access store: {e}")))?;
+
+        let Some(config_value) = store.get(NETWORK_KEY) else {
+            return Ok(ProviderClientConfig::default());
+        };
+
+        serde_json::from_value(config_value.clone())
+            .map_err(|e| DnsError::SerializationError(e.to_string()))
+    }
+
+    /// 保存 IP 地理位置查询设置
+    pub fn save_ip_geo_config(app: &AppHandle, config: &IpGeoConfig) -> Result<()> {
+        let store = app
+            .store(STORE_FILE_NAME)
+            .map_err(|e| DnsError::SerializationError(format!("Failed to access store: {e}")))?;
+
+        let config_json = serde_json::to_value(config)
+            .map_err(|e| DnsError::SerializationError(e.to_string()))?;
+        store.set(IP_GEO_KEY.to_string(), config_json);
+
+        store
+            .save()
+            .map_err(|e| DnsError::SerializationError(format!("Failed to save store: {e}")))?;
+
+        log::info!("Saved IP geo settings to store");
+        Ok(())
+    }
+
+    /// 加载 IP 地理位置查询设置，未保存过时返回默认配置
+    pub fn load_ip_geo_config(app: &AppHandle) -> Result<IpGeoConfig> {
+        let store = app
+            .store(STORE_FILE_NAME)
+            .map_err(|e| DnsError::SerializationError(format!("Failed to access store: {e}")))?;
+
+        let Some(config_value) = store.get(IP_GEO_KEY) else {
+            return Ok(IpGeoConfig::default());
+        };
+
+        serde_json::from_value(config_value.clone())
+            .map_err(|e| DnsError::SerializationError(e.to_string()))
+    }
+}
@@ -8,17 +8,23 @@ pub use dns_orchestrator_provider::{
     CreateDnsRecordRequest,
     DnsRecord,
     DnsRecordType,
+    DnssecStatus,
     // Domain 相关（重命名避免冲突）
     Domain as LibDomain,
     DomainStatus,
     // 分页类型
     PaginatedResponse,
     PaginationParams,
+    PermissionInfo,
     // Provider 元数据类型
+    ProviderClientConfig,
     ProviderCredentials,
     ProviderMetadata,
     ProviderType,
+    RecordLine,
+    RecordPatch,
     RecordQueryParams,
+    RecordSort,
     UpdateDnsRecordRequest,
 };
 
@@ -44,6 +50,9 @@ pub struct Account {
     pub status: Option<AccountStatus>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
+    /// 账号可用但存在问题时的提示（如凭证缺少写入权限）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -53,6 +62,24 @@ pub struct CreateAccountRequest {
     pub credentials: HashMap<String, String>,
 }
 
+/// 凭证测试结果（不保存账号）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialTestResult {
+    /// 凭证是否有效
+    pub valid: bool,
+    /// 凭证拥有的权限，提供商不支持或凭证无效时为空
+    pub permissions: Option<PermissionInfo>,
+}
+
+/// 更新账号请求（字段为空时保持不变）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateAccountRequest {
+    pub id: String,
+    pub name: Option<String>,
+    pub credentials: Option<HashMap<String, String>>,
+}
+
 // ============ 应用层 Domain（包含 account_id）============
 
 /// 应用层 Domain 类型（包含 `account_id`）
@@ -66,13 +93,19 @@ pub struct Domain {
     pub status: DomainStatus,
     #[serde(rename = "recordCount", skip_serializing_if = "Option::is_none")]
     pub record_count: Option<u32>,
+    /// punycode 域名解码后的显示名称，与 `name` 相同时为空
+    #[serde(rename = "unicodeName", skip_serializing_if = "Option::is_none")]
+    pub unicode_name: Option<String>,
 }
 
 impl Domain {
     /// 从库的 Domain 构造应用层 Domain
     pub fn from_lib(lib_domain: LibDomain, account_id: String) -> Self {
+        let unicode_name = Some(crate::providers::domain_to_unicode(&lib_domain.name))
+            .filter(|unicode| *unicode != lib_domain.name);
         Self {
             id: lib_domain.id,
+            unicode_name,
             name: lib_domain.name,
             account_id,
             provider: lib_domain.provider,
@@ -110,8 +143,50 @@ pub struct WhoisResult {
     pub creation_date: Option<String>,
     pub expiration_date: Option<String>,
     pub updated_date: Option<String>,
+    /// 规范化为 RFC3339 的注册时间，无法识别原始格式时为空
+    pub creation_date_parsed: Option<String>,
+    /// 规范化为 RFC3339 的到期时间，无法识别原始格式时为空
+    pub expiration_date_parsed: Option<String>,
+    /// 注册人组织（被脱敏时为空）
+    pub registrant_org: Option<String>,
+    /// 注册人国家/地区代码
+    pub registrant_country: Option<String>,
+    /// 注册商滥用投诉邮箱
+    pub abuse_email: Option<String>,
     pub name_servers: Vec<String>,
     pub status: Vec<String>,
+    /// 是否启用了隐私保护或被脱敏
+    pub privacy_protected: bool,
+    /// 被隐私保护或脱敏的字段名
+    pub redacted_fields: Vec<String>,
+    pub raw: String,
+}
+
+/// RDAP 实体（注册商、注册人、联系人等）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RdapEntity {
+    pub handle: Option<String>,
+    /// 角色: "registrar" | "registrant" | "abuse" 等
+    pub roles: Vec<String>,
+    pub name: Option<String>,
+}
+
+/// RDAP 查询结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RdapResult {
+    pub domain: String,
+    /// 数据来源: "rdap" | "whois"（TLD 不支持 RDAP 时回退）
+    pub source: String,
+    pub registrar: Option<String>,
+    pub creation_date: Option<String>,
+    pub expiration_date: Option<String>,
+    pub updated_date: Option<String>,
+    pub status: Vec<String>,
+    pub name_servers: Vec<String>,
+    pub entities: Vec<RdapEntity>,
+    /// 原始 RDAP JSON 或 WHOIS 文本
     pub raw: String,
 }
 
@@ -126,6 +201,18 @@ pub struct DnsLookupRecord {
     pub priority: Option<u16>,
 }
 
+/// DNSSEC 验证状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DnssecValidation {
+    /// 验证通过
+    Secure,
+    /// 未签名或无法验证
+    Insecure,
+    /// 签名验证失败
+    Bogus,
+}
+
 /// DNS 查询结果（包含 nameserver 信息）
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -134,6 +221,163 @@ pub struct DnsLookupResult {
     pub nameserver: String,
     /// 查询记录列表
     pub records: Vec<DnsLookupRecord>,
+    /// DNSSEC 验证状态
+    pub dnssec: DnssecValidation,
+    /// 各记录类型的查询状态（用于区分"没有记录"和"查询失败"）
+    pub type_statuses: Vec<DnsLookupTypeStatus>,
+    /// trace 模式下从根服务器开始的逐级查询过程
+    pub trace: Option<DnsTrace>,
+}
+
+/// trace 模式的查询过程
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DnsTrace {
+    pub hops: Vec<DnsTraceHop>,
+    /// 是否得到了权威应答（包括 NXDOMAIN 和没有记录）
+    pub completed: bool,
+    /// 解析中断的原因，如 lame delegation
+    pub error: Option<String>,
+}
+
+/// trace 模式中的一级委派
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DnsTraceHop {
+    /// 当前查询的 zone，根区为 "."
+    pub zone: String,
+    /// 该 zone 的 nameserver 列表
+    pub nameservers: Vec<String>,
+    /// 实际给出响应的 nameserver
+    pub server: Option<String>,
+    pub server_ip: Option<String>,
+    /// 响应码，如 "No Error"、"Non-Existent Domain"
+    pub response_code: Option<String>,
+    /// 是否为权威应答
+    pub authoritative: bool,
+    /// 应答记录
+    pub records: Vec<DnsLookupRecord>,
+    /// 委派到下一级的 nameserver
+    pub referral: Vec<String>,
+    /// 查询耗时（毫秒）
+    pub elapsed_ms: u64,
+    /// 查询失败的 nameserver 及原因
+    pub error: Option<String>,
+}
+
+/// 区域传送（AXFR）结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AxfrResult {
+    pub zone: String,
+    /// 实际连接的 nameserver 地址
+    pub nameserver: String,
+    /// zone 中的全部记录（以 SOA 开头，不含结尾重复的 SOA）
+    pub records: Vec<DnsLookupRecord>,
+    /// 传送耗时（毫秒）
+    pub elapsed_ms: u64,
+}
+
+/// 单个记录类型的查询状态
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DnsLookupTypeStatus {
+    pub record_type: String,
+    /// 查询失败时的错误信息，为空表示查询成功（可能没有记录）
+    pub error: Option<String>,
+}
+
+/// SPF 检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpfCheck {
+    /// 域名根上的 SPF 记录（存在多条时为第一条）
+    pub record: Option<String>,
+    /// `v=spf1` 之后的机制和修饰符
+    pub mechanisms: Vec<String>,
+    /// all 机制，如 "-all"、"~all"
+    pub all_qualifier: Option<String>,
+    /// 是否以 -all 结尾
+    pub hard_fail: bool,
+    /// 需要额外 DNS 查询的机制数量（RFC 7208 限制为 10）
+    pub dns_lookups: u32,
+    pub issues: Vec<String>,
+}
+
+/// DMARC 检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DmarcCheck {
+    /// `_dmarc.{domain}` 上的 DMARC 记录
+    pub record: Option<String>,
+    /// 策略 (p=): "none" | "quarantine" | "reject"
+    pub policy: Option<String>,
+    /// 子域名策略 (sp=)
+    pub subdomain_policy: Option<String>,
+    /// 生效比例 (pct=)
+    pub pct: Option<u32>,
+    /// 聚合报告地址 (rua=)
+    pub rua: Vec<String>,
+    pub issues: Vec<String>,
+}
+
+/// DKIM 检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DkimCheck {
+    pub selector: String,
+    /// `{selector}._domainkey.{domain}` 上的 DKIM 记录
+    pub record: Option<String>,
+    /// 密钥类型 (k=)，默认为 rsa
+    pub key_type: Option<String>,
+    /// 是否存在非空公钥（p= 为空表示密钥已吊销）
+    pub key_present: bool,
+    /// 公钥能否正常解析
+    pub key_valid: bool,
+    /// 密钥长度（位）
+    pub key_bits: Option<u32>,
+    pub issues: Vec<String>,
+}
+
+/// 邮件认证（SPF/DKIM/DMARC）检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailAuthResult {
+    pub domain: String,
+    pub spf: SpfCheck,
+    pub dmarc: DmarcCheck,
+    /// 未指定 selector 时为空
+    pub dkim: Option<DkimCheck>,
+}
+
+/// 单个公共 DNS 的传播检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PropagationResolverResult {
+    /// DNS 服务商名称
+    pub name: String,
+    pub ip: String,
+    /// 返回的记录值
+    pub values: Vec<String>,
+    pub ttl: Option<u32>,
+    /// 是否包含期望值，未指定期望值或查询失败时为空
+    pub matched: Option<bool>,
+    /// 查询失败时的错误信息
+    pub error: Option<String>,
+    /// 查询耗时（毫秒）
+    pub elapsed_ms: u64,
+}
+
+/// DNS 传播检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PropagationResult {
+    pub domain: String,
+    pub record_type: String,
+    pub expected_value: Option<String>,
+    pub resolvers: Vec<PropagationResolverResult>,
+    /// 结果包含期望值的服务器数量
+    pub matched_count: usize,
 }
 
 /// IP 地理位置信息
@@ -166,6 +410,76 @@ pub struct IpLookupResult {
     pub is_domain: bool,
     /// IP 地理位置结果列表
     pub results: Vec<IpGeoInfo>,
+    /// 查询失败的 IP
+    pub failures: Vec<IpLookupFailure>,
+}
+
+/// 查询失败的 IP
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IpLookupFailure {
+    pub ip: String,
+    pub error: String,
+}
+
+/// IP 地理位置查询服务
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IpGeoProvider {
+    /// ipwho.is（免费，有月度限额）
+    Ipwhois,
+    /// ip-api.com（免费版仅支持 HTTP）
+    Ipapi,
+    /// ipinfo.io（可配置 Token 提高限额）
+    Ipinfo,
+}
+
+/// IP 地理位置查询设置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IpGeoConfig {
+    /// 首选查询服务
+    pub provider: IpGeoProvider,
+    /// 首选服务达到限额时使用的备用服务，为空时不切换
+    #[serde(default)]
+    pub fallback: Option<IpGeoProvider>,
+    /// ipinfo.io 的访问 Token，保存在凭证存储中，不写入设置文件也不返回给前端
+    ///
+    /// 更新设置时为空表示保持不变，空字符串表示清除
+    #[serde(default, skip_serializing)]
+    pub ipinfo_token: Option<String>,
+    /// 是否已配置 ipinfo.io Token
+    #[serde(default)]
+    pub ipinfo_token_configured: bool,
+}
+
+impl Default for IpGeoConfig {
+    fn default() -> Self {
+        Self {
+            provider: IpGeoProvider::Ipwhois,
+            fallback: Some(IpGeoProvider::Ipapi),
+            ipinfo_token: None,
+            ipinfo_token_configured: false,
+        }
+    }
+}
+
+/// IP WHOIS 查询结果（RIR 分配信息）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IpWhoisResult {
+    pub ip: String,
+    /// 分配该地址的 RIR: "ARIN" | "RIPE NCC" | "APNIC" | "LACNIC" | "AFRINIC"
+    pub rir: String,
+    pub whois_server: String,
+    pub net_range: Option<String>,
+    pub cidr: Option<String>,
+    pub net_name: Option<String>,
+    pub org: Option<String>,
+    pub country: Option<String>,
+    /// 滥用举报邮箱
+    pub abuse_email: Option<String>,
+    pub raw: String,
 }
 
 /// SSL 证书信息
@@ -180,10 +494,14 @@ pub struct SslCertInfo {
     pub days_remaining: i64,
     pub is_expired: bool,
     pub is_valid: bool,
+    /// 证书验证失败的原因（过期、域名不匹配、根证书不受信任等）
+    pub validation_errors: Vec<String>,
     pub san: Vec<String>,
     pub serial_number: String,
     pub signature_algorithm: String,
     pub certificate_chain: Vec<CertChainItem>,
+    /// OCSP 吊销状态: "good" | "revoked" | "unknown"，无法查询或响应签名校验失败时为空
+    pub ocsp_status: Option<String>,
 }
 
 /// SSL 检查结果（包含连接状态）
@@ -200,6 +518,114 @@ pub struct SslCheckResult {
     pub cert_info: Option<SslCertInfo>,
     /// 错误信息（连接失败时）
     pub error: Option<String>,
+    /// 协商的 TLS 版本（如 "TLS 1.3"）
+    pub tls_version: Option<String>,
+    /// 协商的密码套件
+    pub cipher_suite: Option<String>,
+}
+
+/// TCP 端口状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PortStatus {
+    /// 连接成功
+    Open,
+    /// 连接被拒绝（RST）
+    Closed,
+    /// 连接超时或不可达，通常被防火墙丢弃
+    Filtered,
+}
+
+/// 单个地址的端口检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortCheckAddress {
+    pub address: String,
+    pub status: PortStatus,
+    /// 建立连接的耗时（毫秒，仅端口开放时存在）
+    pub latency_ms: Option<u64>,
+    pub error: Option<String>,
+}
+
+/// TCP 端口检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortCheckResult {
+    pub host: String,
+    pub port: u16,
+    /// 汇总状态：任一地址开放即为 open
+    pub status: PortStatus,
+    /// 主机名解析出的每个地址（IPv4 和 IPv6）的检查结果
+    pub addresses: Vec<PortCheckAddress>,
+}
+
+/// 批量证书检查中单个域名的有效期
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CertExpiryItem {
+    pub domain: String,
+    pub port: u16,
+    /// 证书剩余有效天数，检查失败时为空
+    pub days_remaining: Option<i64>,
+    pub valid_to: Option<String>,
+    /// 证书是否通过验证
+    pub is_valid: Option<bool>,
+    /// 检查失败的原因或首个证书验证错误
+    pub error: Option<String>,
+}
+
+/// 批量域名到期检查中单个域名的结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainExpiryItem {
+    pub domain: String,
+    pub registrar: Option<String>,
+    /// 注册到期时间（RFC3339），查询失败时为空
+    pub expiration_date: Option<String>,
+    /// 剩余天数，已过期时为负数
+    pub days_remaining: Option<i64>,
+    /// 是否在提醒阈值内到期（含已过期）
+    pub expiring_soon: bool,
+    /// 查询失败的原因
+    pub error: Option<String>,
+}
+
+/// HTTP 重定向链中的一跳
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpRedirectHop {
+    pub url: String,
+    pub status: u16,
+    /// 重定向目标（已解析为绝对地址）
+    pub location: String,
+}
+
+/// HTTP 响应头
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HttpHeader {
+    pub name: String,
+    pub value: String,
+}
+
+/// HTTP 检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpInspectResult {
+    /// 请求的 URL（补全协议后）
+    pub url: String,
+    /// 跟随重定向后的最终 URL
+    pub final_url: String,
+    /// 最终响应的状态码
+    pub status: u16,
+    pub redirects: Vec<HttpRedirectHop>,
+    /// 最终响应的 Server 头
+    pub server: Option<String>,
+    /// 最终响应的 Strict-Transport-Security 头，None 表示未启用 HSTS
+    pub hsts: Option<String>,
+    /// 最终响应的全部响应头
+    pub headers: Vec<HttpHeader>,
+    /// 总耗时（毫秒）
+    pub elapsed_ms: u64,
 }
 
 /// 证书链项
@@ -219,6 +645,12 @@ pub struct CertChainItem {
 pub struct BatchDeleteRequest {
     pub domain_id: String,
     pub record_ids: Vec<String>,
+    /// 同时进行的删除请求数，不填使用提供商默认值
+    #[serde(default)]
+    pub concurrency: Option<usize>,
+    /// 操作 ID，用于 `cancel_operation` 取消
+    #[serde(default)]
+    pub operation_id: Option<String>,
 }
 
 /// 批量删除结果
@@ -227,17 +659,108 @@ pub struct BatchDeleteRequest {
 pub struct BatchDeleteResult {
     pub success_count: usize,
     pub failed_count: usize,
-    pub failures: Vec<BatchDeleteFailure>,
+    /// 因操作取消而未删除的记录数
+    pub cancelled_count: usize,
+    pub failures: Vec<BatchFailure>,
+}
+
+/// 批量操作（删除、更新）的失败项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchFailure {
+    pub record_id: String,
+    pub reason: String,
+}
+
+/// 批量更新 DNS 记录请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchUpdateRequest {
+    pub updates: Vec<BatchUpdateItem>,
+    /// 同时进行的更新请求数，不填使用提供商默认值
+    #[serde(default)]
+    pub concurrency: Option<usize>,
+    /// 操作 ID，用于 `cancel_operation` 取消
+    #[serde(default)]
+    pub operation_id: Option<String>,
 }
 
-/// 批量删除失败项
+/// 批量更新中的单条记录
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct BatchDeleteFailure {
+pub struct BatchUpdateItem {
     pub record_id: String,
+    pub request: UpdateDnsRecordRequest,
+}
+
+/// 批量更新结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchUpdateResult {
+    pub success_count: usize,
+    pub failed_count: usize,
+    /// 因操作取消而未更新的记录数
+    pub cancelled_count: usize,
+    /// 更新成功后的记录
+    pub records: Vec<DnsRecord>,
+    pub failures: Vec<BatchFailure>,
+}
+
+// ============ 全局搜索相关类型 ============
+
+/// 全局搜索命中的记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GlobalSearchMatch {
+    pub account_id: String,
+    pub provider: String,
+    pub domain_id: String,
+    pub domain_name: String,
+    pub record: DnsRecord,
+}
+
+/// 全局搜索中失败的账号或域名
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GlobalSearchFailure {
+    pub account_id: String,
+    /// 为空表示获取域名列表失败
+    pub domain_id: Option<String>,
     pub reason: String,
 }
 
+/// 全局搜索结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GlobalSearchResult {
+    pub matches: Vec<GlobalSearchMatch>,
+    pub failures: Vec<GlobalSearchFailure>,
+    /// 搜索被取消，结果只包含已搜索的域名
+    pub cancelled: bool,
+}
+
+/// 账号下全部域名的记录（分页），附带获取失败的域名
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountRecordsResult {
+    #[serde(flatten)]
+    pub page: PaginatedResponse<GlobalSearchMatch>,
+    pub failures: Vec<GlobalSearchFailure>,
+}
+
+/// DDNS 更新结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DdnsUpdateResult {
+    /// 是否调用了更新接口（记录值已是当前 IP 时为 false）
+    pub updated: bool,
+    /// 检测到的公网 IP
+    pub ip: String,
+    /// 更新前的记录值
+    pub previous_value: String,
+    pub record: DnsRecord,
+}
+
 // ============ 导入导出相关类型 ============
 
 /// 单个账号的导出数据（包含凭证）
@@ -253,6 +776,23 @@ pub struct ExportedAccount {
     pub credentials: HashMap<String, String>,
 }
 
+/// 密钥派生算法及参数（随导出文件保存，调整参数后旧文件仍可解密）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "algorithm")]
+pub enum KdfParams {
+    /// PBKDF2-HMAC-SHA256（旧版导出文件）
+    #[serde(rename = "pbkdf2-sha256")]
+    Pbkdf2Sha256 { iterations: u32 },
+    /// Argon2id
+    #[serde(rename = "argon2id", rename_all = "camelCase")]
+    Argon2id {
+        /// 内存开销（KiB）
+        memory_kib: u32,
+        iterations: u32,
+        parallelism: u32,
+    },
+}
+
 /// 导出文件头部（明文部分）
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -267,6 +807,12 @@ pub struct ExportFileHeader {
     /// 加密时使用的 IV/Nonce（Base64 编码）
     #[serde(skip_serializing_if = "Option::is_none")]
     pub nonce: Option<String>,
+    /// 加密时使用的密钥派生算法及参数，旧版文件缺省为 PBKDF2
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kdf: Option<KdfParams>,
+    /// `data` 字段的 SHA-256 校验和（十六进制，v2 起）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
     /// 导出时间
     pub exported_at: String,
     /// 应用版本
@@ -314,6 +860,34 @@ pub struct ImportAccountsRequest {
     /// 解密密码（如果文件加密）
     #[serde(skip_serializing_if = "Option::is_none")]
     pub password: Option<String>,
+    /// 与现有账号冲突时的处理方式
+    #[serde(default)]
+    pub strategy: ImportStrategy,
+}
+
+/// 导入冲突处理方式（同一提供商下名称相同视为冲突）
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportStrategy {
+    /// 跳过冲突的账号
+    Skip,
+    /// 用导入的凭证覆盖现有账号
+    Overwrite,
+    /// 重命名后作为新账号导入
+    #[default]
+    Rename,
+}
+
+/// 单个账号的导入动作
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportAction {
+    /// 新建账号
+    Create,
+    /// 更新现有账号的凭证
+    Update,
+    /// 跳过
+    Skip,
 }
 
 /// 导入预览（用于显示将要导入的账号）
@@ -327,6 +901,9 @@ pub struct ImportPreview {
     /// 账号预览列表（仅在未加密或已解密后可用）
     #[serde(skip_serializing_if = "Option::is_none")]
     pub accounts: Option<Vec<ImportPreviewAccount>>,
+    /// 无法导入的账号（如提供商未知、凭证字段不匹配）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub invalid_accounts: Vec<ImportFailure>,
 }
 
 /// 导入预览中的账号信息（不含敏感凭证）
@@ -337,14 +914,24 @@ pub struct ImportPreviewAccount {
     pub provider: ProviderType,
     /// 是否与现有账号名称冲突
     pub has_conflict: bool,
+    /// 按所选冲突处理方式将执行的动作
+    pub action: ImportAction,
+    /// 导入后的账号名称（重命名时与原名称不同）
+    pub target_name: String,
 }
 
 /// 导入结果
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ImportResult {
-    /// 成功导入的账号数量
+    /// 成功导入的账号数量（新建 + 更新）
     pub success_count: usize,
+    /// 新建的账号数量
+    pub created_count: usize,
+    /// 覆盖更新的账号数量
+    pub updated_count: usize,
+    /// 因冲突跳过的账号数量
+    pub skipped_count: usize,
     /// 失败的账号及原因
     pub failures: Vec<ImportFailure>,
 }
@@ -356,3 +943,186 @@ pub struct ImportFailure {
     pub name: String,
     pub reason: String,
 }
+
+/// 刷新账号状态结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefreshAccountStatusesResult {
+    /// 状态正常的账号数量
+    pub active_count: usize,
+    /// 状态异常的账号数量
+    pub error_count: usize,
+    /// 刷新后的账号列表
+    pub accounts: Vec<Account>,
+}
+
+/// 账户恢复结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreAccountsResult {
+    /// 成功恢复的账号数量
+    pub restored_count: usize,
+    /// 恢复失败的账号数量
+    pub failed_count: usize,
+    /// 恢复后的账号列表
+    pub accounts: Vec<Account>,
+}
+
+/// 账号的 Provider 调用指标
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountMetrics {
+    pub account_id: String,
+    /// 请求总数
+    pub request_count: u64,
+    /// 失败的请求数
+    pub error_count: u64,
+    /// 平均耗时（毫秒）
+    pub average_latency_ms: u64,
+}
+
+// ============ 区域同步相关类型 ============
+
+/// 两个账号下同一区域的记录差异
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZoneDiff {
+    pub source_account_id: String,
+    pub source_domain_id: String,
+    pub target_account_id: String,
+    pub target_domain_id: String,
+    /// 目标区域缺少的记录（取自源区域）
+    pub to_add: Vec<DnsRecord>,
+    /// 名称和类型相同但内容不同的记录
+    pub to_update: Vec<ZoneRecordUpdate>,
+    /// 目标区域多出的记录
+    pub to_delete: Vec<DnsRecord>,
+    pub unchanged_count: usize,
+}
+
+/// 需要更新的记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZoneRecordUpdate {
+    /// 目标区域中的现有记录
+    pub current: DnsRecord,
+    /// 源区域中的期望记录
+    pub desired: DnsRecord,
+}
+
+/// 应用区域差异的结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplyZoneDiffResult {
+    pub dry_run: bool,
+    pub created_count: usize,
+    pub updated_count: usize,
+    pub deleted_count: usize,
+    pub failures: Vec<ZoneDiffFailure>,
+}
+
+/// 应用区域差异时的失败项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZoneDiffFailure {
+    /// 操作类型：create / update / delete
+    pub action: String,
+    pub name: String,
+    pub record_type: DnsRecordType,
+    pub reason: String,
+}
+
+/// 克隆区域时单条记录的处理结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CloneRecordStatus {
+    Created,
+    /// 目标区域已有冲突记录或不支持该记录类型
+    Skipped,
+    Failed,
+    /// 操作被取消，未创建
+    Cancelled,
+}
+
+/// 克隆区域时的单条记录报告
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloneZoneRecordResult {
+    pub name: String,
+    pub record_type: DnsRecordType,
+    pub value: String,
+    pub status: CloneRecordStatus,
+    /// 跳过或失败的原因
+    pub reason: Option<String>,
+}
+
+/// 克隆区域结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloneZoneResult {
+    pub created_count: usize,
+    pub skipped_count: usize,
+    pub failed_count: usize,
+    pub cancelled_count: usize,
+    pub records: Vec<CloneZoneRecordResult>,
+}
+
+// ============ 记录模板相关类型 ============
+
+/// 内置记录模板（如 Google Workspace 邮件、GitHub Pages）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordTemplate {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub records: Vec<RecordTemplateEntry>,
+}
+
+/// 模板中的单条记录，值中的 `{domain}` / `{domain_dashed}` 在应用时替换为域名
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordTemplateEntry {
+    #[serde(rename = "type")]
+    pub record_type: DnsRecordType,
+    pub name: String,
+    pub value: String,
+    pub ttl: u32,
+    #[serde(default)]
+    pub priority: Option<u16>,
+}
+
+/// 应用模板时单条记录的处理结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TemplateRecordStatus {
+    Created,
+    /// 域名下已有相同记录
+    Existing,
+    /// 与已有记录冲突（如 CNAME 与同名记录共存、已有其他 SPF 记录），未创建
+    Conflict,
+    Failed,
+}
+
+/// 应用模板时的单条记录报告
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateRecordResult {
+    pub name: String,
+    pub record_type: DnsRecordType,
+    pub value: String,
+    pub status: TemplateRecordStatus,
+    /// 冲突或失败的原因
+    pub reason: Option<String>,
+}
+
+/// 应用模板结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplyTemplateResult {
+    pub created_count: usize,
+    pub existing_count: usize,
+    pub conflict_count: usize,
+    pub failed_count: usize,
+    pub records: Vec<TemplateRecordResult>,
+}
@@ -0,0 +1 @@
+# Consumer rules for Android Keystore plugin
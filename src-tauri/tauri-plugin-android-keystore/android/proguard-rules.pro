@@ -0,0 +1 @@
+# Add project specific ProGuard rules here.
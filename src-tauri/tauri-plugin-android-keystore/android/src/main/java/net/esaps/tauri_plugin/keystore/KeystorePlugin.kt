@@ -0,0 +1,98 @@
+package net.esaps.tauri_plugin.keystore
+
+import android.app.Activity
+import android.security.keystore.KeyGenParameterSpec
+import android.security.keystore.KeyProperties
+import android.util.Base64
+import app.tauri.annotation.Command
+import app.tauri.annotation.InvokeArg
+import app.tauri.annotation.TauriPlugin
+import app.tauri.plugin.Invoke
+import app.tauri.plugin.JSObject
+import app.tauri.plugin.Plugin
+import java.security.KeyStore
+import javax.crypto.Cipher
+import javax.crypto.KeyGenerator
+import javax.crypto.SecretKey
+import javax.crypto.spec.GCMParameterSpec
+
+@InvokeArg
+class KeystoreArgs {
+    lateinit var data: String
+}
+
+@TauriPlugin
+class KeystorePlugin(private val activity: Activity) : Plugin(activity) {
+
+    companion object {
+        private const val KEYSTORE = "AndroidKeyStore"
+        private const val KEY_ALIAS = "dns-orchestrator-wrapping-key"
+        private const val TRANSFORMATION = "AES/GCM/NoPadding"
+        private const val IV_LENGTH = 12
+        private const val TAG_LENGTH_BITS = 128
+    }
+
+    /** 获取 Keystore 中的 AES 密钥，不存在时生成（不可导出） */
+    private fun secretKey(): SecretKey {
+        val keyStore = KeyStore.getInstance(KEYSTORE).apply { load(null) }
+        (keyStore.getKey(KEY_ALIAS, null) as? SecretKey)?.let { return it }
+
+        val generator = KeyGenerator.getInstance(KeyProperties.KEY_ALGORITHM_AES, KEYSTORE)
+        generator.init(
+            KeyGenParameterSpec.Builder(
+                KEY_ALIAS,
+                KeyProperties.PURPOSE_ENCRYPT or KeyProperties.PURPOSE_DECRYPT
+            )
+                .setBlockModes(KeyProperties.BLOCK_MODE_GCM)
+                .setEncryptionPaddings(KeyProperties.ENCRYPTION_PADDING_NONE)
+                .setKeySize(256)
+                .build()
+        )
+        return generator.generateKey()
+    }
+
+    /** 加密：输出为 IV || 密文（含 GCM tag） */
+    @Command
+    fun wrap(invoke: Invoke) {
+        try {
+            val args = invoke.parseArgs(KeystoreArgs::class.java)
+            val plain = Base64.decode(args.data, Base64.NO_WRAP)
+
+            val cipher = Cipher.getInstance(TRANSFORMATION)
+            cipher.init(Cipher.ENCRYPT_MODE, secretKey())
+            val wrapped = cipher.iv + cipher.doFinal(plain)
+
+            val result = JSObject()
+            result.put("data", Base64.encodeToString(wrapped, Base64.NO_WRAP))
+            invoke.resolve(result)
+        } catch (e: Exception) {
+            invoke.reject("Failed to wrap data: ${e.message}")
+        }
+    }
+
+    @Command
+    fun unwrap(invoke: Invoke) {
+        try {
+            val args = invoke.parseArgs(KeystoreArgs::class.java)
+            val wrapped = Base64.decode(args.data, Base64.NO_WRAP)
+            if (wrapped.size <= IV_LENGTH) {
+                invoke.reject("Wrapped data is too short")
+                return
+            }
+
+            val cipher = Cipher.getInstance(TRANSFORMATION)
+            cipher.init(
+                Cipher.DECRYPT_MODE,
+                secretKey(),
+                GCMParameterSpec(TAG_LENGTH_BITS, wrapped, 0, IV_LENGTH)
+            )
+            val plain = cipher.doFinal(wrapped, IV_LENGTH, wrapped.size - IV_LENGTH)
+
+            val result = JSObject()
+            result.put("data", Base64.encodeToString(plain, Base64.NO_WRAP))
+            invoke.resolve(result)
+        } catch (e: Exception) {
+            invoke.reject("Failed to unwrap data: ${e.message}")
+        }
+    }
+}
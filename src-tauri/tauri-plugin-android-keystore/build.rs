@@ -0,0 +1,8 @@
+// 仅供 Rust 端调用，不向前端暴露命令
+const COMMANDS: &[&str] = &[];
+
+fn main() {
+    tauri_plugin::Builder::new(COMMANDS)
+        .android_path("android")
+        .build();
+}
@@ -0,0 +1,69 @@
+//! Tauri Android Keystore Plugin
+//!
+//! 使用 Android Keystore 中不可导出的 AES 密钥加密/解密少量数据（如 Stronghold 快照密钥），
+//! 密钥材料不离开 Keystore。
+
+use tauri::{
+    plugin::{Builder, TauriPlugin},
+    Runtime,
+};
+
+#[cfg(mobile)]
+use tauri::Manager;
+
+mod models;
+
+#[cfg(mobile)]
+mod mobile;
+
+pub use models::*;
+
+/// 插件错误类型
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Plugin invoke error: {0}")]
+    PluginInvoke(#[from] tauri::plugin::mobile::PluginInvokeError),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl serde::Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.to_string().as_ref())
+    }
+}
+
+#[cfg(mobile)]
+pub use mobile::Keystore;
+
+/// 为 AppHandle 扩展 Keystore 方法
+#[cfg(mobile)]
+pub trait KeystoreExt<R: Runtime> {
+    fn keystore(&self) -> &Keystore<R>;
+}
+
+#[cfg(mobile)]
+impl<R: Runtime, T: Manager<R>> KeystoreExt<R> for T {
+    fn keystore(&self) -> &Keystore<R> {
+        self.state::<Keystore<R>>().inner()
+    }
+}
+
+/// 初始化插件
+pub fn init<R: Runtime>() -> TauriPlugin<R> {
+    Builder::new("android-keystore")
+        .setup(|app, api| {
+            #[cfg(mobile)]
+            {
+                let keystore = mobile::Keystore::new(app, api)?;
+                app.manage(keystore);
+            }
+            #[cfg(not(mobile))]
+            let _ = (app, api);
+            Ok(())
+        })
+        .build()
+}
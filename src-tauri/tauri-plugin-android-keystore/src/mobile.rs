@@ -0,0 +1,39 @@
+use tauri::{
+    plugin::{PluginApi, PluginHandle},
+    AppHandle, Runtime,
+};
+
+use crate::models::{KeystoreRequest, KeystoreResponse};
+
+#[cfg(target_os = "android")]
+const PLUGIN_IDENTIFIER: &str = "net.esaps.tauri_plugin.keystore";
+
+/// Android Keystore 移动端实现
+pub struct Keystore<R: Runtime>(PluginHandle<R>);
+
+impl<R: Runtime> Keystore<R> {
+    pub fn new(_app: &AppHandle<R>, api: PluginApi<R, ()>) -> crate::Result<Self> {
+        #[cfg(target_os = "android")]
+        let handle = api.register_android_plugin(PLUGIN_IDENTIFIER, "KeystorePlugin")?;
+
+        #[cfg(target_os = "ios")]
+        let handle = api.register_ios_plugin(())?;
+
+        Ok(Self(handle))
+    }
+
+    /// 用 Keystore 密钥加密数据，输入输出均为 Base64
+    pub fn wrap_key(&self, data: String) -> crate::Result<String> {
+        let response: KeystoreResponse =
+            self.0.run_mobile_plugin("wrap", KeystoreRequest { data })?;
+        Ok(response.data)
+    }
+
+    /// 用 Keystore 密钥解密 `wrap_key` 的结果，输入输出均为 Base64
+    pub fn unwrap_key(&self, data: String) -> crate::Result<String> {
+        let response: KeystoreResponse = self
+            .0
+            .run_mobile_plugin("unwrap", KeystoreRequest { data })?;
+        Ok(response.data)
+    }
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// 待加密/解密的数据（Base64）
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeystoreRequest {
+    pub data: String,
+}
+
+/// 加密/解密结果（Base64）
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeystoreResponse {
+    pub data: String,
+}
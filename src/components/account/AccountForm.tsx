@@ -91,7 +91,9 @@ export function AccountForm({ open, onOpenChange }: AccountFormProps) {
   }
 
   const isValid =
-    providerInfo?.requiredFields.every((field) => credentials[field.key]?.trim()) ?? false
+    providerInfo?.requiredFields.every(
+      (field) => field.optional || credentials[field.key]?.trim()
+    ) ?? false
 
   const handleOpenChange = (isOpen: boolean) => {
     if (!isOpen) {
@@ -162,7 +164,7 @@ export function AccountForm({ open, onOpenChange }: AccountFormProps) {
                   onChange={(e) => handleCredentialChange(field.key, e.target.value)}
                   placeholder={field.placeholder}
                   className={`pr-10 ${fieldErrors[field.key] ? "border-destructive" : ""}`}
-                  required
+                  required={!field.optional}
                 />
                 {field.type === "password" && (
                   <Button
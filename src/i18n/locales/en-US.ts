@@ -141,6 +141,7 @@ export default {
       NS: "Name Server",
       SRV: "Service Record",
       CAA: "CA Authorization",
+      ALIAS: "Apex Alias (CNAME flattening)",
     },
     // Toast messages
     fetchFailed: "Failed to fetch DNS records",
@@ -399,6 +400,7 @@ export default {
         network_error: "Network request failed: {{detail}}",
         invalid_credentials: "Invalid or expired credentials",
         record_exists: 'Record "{{record_name}}" already exists',
+        record_exists_with_value: 'Record "{{record_name}}" conflicts with an existing record: {{existing_value}}',
         record_not_found: "Record not found",
         invalid_parameter: 'Invalid parameter "{{param}}": {{detail}}',
         quota_exceeded: "Quota exceeded",
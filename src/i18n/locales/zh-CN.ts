@@ -137,6 +137,7 @@ export default {
       NS: "名称服务器",
       SRV: "服务记录",
       CAA: "CA 授权",
+      ALIAS: "根域别名（CNAME 展平）",
     },
     // Toast messages
     fetchFailed: "获取 DNS 记录失败",
@@ -393,6 +394,7 @@ export default {
         network_error: "网络请求失败: {{detail}}",
         invalid_credentials: "凭证无效或已过期",
         record_exists: '记录 "{{record_name}}" 已存在',
+        record_exists_with_value: '记录 "{{record_name}}" 与已有记录冲突: {{existing_value}}',
         record_not_found: "记录不存在",
         invalid_parameter: '参数 "{{param}}" 无效: {{detail}}',
         quota_exceeded: "配额已用尽",
@@ -43,7 +43,11 @@ function isProviderError(error: ApiError): error is ApiError & { details: Provid
  */
 function getProviderErrorMessage(details: ProviderErrorDetails): string {
   const provider = details.provider
-  const errorCode = toSnakeCase(details.code)
+  // 预检发现的冲突附带现有记录的值，使用带值的文案
+  const errorCode =
+    details.code === "RecordExists" && details.existing_value
+      ? "record_exists_with_value"
+      : toSnakeCase(details.code)
 
   // 构建翻译参数
   const params: Record<string, unknown> = { ...details }
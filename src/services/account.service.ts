@@ -4,14 +4,20 @@
 
 import type {
   Account,
+  AccountMetrics,
   ApiResponse,
   CreateAccountRequest,
+  CredentialTestResult,
   ExportAccountsRequest,
   ExportAccountsResponse,
   ImportAccountsRequest,
   ImportPreview,
   ImportResult,
+  ImportStrategy,
   ProviderInfo,
+  RefreshAccountStatusesResult,
+  RestoreAccountsResult,
+  UpdateAccountRequest,
 } from "@/types"
 import { transport } from "./transport"
 
@@ -24,6 +30,10 @@ class AccountService {
     return transport.invoke("create_account", { request })
   }
 
+  updateAccount(request: UpdateAccountRequest): Promise<ApiResponse<Account>> {
+    return transport.invoke("update_account", { request })
+  }
+
   deleteAccount(accountId: string): Promise<ApiResponse<void>> {
     return transport.invoke("delete_account", { accountId })
   }
@@ -32,17 +42,48 @@ class AccountService {
     return transport.invoke("list_providers")
   }
 
+  testCredentials(
+    provider: string,
+    credentials: Record<string, string>
+  ): Promise<ApiResponse<CredentialTestResult>> {
+    return transport.invoke("test_credentials", { provider, credentials })
+  }
+
   exportAccounts(request: ExportAccountsRequest): Promise<ApiResponse<ExportAccountsResponse>> {
     return transport.invoke("export_accounts", { request })
   }
 
-  previewImport(content: string, password: string | null): Promise<ApiResponse<ImportPreview>> {
-    return transport.invoke("preview_import", { content, password })
+  exportAllAccounts(
+    encrypt: boolean,
+    password?: string
+  ): Promise<ApiResponse<ExportAccountsResponse>> {
+    return transport.invoke("export_all_accounts", { encrypt, password })
+  }
+
+  previewImport(
+    content: string,
+    password: string | null,
+    strategy?: ImportStrategy
+  ): Promise<ApiResponse<ImportPreview>> {
+    return transport.invoke("preview_import", { content, password, strategy })
   }
 
   importAccounts(request: ImportAccountsRequest): Promise<ApiResponse<ImportResult>> {
     return transport.invoke("import_accounts", { request })
   }
+
+  refreshAccountStatuses(): Promise<ApiResponse<RefreshAccountStatusesResult>> {
+    return transport.invoke("refresh_account_statuses")
+  }
+
+  /** 重新读取账户存储和 Keychain，重建所有账号的 Provider；validate 为 true 时同时验证凭证 */
+  reloadCredentials(validate?: boolean): Promise<ApiResponse<RestoreAccountsResult>> {
+    return transport.invoke("reload_credentials", { validate })
+  }
+
+  getAccountMetrics(accountId: string): Promise<ApiResponse<AccountMetrics>> {
+    return transport.invoke("get_account_metrics", { accountId })
+  }
 }
 
 export const accountService = new AccountService()
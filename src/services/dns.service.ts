@@ -3,12 +3,22 @@
  */
 
 import type {
+  AccountRecordsResult,
   ApiResponse,
+  ApplyTemplateResult,
   BatchDeleteRequest,
   BatchDeleteResult,
+  BatchUpdateRequest,
+  BatchUpdateResult,
   CreateDnsRecordRequest,
+  DdnsUpdateResult,
   DnsRecord,
+  DnsRecordType,
+  GlobalSearchResult,
   PaginatedResponse,
+  RecordPatch,
+  RecordTemplate,
+  RecordSort,
   UpdateDnsRecordRequest,
 } from "@/types"
 import { transport } from "./transport"
@@ -20,6 +30,13 @@ export interface ListDnsRecordsParams {
   pageSize?: number
   keyword?: string | null
   recordType?: string | null
+  /** 游标分页的游标（上一页响应的 nextCursor） */
+  cursor?: string | null
+  /** 只返回该时间（RFC3339）之后修改过的记录，没有修改时间的记录总是返回 */
+  modifiedAfter?: string | null
+  /** 排序字段，不支持服务端排序的提供商只对当前页排序 */
+  sortBy?: RecordSort | null
+  sortDesc?: boolean
 }
 
 class DnsService {
@@ -42,16 +59,100 @@ class DnsService {
     return transport.invoke("update_dns_record", { accountId, recordId, request })
   }
 
+  patchRecord(
+    accountId: string,
+    domainId: string,
+    recordId: string,
+    changes: RecordPatch
+  ): Promise<ApiResponse<DnsRecord>> {
+    return transport.invoke("patch_dns_record", { accountId, domainId, recordId, changes })
+  }
+
   deleteRecord(accountId: string, recordId: string, domainId: string): Promise<ApiResponse<void>> {
     return transport.invoke("delete_dns_record", { accountId, recordId, domainId })
   }
 
+  toggleRecord(
+    accountId: string,
+    domainId: string,
+    recordId: string,
+    enabled: boolean
+  ): Promise<ApiResponse<void>> {
+    return transport.invoke("toggle_dns_record", { accountId, domainId, recordId, enabled })
+  }
+
   batchDeleteRecords(
     accountId: string,
     request: BatchDeleteRequest
   ): Promise<ApiResponse<BatchDeleteResult>> {
     return transport.invoke("batch_delete_dns_records", { accountId, request })
   }
+
+  batchUpdateRecords(
+    accountId: string,
+    request: BatchUpdateRequest
+  ): Promise<ApiResponse<BatchUpdateResult>> {
+    return transport.invoke("batch_update_dns_records", { accountId, request })
+  }
+
+  listRecordTemplates(): Promise<ApiResponse<RecordTemplate[]>> {
+    return transport.invoke("list_record_templates")
+  }
+
+  applyRecordTemplate(
+    accountId: string,
+    domainId: string,
+    templateId: string
+  ): Promise<ApiResponse<ApplyTemplateResult>> {
+    return transport.invoke("apply_record_template", { accountId, domainId, templateId })
+  }
+
+  searchRecordsGlobal(
+    keyword: string,
+    recordType?: DnsRecordType,
+    operationId?: string
+  ): Promise<ApiResponse<GlobalSearchResult>> {
+    return transport.invoke("search_records_global", { keyword, recordType, operationId })
+  }
+
+  /** 列出账号下所有域名的记录（合并分页） */
+  listAccountRecords(
+    accountId: string,
+    keyword?: string,
+    recordType?: DnsRecordType,
+    page?: number,
+    pageSize?: number
+  ): Promise<ApiResponse<AccountRecordsResult>> {
+    return transport.invoke("list_account_records", {
+      accountId,
+      keyword,
+      recordType,
+      page,
+      pageSize,
+    })
+  }
+
+  /** 取消进行中的批量操作，返回是否找到该操作 */
+  cancelOperation(operationId: string): Promise<ApiResponse<boolean>> {
+    return transport.invoke("cancel_operation", { operationId })
+  }
+
+  /** 将 A/AAAA 记录指向本机当前的公网 IP，同名记录有多条时需要传入 recordId */
+  ddnsUpdate(
+    accountId: string,
+    domainId: string,
+    recordName: string,
+    recordType: DnsRecordType,
+    recordId?: string
+  ): Promise<ApiResponse<DdnsUpdateResult>> {
+    return transport.invoke("ddns_update", {
+      accountId,
+      domainId,
+      recordName,
+      recordType,
+      recordId,
+    })
+  }
 }
 
 export const dnsService = new DnsService()
@@ -2,21 +2,30 @@
  * 域名服务
  */
 
-import type { ApiResponse, Domain, PaginatedResponse } from "@/types"
+import type { ApiResponse, Domain, PaginatedResponse, RecordLine } from "@/types"
 import { transport } from "./transport"
 
 class DomainService {
   listDomains(
     accountId: string,
     page?: number,
-    pageSize?: number
+    pageSize?: number,
+    cursor?: string | null
   ): Promise<ApiResponse<PaginatedResponse<Domain>>> {
-    return transport.invoke("list_domains", { accountId, page, pageSize })
+    return transport.invoke("list_domains", { accountId, page, pageSize, cursor })
   }
 
   getDomain(accountId: string, domainId: string): Promise<ApiResponse<Domain>> {
     return transport.invoke("get_domain", { accountId, domainId })
   }
+
+  getDomainNameservers(accountId: string, domainId: string): Promise<ApiResponse<string[]>> {
+    return transport.invoke("get_domain_nameservers", { accountId, domainId })
+  }
+
+  listRecordLines(accountId: string, domainId: string): Promise<ApiResponse<RecordLine[]>> {
+    return transport.invoke("list_record_lines", { accountId, domainId })
+  }
 }
 
 export const domainService = new DomainService()
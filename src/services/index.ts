@@ -5,6 +5,7 @@
 export { accountService } from "./account.service"
 export { dnsService, type ListDnsRecordsParams } from "./dns.service"
 export { domainService } from "./domain.service"
+export { settingsService } from "./settings.service"
 export { toolboxService } from "./toolbox.service"
 
 // Transport 相关类型导出
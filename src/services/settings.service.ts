@@ -0,0 +1,26 @@
+/**
+ * 设置服务
+ */
+
+import type { ApiResponse, IpGeoConfig, ProviderClientConfig } from "@/types"
+import { transport } from "./transport"
+
+class SettingsService {
+  getNetworkConfig(): Promise<ApiResponse<ProviderClientConfig>> {
+    return transport.invoke("get_network_config")
+  }
+
+  setNetworkConfig(config: ProviderClientConfig): Promise<ApiResponse<ProviderClientConfig>> {
+    return transport.invoke("set_network_config", { config })
+  }
+
+  getIpGeoConfig(): Promise<ApiResponse<IpGeoConfig>> {
+    return transport.invoke("get_ip_geo_config")
+  }
+
+  setIpGeoConfig(config: IpGeoConfig): Promise<ApiResponse<IpGeoConfig>> {
+    return transport.invoke("set_ip_geo_config", { config })
+  }
+}
+
+export const settingsService = new SettingsService()
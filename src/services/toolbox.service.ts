@@ -4,8 +4,19 @@
 
 import type {
   ApiResponse,
+  AxfrResult,
+  CertExpiryItem,
   DnsLookupResult,
+  DnsRecord,
+  DnsRecordType,
+  DomainExpiryItem,
+  EmailAuthResult,
+  HttpInspectResult,
   IpLookupResult,
+  IpWhoisResult,
+  PortCheckResult,
+  PropagationResult,
+  RdapResult,
   SslCheckResult,
   WhoisResult,
 } from "@/types"
@@ -16,20 +27,85 @@ class ToolboxService {
     return transport.invoke("whois_lookup", { domain })
   }
 
+  rdapLookup(domain: string): Promise<ApiResponse<RdapResult>> {
+    return transport.invoke("rdap_lookup", { domain })
+  }
+
   dnsLookup(
     domain: string,
     recordType: string,
-    nameserver: string | null
+    nameserver: string | null,
+    protocol?: "udp" | "doh" | "dot",
+    trace?: boolean
   ): Promise<ApiResponse<DnsLookupResult>> {
-    return transport.invoke("dns_lookup", { domain, recordType, nameserver })
+    return transport.invoke("dns_lookup", { domain, recordType, nameserver, protocol, trace })
+  }
+
+  /** 通过提供商 API 读取记录，得到传播完成前的预期结果 */
+  resolveViaProvider(
+    accountId: string,
+    domainId: string,
+    name: string,
+    recordType?: DnsRecordType
+  ): Promise<ApiResponse<DnsRecord[]>> {
+    return transport.invoke("resolve_via_provider", { accountId, domainId, name, recordType })
+  }
+
+  /** 向权威服务器发起区域传送（AXFR），导出 zone 的全部记录 */
+  axfrTransfer(nameserver: string, zone: string): Promise<ApiResponse<AxfrResult>> {
+    return transport.invoke("axfr_transfer", { nameserver, zone })
+  }
+
+  emailAuthCheck(domain: string, dkimSelector?: string): Promise<ApiResponse<EmailAuthResult>> {
+    return transport.invoke("email_auth_check", { domain, dkimSelector })
+  }
+
+  checkPropagation(
+    domain: string,
+    recordType: string,
+    expectedValue?: string
+  ): Promise<ApiResponse<PropagationResult>> {
+    return transport.invoke("check_propagation", { domain, recordType, expectedValue })
   }
 
   ipLookup(query: string): Promise<ApiResponse<IpLookupResult>> {
     return transport.invoke("ip_lookup", { query })
   }
 
-  sslCheck(domain: string, port?: number): Promise<ApiResponse<SslCheckResult>> {
-    return transport.invoke("ssl_check", { domain, port })
+  ipWhois(ip: string): Promise<ApiResponse<IpWhoisResult>> {
+    return transport.invoke("ip_whois", { ip })
+  }
+
+  sslCheck(
+    domain: string,
+    port?: number,
+    serverName?: string,
+    connectAddr?: string
+  ): Promise<ApiResponse<SslCheckResult>> {
+    return transport.invoke("ssl_check", { domain, port, serverName, connectAddr })
+  }
+
+  /** 批量检查证书有效期，结果按剩余天数排序 */
+  checkCertsBulk(domains: string[], port?: number): Promise<ApiResponse<CertExpiryItem[]>> {
+    return transport.invoke("check_certs_bulk", { domains, port })
+  }
+
+  /** 批量检查域名注册到期时间，结果按剩余天数排序 */
+  domainExpiryReport(
+    domains: string[],
+    thresholdDays?: number
+  ): Promise<ApiResponse<DomainExpiryItem[]>> {
+    return transport.invoke("domain_expiry_report", { domains, thresholdDays })
+  }
+
+  /** TCP 端口连通性检查 */
+  portCheck(host: string, port: number, timeoutMs?: number): Promise<ApiResponse<PortCheckResult>> {
+    return transport.invoke("port_check", { host, port, timeoutMs })
+  }
+
+  /** 检查 URL 的重定向链、响应头和 HSTS */
+  httpInspect(url: string): Promise<ApiResponse<HttpInspectResult>> {
+    return transport.invoke("http_inspect", { url })
   }
 }
 
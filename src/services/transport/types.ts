@@ -5,23 +5,51 @@
 
 import type {
   Account,
+  AccountMetrics,
+  AccountRecordsResult,
   ApiResponse,
+  ApplyTemplateResult,
+  AxfrResult,
+  CertExpiryItem,
   BatchDeleteRequest,
   BatchDeleteResult,
+  BatchUpdateRequest,
+  BatchUpdateResult,
   CreateAccountRequest,
   CreateDnsRecordRequest,
+  CredentialTestResult,
+  DdnsUpdateResult,
   DnsLookupResult,
   DnsRecord,
+  DnsRecordType,
   Domain,
+  DomainExpiryItem,
+  EmailAuthResult,
   ExportAccountsRequest,
   ExportAccountsResponse,
+  GlobalSearchResult,
+  HttpInspectResult,
   ImportAccountsRequest,
   ImportPreview,
   ImportResult,
+  ImportStrategy,
+  IpGeoConfig,
   IpLookupResult,
+  IpWhoisResult,
   PaginatedResponse,
+  PortCheckResult,
+  PropagationResult,
+  ProviderClientConfig,
   ProviderInfo,
+  RdapResult,
+  RecordLine,
+  RecordPatch,
+  RecordSort,
+  RecordTemplate,
+  RefreshAccountStatusesResult,
+  RestoreAccountsResult,
   SslCheckResult,
+  UpdateAccountRequest,
   UpdateDnsRecordRequest,
   WhoisResult,
 } from "@/types"
@@ -39,6 +67,10 @@ export interface CommandMap {
     args: { request: CreateAccountRequest }
     result: ApiResponse<Account>
   }
+  update_account: {
+    args: { request: UpdateAccountRequest }
+    result: ApiResponse<Account>
+  }
   delete_account: {
     args: { accountId: string }
     result: ApiResponse<void>
@@ -47,28 +79,56 @@ export interface CommandMap {
     args: Record<string, never>
     result: ApiResponse<ProviderInfo[]>
   }
+  test_credentials: {
+    args: { provider: string; credentials: Record<string, string> }
+    result: ApiResponse<CredentialTestResult>
+  }
   export_accounts: {
     args: { request: ExportAccountsRequest }
     result: ApiResponse<ExportAccountsResponse>
   }
+  export_all_accounts: {
+    args: { encrypt: boolean; password?: string }
+    result: ApiResponse<ExportAccountsResponse>
+  }
   preview_import: {
-    args: { content: string; password: string | null }
+    args: { content: string; password: string | null; strategy?: ImportStrategy }
     result: ApiResponse<ImportPreview>
   }
   import_accounts: {
     args: { request: ImportAccountsRequest }
     result: ApiResponse<ImportResult>
   }
+  refresh_account_statuses: {
+    args: Record<string, never>
+    result: ApiResponse<RefreshAccountStatusesResult>
+  }
+  reload_credentials: {
+    args: { validate?: boolean }
+    result: ApiResponse<RestoreAccountsResult>
+  }
+  get_account_metrics: {
+    args: { accountId: string }
+    result: ApiResponse<AccountMetrics>
+  }
 
   // Domain commands
   list_domains: {
-    args: { accountId: string; page?: number; pageSize?: number }
+    args: { accountId: string; page?: number; pageSize?: number; cursor?: string | null }
     result: ApiResponse<PaginatedResponse<Domain>>
   }
   get_domain: {
     args: { accountId: string; domainId: string }
     result: ApiResponse<Domain>
   }
+  get_domain_nameservers: {
+    args: { accountId: string; domainId: string }
+    result: ApiResponse<string[]>
+  }
+  list_record_lines: {
+    args: { accountId: string; domainId: string }
+    result: ApiResponse<RecordLine[]>
+  }
 
   // DNS commands
   list_dns_records: {
@@ -79,6 +139,10 @@ export interface CommandMap {
       pageSize?: number
       keyword?: string | null
       recordType?: string | null
+      cursor?: string | null
+      modifiedAfter?: string | null
+      sortBy?: RecordSort | null
+      sortDesc?: boolean
     }
     result: ApiResponse<PaginatedResponse<DnsRecord>>
   }
@@ -90,32 +154,149 @@ export interface CommandMap {
     args: { accountId: string; recordId: string; request: UpdateDnsRecordRequest }
     result: ApiResponse<DnsRecord>
   }
+  patch_dns_record: {
+    args: { accountId: string; domainId: string; recordId: string; changes: RecordPatch }
+    result: ApiResponse<DnsRecord>
+  }
   delete_dns_record: {
     args: { accountId: string; recordId: string; domainId: string }
     result: ApiResponse<void>
   }
+  toggle_dns_record: {
+    args: { accountId: string; domainId: string; recordId: string; enabled: boolean }
+    result: ApiResponse<void>
+  }
   batch_delete_dns_records: {
     args: { accountId: string; request: BatchDeleteRequest }
     result: ApiResponse<BatchDeleteResult>
   }
+  batch_update_dns_records: {
+    args: { accountId: string; request: BatchUpdateRequest }
+    result: ApiResponse<BatchUpdateResult>
+  }
+  list_record_templates: {
+    args: Record<string, never>
+    result: ApiResponse<RecordTemplate[]>
+  }
+  apply_record_template: {
+    args: { accountId: string; domainId: string; templateId: string }
+    result: ApiResponse<ApplyTemplateResult>
+  }
+  search_records_global: {
+    args: { keyword: string; recordType?: DnsRecordType; operationId?: string }
+    result: ApiResponse<GlobalSearchResult>
+  }
+  list_account_records: {
+    args: {
+      accountId: string
+      keyword?: string
+      recordType?: DnsRecordType
+      page?: number
+      pageSize?: number
+    }
+    result: ApiResponse<AccountRecordsResult>
+  }
+  cancel_operation: {
+    args: { operationId: string }
+    result: ApiResponse<boolean>
+  }
+  ddns_update: {
+    args: {
+      accountId: string
+      domainId: string
+      recordName: string
+      recordType: DnsRecordType
+      recordId?: string
+    }
+    result: ApiResponse<DdnsUpdateResult>
+  }
+
+  // Settings commands
+  get_network_config: {
+    args: Record<string, never>
+    result: ApiResponse<ProviderClientConfig>
+  }
+  set_network_config: {
+    args: { config: ProviderClientConfig }
+    result: ApiResponse<ProviderClientConfig>
+  }
+  get_ip_geo_config: {
+    args: Record<string, never>
+    result: ApiResponse<IpGeoConfig>
+  }
+  set_ip_geo_config: {
+    args: { config: IpGeoConfig }
+    result: ApiResponse<IpGeoConfig>
+  }
 
   // Toolbox commands
   whois_lookup: {
     args: { domain: string }
     result: ApiResponse<WhoisResult>
   }
+  rdap_lookup: {
+    args: { domain: string }
+    result: ApiResponse<RdapResult>
+  }
   dns_lookup: {
-    args: { domain: string; recordType: string; nameserver: string | null }
+    args: {
+      domain: string
+      recordType: string
+      nameserver: string | null
+      protocol?: string
+      trace?: boolean
+    }
     result: ApiResponse<DnsLookupResult>
   }
+  resolve_via_provider: {
+    args: {
+      accountId: string
+      domainId: string
+      name: string
+      recordType?: DnsRecordType
+    }
+    result: ApiResponse<DnsRecord[]>
+  }
+  axfr_transfer: {
+    args: { nameserver: string; zone: string }
+    result: ApiResponse<AxfrResult>
+  }
+  email_auth_check: {
+    args: { domain: string; dkimSelector?: string }
+    result: ApiResponse<EmailAuthResult>
+  }
+  check_propagation: {
+    args: { domain: string; recordType: string; expectedValue?: string }
+    result: ApiResponse<PropagationResult>
+  }
   ip_lookup: {
     args: { query: string }
     result: ApiResponse<IpLookupResult>
   }
+  ip_whois: {
+    args: { ip: string }
+    result: ApiResponse<IpWhoisResult>
+  }
   ssl_check: {
-    args: { domain: string; port?: number }
+    args: { domain: string; port?: number; serverName?: string; connectAddr?: string }
     result: ApiResponse<SslCheckResult>
   }
+  check_certs_bulk: {
+    args: { domains: string[]; port?: number }
+    result: ApiResponse<CertExpiryItem[]>
+  }
+  domain_expiry_report: {
+    args: { domains: string[]; thresholdDays?: number }
+    result: ApiResponse<DomainExpiryItem[]>
+  }
+  port_check: {
+    args: { host: string; port: number; timeoutMs?: number }
+    result: ApiResponse<PortCheckResult>
+  }
+  http_inspect: {
+    args: { url: string }
+    result: ApiResponse<HttpInspectResult>
+  }
 }
 
 // ============ 类型工具 ============
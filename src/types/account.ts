@@ -10,6 +10,8 @@ export interface Account {
   updatedAt: string
   status?: AccountStatus
   error?: string
+  /** 账号可用但存在问题时的提示（如凭证缺少写入权限） */
+  warning?: string
 }
 
 /** 创建账号请求 */
@@ -19,6 +21,22 @@ export interface CreateAccountRequest {
   credentials: Record<string, string>
 }
 
+/** 凭证的权限信息 */
+export interface PermissionInfo {
+  /** 已授予的权限名称（如 Cloudflare 的 "DNS Write"） */
+  permissions: string[]
+  /** 是否可以修改 DNS 记录，无法确定时为空 */
+  canWriteDns?: boolean | null
+}
+
+/** 凭证测试结果（不保存账号） */
+export interface CredentialTestResult {
+  /** 凭证是否有效 */
+  valid: boolean
+  /** 凭证拥有的权限，提供商不支持或凭证无效时为空 */
+  permissions?: PermissionInfo | null
+}
+
 /** 更新账号请求 */
 export interface UpdateAccountRequest {
   id: string
@@ -45,13 +63,23 @@ export interface ExportAccountsResponse {
 export interface ImportAccountsRequest {
   content: string
   password?: string
+  /** 与现有账号冲突时的处理方式，默认 rename */
+  strategy?: ImportStrategy
 }
 
+/** 导入冲突处理方式（同一提供商下名称相同视为冲突） */
+export type ImportStrategy = "skip" | "overwrite" | "rename"
+
+/** 单个账号的导入动作 */
+export type ImportAction = "create" | "update" | "skip"
+
 /** 导入预览 */
 export interface ImportPreview {
   encrypted: boolean
   accountCount: number
   accounts?: ImportPreviewAccount[]
+  /** 无法导入的账号（如提供商未知、凭证字段不匹配） */
+  invalidAccounts?: ImportFailure[]
 }
 
 /** 导入预览账号 */
@@ -59,14 +87,45 @@ export interface ImportPreviewAccount {
   name: string
   provider: string
   hasConflict: boolean
+  /** 按所选冲突处理方式将执行的动作 */
+  action: ImportAction
+  /** 导入后的账号名称（重命名时与原名称不同） */
+  targetName: string
 }
 
 /** 导入结果 */
 export interface ImportResult {
+  /** 成功导入的账号数量（新建 + 更新） */
   successCount: number
+  createdCount: number
+  updatedCount: number
+  skippedCount: number
   failures: ImportFailure[]
 }
 
+/** 刷新账号状态结果 */
+export interface RefreshAccountStatusesResult {
+  activeCount: number
+  errorCount: number
+  accounts: Account[]
+}
+
+/** 账户恢复结果 */
+export interface RestoreAccountsResult {
+  restoredCount: number
+  failedCount: number
+  accounts: Account[]
+}
+
+/** 账号的 Provider 调用指标 */
+export interface AccountMetrics {
+  accountId: string
+  requestCount: number
+  errorCount: number
+  /** 平均耗时（毫秒） */
+  averageLatencyMs: number
+}
+
 /** 导入失败项 */
 export interface ImportFailure {
   name: string
@@ -1,8 +1,21 @@
 /** DNS 记录类型枚举 */
-export type DnsRecordType = "A" | "AAAA" | "CNAME" | "MX" | "TXT" | "NS" | "SRV" | "CAA"
+export type DnsRecordType = "A" | "AAAA" | "CNAME" | "MX" | "TXT" | "NS" | "SRV" | "CAA" | "ALIAS"
 
 /** 所有可用的记录类型列表 */
-export const RECORD_TYPES: DnsRecordType[] = ["A", "AAAA", "CNAME", "MX", "TXT", "NS", "SRV", "CAA"]
+export const RECORD_TYPES: DnsRecordType[] = [
+  "A",
+  "AAAA",
+  "CNAME",
+  "MX",
+  "TXT",
+  "NS",
+  "SRV",
+  "CAA",
+  "ALIAS",
+]
+
+/** DNS 记录排序字段 */
+export type RecordSort = "name" | "type" | "ttl"
 
 /** DNS 记录 */
 export interface DnsRecord {
@@ -10,10 +23,18 @@ export interface DnsRecord {
   domainId: string
   type: DnsRecordType
   name: string
+  /** 完整域名（如 www.example.com，根域为域名本身），列表接口总是返回 */
+  fqdn?: string
   value: string
   ttl: number
   priority?: number
   proxied?: boolean
+  /** 解析线路（阿里云 / DNSPod），不填为默认线路 */
+  line?: string
+  /** 备注（DNSPod） */
+  remark?: string
+  /** 是否启用，未返回表示提供商不支持暂停记录 */
+  enabled?: boolean
   createdAt?: string
   updatedAt?: string
 }
@@ -27,6 +48,10 @@ export interface CreateDnsRecordRequest {
   ttl: number
   priority?: number
   proxied?: boolean
+  /** 解析线路（阿里云 / DNSPod），不填为默认线路 */
+  line?: string
+  /** 备注（DNSPod） */
+  remark?: string
 }
 
 /** 更新 DNS 记录请求 */
@@ -38,27 +63,109 @@ export interface UpdateDnsRecordRequest {
   ttl: number
   priority?: number
   proxied?: boolean
+  /** 解析线路（阿里云 / DNSPod），不填为默认线路 */
+  line?: string
+  /** 备注（DNSPod） */
+  remark?: string
+}
+
+/** DNS 记录的部分更新，只包含需要修改的字段；可选字段传 null 表示清空 */
+export interface RecordPatch {
+  name?: string
+  value?: string
+  ttl?: number
+  priority?: number | null
+  proxied?: boolean | null
+  line?: string | null
+  remark?: string | null
 }
 
 /** 批量删除请求 */
 export interface BatchDeleteRequest {
   domainId: string
   recordIds: string[]
+  /** 同时进行的删除请求数，不填使用提供商默认值 */
+  concurrency?: number
+  /** 操作 ID，用于 cancel_operation 取消 */
+  operationId?: string
 }
 
 /** 批量删除结果 */
 export interface BatchDeleteResult {
   successCount: number
   failedCount: number
-  failures: BatchDeleteFailure[]
+  /** 因操作取消而未删除的记录数 */
+  cancelledCount: number
+  failures: BatchFailure[]
+}
+
+/** 批量操作（删除、更新）的失败项 */
+export interface BatchFailure {
+  recordId: string
+  reason: string
 }
 
-/** 批量删除失败项 */
-export interface BatchDeleteFailure {
+/** 批量更新 DNS 记录请求 */
+export interface BatchUpdateRequest {
+  updates: BatchUpdateItem[]
+  /** 同时进行的更新请求数，不填使用提供商默认值 */
+  concurrency?: number
+  /** 操作 ID，用于 cancel_operation 取消 */
+  operationId?: string
+}
+
+/** 批量更新中的单条记录 */
+export interface BatchUpdateItem {
   recordId: string
+  request: UpdateDnsRecordRequest
+}
+
+/** 批量更新结果 */
+export interface BatchUpdateResult {
+  successCount: number
+  failedCount: number
+  /** 因操作取消而未更新的记录数 */
+  cancelledCount: number
+  /** 更新成功后的记录 */
+  records: DnsRecord[]
+  failures: BatchFailure[]
+}
+
+/** 全局搜索命中的记录 */
+export interface GlobalSearchMatch {
+  accountId: string
+  provider: string
+  domainId: string
+  domainName: string
+  record: DnsRecord
+}
+
+/** 全局搜索失败项（domainId 为空表示获取域名列表失败） */
+export interface GlobalSearchFailure {
+  accountId: string
+  domainId?: string
   reason: string
 }
 
+/** 全局搜索结果 */
+export interface GlobalSearchResult {
+  matches: GlobalSearchMatch[]
+  failures: GlobalSearchFailure[]
+  /** 搜索被取消，结果只包含已搜索的域名 */
+  cancelled: boolean
+}
+
+/** DDNS 更新结果 */
+export interface DdnsUpdateResult {
+  /** 是否调用了更新接口（记录值已是当前 IP 时为 false） */
+  updated: boolean
+  /** 检测到的公网 IP */
+  ip: string
+  /** 更新前的记录值 */
+  previousValue: string
+  record: DnsRecord
+}
+
 /** 常用 TTL 选项 */
 export const TTL_OPTIONS = [
   { value: 1, labelKey: "dns.ttlAuto" },
@@ -84,4 +191,44 @@ export const RECORD_TYPE_INFO: Record<DnsRecordType, { descriptionKey: string; e
     NS: { descriptionKey: "dns.recordTypes.NS", example: "ns1.example.com" },
     SRV: { descriptionKey: "dns.recordTypes.SRV", example: "0 5 5060 sip.example.com" },
     CAA: { descriptionKey: "dns.recordTypes.CAA", example: '0 issue "letsencrypt.org"' },
+    ALIAS: { descriptionKey: "dns.recordTypes.ALIAS", example: "target.example.com" },
   }
+
+/** 内置记录模板（如 Google Workspace 邮件、GitHub Pages） */
+export interface RecordTemplate {
+  id: string
+  name: string
+  description: string
+  records: RecordTemplateEntry[]
+}
+
+/** 模板中的单条记录，值中的 {domain} / {domain_dashed} 在应用时替换为域名 */
+export interface RecordTemplateEntry {
+  type: DnsRecordType
+  name: string
+  value: string
+  ttl: number
+  priority?: number | null
+}
+
+/** 应用模板时单条记录的处理结果 */
+export type TemplateRecordStatus = "created" | "existing" | "conflict" | "failed"
+
+/** 应用模板时的单条记录报告 */
+export interface TemplateRecordResult {
+  name: string
+  recordType: DnsRecordType
+  value: string
+  status: TemplateRecordStatus
+  /** 冲突或失败的原因 */
+  reason?: string | null
+}
+
+/** 应用模板结果 */
+export interface ApplyTemplateResult {
+  createdCount: number
+  existingCount: number
+  conflictCount: number
+  failedCount: number
+  records: TemplateRecordResult[]
+}
@@ -10,4 +10,21 @@ export interface Domain {
   status: DomainStatus
   recordCount?: number
   createdAt?: string
+  /** punycode 域名解码后的显示名称，与 name 相同时为空 */
+  unicodeName?: string
+}
+
+/** 解析线路 */
+export interface RecordLine {
+  /** 创建记录时 line 字段传入的值 */
+  id: string
+  name: string
+}
+
+/** DNSSEC 状态 */
+export interface DnssecStatus {
+  enabled: boolean
+  /** 需要在注册商处配置的 DS 记录 */
+  dsRecords: string[]
+  algorithm?: string
 }
@@ -1,3 +1,5 @@
+import type { GlobalSearchFailure, GlobalSearchMatch } from "./dns"
+
 export * from "./account"
 export * from "./dns"
 export * from "./domain"
@@ -26,6 +28,7 @@ export type DnsErrorCode =
   | "ImportExportError"
   | "NoAccountsSelected" // 导出时没有选中账号
   | "UnsupportedFileVersion" // 导入时版本不支持
+  | "NotSupported" // Provider 不支持该功能
   | "Provider" // ProviderError 变体
 
 /** 凭证验证错误详情 */
@@ -47,41 +50,61 @@ export type ProviderErrorCode =
   | "PermissionDenied"
   | "ParseError"
   | "SerializationError"
+  | "NotSupported"
   | "Unknown"
 
 /** Provider 错误详情（根据 code 不同，结构不同） */
 export type ProviderErrorDetails =
   | { code: "NetworkError"; provider: string; detail: string }
-  | { code: "InvalidCredentials"; provider: string; raw_message?: string }
+  | { code: "InvalidCredentials"; provider: string; raw_message?: string; request_id?: string }
   | {
       code: "RecordExists"
       provider: string
       record_name: string
       raw_message?: string
+      /** 冲突记录的类型和当前值（创建前预检发现冲突时存在） */
+      existing_value?: string
+      request_id?: string
     }
   | {
       code: "RecordNotFound"
       provider: string
       record_id: string
       raw_message?: string
+      request_id?: string
     }
   | {
       code: "InvalidParameter"
       provider: string
       param: string
       detail: string
+      request_id?: string
+    }
+  | { code: "QuotaExceeded"; provider: string; raw_message?: string; request_id?: string }
+  | {
+      code: "DomainNotFound"
+      provider: string
+      domain: string
+      raw_message?: string
+      request_id?: string
     }
-  | { code: "QuotaExceeded"; provider: string; raw_message?: string }
-  | { code: "DomainNotFound"; provider: string; domain: string; raw_message?: string }
-  | { code: "DomainLocked"; provider: string; domain: string; raw_message?: string }
-  | { code: "PermissionDenied"; provider: string; raw_message?: string }
+  | {
+      code: "DomainLocked"
+      provider: string
+      domain: string
+      raw_message?: string
+      request_id?: string
+    }
+  | { code: "PermissionDenied"; provider: string; raw_message?: string; request_id?: string }
   | { code: "ParseError"; provider: string; detail: string }
   | { code: "SerializationError"; provider: string; detail: string }
+  | { code: "NotSupported"; provider: string; capability: string }
   | {
       code: "Unknown"
       provider: string
       raw_code?: string
       raw_message: string
+      request_id?: string
     }
 
 /** API 错误（匹配后端 DnsError 序列化格式） */
@@ -90,6 +113,7 @@ export interface ApiError {
   details?:
     | string
     | { provider: string; message: string }
+    | { provider: string; capability: string }
     | ProviderErrorDetails
     | CredentialValidationDetails
 }
@@ -107,4 +131,11 @@ export interface PaginatedResponse<T> {
   pageSize: number
   totalCount: number
   hasMore: boolean
+  /** 下一页的游标（仅游标分页的提供商返回） */
+  nextCursor?: string
+}
+
+/** 账号下全部域名的记录（分页），附带获取失败的域名 */
+export interface AccountRecordsResult extends PaginatedResponse<GlobalSearchMatch> {
+  failures: GlobalSearchFailure[]
 }
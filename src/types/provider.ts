@@ -1,3 +1,5 @@
+import type { DnsRecordType } from "./dns"
+
 /** 凭证字段定义 */
 export interface ProviderCredentialField {
   key: string
@@ -5,12 +7,36 @@ export interface ProviderCredentialField {
   type: "text" | "password"
   placeholder?: string
   helpText?: string
+  /** 是否可留空 */
+  optional?: boolean
 }
 
 /** 提供商支持的功能 */
+/** TTL 取值范围 */
+export interface TtlRange {
+  min: number
+  max: number
+  /** 表示"自动"的特殊值 (如 Cloudflare 的 1) */
+  auto?: number
+}
+
 export interface ProviderFeatures {
   /** 是否支持代理功能 (如 Cloudflare 的 CDN 代理) */
   proxy: boolean
+  /** 允许的 TTL 范围 */
+  ttlRange: TtlRange
+  /** 支持的记录类型 */
+  supportedRecordTypes: DnsRecordType[]
+  /** 是否允许在根域（@）创建 CNAME（如 Cloudflare 的 CNAME 展平） */
+  supportsApexCname: boolean
+  /** 官方文档中的 API 限流，未公开时为空 */
+  rateLimit?: RateLimit
+}
+
+/** API 限流：每个时间窗口内允许的请求数 */
+export interface RateLimit {
+  requests: number
+  windowSecs: number
 }
 
 /** 提供商信息 (从后端获取) */
@@ -21,3 +47,12 @@ export interface ProviderInfo {
   requiredFields: ProviderCredentialField[]
   features: ProviderFeatures
 }
+
+/** Provider HTTP 客户端配置（代理、超时等） */
+export interface ProviderClientConfig {
+  /** 代理地址，支持 http://、https://、socks5:// */
+  proxy?: string
+  /** 请求超时（秒） */
+  timeoutSecs?: number
+  userAgent?: string
+}
@@ -5,8 +5,44 @@ export interface WhoisResult {
   creationDate?: string
   expirationDate?: string
   updatedDate?: string
+  /** 规范化为 RFC3339 的注册时间，无法识别原始格式时为空 */
+  creationDateParsed?: string
+  /** 规范化为 RFC3339 的到期时间，无法识别原始格式时为空 */
+  expirationDateParsed?: string
+  /** 注册人组织（被脱敏时为空） */
+  registrantOrg?: string
+  /** 注册人国家/地区代码 */
+  registrantCountry?: string
+  /** 注册商滥用投诉邮箱 */
+  abuseEmail?: string
   nameServers: string[]
   status: string[]
+  /** 是否启用了隐私保护或被脱敏 */
+  privacyProtected: boolean
+  /** 被隐私保护或脱敏的字段名 */
+  redactedFields: string[]
+  raw: string
+}
+
+/** RDAP 实体 */
+export interface RdapEntity {
+  handle?: string
+  roles: string[]
+  name?: string
+}
+
+/** RDAP 查询结果 */
+export interface RdapResult {
+  domain: string
+  /** 数据来源，TLD 不支持 RDAP 时回退到 WHOIS */
+  source: "rdap" | "whois"
+  registrar?: string
+  creationDate?: string
+  expirationDate?: string
+  updatedDate?: string
+  status: string[]
+  nameServers: string[]
+  entities: RdapEntity[]
   raw: string
 }
 
@@ -19,12 +55,146 @@ export interface DnsLookupRecord {
   priority?: number
 }
 
+/** DNSSEC 验证状态 */
+export type DnssecValidation = "secure" | "insecure" | "bogus"
+
 /** DNS 查询结果（包含 nameserver 信息） */
 export interface DnsLookupResult {
   /** 使用的 DNS 服务器 */
   nameserver: string
   /** 查询记录列表 */
   records: DnsLookupRecord[]
+  /** DNSSEC 验证状态 */
+  dnssec: DnssecValidation
+  /** 各记录类型的查询状态（用于区分"没有记录"和"查询失败"） */
+  typeStatuses: DnsLookupTypeStatus[]
+  /** trace 模式下从根服务器开始的逐级查询过程 */
+  trace?: DnsTrace | null
+}
+
+/** trace 模式的查询过程 */
+export interface DnsTrace {
+  hops: DnsTraceHop[]
+  /** 是否得到了权威应答（包括 NXDOMAIN 和没有记录） */
+  completed: boolean
+  /** 解析中断的原因，如 lame delegation */
+  error?: string | null
+}
+
+/** trace 模式中的一级委派 */
+export interface DnsTraceHop {
+  /** 当前查询的 zone，根区为 "." */
+  zone: string
+  /** 该 zone 的 nameserver 列表 */
+  nameservers: string[]
+  /** 实际给出响应的 nameserver */
+  server?: string | null
+  serverIp?: string | null
+  responseCode?: string | null
+  /** 是否为权威应答 */
+  authoritative: boolean
+  /** 应答记录 */
+  records: DnsLookupRecord[]
+  /** 委派到下一级的 nameserver */
+  referral: string[]
+  /** 查询耗时（毫秒） */
+  elapsedMs: number
+  /** 查询失败的 nameserver 及原因 */
+  error?: string | null
+}
+
+/** 区域传送（AXFR）结果 */
+export interface AxfrResult {
+  zone: string
+  /** 实际连接的 nameserver 地址 */
+  nameserver: string
+  /** zone 中的全部记录（以 SOA 开头） */
+  records: DnsLookupRecord[]
+  /** 传送耗时（毫秒） */
+  elapsedMs: number
+}
+
+/** 单个记录类型的查询状态 */
+export interface DnsLookupTypeStatus {
+  recordType: string
+  /** 查询失败时的错误信息，为空表示查询成功（可能没有记录） */
+  error?: string | null
+}
+
+/** SPF 检查结果 */
+export interface SpfCheck {
+  /** 域名根上的 SPF 记录（存在多条时为第一条） */
+  record?: string | null
+  /** `v=spf1` 之后的机制和修饰符 */
+  mechanisms: string[]
+  /** all 机制，如 "-all"、"~all" */
+  allQualifier?: string | null
+  /** 是否以 -all 结尾 */
+  hardFail: boolean
+  /** 需要额外 DNS 查询的机制数量（限制为 10） */
+  dnsLookups: number
+  issues: string[]
+}
+
+/** DMARC 检查结果 */
+export interface DmarcCheck {
+  record?: string | null
+  /** 策略 (p=) */
+  policy?: string | null
+  /** 子域名策略 (sp=) */
+  subdomainPolicy?: string | null
+  pct?: number | null
+  /** 聚合报告地址 (rua=) */
+  rua: string[]
+  issues: string[]
+}
+
+/** DKIM 检查结果 */
+export interface DkimCheck {
+  selector: string
+  record?: string | null
+  /** 密钥类型 (k=)，默认为 rsa */
+  keyType?: string | null
+  /** 是否存在非空公钥 */
+  keyPresent: boolean
+  /** 公钥能否正常解析 */
+  keyValid: boolean
+  keyBits?: number | null
+  issues: string[]
+}
+
+/** 邮件认证（SPF/DKIM/DMARC）检查结果 */
+export interface EmailAuthResult {
+  domain: string
+  spf: SpfCheck
+  dmarc: DmarcCheck
+  /** 未指定 selector 时为空 */
+  dkim?: DkimCheck | null
+}
+
+/** 单个公共 DNS 的传播检查结果 */
+export interface PropagationResolverResult {
+  /** DNS 服务商名称 */
+  name: string
+  ip: string
+  /** 返回的记录值 */
+  values: string[]
+  ttl?: number | null
+  /** 是否包含期望值，未指定期望值或查询失败时为空 */
+  matched?: boolean | null
+  error?: string | null
+  /** 查询耗时（毫秒） */
+  elapsedMs: number
+}
+
+/** DNS 传播检查结果 */
+export interface PropagationResult {
+  domain: string
+  recordType: string
+  expectedValue?: string | null
+  resolvers: PropagationResolverResult[]
+  /** 结果包含期望值的服务器数量 */
+  matchedCount: number
 }
 
 /** IP 地理位置信息 */
@@ -53,6 +223,44 @@ export interface IpLookupResult {
   isDomain: boolean
   /** IP 地理位置结果列表 */
   results: IpGeoInfo[]
+  /** 查询失败的 IP */
+  failures: IpLookupFailure[]
+}
+
+/** 查询失败的 IP */
+export interface IpLookupFailure {
+  ip: string
+  error: string
+}
+
+/** IP 地理位置查询服务 */
+export type IpGeoProvider = "ipwhois" | "ipapi" | "ipinfo"
+
+/** IP 地理位置查询设置 */
+export interface IpGeoConfig {
+  /** 首选查询服务 */
+  provider: IpGeoProvider
+  /** 首选服务达到限额时使用的备用服务，为空时不切换 */
+  fallback?: IpGeoProvider | null
+  /** ipinfo.io 的访问 Token（只写，读取设置时不返回）；未传入时保持不变，空字符串表示清除 */
+  ipinfoToken?: string | null
+  /** 是否已配置 ipinfo.io Token */
+  ipinfoTokenConfigured?: boolean
+}
+
+/** IP WHOIS 查询结果（RIR 分配信息） */
+export interface IpWhoisResult {
+  ip: string
+  rir: string
+  whoisServer: string
+  netRange?: string
+  cidr?: string
+  netName?: string
+  org?: string
+  country?: string
+  /** 滥用举报邮箱 */
+  abuseEmail?: string
+  raw: string
 }
 
 /** SSL 证书信息 */
@@ -65,10 +273,14 @@ export interface SslCertInfo {
   daysRemaining: number
   isExpired: boolean
   isValid: boolean
+  /** 证书验证失败的原因（过期、域名不匹配、根证书不受信任等） */
+  validationErrors: string[]
   san: string[]
   serialNumber: string
   signatureAlgorithm: string
   certificateChain: CertChainItem[]
+  /** OCSP 吊销状态（响应签名已校验），无法查询或校验失败时为空 */
+  ocspStatus?: "good" | "revoked" | "unknown"
 }
 
 /** 证书链项 */
@@ -90,6 +302,92 @@ export interface SslCheckResult {
   certInfo?: SslCertInfo
   /** 错误信息（连接失败时） */
   error?: string
+  /** 协商的 TLS 版本（如 "TLS 1.3"） */
+  tlsVersion?: string
+  /** 协商的密码套件 */
+  cipherSuite?: string
+}
+
+/** 批量证书检查中单个域名的有效期 */
+export interface CertExpiryItem {
+  domain: string
+  port: number
+  /** 证书剩余有效天数，检查失败时为空 */
+  daysRemaining?: number | null
+  validTo?: string | null
+  /** 证书是否通过验证 */
+  isValid?: boolean | null
+  /** 检查失败的原因或首个证书验证错误 */
+  error?: string | null
+}
+
+/** 批量域名到期检查中单个域名的结果 */
+export interface DomainExpiryItem {
+  domain: string
+  registrar?: string | null
+  /** 注册到期时间（RFC3339），查询失败时为空 */
+  expirationDate?: string | null
+  /** 剩余天数，已过期时为负数 */
+  daysRemaining?: number | null
+  /** 是否在提醒阈值内到期（含已过期） */
+  expiringSoon: boolean
+  /** 查询失败的原因 */
+  error?: string | null
+}
+
+/** HTTP 重定向链中的一跳 */
+export interface HttpRedirectHop {
+  url: string
+  status: number
+  /** 重定向目标（绝对地址） */
+  location: string
+}
+
+/** HTTP 响应头 */
+export interface HttpHeader {
+  name: string
+  value: string
+}
+
+/** HTTP 检查结果 */
+export interface HttpInspectResult {
+  /** 请求的 URL（补全协议后） */
+  url: string
+  /** 跟随重定向后的最终 URL */
+  finalUrl: string
+  /** 最终响应的状态码 */
+  status: number
+  redirects: HttpRedirectHop[]
+  /** 最终响应的 Server 头 */
+  server?: string | null
+  /** Strict-Transport-Security 头，为空表示未启用 HSTS */
+  hsts?: string | null
+  /** 最终响应的全部响应头 */
+  headers: HttpHeader[]
+  /** 总耗时（毫秒） */
+  elapsedMs: number
+}
+
+/** TCP 端口状态：开放 / 被拒绝 / 超时或不可达 */
+export type PortStatus = "open" | "closed" | "filtered"
+
+/** 单个地址的端口检查结果 */
+export interface PortCheckAddress {
+  address: string
+  status: PortStatus
+  /** 建立连接的耗时（毫秒，仅端口开放时存在） */
+  latencyMs?: number | null
+  error?: string | null
+}
+
+/** TCP 端口检查结果 */
+export interface PortCheckResult {
+  host: string
+  port: number
+  /** 汇总状态：任一地址开放即为 open */
+  status: PortStatus
+  /** 主机名解析出的每个地址的检查结果 */
+  addresses: PortCheckAddress[]
 }
 
 /** 查询历史项 */